@@ -5,60 +5,125 @@
 //! - Request/response handling
 //! - Connection retry logic
 //! - Timeout handling
+//! - Optional MessagePack encoding for high-frequency subscribers
+//! - Sessions that send several requests over one connection
+//! - Event subscriptions read one event at a time
 
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::UnixStream;
 use tokio::time::timeout;
 
 use crate::cli::commands::StartArgs;
-use crate::types::{IpcRequest, IpcResponse, StartParams};
+use crate::daemon::ipc::{read_frame, write_frame, Encoding, IpcError};
+use crate::daemon::TimestampedEvent;
+use crate::paths;
+use crate::types::{IpcRequest, IpcResponse, PomodoroConfig, TimerPhase, PROTOCOL_VERSION};
 
 // ============================================================================
 // Constants
 // ============================================================================
 
-/// Default socket path
-const DEFAULT_SOCKET_PATH: &str = ".pomodoro/pomodoro.sock";
-
-/// Connection timeout in seconds
+/// Default connection timeout in seconds
 const CONNECTION_TIMEOUT_SECS: u64 = 5;
 
-/// Read/write timeout in seconds
+/// Default read/write timeout in seconds
 const IO_TIMEOUT_SECS: u64 = 5;
 
 /// Maximum response size in bytes (64KB)
 const MAX_RESPONSE_SIZE: usize = 65536;
 
-/// Maximum retry attempts
-const MAX_RETRIES: u32 = 3;
+/// Default number of retries after the first failed attempt
+const MAX_RETRIES: u32 = 2;
 
 /// Retry delay in milliseconds (base delay, multiplied by attempt number)
 const RETRY_DELAY_MS: u64 = 500;
 
+/// Timeout in milliseconds for single-attempt status queries (`status --short`)
+const QUICK_TIMEOUT_MS: u64 = 300;
+
+/// Timeout in milliseconds for the connect of [`IpcClient::is_daemon_running`]
+const PROBE_TIMEOUT_MS: u64 = 100;
+
+// ============================================================================
+// Probe
+// ============================================================================
+
+/// Result of probing the daemon socket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Probe {
+    /// The daemon accepted the connection
+    Running,
+    /// There is no socket file (the daemon was never started)
+    SocketMissing,
+    /// The socket file is left over from a daemon that is gone
+    Refused,
+    /// The connect failed otherwise or timed out
+    Unreachable,
+}
+
+// ============================================================================
+// ProtocolVersionMismatch
+// ============================================================================
+
+/// Error for a response from a daemon that speaks an older protocol.
+///
+/// Such a daemon may have misread the request, so its response is not used
+/// and the request is not sent again.
+#[derive(Debug, thiserror::Error)]
+#[error("Daemonのプロトコルバージョン ({daemon}) がこのクライアント ({client}) より古いため通信できません。Daemonを再起動してください")]
+pub struct ProtocolVersionMismatch {
+    /// Protocol version of the daemon
+    pub daemon: u32,
+    /// Protocol version of this client
+    pub client: u32,
+}
+
+// ============================================================================
+// StartOutcome
+// ============================================================================
+
+/// What [`IpcClient::start_or_resume`] ended up doing.
+#[derive(Debug, Clone)]
+pub enum StartOutcome {
+    /// A new session was started
+    Started(IpcResponse),
+    /// The paused session was resumed
+    Resumed(IpcResponse),
+}
+
 // ============================================================================
 // IpcClient
 // ============================================================================
 
 /// IPC client for daemon communication.
+#[derive(Debug, Clone)]
 pub struct IpcClient {
     /// Socket path
     socket_path: PathBuf,
     /// Connection timeout
     timeout: Duration,
+    /// Read/write timeout
+    io_timeout: Duration,
+    /// Retries after the first failed attempt
+    max_retries: u32,
+    /// Encoding of requests (and therefore of the daemon's replies)
+    encoding: Encoding,
+    /// Whether a newer daemon protocol has already been reported (shared by clones)
+    version_warned: Arc<AtomicBool>,
 }
 
 impl IpcClient {
     /// Creates a new IPC client with default socket path.
+    ///
+    /// The path is resolved by [`paths::socket_path`], so `POMODORO_SOCKET`
+    /// and `POMODORO_HOME` are honored.
     pub fn new() -> Result<Self> {
-        let socket_path = Self::default_socket_path()?;
-        Ok(Self {
-            socket_path,
-            timeout: Duration::from_secs(CONNECTION_TIMEOUT_SECS),
-        })
+        Ok(Self::with_socket_path(paths::socket_path()?))
     }
 
     /// Creates a new IPC client with a custom socket path.
@@ -66,13 +131,37 @@ impl IpcClient {
         Self {
             socket_path,
             timeout: Duration::from_secs(CONNECTION_TIMEOUT_SECS),
+            io_timeout: Duration::from_secs(IO_TIMEOUT_SECS),
+            max_retries: MAX_RETRIES,
+            encoding: Encoding::default(),
+            version_warned: Arc::new(AtomicBool::new(false)),
         }
     }
 
-    /// Returns the default socket path.
-    fn default_socket_path() -> Result<PathBuf> {
-        let home = std::env::var("HOME").context("HOME環境変数が設定されていません")?;
-        Ok(PathBuf::from(home).join(DEFAULT_SOCKET_PATH))
+    /// Sets the timeout for connecting and for each read or write.
+    ///
+    /// Defaults to 5 seconds.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self.io_timeout = timeout;
+        self
+    }
+
+    /// Sets how many times a failed request is retried.
+    ///
+    /// Defaults to 2; `0` makes exactly one attempt.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the wire encoding.
+    ///
+    /// [`Encoding::MessagePack`] avoids JSON parsing for clients that stream
+    /// events at high frequency; JSON stays the default.
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
     }
 
     /// Returns the socket path.
@@ -81,125 +170,406 @@ impl IpcClient {
     }
 
     /// Sends a start command to the daemon.
+    ///
+    /// Only the settings given on the command line are sent (see
+    /// [`StartArgs::start_params`]); the daemon's configuration supplies the rest.
     pub async fn start(&self, args: &StartArgs) -> Result<IpcResponse> {
-        let params = StartParams {
-            work_minutes: Some(args.work),
-            break_minutes: Some(args.break_time),
-            long_break_minutes: Some(args.long_break),
-            task_name: args.task.clone(),
-            auto_cycle: Some(args.auto_cycle),
-            focus_mode: Some(args.focus_mode),
+        let request = IpcRequest::Start {
+            params: args.start_params(),
         };
+        self.send(&request).await
+    }
+
+    /// Resumes the timer if it is paused, otherwise starts a new session.
+    ///
+    /// This is best-effort rather than atomic: the status check and the
+    /// follow-up command are separate requests. If the timer is no longer
+    /// paused by the time `resume` arrives, the daemon rejects it and a new
+    /// session is started instead, so a paused session is never silently
+    /// discarded. Without a running daemon there is nothing to resume, so
+    /// the status check is skipped.
+    pub async fn start_or_resume(&self, args: &StartArgs) -> Result<StartOutcome> {
+        let paused = self.is_daemon_running().await
+            && self.status().await?.phase() == Some(TimerPhase::Paused);
+
+        if paused {
+            // Sent once without retries: an error response means the timer
+            // left the paused state since the status check
+            let mut stream = self.connect().await?;
+            let response = self.exchange(&mut stream, &IpcRequest::Resume).await?;
+            if response.status != "error" {
+                return Ok(StartOutcome::Resumed(response));
+            }
+            tracing::debug!("再開に失敗したため新しく開始します: {}", response.message);
+        }
 
-        let request = IpcRequest::Start { params };
-        self.send_request_with_retry(&request).await
+        Ok(StartOutcome::Started(self.start(args).await?))
     }
 
     /// Sends a pause command to the daemon.
     pub async fn pause(&self) -> Result<IpcResponse> {
-        self.send_request_with_retry(&IpcRequest::Pause).await
+        self.pause_with_reason(None).await
+    }
+
+    /// Sends a pause command with the reason for pausing (`pause --reason`).
+    pub async fn pause_with_reason(&self, reason: Option<String>) -> Result<IpcResponse> {
+        self.send(&IpcRequest::Pause { reason }).await
     }
 
     /// Sends a resume command to the daemon.
     pub async fn resume(&self) -> Result<IpcResponse> {
-        self.send_request_with_retry(&IpcRequest::Resume).await
+        self.send(&IpcRequest::Resume).await
     }
 
     /// Sends a stop command to the daemon.
     pub async fn stop(&self) -> Result<IpcResponse> {
-        self.send_request_with_retry(&IpcRequest::Stop).await
+        self.send(&IpcRequest::Stop).await
     }
 
     /// Sends a status query to the daemon.
     pub async fn status(&self) -> Result<IpcResponse> {
-        self.send_request_with_retry(&IpcRequest::Status).await
+        self.send(&IpcRequest::Status).await
+    }
+
+    /// Queries the status with a single short-timeout attempt.
+    ///
+    /// Used by `status --short`, where a missing daemon must not delay the
+    /// shell prompt with retries.
+    pub async fn status_quick(&self) -> Result<IpcResponse> {
+        self.clone()
+            .with_timeout(Duration::from_millis(QUICK_TIMEOUT_MS))
+            .with_max_retries(0)
+            .status()
+            .await
+    }
+
+    /// Returns whether a daemon is listening on the socket.
+    ///
+    /// Only connects (with a short timeout and no retries) and closes the
+    /// connection again; no request is sent, so the daemon's state is left
+    /// untouched.
+    pub async fn is_daemon_running(&self) -> bool {
+        let probe = self.probe().await;
+        tracing::debug!(?probe, "Daemonの起動確認");
+        probe == Probe::Running
+    }
+
+    /// Connects once to find out whether the daemon is there.
+    async fn probe(&self) -> Probe {
+        if !self.socket_path.exists() {
+            return Probe::SocketMissing;
+        }
+        let connect = UnixStream::connect(&self.socket_path);
+        match timeout(Duration::from_millis(PROBE_TIMEOUT_MS), connect).await {
+            Ok(Ok(_)) => Probe::Running,
+            Ok(Err(e)) if e.kind() == std::io::ErrorKind::ConnectionRefused => Probe::Refused,
+            Ok(Err(e)) if e.kind() == std::io::ErrorKind::NotFound => Probe::SocketMissing,
+            Ok(Err(_)) | Err(_) => Probe::Unreachable,
+        }
+    }
+
+    /// Sends a skip command to the daemon.
+    pub async fn skip(&self) -> Result<IpcResponse> {
+        self.send(&IpcRequest::Skip).await
     }
 
-    /// Sends a request to the daemon with retry logic.
-    async fn send_request_with_retry(&self, request: &IpcRequest) -> Result<IpcResponse> {
-        let mut last_error = None;
+    /// Sends a suspend command to the daemon.
+    pub async fn suspend(&self) -> Result<IpcResponse> {
+        self.send(&IpcRequest::Suspend).await
+    }
+
+    /// Sends an unsuspend command to the daemon.
+    pub async fn unsuspend(&self) -> Result<IpcResponse> {
+        self.send(&IpcRequest::Unsuspend).await
+    }
+
+    /// Sends a reset command to the daemon.
+    pub async fn reset(&self) -> Result<IpcResponse> {
+        self.send(&IpcRequest::Reset).await
+    }
 
-        for attempt in 1..=MAX_RETRIES {
+    /// Fetches the daemon's timer configuration.
+    pub async fn get_config(&self) -> Result<IpcResponse> {
+        self.send(&IpcRequest::GetConfig).await
+    }
+
+    /// Replaces the daemon's timer configuration (applied from the next phase).
+    pub async fn set_config(&self, config: PomodoroConfig) -> Result<IpcResponse> {
+        self.send(&IpcRequest::SetConfig { config }).await
+    }
+
+    /// Starts a standalone long break (the configured long break length if
+    /// `minutes` is `None`).
+    pub async fn start_long_break(&self, minutes: Option<u32>) -> Result<IpcResponse> {
+        self.send(&IpcRequest::LongBreak { minutes }).await
+    }
+
+    /// Starts a standalone break (the configured length if `minutes` is `None`),
+    /// repeated `repeat` times back to back.
+    pub async fn start_break(
+        &self,
+        minutes: Option<u32>,
+        repeat: Option<u32>,
+    ) -> Result<IpcResponse> {
+        self.send(&IpcRequest::Break { minutes, repeat }).await
+    }
+
+    /// Sends a snooze command to the daemon.
+    pub async fn snooze(&self, minutes: u32) -> Result<IpcResponse> {
+        self.send(&IpcRequest::Snooze { minutes }).await
+    }
+
+    /// Subscribes to timer events and calls `on_event` for each one.
+    ///
+    /// Returns when the daemon closes the connection.
+    pub async fn watch_events<F>(&self, mut on_event: F) -> Result<()>
+    where
+        F: FnMut(TimestampedEvent),
+    {
+        let mut subscription = self.subscribe().await?;
+        while let Some(event) = subscription.next_event().await? {
+            on_event(event);
+        }
+        Ok(())
+    }
+
+    /// Subscribes to timer events, to be read with
+    /// [`EventSubscription::next_event`].
+    ///
+    /// Events are delivered from the moment this returns, so requests sent
+    /// afterwards have all their events in the subscription.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the daemon cannot be reached or refuses the
+    /// subscription.
+    pub async fn subscribe(&self) -> Result<EventSubscription> {
+        let mut stream = self.connect().await?;
+        let response = self.exchange(&mut stream, &IpcRequest::Subscribe).await?;
+        if response.status == "error" {
+            anyhow::bail!("{}", response.message);
+        }
+        Ok(EventSubscription { stream })
+    }
+
+    /// Opens a connection for sending several requests in a row.
+    ///
+    /// See [`IpcSession`]. Failed requests are not retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the daemon cannot be reached.
+    pub async fn session(&self) -> Result<IpcSession> {
+        let stream = self.connect().await?;
+        Ok(IpcSession {
+            client: self.clone(),
+            stream: Some(stream),
+        })
+    }
+
+    /// Sends any request to the daemon, retrying failed attempts.
+    ///
+    /// The typed methods (`pause`, `status`, ...) are thin wrappers around
+    /// this. Use it directly to send requests without a dedicated method.
+    /// `Subscribe` needs a streaming connection; use [`Self::watch_events`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the daemon cannot be reached after all retries,
+    /// if it answers with an error response, or right away with a
+    /// [`ProtocolVersionMismatch`] if it is older than this client.
+    pub async fn send(&self, request: &IpcRequest) -> Result<IpcResponse> {
+        let attempts = self.max_retries + 1;
+        let mut attempt = 1;
+
+        loop {
             match self.send_request(request).await {
                 Ok(response) => return Ok(response),
-                Err(e) => {
-                    tracing::warn!("リクエスト失敗 (試行 {}/{}): {}", attempt, MAX_RETRIES, e);
-                    last_error = Some(e);
-
-                    if attempt < MAX_RETRIES {
-                        let delay = Duration::from_millis(RETRY_DELAY_MS * u64::from(attempt));
-                        tokio::time::sleep(delay).await;
-                    }
+                Err(e) if attempt < attempts && !e.is::<ProtocolVersionMismatch>() => {
+                    tracing::warn!("リクエスト失敗 (試行 {}/{}): {}", attempt, attempts, e);
+                    let delay = Duration::from_millis(RETRY_DELAY_MS * u64::from(attempt));
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
                 }
+                Err(e) => return Err(e),
             }
         }
-
-        Err(last_error.unwrap())
     }
 
     /// Sends a single request to the daemon.
     async fn send_request(&self, request: &IpcRequest) -> Result<IpcResponse> {
-        // Connect with timeout
-        let mut stream = timeout(self.timeout, UnixStream::connect(&self.socket_path))
+        let mut stream = self.connect().await?;
+        let response = self.exchange(&mut stream, request).await?;
+
+        // Check for error response
+        if response.status == "error" {
+            anyhow::bail!("{}", response.message);
+        }
+
+        Ok(response)
+    }
+
+    /// Connects to the daemon socket with timeout.
+    async fn connect(&self) -> Result<UnixStream> {
+        timeout(self.timeout, UnixStream::connect(&self.socket_path))
             .await
             .context("接続がタイムアウトしました")?
-            .context("Daemonに接続できません。'pomodoro daemon' を起動してください")?;
+            .context("Daemonに接続できません。'pomodoro daemon' を起動してください")
+    }
 
+    /// Sends a request and reads the response on an open connection.
+    async fn exchange(&self, stream: &mut UnixStream, request: &IpcRequest) -> Result<IpcResponse> {
         // Serialize request
-        let request_json =
-            serde_json::to_string(request).context("リクエストのシリアライズに失敗しました")?;
-
-        // Send request with timeout
-        timeout(
-            Duration::from_secs(IO_TIMEOUT_SECS),
-            stream.write_all(request_json.as_bytes()),
-        )
-        .await
-        .context("書き込みがタイムアウトしました")?
-        .context("リクエストの送信に失敗しました")?;
-
-        // Flush
-        timeout(Duration::from_secs(IO_TIMEOUT_SECS), stream.flush())
+        let payload = self
+            .encoding
+            .encode(request)
+            .context("リクエストのシリアライズに失敗しました")?;
+
+        // Send request frame with timeout
+        timeout(self.io_timeout, write_frame(stream, &payload))
             .await
-            .context("フラッシュがタイムアウトしました")?
-            .context("フラッシュに失敗しました")?;
+            .context("書き込みがタイムアウトしました")?
+            .context("リクエストの送信に失敗しました")?;
 
-        // Shutdown write side to signal end of request
-        stream
-            .shutdown()
+        // Read response frame with timeout
+        let payload = timeout(self.io_timeout, read_frame(stream, MAX_RESPONSE_SIZE))
             .await
-            .context("シャットダウンに失敗しました")?;
+            .context("読み込みがタイムアウトしました")?
+            .context("Daemonからの応答がありませんでした")?;
 
-        // Read response with timeout
-        let mut buffer = vec![0u8; MAX_RESPONSE_SIZE];
-        let n = timeout(
-            Duration::from_secs(IO_TIMEOUT_SECS),
-            stream.read(&mut buffer),
-        )
-        .await
-        .context("読み込みがタイムアウトしました")?
-        .context("レスポンスの受信に失敗しました")?;
+        // Deserialize response
+        let (response, _) =
+            Encoding::decode(&payload).context("レスポンスのパースに失敗しました")?;
+        self.check_protocol_version(&response)?;
+        Ok(response)
+    }
 
-        if n == 0 {
-            anyhow::bail!("Daemonからの応答がありませんでした");
+    /// Checks the protocol version of a response from the daemon.
+    ///
+    /// A newer daemon is warned about once: its responses still decode
+    /// (unknown fields are ignored), so at worst some output is incomplete.
+    /// Returns whether the warning was emitted by this call.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ProtocolVersionMismatch`] if the daemon is older, since it
+    /// may have misread the request.
+    fn check_protocol_version(&self, response: &IpcResponse) -> Result<bool> {
+        if response.protocol_version < PROTOCOL_VERSION {
+            return Err(ProtocolVersionMismatch {
+                daemon: response.protocol_version,
+                client: PROTOCOL_VERSION,
+            }
+            .into());
+        }
+        if response.protocol_version == PROTOCOL_VERSION
+            || self.version_warned.swap(true, Ordering::Relaxed)
+        {
+            return Ok(false);
         }
+        tracing::warn!(
+            "Daemonのプロトコルバージョン ({}) がこのクライアント ({}) より新しいため、一部の情報が表示されない可能性があります。pomodoro を更新してください",
+            response.protocol_version,
+            PROTOCOL_VERSION
+        );
+        Ok(true)
+    }
+}
 
-        // Deserialize response
-        let response: IpcResponse =
-            serde_json::from_slice(&buffer[..n]).context("レスポンスのパースに失敗しました")?;
+impl Default for IpcClient {
+    fn default() -> Self {
+        Self::new().expect("Failed to create IPC client")
+    }
+}
+
+// ============================================================================
+// IpcSession
+// ============================================================================
+
+/// A connection to the daemon kept open across requests.
+///
+/// Tools that send several commands in a row save a reconnect per command.
+/// Daemons from before sessions close the connection after one reply; the
+/// session then reconnects for the next request, so it works with them too,
+/// just without the saving. The daemon also closes a session left idle for
+/// a few seconds, which is handled the same way.
+#[derive(Debug)]
+pub struct IpcSession {
+    client: IpcClient,
+    /// Open connection (`None` once it has failed)
+    stream: Option<UnixStream>,
+}
+
+impl IpcSession {
+    /// Sends a request over the session's connection.
+    ///
+    /// If the daemon has closed the connection, one new connection is made
+    /// and the request is sent again on it. `Subscribe` takes over the
+    /// connection; use [`IpcClient::subscribe`] instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the daemon cannot be reached, or if it answers
+    /// with an error response.
+    pub async fn send(&mut self, request: &IpcRequest) -> Result<IpcResponse> {
+        let reused = match self.stream.as_mut() {
+            Some(stream) => match self.client.exchange(stream, request).await {
+                Ok(response) => Some(response),
+                // The daemon did answer; a new connection would not help
+                Err(e) if e.is::<ProtocolVersionMismatch>() => return Err(e),
+                Err(_) => None,
+            },
+            None => None,
+        };
+        let response = match reused {
+            Some(response) => response,
+            None => {
+                tracing::debug!("接続が閉じられていたため再接続します");
+                self.stream = None;
+                let mut stream = self.client.connect().await?;
+                let response = self.client.exchange(&mut stream, request).await?;
+                self.stream = Some(stream);
+                response
+            }
+        };
 
-        // Check for error response
         if response.status == "error" {
             anyhow::bail!("{}", response.message);
         }
-
         Ok(response)
     }
 }
 
-impl Default for IpcClient {
-    fn default() -> Self {
-        Self::new().expect("Failed to create IPC client")
+// ============================================================================
+// EventSubscription
+// ============================================================================
+
+/// A connection the daemon streams timer events on (see
+/// [`IpcClient::subscribe`]).
+#[derive(Debug)]
+pub struct EventSubscription {
+    stream: UnixStream,
+}
+
+impl EventSubscription {
+    /// Waits for the next event.
+    ///
+    /// Returns `None` once the daemon closes the connection. Not
+    /// cancel-safe: a call dropped halfway through an event leaves the
+    /// subscription unusable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an event cannot be received or parsed.
+    pub async fn next_event(&mut self) -> Result<Option<TimestampedEvent>> {
+        let payload = match read_frame(&mut self.stream, MAX_RESPONSE_SIZE).await {
+            Ok(payload) => payload,
+            Err(IpcError::ConnectionError(_)) => return Ok(None),
+            Err(e) => return Err(e).context("イベントの受信に失敗しました"),
+        };
+        let (event, _): (TimestampedEvent, _) =
+            Encoding::decode(&payload).context("イベントのパースに失敗しました")?;
+        Ok(Some(event))
     }
 }
 
@@ -262,6 +632,25 @@ mod tests {
             assert!(result.is_err());
         }
 
+        #[tokio::test]
+        async fn test_is_daemon_running() {
+            let socket_path = create_temp_socket_path();
+            let client = IpcClient::with_socket_path(socket_path.clone());
+
+            assert_eq!(client.probe().await, Probe::SocketMissing);
+            assert!(!client.is_daemon_running().await);
+
+            let listener = create_mock_server(&socket_path).await;
+            assert_eq!(client.probe().await, Probe::Running);
+            assert!(client.is_daemon_running().await);
+
+            // The socket file outlives the listener, like after a crash
+            drop(listener);
+            assert!(socket_path.exists());
+            assert_eq!(client.probe().await, Probe::Refused);
+            assert!(!client.is_daemon_running().await);
+        }
+
         #[tokio::test]
         async fn test_send_status_request() {
             let socket_path = create_temp_socket_path();
@@ -272,9 +661,8 @@ mod tests {
                 let (mut stream, _) = listener.accept().await.unwrap();
 
                 // Read request
-                let mut buffer = vec![0u8; 4096];
-                let n = stream.read(&mut buffer).await.unwrap();
-                let request: IpcRequest = serde_json::from_slice(&buffer[..n]).unwrap();
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
 
                 // Verify it's a status request
                 assert!(matches!(request, IpcRequest::Status));
@@ -287,11 +675,11 @@ mod tests {
                         remaining_seconds: Some(0),
                         pomodoro_count: Some(0),
                         task_name: None,
+                        ..Default::default()
                     }),
                 );
                 let json = serde_json::to_vec(&response).unwrap();
-                stream.write_all(&json).await.unwrap();
-                stream.flush().await.unwrap();
+                write_frame(&mut stream, &json).await.unwrap();
             });
 
             // Create client and send request
@@ -302,7 +690,7 @@ mod tests {
             assert!(response.data.is_some());
 
             let data = response.data.unwrap();
-            assert_eq!(data.state, Some("stopped".to_string()));
+            assert_eq!(data.phase(), Some(TimerPhase::Stopped));
 
             server_handle.await.unwrap();
         }
@@ -320,9 +708,8 @@ mod tests {
                 let (mut stream, _) = listener.accept().await.unwrap();
 
                 // Read request
-                let mut buffer = vec![0u8; 4096];
-                let n = stream.read(&mut buffer).await.unwrap();
-                let request: IpcRequest = serde_json::from_slice(&buffer[..n]).unwrap();
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
 
                 // Store received request
                 *received_clone.lock().await = Some(request);
@@ -335,23 +722,25 @@ mod tests {
                         remaining_seconds: Some(1500),
                         pomodoro_count: Some(0),
                         task_name: Some("Test Task".to_string()),
+                        ..Default::default()
                     }),
                 );
                 let json = serde_json::to_vec(&response).unwrap();
-                stream.write_all(&json).await.unwrap();
-                stream.flush().await.unwrap();
+                write_frame(&mut stream, &json).await.unwrap();
             });
 
             // Create client and send request
             let client = IpcClient::with_socket_path(socket_path);
             let args = StartArgs {
-                work: 25,
-                break_time: 5,
-                long_break: 15,
+                work: Some(25),
+                break_time: Some(5),
+                long_break: Some(15),
                 task: Some("Test Task".to_string()),
+                tag: Some("project-x".to_string()),
                 auto_cycle: false,
                 focus_mode: false,
                 no_sound: false,
+                ..Default::default()
             };
             let response = client.start(&args).await.unwrap();
 
@@ -359,7 +748,7 @@ mod tests {
             assert_eq!(response.message, "タイマーを開始しました");
 
             let data = response.data.unwrap();
-            assert_eq!(data.state, Some("working".to_string()));
+            assert_eq!(data.phase(), Some(TimerPhase::Working));
             assert_eq!(data.remaining_seconds, Some(1500));
             assert_eq!(data.task_name, Some("Test Task".to_string()));
 
@@ -371,6 +760,11 @@ mod tests {
                     assert_eq!(params.break_minutes, Some(5));
                     assert_eq!(params.long_break_minutes, Some(15));
                     assert_eq!(params.task_name, Some("Test Task".to_string()));
+                    assert_eq!(params.tag.as_deref(), Some("project-x"));
+                    // Settings not given on the command line are left to the daemon
+                    assert_eq!(params.long_break_interval, None);
+                    assert_eq!(params.auto_cycle, None);
+                    assert_eq!(params.strict, None);
                 }
                 _ => panic!("Expected Start request"),
             }
@@ -378,6 +772,125 @@ mod tests {
             server_handle.await.unwrap();
         }
 
+        #[tokio::test]
+        async fn test_send_generic_status_request() {
+            let socket_path = create_temp_socket_path();
+            let listener = create_mock_server(&socket_path).await;
+
+            let server_handle = tokio::spawn(async move {
+                let (mut stream, _) = listener.accept().await.unwrap();
+
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
+                assert!(matches!(request, IpcRequest::Status));
+
+                let response = IpcResponse::success(
+                    "",
+                    Some(ResponseData {
+                        state: Some("stopped".to_string()),
+                        ..Default::default()
+                    }),
+                );
+                let json = serde_json::to_vec(&response).unwrap();
+                write_frame(&mut stream, &json).await.unwrap();
+            });
+
+            let client = IpcClient::with_socket_path(socket_path);
+            let response = client.send(&IpcRequest::Status).await.unwrap();
+
+            assert_eq!(response.status, "success");
+            assert_eq!(response.phase(), Some(TimerPhase::Stopped));
+
+            server_handle.await.unwrap();
+        }
+
+        #[tokio::test]
+        async fn test_newer_protocol_version_warns_once() {
+            let socket_path = create_temp_socket_path();
+            let listener = create_mock_server(&socket_path).await;
+
+            let server_handle = tokio::spawn(async move {
+                for _ in 0..2 {
+                    let (mut stream, _) = listener.accept().await.unwrap();
+                    read_frame(&mut stream, 4096).await.unwrap();
+
+                    let response = IpcResponse {
+                        protocol_version: PROTOCOL_VERSION + 1,
+                        ..IpcResponse::success("", None)
+                    };
+                    let json = serde_json::to_vec(&response).unwrap();
+                    write_frame(&mut stream, &json).await.unwrap();
+                }
+            });
+
+            let client = IpcClient::with_socket_path(socket_path);
+            client.status().await.unwrap();
+            assert!(client.version_warned.load(Ordering::Relaxed));
+
+            // Clones share the flag, so the second response stays silent
+            let response = client.clone().status().await.unwrap();
+            assert!(!client.check_protocol_version(&response).unwrap());
+
+            server_handle.await.unwrap();
+        }
+
+        #[test]
+        fn test_same_protocol_version_does_not_warn() {
+            let client = IpcClient::with_socket_path(PathBuf::from("/tmp/test.sock"));
+
+            assert!(!client
+                .check_protocol_version(&IpcResponse::success("", None))
+                .unwrap());
+            assert!(!client.version_warned.load(Ordering::Relaxed));
+        }
+
+        #[test]
+        fn test_older_protocol_version_is_refused() {
+            let client = IpcClient::with_socket_path(PathBuf::from("/tmp/test.sock"));
+            // Version 0 is a daemon from before versioning
+            for version in [0, PROTOCOL_VERSION - 1] {
+                let response = IpcResponse {
+                    protocol_version: version,
+                    ..IpcResponse::success("", None)
+                };
+
+                let err = client.check_protocol_version(&response).unwrap_err();
+                let mismatch = err.downcast_ref::<ProtocolVersionMismatch>().unwrap();
+                assert_eq!(mismatch.daemon, version);
+                assert_eq!(mismatch.client, PROTOCOL_VERSION);
+            }
+        }
+
+        #[tokio::test]
+        async fn test_older_daemon_is_not_retried() {
+            let socket_path = create_temp_socket_path();
+            let listener = create_mock_server(&socket_path).await;
+            let accepted = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+            let server_accepted = Arc::clone(&accepted);
+            let server_handle = tokio::spawn(async move {
+                loop {
+                    let (mut stream, _) = listener.accept().await.unwrap();
+                    server_accepted.fetch_add(1, Ordering::SeqCst);
+                    read_frame(&mut stream, 4096).await.unwrap();
+
+                    let response = IpcResponse {
+                        protocol_version: PROTOCOL_VERSION - 1,
+                        ..IpcResponse::success("", None)
+                    };
+                    let json = serde_json::to_vec(&response).unwrap();
+                    write_frame(&mut stream, &json).await.unwrap();
+                }
+            });
+
+            let client = IpcClient::with_socket_path(socket_path);
+            let err = client.status().await.unwrap_err();
+
+            assert!(err.to_string().contains("より古い"));
+            assert_eq!(accepted.load(Ordering::SeqCst), 1);
+            server_handle.abort();
+        }
+
         #[tokio::test]
         async fn test_send_pause_request() {
             let socket_path = create_temp_socket_path();
@@ -388,10 +901,9 @@ mod tests {
                 let (mut stream, _) = listener.accept().await.unwrap();
 
                 // Read request
-                let mut buffer = vec![0u8; 4096];
-                let n = stream.read(&mut buffer).await.unwrap();
-                let request: IpcRequest = serde_json::from_slice(&buffer[..n]).unwrap();
-                assert!(matches!(request, IpcRequest::Pause));
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
+                assert!(matches!(request, IpcRequest::Pause { reason: None }));
 
                 // Send response
                 let response = IpcResponse::success(
@@ -401,10 +913,11 @@ mod tests {
                         remaining_seconds: Some(1200),
                         pomodoro_count: Some(0),
                         task_name: None,
+                        ..Default::default()
                     }),
                 );
                 let json = serde_json::to_vec(&response).unwrap();
-                stream.write_all(&json).await.unwrap();
+                write_frame(&mut stream, &json).await.unwrap();
             });
 
             let client = IpcClient::with_socket_path(socket_path);
@@ -426,9 +939,8 @@ mod tests {
                 let (mut stream, _) = listener.accept().await.unwrap();
 
                 // Read request
-                let mut buffer = vec![0u8; 4096];
-                let n = stream.read(&mut buffer).await.unwrap();
-                let request: IpcRequest = serde_json::from_slice(&buffer[..n]).unwrap();
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
                 assert!(matches!(request, IpcRequest::Resume));
 
                 // Send response
@@ -439,10 +951,11 @@ mod tests {
                         remaining_seconds: Some(1200),
                         pomodoro_count: Some(0),
                         task_name: None,
+                        ..Default::default()
                     }),
                 );
                 let json = serde_json::to_vec(&response).unwrap();
-                stream.write_all(&json).await.unwrap();
+                write_frame(&mut stream, &json).await.unwrap();
             });
 
             let client = IpcClient::with_socket_path(socket_path);
@@ -464,9 +977,8 @@ mod tests {
                 let (mut stream, _) = listener.accept().await.unwrap();
 
                 // Read request
-                let mut buffer = vec![0u8; 4096];
-                let n = stream.read(&mut buffer).await.unwrap();
-                let request: IpcRequest = serde_json::from_slice(&buffer[..n]).unwrap();
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
                 assert!(matches!(request, IpcRequest::Stop));
 
                 // Send response
@@ -477,10 +989,11 @@ mod tests {
                         remaining_seconds: Some(0),
                         pomodoro_count: Some(0),
                         task_name: None,
+                        ..Default::default()
                     }),
                 );
                 let json = serde_json::to_vec(&response).unwrap();
-                stream.write_all(&json).await.unwrap();
+                write_frame(&mut stream, &json).await.unwrap();
             });
 
             let client = IpcClient::with_socket_path(socket_path);
@@ -499,17 +1012,16 @@ mod tests {
 
             // Spawn mock server that returns error (handles all retry attempts)
             let server_handle = tokio::spawn(async move {
-                // Handle up to MAX_RETRIES connections
-                for _ in 0..MAX_RETRIES {
+                // Handle every attempt (the first one plus retries)
+                for _ in 0..=MAX_RETRIES {
                     if let Ok((mut stream, _)) = listener.accept().await {
                         // Read request
-                        let mut buffer = vec![0u8; 4096];
-                        let _ = stream.read(&mut buffer).await;
+                        let _ = read_frame(&mut stream, 4096).await;
 
                         // Send error response
                         let response = IpcResponse::error("タイマーは既に実行中です");
                         let json = serde_json::to_vec(&response).unwrap();
-                        let _ = stream.write_all(&json).await;
+                        let _ = write_frame(&mut stream, &json).await;
                     }
                 }
             });
@@ -530,6 +1042,279 @@ mod tests {
         }
     }
 
+    // ------------------------------------------------------------------------
+    // Retry Tests
+    // ------------------------------------------------------------------------
+
+    mod retry_tests {
+        use super::*;
+
+        /// Answers every connection with an error and counts the attempts.
+        fn spawn_failing_daemon(listener: UnixListener) -> Arc<Mutex<u32>> {
+            let attempts = Arc::new(Mutex::new(0));
+            let counter = Arc::clone(&attempts);
+            tokio::spawn(async move {
+                while let Ok((mut stream, _)) = listener.accept().await {
+                    *counter.lock().await += 1;
+                    let _ = read_frame(&mut stream, 4096).await;
+                    let json = serde_json::to_vec(&IpcResponse::error("失敗")).unwrap();
+                    let _ = write_frame(&mut stream, &json).await;
+                }
+            });
+            attempts
+        }
+
+        #[tokio::test]
+        async fn test_zero_retries_makes_one_attempt() {
+            let socket_path = create_temp_socket_path();
+            let attempts = spawn_failing_daemon(create_mock_server(&socket_path).await);
+
+            let client = IpcClient::with_socket_path(socket_path).with_max_retries(0);
+            assert!(client.status().await.is_err());
+
+            assert_eq!(*attempts.lock().await, 1);
+        }
+
+        #[tokio::test]
+        async fn test_retries_are_configurable() {
+            let socket_path = create_temp_socket_path();
+            let attempts = spawn_failing_daemon(create_mock_server(&socket_path).await);
+
+            let client = IpcClient::with_socket_path(socket_path).with_max_retries(1);
+            assert!(client.status().await.is_err());
+
+            assert_eq!(*attempts.lock().await, 2);
+        }
+
+        #[tokio::test]
+        async fn test_timeout_is_configurable() {
+            let socket_path = create_temp_socket_path();
+            let listener = create_mock_server(&socket_path).await;
+            // Accept but never answer
+            let server_handle = tokio::spawn(async move {
+                let (_stream, _) = listener.accept().await.unwrap();
+                std::future::pending::<()>().await;
+            });
+
+            let client = IpcClient::with_socket_path(socket_path)
+                .with_timeout(Duration::from_millis(50))
+                .with_max_retries(0);
+            let started = std::time::Instant::now();
+            let error = client.status().await.unwrap_err();
+
+            assert!(error.to_string().contains("タイムアウト"));
+            assert!(started.elapsed() < Duration::from_secs(1));
+            server_handle.abort();
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Session Tests
+    // ------------------------------------------------------------------------
+
+    mod session_tests {
+        use super::*;
+
+        /// Answers `requests` requests on each connection with `status`
+        /// responses, for `connections` connections.
+        fn spawn_status_server(
+            listener: UnixListener,
+            connections: usize,
+            requests: usize,
+        ) -> tokio::task::JoinHandle<()> {
+            tokio::spawn(async move {
+                for _ in 0..connections {
+                    let (mut stream, _) = listener.accept().await.unwrap();
+                    for _ in 0..requests {
+                        let frame = read_frame(&mut stream, 4096).await.unwrap();
+                        let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
+                        assert!(matches!(request, IpcRequest::Status));
+                        let response = IpcResponse::success("", None);
+                        let json = serde_json::to_vec(&response).unwrap();
+                        write_frame(&mut stream, &json).await.unwrap();
+                    }
+                }
+            })
+        }
+
+        #[tokio::test]
+        async fn test_session_sends_several_requests_on_one_connection() {
+            let socket_path = create_temp_socket_path();
+            let listener = create_mock_server(&socket_path).await;
+            // Accepts a single connection only
+            let server_handle = spawn_status_server(listener, 1, 3);
+
+            let client = IpcClient::with_socket_path(socket_path);
+            let mut session = client.session().await.unwrap();
+            for _ in 0..3 {
+                let response = session.send(&IpcRequest::Status).await.unwrap();
+                assert_eq!(response.status, "success");
+            }
+
+            server_handle.await.unwrap();
+        }
+
+        #[tokio::test]
+        async fn test_session_reconnects_to_one_shot_server() {
+            let socket_path = create_temp_socket_path();
+            let listener = create_mock_server(&socket_path).await;
+            // Closes each connection after one reply, like older daemons
+            let server_handle = spawn_status_server(listener, 2, 1);
+
+            let client = IpcClient::with_socket_path(socket_path);
+            let mut session = client.session().await.unwrap();
+            for _ in 0..2 {
+                let response = session.send(&IpcRequest::Status).await.unwrap();
+                assert_eq!(response.status, "success");
+            }
+
+            server_handle.await.unwrap();
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Resume-if-paused Tests
+    // ------------------------------------------------------------------------
+
+    mod start_or_resume_tests {
+        use super::*;
+
+        /// Serves `connections` requests, reporting `state` for status queries
+        /// and recording every request received. Probe connections that
+        /// send nothing are not counted.
+        fn spawn_mock_daemon(
+            listener: UnixListener,
+            state: &'static str,
+            connections: usize,
+        ) -> (tokio::task::JoinHandle<()>, Arc<Mutex<Vec<IpcRequest>>>) {
+            spawn_mock_daemon_with_resume(
+                listener,
+                state,
+                connections,
+                IpcResponse::success("タイマーを再開しました", None),
+            )
+        }
+
+        /// Like [`spawn_mock_daemon`], answering `resume` with `resume_response`.
+        fn spawn_mock_daemon_with_resume(
+            listener: UnixListener,
+            state: &'static str,
+            connections: usize,
+            resume_response: IpcResponse,
+        ) -> (tokio::task::JoinHandle<()>, Arc<Mutex<Vec<IpcRequest>>>) {
+            let received = Arc::new(Mutex::new(Vec::new()));
+            let received_clone = Arc::clone(&received);
+
+            let handle = tokio::spawn(async move {
+                let mut served = 0;
+                while served < connections {
+                    let (mut stream, _) = listener.accept().await.unwrap();
+                    let Ok(frame) = read_frame(&mut stream, 4096).await else {
+                        continue;
+                    };
+                    served += 1;
+                    let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
+
+                    let response = match request {
+                        IpcRequest::Status => IpcResponse::success(
+                            "",
+                            Some(ResponseData {
+                                state: Some(state.to_string()),
+                                ..Default::default()
+                            }),
+                        ),
+                        IpcRequest::Resume => resume_response.clone(),
+                        _ => IpcResponse::success("タイマーを開始しました", None),
+                    };
+                    received_clone.lock().await.push(request);
+
+                    let json = serde_json::to_vec(&response).unwrap();
+                    write_frame(&mut stream, &json).await.unwrap();
+                }
+            });
+
+            (handle, received)
+        }
+
+        #[tokio::test]
+        async fn test_resumes_when_paused() {
+            let socket_path = create_temp_socket_path();
+            let listener = create_mock_server(&socket_path).await;
+            let (server_handle, received) = spawn_mock_daemon(listener, "paused", 2);
+
+            let client = IpcClient::with_socket_path(socket_path);
+            let outcome = client.start_or_resume(&StartArgs::default()).await.unwrap();
+
+            server_handle.await.unwrap();
+            assert!(matches!(outcome, StartOutcome::Resumed(_)));
+            let received = received.lock().await;
+            assert!(matches!(
+                received.as_slice(),
+                [IpcRequest::Status, IpcRequest::Resume]
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_starts_when_stopped() {
+            let socket_path = create_temp_socket_path();
+            let listener = create_mock_server(&socket_path).await;
+            let (server_handle, received) = spawn_mock_daemon(listener, "stopped", 2);
+
+            let client = IpcClient::with_socket_path(socket_path);
+            let outcome = client.start_or_resume(&StartArgs::default()).await.unwrap();
+
+            server_handle.await.unwrap();
+            assert!(matches!(outcome, StartOutcome::Started(_)));
+            let received = received.lock().await;
+            assert!(matches!(
+                received.as_slice(),
+                [IpcRequest::Status, IpcRequest::Start { .. }]
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_starts_when_timer_left_pause_before_resume() {
+            let socket_path = create_temp_socket_path();
+            let listener = create_mock_server(&socket_path).await;
+            // Paused at the status check, idle by the time resume arrives
+            let (server_handle, received) = spawn_mock_daemon_with_resume(
+                listener,
+                "paused",
+                3,
+                IpcResponse::error("タイマーは一時停止していません"),
+            );
+
+            let client = IpcClient::with_socket_path(socket_path);
+            let outcome = client.start_or_resume(&StartArgs::default()).await.unwrap();
+
+            server_handle.await.unwrap();
+            assert!(matches!(outcome, StartOutcome::Started(_)));
+            let received = received.lock().await;
+            assert!(matches!(
+                received.as_slice(),
+                [
+                    IpcRequest::Status,
+                    IpcRequest::Resume,
+                    IpcRequest::Start { .. }
+                ]
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_starts_without_status_when_daemon_is_down() {
+            let socket_path = create_temp_socket_path();
+            let client = IpcClient::with_socket_path(socket_path)
+                .with_max_retries(0)
+                .with_timeout(Duration::from_millis(100));
+
+            let result = client.start_or_resume(&StartArgs::default()).await;
+
+            // The start itself reports the missing daemon
+            let message = format!("{:#}", result.unwrap_err());
+            assert!(message.contains("pomodoro daemon"), "{}", message);
+        }
+    }
+
     // ------------------------------------------------------------------------
     // StartArgs Conversion Tests
     // ------------------------------------------------------------------------
@@ -549,14 +1334,13 @@ mod tests {
             let server_handle = tokio::spawn(async move {
                 let (mut stream, _) = listener.accept().await.unwrap();
 
-                let mut buffer = vec![0u8; 4096];
-                let n = stream.read(&mut buffer).await.unwrap();
-                let request: IpcRequest = serde_json::from_slice(&buffer[..n]).unwrap();
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
                 *received_clone.lock().await = Some(request);
 
                 let response = IpcResponse::success("OK", None);
                 let json = serde_json::to_vec(&response).unwrap();
-                stream.write_all(&json).await.unwrap();
+                write_frame(&mut stream, &json).await.unwrap();
             });
 
             let client = IpcClient::with_socket_path(socket_path);
@@ -565,12 +1349,9 @@ mod tests {
             let received = received_request.lock().await;
             match received.as_ref() {
                 Some(IpcRequest::Start { params }) => {
-                    assert_eq!(params.work_minutes, Some(25));
-                    assert_eq!(params.break_minutes, Some(5));
-                    assert_eq!(params.long_break_minutes, Some(15));
+                    // Without flags the daemon's configuration decides everything
+                    assert!(params.apply_to(&PomodoroConfig::default()).is_none());
                     assert!(params.task_name.is_none());
-                    assert_eq!(params.auto_cycle, Some(false));
-                    assert_eq!(params.focus_mode, Some(false));
                 }
                 _ => panic!("Expected Start request"),
             }
@@ -590,25 +1371,25 @@ mod tests {
             let server_handle = tokio::spawn(async move {
                 let (mut stream, _) = listener.accept().await.unwrap();
 
-                let mut buffer = vec![0u8; 4096];
-                let n = stream.read(&mut buffer).await.unwrap();
-                let request: IpcRequest = serde_json::from_slice(&buffer[..n]).unwrap();
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
                 *received_clone.lock().await = Some(request);
 
                 let response = IpcResponse::success("OK", None);
                 let json = serde_json::to_vec(&response).unwrap();
-                stream.write_all(&json).await.unwrap();
+                write_frame(&mut stream, &json).await.unwrap();
             });
 
             let client = IpcClient::with_socket_path(socket_path);
             let args = StartArgs {
-                work: 50,
-                break_time: 10,
-                long_break: 30,
+                work: Some(50),
+                break_time: Some(10),
+                long_break: Some(30),
                 task: Some("Custom Task".to_string()),
                 auto_cycle: true,
                 focus_mode: true,
                 no_sound: true,
+                ..Default::default()
             };
             let _ = client.start(&args).await;
 
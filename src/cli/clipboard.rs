@@ -0,0 +1,120 @@
+//! Clipboard support for the `start` command.
+//!
+//! Lets `pomodoro start --task-from-clipboard` take the task name from the
+//! system clipboard. Clipboard access requires the `clipboard` feature;
+//! without it the flag is accepted but only logs a warning.
+
+use super::commands::validate_task_name;
+
+// ============================================================================
+// Task Name Resolution
+// ============================================================================
+
+/// Resolves the task name for the start command.
+///
+/// - `--task` always wins; combining it with `--task-from-clipboard` logs a warning.
+/// - Clipboard text is trimmed and checked with the same rules as `--task`.
+/// - Any clipboard failure falls back to no task name with a warning.
+pub fn resolve_task_name<F>(task: Option<String>, from_clipboard: bool, read: F) -> Option<String>
+where
+    F: FnOnce() -> Result<String, String>,
+{
+    if !from_clipboard {
+        return task;
+    }
+
+    if task.is_some() {
+        tracing::warn!("--task が指定されているため --task-from-clipboard は無視されます");
+        return task;
+    }
+
+    let text = match read() {
+        Ok(text) => text,
+        Err(e) => {
+            tracing::warn!("クリップボードの読み取りに失敗しました: {}", e);
+            return None;
+        }
+    };
+
+    match validate_task_name(text.trim()) {
+        Ok(name) => Some(name),
+        Err(e) => {
+            tracing::warn!("クリップボードの内容をタスク名に使用できません: {}", e);
+            None
+        }
+    }
+}
+
+/// Reads text from the system clipboard.
+///
+/// # Errors
+///
+/// Returns an error message if the clipboard cannot be read.
+#[cfg(feature = "clipboard")]
+pub fn read_clipboard() -> Result<String, String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .map_err(|e| e.to_string())
+}
+
+/// Reads text from the system clipboard (clipboard feature disabled).
+///
+/// # Errors
+///
+/// Always returns an error because clipboard support was not compiled in.
+#[cfg(not(feature = "clipboard"))]
+pub fn read_clipboard() -> Result<String, String> {
+    Err("clipboard 機能が有効になっていません".to_string())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clipboard_with(text: &str) -> impl FnOnce() -> Result<String, String> + '_ {
+        move || Ok(text.to_string())
+    }
+
+    #[test]
+    fn test_without_flag_uses_task() {
+        let task = resolve_task_name(Some("Task".to_string()), false, || {
+            panic!("clipboard should not be read")
+        });
+        assert_eq!(task, Some("Task".to_string()));
+    }
+
+    #[test]
+    fn test_task_wins_over_clipboard() {
+        let task = resolve_task_name(Some("Task".to_string()), true, || {
+            panic!("clipboard should not be read")
+        });
+        assert_eq!(task, Some("Task".to_string()));
+    }
+
+    #[test]
+    fn test_clipboard_text_is_trimmed() {
+        let task = resolve_task_name(None, true, clipboard_with("  JIRA-123 Fix login\n"));
+        assert_eq!(task, Some("JIRA-123 Fix login".to_string()));
+    }
+
+    #[test]
+    fn test_invalid_clipboard_text_falls_back_to_none() {
+        assert_eq!(resolve_task_name(None, true, clipboard_with("   ")), None);
+
+        let long_text = "a".repeat(101);
+        assert_eq!(
+            resolve_task_name(None, true, clipboard_with(&long_text)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_clipboard_error_falls_back_to_none() {
+        let task = resolve_task_name(None, true, || Err("no clipboard".to_string()));
+        assert_eq!(task, None);
+    }
+}
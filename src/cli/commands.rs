@@ -2,7 +2,22 @@
 //!
 //! Uses clap derive macro for argument parsing.
 
-use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::config::Preset;
+use crate::daemon::history::{DayRange, ExportFormat};
+use crate::daemon::TimerEvent;
+use crate::menubar::IconStyle;
+use crate::template;
+use crate::types::{PhaseDuration, PomodoroConfig, ResponseData, StartParams};
+
+use super::display::ColorMode;
+use super::log_tail::DEFAULT_LOG_LINES;
+use super::status_cache::DEFAULT_STATUS_CACHE_TTL;
 
 // ============================================================================
 // CLI Structure
@@ -26,6 +41,18 @@ pub struct Cli {
     /// Enable verbose output for debugging
     #[arg(short, long, global = true)]
     pub verbose: bool,
+
+    /// Configuration file to use (default: ~/.pomodoro/config.toml, or under XDG_CONFIG_HOME off macOS; must exist if given)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Profile in the configuration file to apply ([profiles.<NAME>]; also POMODORO_PROFILE)
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// When to color output (auto honors NO_COLOR and only colors terminals)
+    #[arg(long, global = true, value_enum, default_value_t = ColorMode::Auto)]
+    pub color: ColorMode,
 }
 
 // ============================================================================
@@ -38,71 +65,343 @@ pub enum Commands {
     /// Start a new pomodoro timer session
     Start(StartArgs),
 
+    /// Run a single work session in the foreground, without the daemon
+    Run(RunArgs),
+
     /// Pause the current timer
-    Pause,
+    Pause(PauseArgs),
 
     /// Resume a paused timer
     Resume,
 
     /// Stop the current timer
-    Stop,
+    Stop(StopArgs),
 
     /// Show current timer status
-    Status,
+    Status(StatusArgs),
+
+    /// Start a standalone break without a work session
+    Break(BreakArgs),
+
+    /// Start a standalone long break (e.g. lunch) without a work session
+    LongBreak(LongBreakArgs),
+
+    /// Extend the current break (or re-enter a just-ended break)
+    Snooze(SnoozeArgs),
+
+    /// End the current break early and start working
+    Skip,
+
+    /// Set the current session aside and stop (resume it with `unsuspend`)
+    Suspend,
+
+    /// Resume the session set aside with `suspend`
+    Unsuspend,
+
+    /// Reset the completed pomodoro count (restarts the long-break cadence)
+    Reset,
+
+    /// Show statistics from the session history (e.g. breaks skipped today)
+    Stats(StatsArgs),
+
+    /// Print timer events from the daemon as JSON (for debugging)
+    WatchEvents(WatchEventsArgs),
+
+    /// Diagnose the environment (daemon, socket, audio, notifications)
+    Doctor,
+
+    /// Inspect the configuration
+    #[command(subcommand)]
+    Config(ConfigCommand),
+
+    /// One-time setup steps (macOS permissions)
+    #[command(subcommand)]
+    Setup(SetupCommand),
+
+    /// Show the daemon log (`-f` to follow it)
+    Log(LogArgs),
+
+    /// List or clear this app's notifications (macOS, for debugging)
+    #[command(subcommand)]
+    Notifications(NotificationsCommand),
+
+    /// Inspect sound output
+    #[command(subcommand)]
+    Sound(SoundCommand),
 
     /// Run as daemon (background service)
     #[command(hide = true)]
-    Daemon,
+    Daemon(DaemonArgs),
 
     /// Install LaunchAgent for auto-start on login
-    Install,
+    Install(InstallArgs),
 
     /// Uninstall LaunchAgent
-    Uninstall,
+    Uninstall(UninstallArgs),
 
     /// Generate shell completion scripts
     Completions {
         /// Shell type for completion script
         #[arg(value_enum)]
         shell: clap_complete::Shell,
+
+        /// Write the script to the shell's completion directory instead of stdout
+        #[arg(long)]
+        install: bool,
+
+        /// Overwrite an existing script when installing
+        #[arg(long, requires = "install")]
+        force: bool,
     },
 }
 
 // ============================================================================
-// Start Command Arguments
+// Snooze Command Arguments
 // ============================================================================
 
-/// Arguments for the start command
+/// Arguments for the status command
+#[derive(Args, Debug, Clone, Default)]
+pub struct StatusArgs {
+    /// Print only the menu bar title (e.g. "🍅 15:30") for shell prompts
+    #[arg(long)]
+    pub short: bool,
+
+    /// Print the status with a template, e.g. "{phase} {remaining_mmss}"
+    /// (placeholders: phase, remaining, remaining_mmss, count, task, tag)
+    #[arg(long, value_parser = validate_status_template, conflicts_with = "short")]
+    pub format: Option<String>,
+
+    /// With --short, the glyphs for the phase (default: `[display]
+    /// icon_style` in the config file, or emoji)
+    #[arg(long, value_enum, requires = "short")]
+    pub icon_style: Option<IconStyle>,
+
+    /// With --short, reuse the last status for a while instead of asking
+    /// the daemon every time
+    #[arg(long, requires = "short")]
+    pub cache: bool,
+
+    /// How long --cache reuses a status, in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = DEFAULT_STATUS_CACHE_TTL.as_millis() as u64, requires = "cache")]
+    pub cache_ttl: u64,
+}
+
+/// Arguments for the install command
+#[derive(Args, Debug, Clone, Default)]
+pub struct InstallArgs {
+    /// Check that the installed LaunchAgent runs the current binary
+    #[arg(long)]
+    pub check: bool,
+}
+
+/// Arguments for the uninstall command
+#[derive(Args, Debug, Clone, Default)]
+pub struct UninstallArgs {
+    /// Also delete state, history, logs and the socket (config.toml is kept)
+    #[arg(long)]
+    pub purge: bool,
+
+    /// Skip the confirmation prompt for --purge
+    #[arg(short, long, requires = "purge")]
+    pub yes: bool,
+}
+
+/// Arguments for the stop command
+#[derive(Args, Debug, Clone, Default)]
+pub struct StopArgs {
+    /// Stop without asking, even after a long work session
+    #[arg(short, long, visible_alias = "force")]
+    pub yes: bool,
+}
+
+/// Arguments for the break command
+#[derive(Args, Debug, Clone, Default)]
+pub struct BreakArgs {
+    /// Break length in minutes (1-60, defaults to the configured break length)
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=60))]
+    pub minutes: Option<u32>,
+
+    /// Run this many breaks back to back, with a chime between them (1-20)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=20))]
+    pub repeat: Option<u32>,
+}
+
+/// Arguments for the pause command
+#[derive(Args, Debug, Clone, Default)]
+pub struct PauseArgs {
+    /// Why you are pausing (e.g. "meeting"), shown by `status`
+    #[arg(short, long)]
+    pub reason: Option<String>,
+}
+
+/// Arguments for the long-break command
+#[derive(Args, Debug, Clone, Default)]
+pub struct LongBreakArgs {
+    /// Break length in minutes (1-60, defaults to the configured long break length)
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=60))]
+    pub minutes: Option<u32>,
+}
+
+/// Arguments for the snooze command
 #[derive(Args, Debug, Clone)]
-pub struct StartArgs {
-    /// Work duration in minutes (1-120)
+pub struct SnoozeArgs {
+    /// Minutes to extend the break by (1-30)
     #[arg(
         short,
         long,
-        default_value = "25",
-        value_parser = clap::value_parser!(u32).range(1..=120)
+        default_value = "5",
+        value_parser = clap::value_parser!(u32).range(1..=30)
     )]
-    pub work: u32,
+    pub minutes: u32,
+}
 
-    /// Short break duration in minutes (1-60)
+// ============================================================================
+// Stats Command Arguments
+// ============================================================================
+
+/// Arguments for the stats command
+#[derive(Args, Debug, Clone, Default)]
+pub struct StatsArgs {
+    /// Count today's completed pomodoros per tag (`start --tag`)
+    #[arg(long)]
+    pub by_tag: bool,
+
+    /// Group days by UTC instead of local time
+    #[arg(long)]
+    pub utc: bool,
+
+    /// First day to report on (YYYY-MM-DD); defaults to --until
+    #[arg(long, value_name = "DATE")]
+    pub since: Option<NaiveDate>,
+
+    /// Last day to report on (YYYY-MM-DD); defaults to today
+    #[arg(long, value_name = "DATE")]
+    pub until: Option<NaiveDate>,
+
+    /// Print the history entries (all, or those of --since/--until) instead
+    #[arg(long, value_enum, value_name = "FORMAT", conflicts_with = "by_tag")]
+    pub export: Option<ExportFormat>,
+}
+
+impl StatsArgs {
+    /// Returns the days given with `--since/--until`, or `None` for the
+    /// default report on today.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `--since` is after `--until`.
+    pub fn range(&self, today: NaiveDate) -> anyhow::Result<Option<DayRange>> {
+        if self.since.is_none() && self.until.is_none() {
+            return Ok(None);
+        }
+        let until = self.until.unwrap_or(today);
+        let since = self.since.unwrap_or(until);
+        DayRange::new(since, until).map(Some)
+    }
+}
+
+// ============================================================================
+// Watch Events Command Arguments
+// ============================================================================
+
+/// Arguments for the watch-events command
+#[derive(Args, Debug, Clone, Default)]
+pub struct WatchEventsArgs {
+    /// Event kinds to suppress (e.g. `--filter tick`); may be repeated
     #[arg(
-        short,
         long,
-        default_value = "5",
-        value_parser = clap::value_parser!(u32).range(1..=60)
+        value_name = "EVENT",
+        value_parser = clap::builder::PossibleValuesParser::new(TimerEvent::NAMES)
     )]
-    pub break_time: u32,
+    pub filter: Vec<String>,
+}
+
+impl WatchEventsArgs {
+    /// Returns true if the event should be printed.
+    pub fn shows(&self, event: &TimerEvent) -> bool {
+        !self.filter.iter().any(|name| name == event.name())
+    }
+}
+
+// ============================================================================
+// Daemon Command Arguments
+// ============================================================================
+
+/// Arguments for the daemon command
+#[derive(Args, Debug, Clone, Default)]
+pub struct DaemonArgs {
+    /// Write logs to this file instead of stderr
+    #[arg(long, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Alert on the terminal (stderr + bell) instead of the notification center
+    #[arg(long)]
+    pub terminal_notifications: bool,
+
+    /// Silence terminal alerts and the READY line printed once clients can connect
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// Append every timer event to this file as JSON lines
+    #[arg(long, value_name = "PATH")]
+    pub events_file: Option<PathBuf>,
+
+    /// Timer events buffered for slow consumers before ticks are dropped
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(16..=65536))]
+    pub event_capacity: Option<u32>,
+
+    /// Speak phase changes with `say` (macOS)
+    #[arg(long)]
+    pub announce: bool,
+
+    /// Voice for spoken announcements (see `say -v '?'`)
+    #[arg(long, value_name = "NAME")]
+    pub voice: Option<String>,
+
+    /// Start a work session this many minutes after launch (0: don't)
+    #[arg(long, value_name = "MINUTES", default_value_t = 0)]
+    pub auto_start_after: u32,
+
+    /// Remind of the time left this many times during a long break (0: don't)
+    #[arg(long, value_name = "COUNT", default_value_t = 0, value_parser = clap::value_parser!(u32).range(0..=10))]
+    pub break_reminder: u32,
+
+    /// Fail if a daemon is already running instead of taking its socket
+    #[arg(long)]
+    pub exclusive: bool,
+
+    /// Write task names to the debug request log (redacted by default)
+    #[arg(long)]
+    pub log_task_names: bool,
+}
+
+// ============================================================================
+// Start Command Arguments
+// ============================================================================
+
+/// Arguments for the start command
+///
+/// Durations left unset come from `--preset`, then the config file, then the
+/// built-in defaults (25/5/15).
+#[derive(Args, Debug, Clone, Default)]
+pub struct StartArgs {
+    /// Duration preset (explicit --work/--break-time/--long-break override it)
+    #[arg(long, value_enum)]
+    pub preset: Option<Preset>,
+
+    /// Work duration in minutes (1-120)
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=120))]
+    pub work: Option<u32>,
+
+    /// Short break duration in minutes (1-60)
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=60))]
+    pub break_time: Option<u32>,
 
     /// Long break duration in minutes (1-60)
-    #[arg(
-        short,
-        long,
-        default_value = "15",
-        value_parser = clap::value_parser!(u32).range(1..=60)
-    )]
-    pub long_break: u32,
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=60))]
+    pub long_break: Option<u32>,
 
-    /// Task name for this session
+    /// Task name for this session (`-` reads it from stdin)
     #[arg(short, long, value_parser = validate_task_name)]
     pub task: Option<String>,
 
@@ -117,121 +416,938 @@ pub struct StartArgs {
     /// Disable notification sounds
     #[arg(long)]
     pub no_sound: bool,
+
+    /// Tag categorizing the session (e.g. a project), kept apart from the task name
+    #[arg(long, value_parser = validate_tag)]
+    pub tag: Option<String>,
+
+    /// Use the clipboard contents as the task name (`--task` takes precedence)
+    #[arg(long)]
+    pub task_from_clipboard: bool,
+
+    /// Enable strict mode (pausing is disabled; stopping is still allowed)
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Disable long breaks (every break is a short break)
+    #[arg(long)]
+    pub no_long_break: bool,
+
+    /// Stop after this many work sessions (implies --auto-cycle)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=100))]
+    pub cycles: Option<u32>,
+
+    /// Resume the timer instead if it is paused (other options are then ignored)
+    #[arg(long)]
+    pub resume_if_paused: bool,
+
+    /// Start paused with the full work duration; `resume` begins the countdown
+    #[arg(long, conflicts_with = "resume_if_paused")]
+    pub paused: bool,
+
+    /// Tasks separated by `;`, one per work session (implies --auto-cycle)
+    #[arg(
+        long,
+        value_delimiter = ';',
+        value_parser = validate_task_name,
+        conflicts_with_all = ["task", "task_from_clipboard"]
+    )]
+    pub task_queue: Vec<String>,
+
+    /// Do nothing if the same session (task and settings) is already running
+    #[arg(long, conflicts_with = "resume_if_paused")]
+    pub if_not_running: bool,
+
+    /// Stop a running session and start this one in its place (refused in strict mode)
+    #[arg(long)]
+    pub force: bool,
+
+    /// Block until the work session completes (exit non-zero if it is stopped; Ctrl+C leaves it running)
+    #[arg(long)]
+    pub wait: bool,
+}
+
+impl StartArgs {
+    /// Resolves the timer configuration for this session.
+    ///
+    /// Precedence: explicit flags, then `--preset`, then `base` (the config
+    /// file). Switch flags such as `--strict` only turn settings on.
+    pub fn resolve_config(&self, base: &PomodoroConfig) -> PomodoroConfig {
+        let mut config = match self.preset {
+            Some(preset) => preset.apply(base.clone()),
+            None => base.clone(),
+        };
+
+        if let Some(work) = self.work {
+            config.work_duration = PhaseDuration::from_minutes(work);
+        }
+        if let Some(brk) = self.break_time {
+            config.break_duration = PhaseDuration::from_minutes(brk);
+        }
+        if let Some(long_brk) = self.long_break {
+            config.long_break_duration = PhaseDuration::from_minutes(long_brk);
+        }
+        if self.cycles.is_some() {
+            config.max_cycles = self.cycles;
+        }
+        config.auto_cycle |=
+            self.auto_cycle || self.cycles.is_some() || !self.task_queue.is_empty();
+        config.focus_mode |= self.focus_mode;
+        config.strict |= self.strict;
+        config.long_break_enabled &= !self.no_long_break;
+        config
+    }
+
+    /// Builds the start request parameters for this session.
+    ///
+    /// Only the settings the flags and `--preset` set are included. The
+    /// daemon takes everything else from its own configuration, so a reload
+    /// of the config file during the run still reaches those settings.
+    pub fn start_params(&self) -> StartParams {
+        let preset = self.preset.map(Preset::config);
+        let from_preset = |minutes: fn(&PomodoroConfig) -> u32| preset.as_ref().map(minutes);
+        let auto_cycle = self.auto_cycle || self.cycles.is_some() || !self.task_queue.is_empty();
+
+        StartParams {
+            work_minutes: self
+                .work
+                .or_else(|| from_preset(|p| p.work_duration.as_minutes())),
+            break_minutes: self
+                .break_time
+                .or_else(|| from_preset(|p| p.break_duration.as_minutes())),
+            long_break_minutes: self
+                .long_break
+                .or_else(|| from_preset(|p| p.long_break_duration.as_minutes())),
+            long_break_interval: from_preset(|p| p.long_break_interval),
+            task_name: self.task.clone(),
+            tag: self.tag.clone(),
+            auto_cycle: auto_cycle.then_some(true),
+            focus_mode: self.focus_mode.then_some(true),
+            strict: self.strict.then_some(true),
+            long_break_enabled: self.no_long_break.then_some(false),
+            max_cycles: self.cycles,
+            paused: self.paused.then_some(true),
+            task_queue: (!self.task_queue.is_empty()).then(|| self.task_queue.clone()),
+            if_not_running: self.if_not_running.then_some(true),
+            force: self.force.then_some(true),
+        }
+    }
+}
+
+// ============================================================================
+// Run Command Arguments
+// ============================================================================
+
+/// Arguments for the run command (one session in the foreground)
+#[derive(Args, Debug, Clone)]
+pub struct RunArgs {
+    /// Duration preset (an explicit --work overrides it)
+    #[arg(long, value_enum)]
+    pub preset: Option<Preset>,
+
+    /// Work duration in minutes (1-120)
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=120))]
+    pub work: Option<u32>,
+
+    /// Task name for this session
+    #[arg(short, long, value_parser = validate_task_name)]
+    pub task: Option<String>,
+
+    /// Disable the completion sound
+    #[arg(long)]
+    pub no_sound: bool,
+
+    /// Show the countdown in whole minutes, without seconds
+    #[arg(long)]
+    pub no_seconds: bool,
+
+    /// Milliseconds per timer second (debug builds only, for tests)
+    #[cfg(debug_assertions)]
+    #[arg(long, hide = true, default_value_t = 1000)]
+    pub tick_ms: u64,
+}
+
+impl RunArgs {
+    /// Resolves the timer configuration, with the same precedence as `start`.
+    pub fn resolve_config(&self, base: &PomodoroConfig) -> PomodoroConfig {
+        StartArgs {
+            preset: self.preset,
+            work: self.work,
+            ..Default::default()
+        }
+        .resolve_config(base)
+    }
+
+    /// Returns the real time one timer second takes.
+    ///
+    /// Always one second in release builds, where `--tick-ms` does not exist.
+    pub fn tick_interval(&self) -> Duration {
+        #[cfg(debug_assertions)]
+        let millis = self.tick_ms;
+        #[cfg(not(debug_assertions))]
+        let millis = 1000;
+        Duration::from_millis(millis)
+    }
+}
+
+// ============================================================================
+// Config Command Arguments
+// ============================================================================
+
+/// Subcommands of the config command
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigCommand {
+    /// Show the resolved configuration (config file plus profile and preset)
+    Show(ConfigShowArgs),
+
+    /// Show the running daemon's timer configuration
+    Get,
+
+    /// Change a setting of the running daemon (applied from the next phase)
+    Set(ConfigSetArgs),
 }
 
-impl Default for StartArgs {
-    fn default() -> Self {
-        Self {
-            work: 25,
-            break_time: 5,
-            long_break: 15,
-            task: None,
-            auto_cycle: false,
-            focus_mode: false,
-            no_sound: false,
+/// Arguments for the config set command
+#[derive(Args, Debug, Clone)]
+pub struct ConfigSetArgs {
+    /// Setting to change
+    #[arg(value_enum)]
+    pub key: ConfigKey,
+
+    /// New value (minutes, or pomodoros for `interval`)
+    pub value: u32,
+}
+
+/// Settings `config set` can change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ConfigKey {
+    /// Work duration in minutes
+    Work,
+    /// Short break duration in minutes
+    Break,
+    /// Long break duration in minutes
+    LongBreak,
+    /// Number of pomodoros before a long break
+    Interval,
+}
+
+impl ConfigKey {
+    /// Returns `config` with this setting changed to `value`.
+    pub fn apply(self, config: PomodoroConfig, value: u32) -> PomodoroConfig {
+        match self {
+            ConfigKey::Work => config.with_work_minutes(value),
+            ConfigKey::Break => config.with_break_minutes(value),
+            ConfigKey::LongBreak => config.with_long_break_minutes(value),
+            ConfigKey::Interval => config.with_long_break_interval(value),
+        }
+    }
+}
+
+/// Arguments for the config show command
+#[derive(Args, Debug, Clone, Default)]
+pub struct ConfigShowArgs {
+    /// Apply a duration preset on top of the config file
+    #[arg(long, value_enum)]
+    pub preset: Option<Preset>,
+
+    /// Print every setting in effect as JSON (for tools)
+    #[arg(long)]
+    pub json: bool,
+}
+
+// ============================================================================
+// Setup Command Arguments
+// ============================================================================
+
+/// Subcommands of the setup command
+#[derive(Subcommand, Debug, Clone)]
+pub enum SetupCommand {
+    /// Ask macOS for notification permission and report the result
+    Notifications,
+}
+
+// ============================================================================
+// Log Command Arguments
+// ============================================================================
+
+/// Arguments for the log command
+#[derive(Args, Debug, Clone)]
+pub struct LogArgs {
+    /// Keep printing lines as they are appended (until Ctrl+C)
+    #[arg(short, long)]
+    pub follow: bool,
+
+    /// Number of lines to show before following
+    #[arg(short = 'n', long, default_value_t = DEFAULT_LOG_LINES)]
+    pub lines: usize,
+}
+
+// ============================================================================
+// Notifications Command Arguments
+// ============================================================================
+
+/// Subcommands of the notifications command
+#[derive(Subcommand, Debug, Clone, Copy)]
+pub enum NotificationsCommand {
+    /// Show pending and delivered notifications
+    List,
+    /// Remove all pending and delivered notifications
+    Clear,
+}
+
+// ============================================================================
+// Sound Command Arguments
+// ============================================================================
+
+/// Subcommands of the sound command
+#[derive(Subcommand, Debug, Clone, Copy)]
+pub enum SoundCommand {
+    /// List the audio output devices (names for `output_device`)
+    Devices,
+}
+
+// ============================================================================
+// Validation Functions
+// ============================================================================
+
+/// Validates the task name.
+///
+/// - Must not be empty
+/// - Must not exceed 100 characters
+pub(crate) fn validate_task_name(s: &str) -> Result<String, String> {
+    if s.is_empty() {
+        return Err("タスク名は空にできません".to_string());
+    }
+    if s.len() > 100 {
+        return Err("タスク名は100文字以内にしてください".to_string());
+    }
+    Ok(s.to_string())
+}
+
+/// Validates a tag name.
+///
+/// - Must not be empty
+/// - Must be 50 characters or less
+pub(crate) fn validate_tag(s: &str) -> Result<String, String> {
+    if s.trim().is_empty() {
+        return Err("タグは空にできません".to_string());
+    }
+    if s.chars().count() > 50 {
+        return Err("タグは50文字以内にしてください".to_string());
+    }
+    Ok(s.to_string())
+}
+
+/// Validates a `status --format` template.
+///
+/// - Placeholders must be closed and known
+pub(crate) fn validate_status_template(s: &str) -> Result<String, String> {
+    template::render_status(s, &ResponseData::default())
+        .map(|_| s.to_string())
+        .map_err(|e| {
+            format!(
+                "{}（使用可能: {}）",
+                e,
+                template::STATUS_PLACEHOLDERS.join(", ")
+            )
+        })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ------------------------------------------------------------------------
+    // Cli Tests
+    // ------------------------------------------------------------------------
+
+    mod cli_tests {
+        use super::*;
+
+        #[test]
+        fn test_parse_no_args() {
+            let cli = Cli::parse_from(["pomodoro"]);
+            assert!(cli.command.is_none());
+            assert!(!cli.verbose);
+        }
+
+        #[test]
+        fn test_parse_verbose_flag() {
+            let cli = Cli::parse_from(["pomodoro", "--verbose"]);
+            assert!(cli.verbose);
+        }
+
+        #[test]
+        fn test_parse_short_verbose_flag() {
+            let cli = Cli::parse_from(["pomodoro", "-v"]);
+            assert!(cli.verbose);
+        }
+
+        #[test]
+        fn test_parse_color_flag() {
+            assert_eq!(Cli::parse_from(["pomodoro"]).color, ColorMode::Auto);
+
+            let cli = Cli::parse_from(["pomodoro", "status", "--color", "never"]);
+            assert_eq!(cli.color, ColorMode::Never);
+
+            assert!(Cli::try_parse_from(["pomodoro", "--color", "sometimes"]).is_err());
+        }
+
+        #[test]
+        fn test_parse_config_flag() {
+            let cli = Cli::parse_from(["pomodoro", "--config", "/tmp/pomodoro.toml", "status"]);
+            assert_eq!(cli.config, Some(PathBuf::from("/tmp/pomodoro.toml")));
+
+            let cli = Cli::parse_from(["pomodoro", "daemon", "--config", "/tmp/pomodoro.toml"]);
+            assert!(matches!(cli.command, Some(Commands::Daemon(_))));
+            assert_eq!(cli.config, Some(PathBuf::from("/tmp/pomodoro.toml")));
+
+            let cli = Cli::parse_from(["pomodoro", "status"]);
+            assert_eq!(cli.config, None);
+        }
+
+        #[test]
+        fn test_parse_profile_flag() {
+            let cli = Cli::parse_from(["pomodoro", "config", "show", "--profile", "writing"]);
+            assert_eq!(cli.profile.as_deref(), Some("writing"));
+
+            let cli = Cli::parse_from(["pomodoro", "--profile", "coding", "daemon"]);
+            assert_eq!(cli.profile.as_deref(), Some("coding"));
+
+            let cli = Cli::parse_from(["pomodoro", "status"]);
+            assert_eq!(cli.profile, None);
+        }
+
+        #[test]
+        fn test_parse_config_show_command() {
+            let cli = Cli::parse_from(["pomodoro", "config", "show", "--preset", "shortcycle"]);
+            match cli.command {
+                Some(Commands::Config(ConfigCommand::Show(args))) => {
+                    assert_eq!(args.preset, Some(Preset::ShortCycle));
+                    assert!(!args.json);
+                }
+                _ => panic!("Expected Config Show command"),
+            }
+
+            let cli = Cli::parse_from(["pomodoro", "config", "show", "--json"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Config(ConfigCommand::Show(ConfigShowArgs {
+                    json: true,
+                    ..
+                })))
+            ));
+        }
+
+        #[test]
+        fn test_parse_config_get_and_set() {
+            let cli = Cli::parse_from(["pomodoro", "config", "get"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Config(ConfigCommand::Get))
+            ));
+
+            let cli = Cli::parse_from(["pomodoro", "config", "set", "long-break", "20"]);
+            match cli.command {
+                Some(Commands::Config(ConfigCommand::Set(args))) => {
+                    assert_eq!(args.key, ConfigKey::LongBreak);
+                    assert_eq!(args.value, 20);
+                    let config = args.key.apply(PomodoroConfig::default(), args.value);
+                    assert_eq!(config.long_break_duration.as_minutes(), 20);
+                }
+                _ => panic!("Expected Config Set command"),
+            }
+
+            assert!(Cli::try_parse_from(["pomodoro", "config", "set", "color", "1"]).is_err());
+            assert!(Cli::try_parse_from(["pomodoro", "config", "set", "work", "-5"]).is_err());
+        }
+
+        #[test]
+        fn test_parse_notifications_commands() {
+            let cli = Cli::parse_from(["pomodoro", "notifications", "list"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Notifications(NotificationsCommand::List))
+            ));
+
+            let cli = Cli::parse_from(["pomodoro", "notifications", "clear"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Notifications(NotificationsCommand::Clear))
+            ));
+        }
+
+        #[test]
+        fn test_parse_stats_command() {
+            let cli = Cli::parse_from(["pomodoro", "stats"]);
+            assert!(matches!(cli.command, Some(Commands::Stats(args)) if !args.by_tag));
+
+            let cli = Cli::parse_from(["pomodoro", "stats", "--by-tag"]);
+            assert!(matches!(cli.command, Some(Commands::Stats(args)) if args.by_tag));
+
+            let cli = Cli::parse_from(["pomodoro", "stats", "--utc"]);
+            assert!(matches!(cli.command, Some(Commands::Stats(args)) if args.utc));
+        }
+
+        #[test]
+        fn test_parse_stats_range() {
+            let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+            let parse = |args: &[&str]| {
+                let cli = Cli::parse_from([&["pomodoro", "stats"], args].concat());
+                let Some(Commands::Stats(args)) = cli.command else {
+                    panic!("Expected Stats command");
+                };
+                args
+            };
+
+            let args = parse(&["--since", "2026-03-01", "--until", "2026-03-07"]);
+            assert_eq!(
+                args.range(march(10)).unwrap(),
+                Some(DayRange::new(march(1), march(7)).unwrap())
+            );
+
+            let args = parse(&["--since", "2026-03-01"]);
+            assert_eq!(
+                args.range(march(10)).unwrap(),
+                Some(DayRange::new(march(1), march(10)).unwrap())
+            );
+
+            let args = parse(&["--until", "2026-03-07"]);
+            assert_eq!(
+                args.range(march(10)).unwrap(),
+                Some(DayRange::day(march(7)))
+            );
+
+            assert_eq!(parse(&[]).range(march(10)).unwrap(), None);
+
+            let args = parse(&["--since", "2026-03-08", "--until", "2026-03-07"]);
+            assert!(args.range(march(10)).is_err());
+
+            assert!(Cli::try_parse_from(["pomodoro", "stats", "--since", "March 1"]).is_err());
+        }
+
+        #[test]
+        fn test_parse_stats_export() {
+            let cli = Cli::parse_from(["pomodoro", "stats", "--export", "csv"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Stats(args)) if args.export == Some(ExportFormat::Csv)
+            ));
+
+            let cli = Cli::parse_from(["pomodoro", "stats", "--export", "json"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Stats(args)) if args.export == Some(ExportFormat::Json)
+            ));
+
+            assert!(Cli::try_parse_from(["pomodoro", "stats", "--export", "xml"]).is_err());
+            assert!(
+                Cli::try_parse_from(["pomodoro", "stats", "--export", "csv", "--by-tag"]).is_err()
+            );
+        }
+
+        #[test]
+        fn test_parse_sound_devices_command() {
+            let cli = Cli::parse_from(["pomodoro", "sound", "devices"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Sound(SoundCommand::Devices))
+            ));
+        }
+
+        #[test]
+        fn test_parse_log_command() {
+            let cli = Cli::parse_from(["pomodoro", "log"]);
+            match cli.command {
+                Some(Commands::Log(args)) => {
+                    assert!(!args.follow);
+                    assert_eq!(args.lines, DEFAULT_LOG_LINES);
+                }
+                _ => panic!("Expected Log command"),
+            }
+
+            let cli = Cli::parse_from(["pomodoro", "log", "-f", "-n", "5"]);
+            match cli.command {
+                Some(Commands::Log(args)) => {
+                    assert!(args.follow);
+                    assert_eq!(args.lines, 5);
+                }
+                _ => panic!("Expected Log command"),
+            }
+        }
+
+        #[test]
+        fn test_parse_setup_notifications_command() {
+            let cli = Cli::parse_from(["pomodoro", "setup", "notifications"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Setup(SetupCommand::Notifications))
+            ));
+
+            assert!(Cli::try_parse_from(["pomodoro", "setup"]).is_err());
+        }
+
+        #[test]
+        fn test_parse_status_command() {
+            let cli = Cli::parse_from(["pomodoro", "status"]);
+            assert!(matches!(cli.command, Some(Commands::Status(_))));
+        }
+
+        #[test]
+        fn test_parse_status_cache_flags() {
+            let cli = Cli::parse_from(["pomodoro", "status", "--short", "--cache"]);
+            match cli.command {
+                Some(Commands::Status(args)) => {
+                    assert!(args.cache);
+                    assert_eq!(args.cache_ttl, 1000);
+                }
+                _ => panic!("Expected Status command"),
+            }
+
+            let cli = Cli::parse_from([
+                "pomodoro",
+                "status",
+                "--short",
+                "--cache",
+                "--cache-ttl",
+                "250",
+            ]);
+            match cli.command {
+                Some(Commands::Status(args)) => assert_eq!(args.cache_ttl, 250),
+                _ => panic!("Expected Status command"),
+            }
+
+            assert!(Cli::try_parse_from(["pomodoro", "status", "--cache"]).is_err());
+            assert!(
+                Cli::try_parse_from(["pomodoro", "status", "--short", "--cache-ttl", "5"]).is_err()
+            );
+        }
+
+        #[test]
+        fn test_parse_status_icon_style() {
+            let cli = Cli::parse_from(["pomodoro", "status", "--short", "--icon-style", "ascii"]);
+            match cli.command {
+                Some(Commands::Status(args)) => assert_eq!(args.icon_style, Some(IconStyle::Ascii)),
+                _ => panic!("Expected Status command"),
+            }
+
+            assert!(
+                Cli::try_parse_from(["pomodoro", "status", "--icon-style", "nerdfont"]).is_err()
+            );
+        }
+
+        #[test]
+        fn test_parse_status_short_flag() {
+            let cli = Cli::parse_from(["pomodoro", "status", "--short"]);
+            match cli.command {
+                Some(Commands::Status(args)) => assert!(args.short),
+                _ => panic!("Expected Status command"),
+            }
+        }
+
+        #[test]
+        fn test_parse_status_format() {
+            let cli = Cli::parse_from(["pomodoro", "status", "--format", "{phase} {count}"]);
+            match cli.command {
+                Some(Commands::Status(args)) => {
+                    assert_eq!(args.format.as_deref(), Some("{phase} {count}"))
+                }
+                _ => panic!("Expected Status command"),
+            }
+
+            let err = Cli::try_parse_from(["pomodoro", "status", "--format", "{elapsed}"])
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("remaining_mmss"));
+            assert!(
+                Cli::try_parse_from(["pomodoro", "status", "--format", "{phase}", "--short"])
+                    .is_err()
+            );
+        }
+
+        #[test]
+        fn test_parse_pause_command() {
+            let cli = Cli::parse_from(["pomodoro", "pause"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Pause(PauseArgs { reason: None }))
+            ));
+
+            let cli = Cli::parse_from(["pomodoro", "pause", "--reason", "meeting"]);
+            match cli.command {
+                Some(Commands::Pause(args)) => assert_eq!(args.reason.as_deref(), Some("meeting")),
+                _ => panic!("Expected Pause command"),
+            }
+        }
+
+        #[test]
+        fn test_parse_resume_command() {
+            let cli = Cli::parse_from(["pomodoro", "resume"]);
+            assert!(matches!(cli.command, Some(Commands::Resume)));
+        }
+
+        #[test]
+        fn test_parse_stop_command() {
+            let cli = Cli::parse_from(["pomodoro", "stop"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Stop(StopArgs { yes: false }))
+            ));
+
+            let cli = Cli::parse_from(["pomodoro", "stop", "--force"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Stop(StopArgs { yes: true }))
+            ));
+        }
+
+        #[test]
+        fn test_parse_skip_command() {
+            let cli = Cli::parse_from(["pomodoro", "skip"]);
+            assert!(matches!(cli.command, Some(Commands::Skip)));
+        }
+
+        #[test]
+        fn test_parse_suspend_and_unsuspend_commands() {
+            let cli = Cli::parse_from(["pomodoro", "suspend"]);
+            assert!(matches!(cli.command, Some(Commands::Suspend)));
+
+            let cli = Cli::parse_from(["pomodoro", "unsuspend"]);
+            assert!(matches!(cli.command, Some(Commands::Unsuspend)));
+        }
+
+        #[test]
+        fn test_parse_doctor_command() {
+            let cli = Cli::parse_from(["pomodoro", "doctor"]);
+            assert!(matches!(cli.command, Some(Commands::Doctor)));
+        }
+
+        #[test]
+        fn test_parse_reset_command() {
+            let cli = Cli::parse_from(["pomodoro", "reset"]);
+            assert!(matches!(cli.command, Some(Commands::Reset)));
         }
-    }
-}
 
-// ============================================================================
-// Validation Functions
-// ============================================================================
+        #[test]
+        fn test_parse_watch_events_filter() {
+            let cli = Cli::parse_from(["pomodoro", "watch-events", "--filter", "tick"]);
+            match cli.command {
+                Some(Commands::WatchEvents(args)) => {
+                    assert!(!args.shows(&TimerEvent::Tick {
+                        remaining_seconds: 1
+                    }));
+                    assert!(args.shows(&TimerEvent::Paused { reason: None }));
+                }
+                _ => panic!("Expected WatchEvents command"),
+            }
 
-/// Validates the task name.
-///
-/// - Must not be empty
-/// - Must not exceed 100 characters
-fn validate_task_name(s: &str) -> Result<String, String> {
-    if s.is_empty() {
-        return Err("タスク名は空にできません".to_string());
-    }
-    if s.len() > 100 {
-        return Err("タスク名は100文字以内にしてください".to_string());
-    }
-    Ok(s.to_string())
-}
+            assert!(
+                Cli::try_parse_from(["pomodoro", "watch-events", "--filter", "bogus"]).is_err()
+            );
+        }
 
-// ============================================================================
-// Tests
-// ============================================================================
+        #[test]
+        fn test_parse_snooze_command() {
+            let cli = Cli::parse_from(["pomodoro", "snooze"]);
+            match cli.command {
+                Some(Commands::Snooze(args)) => assert_eq!(args.minutes, 5),
+                _ => panic!("Expected Snooze command"),
+            }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+            let cli = Cli::parse_from(["pomodoro", "snooze", "--minutes", "10"]);
+            match cli.command {
+                Some(Commands::Snooze(args)) => assert_eq!(args.minutes, 10),
+                _ => panic!("Expected Snooze command"),
+            }
+        }
 
-    // ------------------------------------------------------------------------
-    // Cli Tests
-    // ------------------------------------------------------------------------
+        #[test]
+        fn test_parse_run_command() {
+            let cli = Cli::parse_from(["pomodoro", "run", "--work", "25", "--task", "foo"]);
+            let Some(Commands::Run(args)) = cli.command else {
+                panic!("Expected Run command");
+            };
+            assert_eq!(args.task, Some("foo".to_string()));
+            assert!(!args.no_sound);
+            assert!(!args.no_seconds);
+
+            let base = PomodoroConfig::default().with_work_minutes(40);
+            assert_eq!(args.resolve_config(&base).work_duration.as_minutes(), 25);
+            assert_eq!(args.tick_interval(), Duration::from_secs(1));
+
+            let cli = Cli::parse_from(["pomodoro", "run", "--no-seconds"]);
+            let Some(Commands::Run(args)) = cli.command else {
+                panic!("Expected Run command");
+            };
+            assert_eq!(args.resolve_config(&base).work_duration.as_minutes(), 40);
+            assert!(args.no_seconds);
+        }
 
-    mod cli_tests {
-        use super::*;
+        #[test]
+        fn test_parse_daemon_command() {
+            let cli = Cli::parse_from(["pomodoro", "daemon"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => assert!(args.log_file.is_none()),
+                _ => panic!("Expected Daemon command"),
+            }
+        }
 
         #[test]
-        fn test_parse_no_args() {
-            let cli = Cli::parse_from(["pomodoro"]);
-            assert!(cli.command.is_none());
-            assert!(!cli.verbose);
+        fn test_parse_daemon_log_file() {
+            let cli = Cli::parse_from(["pomodoro", "daemon", "--log-file", "/tmp/daemon.log"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => {
+                    assert_eq!(args.log_file, Some(PathBuf::from("/tmp/daemon.log")));
+                }
+                _ => panic!("Expected Daemon command"),
+            }
         }
 
         #[test]
-        fn test_parse_verbose_flag() {
-            let cli = Cli::parse_from(["pomodoro", "--verbose"]);
-            assert!(cli.verbose);
+        fn test_parse_daemon_auto_start_after() {
+            let cli = Cli::parse_from(["pomodoro", "daemon"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => assert_eq!(args.auto_start_after, 0),
+                _ => panic!("Expected Daemon command"),
+            }
+
+            let cli = Cli::parse_from(["pomodoro", "daemon", "--auto-start-after", "2"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => assert_eq!(args.auto_start_after, 2),
+                _ => panic!("Expected Daemon command"),
+            }
         }
 
         #[test]
-        fn test_parse_short_verbose_flag() {
-            let cli = Cli::parse_from(["pomodoro", "-v"]);
-            assert!(cli.verbose);
+        fn test_parse_daemon_break_reminder() {
+            let cli = Cli::parse_from(["pomodoro", "daemon"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => assert_eq!(args.break_reminder, 0),
+                _ => panic!("Expected Daemon command"),
+            }
+
+            let cli = Cli::parse_from(["pomodoro", "daemon", "--break-reminder", "1"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => assert_eq!(args.break_reminder, 1),
+                _ => panic!("Expected Daemon command"),
+            }
+
+            assert!(Cli::try_parse_from(["pomodoro", "daemon", "--break-reminder", "11"]).is_err());
         }
 
         #[test]
-        fn test_parse_status_command() {
-            let cli = Cli::parse_from(["pomodoro", "status"]);
-            assert!(matches!(cli.command, Some(Commands::Status)));
+        fn test_parse_daemon_log_task_names() {
+            let cli = Cli::parse_from(["pomodoro", "daemon"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => assert!(!args.log_task_names),
+                _ => panic!("Expected Daemon command"),
+            }
+
+            let cli = Cli::parse_from(["pomodoro", "daemon", "--log-task-names"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => assert!(args.log_task_names),
+                _ => panic!("Expected Daemon command"),
+            }
         }
 
         #[test]
-        fn test_parse_pause_command() {
-            let cli = Cli::parse_from(["pomodoro", "pause"]);
-            assert!(matches!(cli.command, Some(Commands::Pause)));
+        fn test_parse_daemon_exclusive() {
+            let cli = Cli::parse_from(["pomodoro", "daemon", "--exclusive"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => assert!(args.exclusive),
+                _ => panic!("Expected Daemon command"),
+            }
         }
 
         #[test]
-        fn test_parse_resume_command() {
-            let cli = Cli::parse_from(["pomodoro", "resume"]);
-            assert!(matches!(cli.command, Some(Commands::Resume)));
+        fn test_parse_daemon_event_capacity() {
+            let cli = Cli::parse_from(["pomodoro", "daemon", "--event-capacity", "1024"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => assert_eq!(args.event_capacity, Some(1024)),
+                _ => panic!("Expected Daemon command"),
+            }
+
+            assert!(Cli::try_parse_from(["pomodoro", "daemon", "--event-capacity", "0"]).is_err());
         }
 
         #[test]
-        fn test_parse_stop_command() {
-            let cli = Cli::parse_from(["pomodoro", "stop"]);
-            assert!(matches!(cli.command, Some(Commands::Stop)));
+        fn test_parse_daemon_notification_flags() {
+            let cli =
+                Cli::parse_from(["pomodoro", "daemon", "--terminal-notifications", "--quiet"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => {
+                    assert!(args.terminal_notifications);
+                    assert!(args.quiet);
+                }
+                _ => panic!("Expected Daemon command"),
+            }
         }
 
         #[test]
-        fn test_parse_daemon_command() {
-            let cli = Cli::parse_from(["pomodoro", "daemon"]);
-            assert!(matches!(cli.command, Some(Commands::Daemon)));
+        fn test_parse_daemon_events_file() {
+            let cli = Cli::parse_from(["pomodoro", "daemon", "--events-file", "/tmp/events.jsonl"]);
+            match cli.command {
+                Some(Commands::Daemon(args)) => {
+                    assert_eq!(args.events_file, Some(PathBuf::from("/tmp/events.jsonl")));
+                }
+                _ => panic!("Expected Daemon command"),
+            }
         }
 
         #[test]
         fn test_parse_install_command() {
             let cli = Cli::parse_from(["pomodoro", "install"]);
-            assert!(matches!(cli.command, Some(Commands::Install)));
+            match cli.command {
+                Some(Commands::Install(args)) => assert!(!args.check),
+                _ => panic!("Expected Install command"),
+            }
+        }
+
+        #[test]
+        fn test_parse_install_check() {
+            let cli = Cli::parse_from(["pomodoro", "install", "--check"]);
+            match cli.command {
+                Some(Commands::Install(args)) => assert!(args.check),
+                _ => panic!("Expected Install command"),
+            }
         }
 
         #[test]
         fn test_parse_uninstall_command() {
             let cli = Cli::parse_from(["pomodoro", "uninstall"]);
-            assert!(matches!(cli.command, Some(Commands::Uninstall)));
+            match cli.command {
+                Some(Commands::Uninstall(args)) => {
+                    assert!(!args.purge);
+                    assert!(!args.yes);
+                }
+                _ => panic!("Expected Uninstall command"),
+            }
+        }
+
+        #[test]
+        fn test_parse_uninstall_purge() {
+            let cli = Cli::parse_from(["pomodoro", "uninstall", "--purge", "--yes"]);
+            match cli.command {
+                Some(Commands::Uninstall(args)) => {
+                    assert!(args.purge);
+                    assert!(args.yes);
+                }
+                _ => panic!("Expected Uninstall command"),
+            }
+
+            // --yes only makes sense together with --purge
+            assert!(Cli::try_parse_from(["pomodoro", "uninstall", "--yes"]).is_err());
         }
 
         #[test]
         fn test_parse_completions_bash() {
             let cli = Cli::parse_from(["pomodoro", "completions", "bash"]);
             match cli.command {
-                Some(Commands::Completions { shell }) => {
+                Some(Commands::Completions { shell, .. }) => {
                     assert_eq!(shell, clap_complete::Shell::Bash);
                 }
                 _ => panic!("Expected Completions command"),
@@ -242,18 +1358,40 @@ mod tests {
         fn test_parse_completions_zsh() {
             let cli = Cli::parse_from(["pomodoro", "completions", "zsh"]);
             match cli.command {
-                Some(Commands::Completions { shell }) => {
+                Some(Commands::Completions { shell, .. }) => {
+                    assert_eq!(shell, clap_complete::Shell::Zsh);
+                }
+                _ => panic!("Expected Completions command"),
+            }
+        }
+
+        #[test]
+        fn test_parse_completions_install() {
+            let cli = Cli::parse_from(["pomodoro", "completions", "zsh", "--install", "--force"]);
+            match cli.command {
+                Some(Commands::Completions {
+                    shell,
+                    install,
+                    force,
+                }) => {
                     assert_eq!(shell, clap_complete::Shell::Zsh);
+                    assert!(install);
+                    assert!(force);
                 }
                 _ => panic!("Expected Completions command"),
             }
         }
 
+        #[test]
+        fn test_parse_completions_force_requires_install() {
+            assert!(Cli::try_parse_from(["pomodoro", "completions", "zsh", "--force"]).is_err());
+        }
+
         #[test]
         fn test_parse_completions_fish() {
             let cli = Cli::parse_from(["pomodoro", "completions", "fish"]);
             match cli.command {
-                Some(Commands::Completions { shell }) => {
+                Some(Commands::Completions { shell, .. }) => {
                     assert_eq!(shell, clap_complete::Shell::Fish);
                 }
                 _ => panic!("Expected Completions command"),
@@ -273,9 +1411,10 @@ mod tests {
             let cli = Cli::parse_from(["pomodoro", "start"]);
             match cli.command {
                 Some(Commands::Start(args)) => {
-                    assert_eq!(args.work, 25);
-                    assert_eq!(args.break_time, 5);
-                    assert_eq!(args.long_break, 15);
+                    assert_eq!(args.preset, None);
+                    assert_eq!(args.work, None);
+                    assert_eq!(args.break_time, None);
+                    assert_eq!(args.long_break, None);
                     assert!(args.task.is_none());
                     assert!(!args.auto_cycle);
                     assert!(!args.focus_mode);
@@ -290,7 +1429,7 @@ mod tests {
             let cli = Cli::parse_from(["pomodoro", "start", "--work", "30"]);
             match cli.command {
                 Some(Commands::Start(args)) => {
-                    assert_eq!(args.work, 30);
+                    assert_eq!(args.work, Some(30));
                 }
                 _ => panic!("Expected Start command"),
             }
@@ -301,7 +1440,7 @@ mod tests {
             let cli = Cli::parse_from(["pomodoro", "start", "-w", "45"]);
             match cli.command {
                 Some(Commands::Start(args)) => {
-                    assert_eq!(args.work, 45);
+                    assert_eq!(args.work, Some(45));
                 }
                 _ => panic!("Expected Start command"),
             }
@@ -312,7 +1451,7 @@ mod tests {
             let cli = Cli::parse_from(["pomodoro", "start", "--break-time", "10"]);
             match cli.command {
                 Some(Commands::Start(args)) => {
-                    assert_eq!(args.break_time, 10);
+                    assert_eq!(args.break_time, Some(10));
                 }
                 _ => panic!("Expected Start command"),
             }
@@ -323,7 +1462,7 @@ mod tests {
             let cli = Cli::parse_from(["pomodoro", "start", "--long-break", "20"]);
             match cli.command {
                 Some(Commands::Start(args)) => {
-                    assert_eq!(args.long_break, 20);
+                    assert_eq!(args.long_break, Some(20));
                 }
                 _ => panic!("Expected Start command"),
             }
@@ -373,6 +1512,15 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_parse_start_wait() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--wait"]);
+            match cli.command {
+                Some(Commands::Start(args)) => assert!(args.wait),
+                _ => panic!("Expected Start command"),
+            }
+        }
+
         #[test]
         fn test_parse_start_no_sound() {
             let cli = Cli::parse_from(["pomodoro", "start", "--no-sound"]);
@@ -384,6 +1532,47 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_parse_start_task_from_clipboard() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--task-from-clipboard"]);
+            match cli.command {
+                Some(Commands::Start(args)) => {
+                    assert!(args.task_from_clipboard);
+                    assert!(args.task.is_none());
+                }
+                _ => panic!("Expected Start command"),
+            }
+        }
+
+        #[test]
+        fn test_parse_start_no_long_break() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--no-long-break"]);
+            match cli.command {
+                Some(Commands::Start(args)) => assert!(args.no_long_break),
+                _ => panic!("Expected Start command"),
+            }
+        }
+
+        #[test]
+        fn test_parse_start_strict() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--strict"]);
+            match cli.command {
+                Some(Commands::Start(args)) => assert!(args.strict),
+                _ => panic!("Expected Start command"),
+            }
+        }
+
+        #[test]
+        fn test_parse_start_cycles() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--cycles", "4"]);
+            match cli.command {
+                Some(Commands::Start(args)) => assert_eq!(args.cycles, Some(4)),
+                _ => panic!("Expected Start command"),
+            }
+
+            assert!(Cli::try_parse_from(["pomodoro", "start", "--cycles", "0"]).is_err());
+        }
+
         #[test]
         fn test_parse_start_all_options() {
             let cli = Cli::parse_from([
@@ -403,9 +1592,9 @@ mod tests {
             ]);
             match cli.command {
                 Some(Commands::Start(args)) => {
-                    assert_eq!(args.work, 50);
-                    assert_eq!(args.break_time, 10);
-                    assert_eq!(args.long_break, 30);
+                    assert_eq!(args.work, Some(50));
+                    assert_eq!(args.break_time, Some(10));
+                    assert_eq!(args.long_break, Some(30));
                     assert_eq!(args.task, Some("Deep work".to_string()));
                     assert!(args.auto_cycle);
                     assert!(args.focus_mode);
@@ -420,7 +1609,7 @@ mod tests {
             let cli = Cli::parse_from(["pomodoro", "start", "--work", "1"]);
             match cli.command {
                 Some(Commands::Start(args)) => {
-                    assert_eq!(args.work, 1);
+                    assert_eq!(args.work, Some(1));
                 }
                 _ => panic!("Expected Start command"),
             }
@@ -431,7 +1620,7 @@ mod tests {
             let cli = Cli::parse_from(["pomodoro", "start", "--work", "120"]);
             match cli.command {
                 Some(Commands::Start(args)) => {
-                    assert_eq!(args.work, 120);
+                    assert_eq!(args.work, Some(120));
                 }
                 _ => panic!("Expected Start command"),
             }
@@ -442,7 +1631,7 @@ mod tests {
             let cli = Cli::parse_from(["pomodoro", "start", "--break-time", "1"]);
             match cli.command {
                 Some(Commands::Start(args)) => {
-                    assert_eq!(args.break_time, 1);
+                    assert_eq!(args.break_time, Some(1));
                 }
                 _ => panic!("Expected Start command"),
             }
@@ -453,7 +1642,7 @@ mod tests {
             let cli = Cli::parse_from(["pomodoro", "start", "--break-time", "60"]);
             match cli.command {
                 Some(Commands::Start(args)) => {
-                    assert_eq!(args.break_time, 60);
+                    assert_eq!(args.break_time, Some(60));
                 }
                 _ => panic!("Expected Start command"),
             }
@@ -462,13 +1651,186 @@ mod tests {
         #[test]
         fn test_start_args_default() {
             let args = StartArgs::default();
-            assert_eq!(args.work, 25);
-            assert_eq!(args.break_time, 5);
-            assert_eq!(args.long_break, 15);
+            assert_eq!(args.preset, None);
+            assert_eq!(args.work, None);
+            assert_eq!(args.break_time, None);
+            assert_eq!(args.long_break, None);
             assert!(args.task.is_none());
             assert!(!args.auto_cycle);
             assert!(!args.focus_mode);
             assert!(!args.no_sound);
+            assert!(!args.task_from_clipboard);
+            assert!(!args.strict);
+            assert!(args.cycles.is_none());
+        }
+
+        #[test]
+        fn test_parse_start_preset() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--preset", "deepwork"]);
+            match cli.command {
+                Some(Commands::Start(args)) => assert_eq!(args.preset, Some(Preset::DeepWork)),
+                _ => panic!("Expected Start command"),
+            }
+
+            assert!(Cli::try_parse_from(["pomodoro", "start", "--preset", "unknown"]).is_err());
+        }
+
+        #[test]
+        fn test_parse_start_resume_if_paused() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--resume-if-paused"]);
+            match cli.command {
+                Some(Commands::Start(args)) => assert!(args.resume_if_paused),
+                _ => panic!("Expected Start command"),
+            }
+        }
+
+        #[test]
+        fn test_parse_start_paused() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--paused"]);
+            match cli.command {
+                Some(Commands::Start(args)) => assert!(args.paused),
+                _ => panic!("Expected Start command"),
+            }
+
+            assert!(
+                Cli::try_parse_from(["pomodoro", "start", "--paused", "--resume-if-paused"])
+                    .is_err()
+            );
+        }
+
+        #[test]
+        fn test_parse_start_tag() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--tag", "project-x", "-t", "Docs"]);
+            match cli.command {
+                Some(Commands::Start(args)) => {
+                    assert_eq!(args.tag.as_deref(), Some("project-x"));
+                    assert_eq!(args.task.as_deref(), Some("Docs"));
+                }
+                _ => panic!("Expected Start command"),
+            }
+
+            assert!(Cli::try_parse_from(["pomodoro", "start", "--tag", " "]).is_err());
+        }
+
+        #[test]
+        fn test_parse_start_if_not_running_and_force() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--if-not-running", "--force"]);
+            match cli.command {
+                Some(Commands::Start(args)) => {
+                    assert!(args.if_not_running);
+                    assert!(args.force);
+                }
+                _ => panic!("Expected Start command"),
+            }
+
+            assert!(Cli::try_parse_from([
+                "pomodoro",
+                "start",
+                "--if-not-running",
+                "--resume-if-paused"
+            ])
+            .is_err());
+        }
+
+        #[test]
+        fn test_parse_start_task_queue() {
+            let cli = Cli::parse_from(["pomodoro", "start", "--task-queue", "a;b;c"]);
+            let Some(Commands::Start(args)) = cli.command else {
+                panic!("Expected Start command");
+            };
+            assert_eq!(args.task_queue, ["a", "b", "c"]);
+            assert!(args.resolve_config(&PomodoroConfig::default()).auto_cycle);
+
+            assert!(Cli::try_parse_from(["pomodoro", "start", "--task-queue", "a;;b"]).is_err());
+            assert!(
+                Cli::try_parse_from(["pomodoro", "start", "--task-queue", "a", "--task", "b"])
+                    .is_err()
+            );
+        }
+
+        #[test]
+        fn test_resolve_config_without_flags_uses_base() {
+            let base = PomodoroConfig::default()
+                .with_work_minutes(40)
+                .with_strict(true);
+
+            assert_eq!(StartArgs::default().resolve_config(&base), base);
+        }
+
+        #[test]
+        fn test_resolve_config_preset_overrides_base() {
+            let base = PomodoroConfig::default().with_work_minutes(40);
+            let args = StartArgs {
+                preset: Some(Preset::ShortCycle),
+                ..Default::default()
+            };
+
+            assert_eq!(
+                args.resolve_config(&base),
+                Preset::ShortCycle.apply(base.clone())
+            );
+        }
+
+        #[test]
+        fn test_resolve_config_explicit_flags_override_preset() {
+            let cli = Cli::parse_from([
+                "pomodoro",
+                "start",
+                "--preset",
+                "deepwork",
+                "--work",
+                "45",
+                "--no-long-break",
+                "--cycles",
+                "2",
+            ]);
+            let Some(Commands::Start(args)) = cli.command else {
+                panic!("Expected Start command");
+            };
+
+            let config = args.resolve_config(&PomodoroConfig::default());
+
+            assert_eq!(config.work_duration.as_minutes(), 45);
+            assert_eq!(config.break_duration.as_minutes(), 10);
+            assert_eq!(config.long_break_duration.as_minutes(), 30);
+            assert_eq!(config.long_break_interval, 3);
+            assert!(!config.long_break_enabled);
+            assert!(config.auto_cycle);
+            assert_eq!(config.max_cycles, Some(2));
+        }
+
+        #[test]
+        fn test_start_params_without_flags_leave_config_alone() {
+            let base = PomodoroConfig::default()
+                .with_work_minutes(40)
+                .with_strict(true);
+
+            assert_eq!(StartArgs::default().start_params().apply_to(&base), None);
+        }
+
+        #[test]
+        fn test_start_params_apply_like_resolve_config() {
+            let base = PomodoroConfig::default().with_break_minutes(8);
+            let cli = Cli::parse_from([
+                "pomodoro",
+                "start",
+                "--preset",
+                "deepwork",
+                "--work",
+                "45",
+                "--strict",
+                "--no-long-break",
+                "--cycles",
+                "2",
+            ]);
+            let Some(Commands::Start(args)) = cli.command else {
+                panic!("Expected Start command");
+            };
+
+            let params = args.start_params();
+
+            assert_eq!(params.apply_to(&base), Some(args.resolve_config(&base)));
+            assert_eq!(params.focus_mode, None);
         }
     }
 
@@ -528,6 +1890,54 @@ mod tests {
     mod error_tests {
         use super::*;
 
+        #[test]
+        fn test_parse_break_command() {
+            let cli = Cli::parse_from(["pomodoro", "break", "--minutes", "10"]);
+            match cli.command {
+                Some(Commands::Break(args)) => assert_eq!(args.minutes, Some(10)),
+                _ => panic!("Expected Break command"),
+            }
+
+            let cli = Cli::parse_from(["pomodoro", "break"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::Break(BreakArgs {
+                    minutes: None,
+                    repeat: None
+                }))
+            ));
+            assert!(Cli::try_parse_from(["pomodoro", "break", "--minutes", "0"]).is_err());
+
+            let cli = Cli::parse_from(["pomodoro", "break", "--minutes", "2", "--repeat", "5"]);
+            match cli.command {
+                Some(Commands::Break(args)) => assert_eq!(args.repeat, Some(5)),
+                _ => panic!("Expected Break command"),
+            }
+            assert!(Cli::try_parse_from(["pomodoro", "break", "--repeat", "0"]).is_err());
+        }
+
+        #[test]
+        fn test_parse_long_break_command() {
+            let cli = Cli::parse_from(["pomodoro", "long-break", "--minutes", "30"]);
+            match cli.command {
+                Some(Commands::LongBreak(args)) => assert_eq!(args.minutes, Some(30)),
+                _ => panic!("Expected LongBreak command"),
+            }
+
+            let cli = Cli::parse_from(["pomodoro", "long-break"]);
+            assert!(matches!(
+                cli.command,
+                Some(Commands::LongBreak(LongBreakArgs { minutes: None }))
+            ));
+            assert!(Cli::try_parse_from(["pomodoro", "long-break", "--minutes", "61"]).is_err());
+        }
+
+        #[test]
+        fn test_parse_snooze_minutes_out_of_range() {
+            assert!(Cli::try_parse_from(["pomodoro", "snooze", "--minutes", "0"]).is_err());
+            assert!(Cli::try_parse_from(["pomodoro", "snooze", "--minutes", "31"]).is_err());
+        }
+
         #[test]
         fn test_parse_start_work_too_low() {
             let result = Cli::try_parse_from(["pomodoro", "start", "--work", "0"]);
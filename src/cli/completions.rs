@@ -0,0 +1,163 @@
+//! Shell completion scripts for the `completions` command.
+//!
+//! By default the script is printed to stdout. With `--install` it is
+//! written to the conventional per-shell location under the home directory.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+use super::commands::Cli;
+
+// ============================================================================
+// Generation
+// ============================================================================
+
+/// Writes the completion script for `shell` to `writer`.
+pub fn generate(shell: Shell, writer: &mut dyn Write) {
+    let mut cmd = Cli::command();
+    let bin_name = cmd.get_name().to_string();
+    clap_complete::generate(shell, &mut cmd, bin_name, writer);
+}
+
+// ============================================================================
+// Installation
+// ============================================================================
+
+/// Returns where the completion script for `shell` is installed, relative to `home`.
+///
+/// # Errors
+///
+/// Returns an error for shells without a conventional per-user location.
+pub fn install_path(shell: Shell, home: &Path) -> Result<PathBuf> {
+    let path = match shell {
+        Shell::Bash => ".local/share/bash-completion/completions/pomodoro",
+        Shell::Zsh => ".zsh/completions/_pomodoro",
+        Shell::Fish => ".config/fish/completions/pomodoro.fish",
+        Shell::Elvish => ".config/elvish/lib/pomodoro.elv",
+        _ => bail!(
+            "{} の補完スクリプトのインストールには対応していません",
+            shell
+        ),
+    };
+    Ok(home.join(path))
+}
+
+/// Returns a one-line hint on how to enable the installed script.
+pub fn source_hint(shell: Shell, path: &Path) -> String {
+    match shell {
+        Shell::Zsh => format!(
+            "~/.zshrc に `fpath=({} $fpath)` を追加し、compinit を実行してください",
+            path.parent().unwrap_or(path).display()
+        ),
+        Shell::Elvish => "~/.config/elvish/rc.elv に `use pomodoro` を追加してください".to_string(),
+        Shell::Fish => "新しいシェルから自動的に読み込まれます".to_string(),
+        _ => format!(
+            "新しいシェルから自動的に読み込まれます（今すぐ使うには `source {}`）",
+            path.display()
+        ),
+    }
+}
+
+/// Installs the completion script for `shell` under `home`.
+///
+/// Creates the target directory if it is missing. An existing script is
+/// only replaced when `force` is set.
+///
+/// # Errors
+///
+/// Returns an error if the shell is unsupported, the script already exists
+/// without `force`, or the file cannot be written.
+pub fn install(shell: Shell, home: &Path, force: bool) -> Result<PathBuf> {
+    let path = install_path(shell, home)?;
+
+    if path.exists() && !force {
+        bail!(
+            "{} は既に存在します（上書きするには --force を指定してください）",
+            path.display()
+        );
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("ディレクトリを作成できません: {}", parent.display()))?;
+    }
+
+    let mut script = Vec::new();
+    generate(shell, &mut script);
+    fs::write(&path, script)
+        .with_context(|| format!("補完スクリプトを書き込めません: {}", path.display()))?;
+
+    Ok(path)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_install_path_per_shell() {
+        let home = Path::new("/Users/test");
+
+        assert_eq!(
+            install_path(Shell::Zsh, home).unwrap(),
+            home.join(".zsh/completions/_pomodoro")
+        );
+        assert_eq!(
+            install_path(Shell::Bash, home).unwrap(),
+            home.join(".local/share/bash-completion/completions/pomodoro")
+        );
+        assert_eq!(
+            install_path(Shell::Fish, home).unwrap(),
+            home.join(".config/fish/completions/pomodoro.fish")
+        );
+        assert_eq!(
+            install_path(Shell::Elvish, home).unwrap(),
+            home.join(".config/elvish/lib/pomodoro.elv")
+        );
+        assert!(install_path(Shell::PowerShell, home).is_err());
+    }
+
+    #[test]
+    fn test_install_creates_directory_and_script() {
+        let home = TempDir::new().unwrap();
+
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::Elvish] {
+            let path = install(shell, home.path(), false).unwrap();
+
+            assert_eq!(path, install_path(shell, home.path()).unwrap());
+            let script = fs::read_to_string(&path).unwrap();
+            assert!(script.contains("pomodoro"), "{} script is empty", shell);
+        }
+    }
+
+    #[test]
+    fn test_install_refuses_overwrite_without_force() {
+        let home = TempDir::new().unwrap();
+        let path = install(Shell::Zsh, home.path(), false).unwrap();
+        fs::write(&path, "custom").unwrap();
+
+        assert!(install(Shell::Zsh, home.path(), false).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "custom");
+
+        install(Shell::Zsh, home.path(), true).unwrap();
+        assert_ne!(fs::read_to_string(&path).unwrap(), "custom");
+    }
+
+    #[test]
+    fn test_source_hint_mentions_zsh_fpath() {
+        let path = Path::new("/Users/test/.zsh/completions/_pomodoro");
+        let hint = source_hint(Shell::Zsh, path);
+
+        assert!(hint.contains("fpath=(/Users/test/.zsh/completions $fpath)"));
+    }
+}
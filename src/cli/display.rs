@@ -5,8 +5,71 @@
 //! - Error messages
 //! - Status display
 //! - Timer information
+//! - Timer events for `watch-events`
+//! - Phase colors for `status` (`--color`)
 
-use crate::types::IpcResponse;
+use std::io::IsTerminal;
+
+use clap::ValueEnum;
+
+use crate::cli::doctor::Check;
+use crate::daemon::TimestampedEvent;
+use crate::menubar::{format_progress_bar, IconManager, IconStyle, ShowSeconds};
+use crate::types::{
+    IpcResponse, PomodoroConfig, ResponseData, TimerPhase, TimerState, SESSION_ALREADY_RUNNING,
+};
+
+/// Environment variable that turns colors off (see <https://no-color.org>)
+const NO_COLOR_ENV: &str = "NO_COLOR";
+
+/// ANSI sequence ending a colored span
+const COLOR_RESET: &str = "\x1b[0m";
+
+// ============================================================================
+// ColorMode
+// ============================================================================
+
+/// When to color output (`--color`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorMode {
+    /// Color when stdout is a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    /// Always color
+    Always,
+    /// Never color
+    Never,
+}
+
+impl ColorMode {
+    /// Returns whether to color stdout in this environment.
+    pub fn enabled(self) -> bool {
+        let no_color = std::env::var_os(NO_COLOR_ENV).is_some_and(|value| !value.is_empty());
+        self.resolve(no_color, std::io::stdout().is_terminal())
+    }
+
+    /// Returns whether to color, given whether `NO_COLOR` is set and stdout
+    /// is a terminal.
+    pub fn resolve(self, no_color: bool, is_terminal: bool) -> bool {
+        match self {
+            ColorMode::Auto => !no_color && is_terminal,
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+        }
+    }
+}
+
+/// Returns the ANSI color sequence for a phase, if it has one.
+///
+/// Working is red, breaks are green and paused is blue; stopped is plain.
+pub fn phase_color(phase: TimerPhase) -> Option<&'static str> {
+    match phase {
+        TimerPhase::Working => Some("\x1b[31m"),
+        TimerPhase::Breaking | TimerPhase::LongBreaking => Some("\x1b[32m"),
+        TimerPhase::Paused => Some("\x1b[34m"),
+        TimerPhase::Stopped => None,
+    }
+}
 
 // ============================================================================
 // Display
@@ -18,12 +81,29 @@ pub struct Display;
 impl Display {
     /// Shows a success message for timer start.
     pub fn show_start_success(response: &IpcResponse) {
-        println!("* タイマーを開始しました");
+        if response.message == SESSION_ALREADY_RUNNING {
+            println!("* {}", SESSION_ALREADY_RUNNING);
+        } else if response.phase() == Some(TimerPhase::Paused) {
+            println!("|| タイマーを一時停止状態で準備しました（resume で開始します）");
+        } else if matches!(
+            response.phase(),
+            Some(TimerPhase::Breaking | TimerPhase::LongBreaking)
+        ) {
+            println!("* 休憩を開始しました");
+        } else {
+            println!("* タイマーを開始しました");
+        }
 
         if let Some(data) = &response.data {
             if let Some(task_name) = &data.task_name {
                 println!("  タスク: {}", task_name);
             }
+            if let Some(tag) = &data.tag {
+                println!("  タグ: {}", tag);
+            }
+            if let Some(queue) = &data.task_queue {
+                println!("  次のタスク: {}", queue.join(" → "));
+            }
             if let Some(remaining) = data.remaining_seconds {
                 let (minutes, seconds) = Self::format_time(remaining);
                 println!("  残り時間: {}:{:02}", minutes, seconds);
@@ -40,6 +120,9 @@ impl Display {
                 let (minutes, seconds) = Self::format_time(remaining);
                 println!("  残り時間: {}:{:02}", minutes, seconds);
             }
+            if let Some(reason) = &data.pause_reason {
+                println!("  理由: {}", reason);
+            }
         }
     }
 
@@ -55,57 +138,320 @@ impl Display {
         }
     }
 
+    /// Shows a success message for break snooze.
+    pub fn show_snooze_success(response: &IpcResponse) {
+        println!("z {}", response.message);
+
+        if let Some(data) = &response.data {
+            if let Some(remaining) = data.remaining_seconds {
+                let (minutes, seconds) = Self::format_time(remaining);
+                println!("  残り時間: {}:{:02}", minutes, seconds);
+            }
+        }
+    }
+
+    /// Shows a success message for a skipped break.
+    pub fn show_skip_success(response: &IpcResponse) {
+        println!(">> {}", response.message);
+
+        if let Some(data) = &response.data {
+            if let Some(remaining) = data.remaining_seconds {
+                let (minutes, seconds) = Self::format_time(remaining);
+                println!("  残り時間: {}:{:02}", minutes, seconds);
+            }
+        }
+    }
+
+    /// Shows a success message for a standalone break.
+    pub fn show_break_success(response: &IpcResponse) {
+        println!("~ {}", response.message);
+
+        if let Some(data) = &response.data {
+            if let Some(remaining) = data.remaining_seconds {
+                let (minutes, seconds) = Self::format_time(remaining);
+                println!("  残り時間: {}:{:02}", minutes, seconds);
+            }
+        }
+    }
+
+    /// Shows a success message for a suspended session.
+    pub fn show_suspend_success(response: &IpcResponse) {
+        println!("[] {}", response.message);
+    }
+
+    /// Shows a success message for a resumed suspended session.
+    pub fn show_unsuspend_success(response: &IpcResponse) {
+        println!("> {}", response.message);
+
+        if let Some(data) = &response.data {
+            if let Some(remaining) = data.remaining_seconds {
+                let (minutes, seconds) = Self::format_time(remaining);
+                println!("  残り時間: {}:{:02}", minutes, seconds);
+            }
+        }
+    }
+
+    /// Shows a success message for pomodoro count reset.
+    pub fn show_reset_success(response: &IpcResponse) {
+        println!("0 {}", response.message);
+    }
+
     /// Shows a success message for timer stop.
     pub fn show_stop_success(_response: &IpcResponse) {
         println!("[] タイマーを停止しました");
     }
 
     /// Shows the current timer status.
-    pub fn show_status(response: &IpcResponse) {
+    ///
+    /// With `color`, the phase line is colored by [`phase_color`].
+    pub fn show_status(response: &IpcResponse, color: bool) {
         println!("ポモドーロタイマー ステータス");
         println!("─────────────────────────────");
 
         if let Some(data) = &response.data {
-            let state = data.state.as_deref().unwrap_or("unknown");
-            let state_display = match state {
-                "working" => "作業中",
-                "breaking" => "休憩中",
-                "long_breaking" => "長い休憩中",
-                "paused" => "一時停止中",
-                "stopped" => "停止中",
-                _ => state,
-            };
-            println!("状態: {}", state_display);
+            let phase = data.phase();
+            println!(
+                "{}",
+                Self::format_phase_line(phase, data.state.as_deref(), color)
+            );
+            if data.awaiting_break == Some(true) {
+                println!("休憩待ち: start で休憩を開始、skip で作業を再開します");
+            }
+            if let Some(reason) = &data.pause_reason {
+                println!("一時停止の理由: {}", reason);
+            }
+            if let Some(modes) = Self::format_modes_line(data) {
+                println!("{}", modes);
+            }
 
-            if state != "stopped" {
+            if phase != Some(TimerPhase::Stopped) {
                 if let Some(remaining) = data.remaining_seconds {
                     let (minutes, seconds) = Self::format_time(remaining);
                     println!("残り時間: {}:{:02}", minutes, seconds);
                 }
+                if let Some(progress) = data.progress {
+                    println!("進捗: {}", format_progress_bar(progress));
+                }
                 if let Some(count) = data.pomodoro_count {
                     println!("ポモドーロ: #{}", count);
                 }
+                match data.pomodoros_until_long_break {
+                    Some(until) => println!("次の長い休憩: あと{}ポモドーロ", until),
+                    None => println!("次の長い休憩: N/A"),
+                }
                 if let Some(task) = &data.task_name {
                     println!("タスク: {}", task);
                 }
+                if let Some(tag) = &data.tag {
+                    println!("タグ: {}", tag);
+                }
+                if let Some(queue) = &data.task_queue {
+                    println!("次のタスク: {}", queue.join(" → "));
+                }
             }
         } else {
             println!("タイマーは起動していません");
         }
     }
 
+    /// Formats the mode line of `status` (e.g. "自動サイクル: オン, フォーカス: オフ").
+    ///
+    /// Returns `None` if the daemon did not report the modes.
+    pub fn format_modes_line(data: &ResponseData) -> Option<String> {
+        let on_off = |enabled: bool| if enabled { "オン" } else { "オフ" };
+        Some(format!(
+            "自動サイクル: {}, フォーカス: {}",
+            on_off(data.auto_cycle?),
+            on_off(data.focus_mode?)
+        ))
+    }
+
+    /// Formats the phase line of `status` (e.g. "状態: 作業中").
+    ///
+    /// `state` is shown as-is when the phase is not recognized.
+    pub fn format_phase_line(
+        phase: Option<TimerPhase>,
+        state: Option<&str>,
+        color: bool,
+    ) -> String {
+        let state_display = match phase {
+            Some(TimerPhase::Working) => "作業中",
+            Some(TimerPhase::Breaking) => "休憩中",
+            Some(TimerPhase::LongBreaking) => "長い休憩中",
+            Some(TimerPhase::Paused) => "一時停止中",
+            Some(TimerPhase::Stopped) => "停止中",
+            None => state.unwrap_or("unknown"),
+        };
+        let line = format!("状態: {}", state_display);
+
+        match phase.and_then(phase_color).filter(|_| color) {
+            Some(code) => format!("{}{}{}", code, line, COLOR_RESET),
+            None => line,
+        }
+    }
+
+    /// Formats the status as the one-line menu bar title (e.g. "🍅 15:30"),
+    /// drawn with the glyphs of `style` and the time at `show_seconds`.
+    ///
+    /// Returns `None` if the response carries no recognizable state.
+    pub fn format_status_short(
+        response: &IpcResponse,
+        style: IconStyle,
+        show_seconds: ShowSeconds,
+    ) -> Option<String> {
+        let data = response.data.as_ref()?;
+        let mut state = TimerState::new(PomodoroConfig::default());
+        state.phase = data.phase()?;
+        state.remaining_seconds = data.remaining_seconds.unwrap_or(0);
+        let mut manager = IconManager::with_style(style);
+        manager.set_show_seconds(show_seconds);
+        Some(manager.generate_title(&state))
+    }
+
+    /// Shows the status as a single line for shell prompts.
+    pub fn show_status_short(response: &IpcResponse, style: IconStyle, show_seconds: ShowSeconds) {
+        if let Some(title) = Self::format_status_short(response, style, show_seconds) {
+            println!("{}", title);
+        }
+    }
+
+    /// Formats the resolved configuration for `config show`.
+    pub fn format_config(config: &PomodoroConfig) -> String {
+        let on_off = |enabled: bool| if enabled { "オン" } else { "オフ" };
+        let long_break = if config.long_break_enabled {
+            format!(
+                "{}分（{}ポモドーロごと）",
+                config.long_break_duration.as_minutes(),
+                config.long_break_interval
+            )
+        } else {
+            "なし".to_string()
+        };
+        let cycles = match config.max_cycles {
+            Some(cycles) => format!("{}回", cycles),
+            None => "無制限".to_string(),
+        };
+
+        [
+            format!("作業時間: {}分", config.work_duration.as_minutes()),
+            format!("休憩時間: {}分", config.break_duration.as_minutes()),
+            format!("長い休憩: {}", long_break),
+            format!("自動サイクル: {}", on_off(config.auto_cycle)),
+            format!("サイクル数: {}", cycles),
+            format!("フォーカスモード: {}", on_off(config.focus_mode)),
+            format!("厳格モード: {}", on_off(config.strict)),
+        ]
+        .join("\n")
+    }
+
+    /// Shows the resolved configuration.
+    pub fn show_config(config: &PomodoroConfig) {
+        println!("ポモドーロタイマー 設定");
+        println!("─────────────────────────────");
+        println!("{}", Self::format_config(config));
+    }
+
+    /// Shows the daemon's configuration from a `config get`/`config set` response.
+    pub fn show_daemon_config(response: &IpcResponse) {
+        if !response.message.is_empty() {
+            println!("* {}", response.message);
+        }
+        if let Some(config) = response.data.as_ref().and_then(|data| data.config.as_ref()) {
+            Self::show_config(config);
+        }
+    }
+
     /// Shows a success message for LaunchAgent installation.
     pub fn show_install_success() {
         println!("* LaunchAgentをインストールしました");
         println!("  次回ログイン時から自動的に起動します");
     }
 
+    /// Shows the result of `install --check`.
+    pub fn show_install_check(check: &crate::launchagent::InstallCheck) {
+        use crate::launchagent::InstallCheck;
+
+        match check {
+            InstallCheck::NotInstalled => {
+                println!("* LaunchAgentはインストールされていません");
+                println!("  pomodoro install でインストールしてください");
+            }
+            InstallCheck::UpToDate { path } => {
+                println!("* LaunchAgentは現在のバイナリを指しています");
+                println!("  {}", path);
+            }
+            InstallCheck::Stale { installed, current } => {
+                println!("* LaunchAgentが古いバイナリを指しています");
+                println!("  登録済み: {}", installed);
+                println!("  現在:     {}", current);
+                println!("  pomodoro install で再インストールしてください");
+            }
+        }
+    }
+
     /// Shows a success message for LaunchAgent uninstallation.
     pub fn show_uninstall_success() {
         println!("* LaunchAgentをアンインストールしました");
         println!("  次回ログイン時から自動起動しなくなります");
     }
 
+    /// Shows the files deleted by `uninstall --purge`.
+    pub fn show_purge_result(removed: &[std::path::PathBuf]) {
+        if removed.is_empty() {
+            println!("削除するファイルはありませんでした");
+            return;
+        }
+        println!("* 以下のファイルを削除しました");
+        for path in removed {
+            println!("  {}", path.display());
+        }
+    }
+
+    /// Shows the result of `setup notifications`.
+    pub fn show_notification_setup(granted: bool) {
+        if granted {
+            println!("* 通知が許可されました");
+        } else {
+            println!("通知が許可されていません");
+            println!("  システム設定 > 通知 > pomodoro で「通知を許可」をオンにしてください");
+        }
+    }
+
+    /// Formats one `doctor` check, with its hint on an indented second line.
+    pub fn format_check(check: &Check) -> String {
+        let line = format!("{} {}: {}", check.status.symbol(), check.name, check.detail);
+        match &check.hint {
+            Some(hint) => format!("{}\n   → {}", line, hint),
+            None => line,
+        }
+    }
+
+    /// Shows the results of `doctor`.
+    pub fn show_doctor(checks: &[Check]) {
+        println!("ポモドーロタイマー 診断");
+        println!("─────────────────────────────");
+        for check in checks {
+            println!("{}", Self::format_check(check));
+        }
+    }
+
+    /// Formats a timer event as pretty JSON with the time it occurred.
+    pub fn format_event(event: &TimestampedEvent) -> String {
+        serde_json::to_string_pretty(event).unwrap_or_else(|_| format!("{:?}", event))
+    }
+
+    /// Prints a timer event received by `watch-events`.
+    pub fn show_event(event: &TimestampedEvent) {
+        println!("{}", Self::format_event(event));
+    }
+
+    /// Shows the non-fatal warnings for a configuration.
+    pub fn show_config_warnings(config: &PomodoroConfig) {
+        for warning in config.warnings() {
+            eprintln!("注意: {}", warning);
+        }
+    }
+
     /// Shows an error message.
     pub fn show_error(message: &str) {
         eprintln!("エラー: {}", message);
@@ -126,7 +472,6 @@ impl Display {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::ResponseData;
 
     // ------------------------------------------------------------------------
     // Format Time Tests
@@ -193,6 +538,7 @@ mod tests {
                     remaining_seconds: Some(1500),
                     pomodoro_count: Some(1),
                     task_name: Some("Test Task".to_string()),
+                    ..Default::default()
                 }),
             )
         }
@@ -205,6 +551,7 @@ mod tests {
                     remaining_seconds: Some(1200),
                     pomodoro_count: Some(1),
                     task_name: None,
+                    ..Default::default()
                 }),
             )
         }
@@ -217,10 +564,93 @@ mod tests {
                     remaining_seconds: Some(0),
                     pomodoro_count: Some(0),
                     task_name: None,
+                    ..Default::default()
                 }),
             )
         }
 
+        #[test]
+        fn test_format_status_short_matches_menu_bar_title() {
+            assert_eq!(
+                Display::format_status_short(
+                    &create_working_response(),
+                    IconStyle::Emoji,
+                    ShowSeconds::Yes
+                ),
+                Some("🍅 25:00".to_string())
+            );
+            assert_eq!(
+                Display::format_status_short(
+                    &create_paused_response(),
+                    IconStyle::Emoji,
+                    ShowSeconds::Yes
+                ),
+                Some("⏸ 一時停止".to_string())
+            );
+            assert_eq!(
+                Display::format_status_short(
+                    &create_stopped_response(),
+                    IconStyle::Emoji,
+                    ShowSeconds::Yes
+                ),
+                Some("⏸ 停止中".to_string())
+            );
+            assert_eq!(
+                Display::format_status_short(
+                    &create_working_response(),
+                    IconStyle::Ascii,
+                    ShowSeconds::Yes
+                ),
+                Some("[W] 25:00".to_string())
+            );
+            assert_eq!(
+                Display::format_status_short(
+                    &create_working_response(),
+                    IconStyle::Emoji,
+                    ShowSeconds::No
+                ),
+                Some("🍅 25m".to_string())
+            );
+        }
+
+        #[test]
+        fn test_format_modes_line() {
+            let data = ResponseData {
+                auto_cycle: Some(true),
+                focus_mode: Some(false),
+                ..Default::default()
+            };
+            assert_eq!(
+                Display::format_modes_line(&data),
+                Some("自動サイクル: オン, フォーカス: オフ".to_string())
+            );
+            assert_eq!(Display::format_modes_line(&ResponseData::default()), None);
+        }
+
+        #[test]
+        fn test_format_config() {
+            let text = Display::format_config(&PomodoroConfig::default());
+            assert!(text.contains("作業時間: 25分"));
+            assert!(text.contains("長い休憩: 15分（4ポモドーロごと）"));
+            assert!(text.contains("サイクル数: 無制限"));
+
+            let config = PomodoroConfig::default()
+                .with_long_break_enabled(false)
+                .with_strict(true);
+            let text = Display::format_config(&config);
+            assert!(text.contains("長い休憩: なし"));
+            assert!(text.contains("厳格モード: オン"));
+        }
+
+        #[test]
+        fn test_format_status_short_without_data() {
+            let response = IpcResponse::success("", None);
+            assert_eq!(
+                Display::format_status_short(&response, IconStyle::Emoji, ShowSeconds::Yes),
+                None
+            );
+        }
+
         #[test]
         fn test_show_start_success() {
             // This test verifies the function doesn't panic
@@ -246,22 +676,41 @@ mod tests {
             Display::show_stop_success(&response);
         }
 
+        #[test]
+        fn test_show_reset_success() {
+            let response = IpcResponse::success("ポモドーロ数をリセットしました", None);
+            Display::show_reset_success(&response);
+        }
+
+        #[test]
+        fn test_show_snooze_success() {
+            let response = IpcResponse::success(
+                "休憩を5分延長しました",
+                Some(ResponseData {
+                    state: Some("breaking".to_string()),
+                    remaining_seconds: Some(420),
+                    ..Default::default()
+                }),
+            );
+            Display::show_snooze_success(&response);
+        }
+
         #[test]
         fn test_show_status_working() {
             let response = create_working_response();
-            Display::show_status(&response);
+            Display::show_status(&response, false);
         }
 
         #[test]
         fn test_show_status_stopped() {
             let response = create_stopped_response();
-            Display::show_status(&response);
+            Display::show_status(&response, false);
         }
 
         #[test]
         fn test_show_status_no_data() {
             let response = IpcResponse::success("", None);
-            Display::show_status(&response);
+            Display::show_status(&response, false);
         }
 
         #[test]
@@ -288,6 +737,7 @@ mod tests {
                     remaining_seconds: Some(1500),
                     pomodoro_count: Some(0),
                     task_name: None,
+                    ..Default::default()
                 }),
             );
             Display::show_start_success(&response);
@@ -302,9 +752,10 @@ mod tests {
                     remaining_seconds: Some(300),
                     pomodoro_count: Some(1),
                     task_name: None,
+                    ..Default::default()
                 }),
             );
-            Display::show_status(&response);
+            Display::show_status(&response, false);
         }
 
         #[test]
@@ -316,15 +767,16 @@ mod tests {
                     remaining_seconds: Some(900),
                     pomodoro_count: Some(4),
                     task_name: None,
+                    ..Default::default()
                 }),
             );
-            Display::show_status(&response);
+            Display::show_status(&response, false);
         }
 
         #[test]
         fn test_show_status_paused() {
             let response = create_paused_response();
-            Display::show_status(&response);
+            Display::show_status(&response, false);
         }
 
         #[test]
@@ -336,9 +788,140 @@ mod tests {
                     remaining_seconds: Some(100),
                     pomodoro_count: Some(0),
                     task_name: None,
+                    ..Default::default()
                 }),
             );
-            Display::show_status(&response);
+            Display::show_status(&response, false);
+        }
+
+        #[test]
+        fn test_show_status_with_pomodoros_until_long_break() {
+            let response = IpcResponse::success(
+                "",
+                Some(ResponseData {
+                    state: Some("working".to_string()),
+                    remaining_seconds: Some(1200),
+                    pomodoro_count: Some(2),
+                    pomodoros_until_long_break: Some(2),
+                    ..Default::default()
+                }),
+            );
+            Display::show_status(&response, false);
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Event Formatting Tests
+    // ------------------------------------------------------------------------
+
+    mod color_tests {
+        use super::*;
+
+        #[test]
+        fn test_phase_lines_are_colored_per_phase() {
+            let line = |phase| Display::format_phase_line(Some(phase), None, true);
+
+            assert_eq!(line(TimerPhase::Working), "\x1b[31m状態: 作業中\x1b[0m");
+            assert_eq!(line(TimerPhase::Breaking), "\x1b[32m状態: 休憩中\x1b[0m");
+            assert_eq!(
+                line(TimerPhase::LongBreaking),
+                "\x1b[32m状態: 長い休憩中\x1b[0m"
+            );
+            assert_eq!(line(TimerPhase::Paused), "\x1b[34m状態: 一時停止中\x1b[0m");
+            assert_eq!(line(TimerPhase::Stopped), "状態: 停止中");
+        }
+
+        #[test]
+        fn test_plain_when_color_is_disabled() {
+            assert_eq!(
+                Display::format_phase_line(Some(TimerPhase::Working), None, false),
+                "状態: 作業中"
+            );
+            assert_eq!(
+                Display::format_phase_line(None, Some("warming_up"), true),
+                "状態: warming_up"
+            );
+        }
+
+        #[test]
+        fn test_color_mode_resolution() {
+            assert!(ColorMode::Auto.resolve(false, true));
+            assert!(!ColorMode::Auto.resolve(true, true));
+            assert!(!ColorMode::Auto.resolve(false, false));
+
+            assert!(ColorMode::Always.resolve(true, false));
+            assert!(!ColorMode::Never.resolve(false, true));
+        }
+    }
+
+    mod event_format_tests {
+        use super::*;
+        use crate::daemon::TimerEvent;
+        use chrono::{Local, TimeZone};
+
+        #[test]
+        fn test_format_event_round_trips_every_variant() {
+            let at = Local.with_ymd_and_hms(2024, 1, 15, 9, 30, 0).unwrap();
+            let events = [
+                TimerEvent::WorkStarted {
+                    task_name: Some("Task".to_string()),
+                },
+                TimerEvent::WorkCompleted {
+                    pomodoro_count: 2,
+                    task_name: None,
+                    tag: None,
+                    work_seconds: 1500,
+                    extended: false,
+                },
+                TimerEvent::BreakStarted {
+                    is_long_break: true,
+                },
+                TimerEvent::BreakCompleted {
+                    is_long_break: false,
+                },
+                TimerEvent::Paused { reason: None },
+                TimerEvent::Resumed,
+                TimerEvent::Stopped,
+                TimerEvent::Tick {
+                    remaining_seconds: 42,
+                },
+            ];
+
+            for event in events {
+                let stamped = TimestampedEvent {
+                    at,
+                    event: event.clone(),
+                    follows_completion: false,
+                };
+                let json = Display::format_event(&stamped);
+                let parsed: TimestampedEvent = serde_json::from_str(&json).unwrap();
+
+                assert_eq!(parsed, stamped);
+                assert!(json.contains(&format!("\"event\": \"{}\"", event.name())));
+                assert!(json.contains("\"at\": \"2024-01-15T09:30:00"));
+            }
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Doctor Formatting Tests
+    // ------------------------------------------------------------------------
+
+    mod doctor_format_tests {
+        use super::*;
+        use crate::cli::doctor::{check_daemon, check_shortcuts};
+
+        #[test]
+        fn test_format_check_with_and_without_hint() {
+            assert_eq!(
+                Display::format_check(&check_shortcuts(true)),
+                "✅ ショートカット: Shortcuts.appが見つかりました"
+            );
+
+            let text = Display::format_check(&check_daemon(false));
+            let lines: Vec<&str> = text.lines().collect();
+            assert_eq!(lines[0], "❌ Daemon: 応答がありません");
+            assert!(lines[1].starts_with("   → "));
         }
     }
 }
@@ -0,0 +1,317 @@
+//! Environment checks for `pomodoro doctor`.
+//!
+//! Each check turns one observation about the environment into a
+//! [`Check`] with a remediation hint. The probing itself is done by
+//! [`run_checks`]; the check functions only interpret the results, so they
+//! can be tested without a daemon, audio device or notification center.
+
+use std::fs::{self, OpenOptions};
+use std::path::Path;
+
+use crate::cli::client::IpcClient;
+use crate::focus::shortcuts_exists;
+#[cfg(target_os = "macos")]
+use crate::notification::{NotificationError, NotificationManager};
+use crate::sound::{RodioSoundPlayer, SoundError};
+
+// ============================================================================
+// Check
+// ============================================================================
+
+/// Outcome of a single check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// Everything is in order
+    Pass,
+    /// An optional feature will not work
+    Warn,
+    /// The timer cannot work until this is fixed
+    Fail,
+}
+
+impl CheckStatus {
+    /// Returns the symbol printed in front of the check.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "✅",
+            CheckStatus::Warn => "⚠️",
+            CheckStatus::Fail => "❌",
+        }
+    }
+}
+
+/// Result of a single check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    /// What was checked
+    pub name: &'static str,
+    /// Outcome of the check
+    pub status: CheckStatus,
+    /// What was found
+    pub detail: String,
+    /// How to fix it (only for warnings and failures)
+    pub hint: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn problem(
+        name: &'static str,
+        status: CheckStatus,
+        detail: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// Returns true if any hard requirement failed.
+pub fn has_failures(checks: &[Check]) -> bool {
+    checks.iter().any(|check| check.status == CheckStatus::Fail)
+}
+
+// ============================================================================
+// Checks
+// ============================================================================
+
+/// Checks whether the daemon accepts connections.
+pub fn check_daemon(reachable: bool) -> Check {
+    const NAME: &str = "Daemon";
+    if reachable {
+        Check::pass(NAME, "応答しています")
+    } else {
+        Check::problem(
+            NAME,
+            CheckStatus::Fail,
+            "応答がありません",
+            "`pomodoro daemon` で起動するか、`pomodoro install` で自動起動を設定してください",
+        )
+    }
+}
+
+/// Checks that a file can be created next to the socket.
+///
+/// The directory is created if it does not exist yet, as the daemon would.
+pub fn check_socket_dir(socket_path: &Path) -> Check {
+    const NAME: &str = "ソケット";
+    let Some(dir) = socket_path.parent() else {
+        return Check::problem(
+            NAME,
+            CheckStatus::Fail,
+            format!("ディレクトリがありません: {}", socket_path.display()),
+            "ソケットのパスを確認してください",
+        );
+    };
+
+    let probe = dir.join(".pomodoro-doctor");
+    let writable = fs::create_dir_all(dir).and_then(|()| {
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&probe)
+            .map(drop)?;
+        fs::remove_file(&probe)
+    });
+
+    match writable {
+        Ok(()) => Check::pass(NAME, format!("書き込めます: {}", dir.display())),
+        Err(e) => Check::problem(
+            NAME,
+            CheckStatus::Fail,
+            format!("書き込めません: {} ({})", dir.display(), e),
+            "ディレクトリの所有者と権限を確認してください",
+        ),
+    }
+}
+
+/// Checks whether Shortcuts.app is installed (needed for focus mode).
+pub fn check_shortcuts(exists: bool) -> Check {
+    const NAME: &str = "ショートカット";
+    if exists {
+        Check::pass(NAME, "Shortcuts.appが見つかりました")
+    } else {
+        Check::problem(
+            NAME,
+            CheckStatus::Warn,
+            "Shortcuts.appが見つかりません（フォーカスモード連携は使えません）",
+            "macOS 12以降が必要です",
+        )
+    }
+}
+
+/// Checks whether an audio output device could be opened.
+pub fn check_audio(result: Result<(), SoundError>) -> Check {
+    const NAME: &str = "オーディオ";
+    match result {
+        Ok(()) => Check::pass(NAME, "出力デバイスを利用できます"),
+        Err(e) => Check::problem(
+            NAME,
+            CheckStatus::Warn,
+            format!("{}（通知音は鳴りません）", e.detail()),
+            "サウンドの出力先を確認してください",
+        ),
+    }
+}
+
+/// Checks whether notifications are authorized.
+#[cfg(target_os = "macos")]
+pub fn check_notifications(result: Result<bool, NotificationError>) -> Check {
+    const NAME: &str = "通知";
+    match result {
+        Ok(true) => Check::pass(NAME, "許可されています"),
+        Ok(false) => Check::problem(
+            NAME,
+            CheckStatus::Warn,
+            "許可されていません（ターミナルへの通知になります）",
+            "システム設定 > 通知 で pomodoro を許可してください",
+        ),
+        Err(e) => Check::problem(NAME, CheckStatus::Warn, e.to_string(), e.suggestion()),
+    }
+}
+
+/// Checks whether the running binary has a valid code signature.
+#[cfg(target_os = "macos")]
+pub fn check_code_signing(signed: bool, exe: &Path) -> Check {
+    const NAME: &str = "コード署名";
+    if signed {
+        Check::pass(NAME, "署名されています")
+    } else {
+        Check::problem(
+            NAME,
+            CheckStatus::Warn,
+            "署名されていません（通知センターを使えません）",
+            format!("codesign --force --deep --sign - {}", exe.display()),
+        )
+    }
+}
+
+// ============================================================================
+// Probing
+// ============================================================================
+
+/// Probes the environment and returns the results of all checks.
+pub async fn run_checks(client: &IpcClient) -> Vec<Check> {
+    let mut checks = vec![
+        check_daemon(client.is_daemon_running().await),
+        check_socket_dir(client.socket_path()),
+        check_audio(RodioSoundPlayer::new(true).map(drop)),
+    ];
+
+    if cfg!(target_os = "macos") {
+        checks.push(check_shortcuts(shortcuts_exists()));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        checks.push(check_notifications(
+            NotificationManager::is_authorized().await,
+        ));
+        if let Ok(exe) = std::env::current_exe() {
+            let signed = std::process::Command::new("/usr/bin/codesign")
+                .arg("--verify")
+                .arg(&exe)
+                .output()
+                .is_ok_and(|output| output.status.success());
+            checks.push(check_code_signing(signed, &exe));
+        }
+    }
+
+    checks
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_daemon_unreachable_is_failure() {
+        assert_eq!(check_daemon(true).status, CheckStatus::Pass);
+
+        let check = check_daemon(false);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.hint.unwrap().contains("pomodoro daemon"));
+    }
+
+    #[test]
+    fn test_socket_dir_is_created_and_probed() {
+        let dir = TempDir::new().unwrap();
+        let socket = dir.path().join("run").join("pomodoro.sock");
+
+        let check = check_socket_dir(&socket);
+
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(socket.parent().unwrap().is_dir());
+        assert!(!socket.with_file_name(".pomodoro-doctor").exists());
+    }
+
+    #[test]
+    fn test_socket_dir_under_a_file_fails() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("not-a-dir");
+        fs::write(&file, "").unwrap();
+
+        let check = check_socket_dir(&file.join("pomodoro.sock"));
+
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.hint.is_some());
+    }
+
+    #[test]
+    fn test_optional_features_only_warn() {
+        assert_eq!(check_shortcuts(true).status, CheckStatus::Pass);
+        assert_eq!(check_shortcuts(false).status, CheckStatus::Warn);
+
+        assert_eq!(check_audio(Ok(())).status, CheckStatus::Pass);
+        let check = check_audio(Err(SoundError::DeviceUnavailable(
+            rodio::StreamError::NoDevice,
+        )));
+        assert_eq!(check.status, CheckStatus::Warn);
+        // The root cause is shown after the message
+        assert!(check.detail.contains("NoDevice"));
+    }
+
+    #[test]
+    fn test_has_failures() {
+        let warn_only = vec![check_daemon(true), check_shortcuts(false)];
+        assert!(!has_failures(&warn_only));
+
+        let failed = vec![check_daemon(false), check_shortcuts(true)];
+        assert!(has_failures(&failed));
+    }
+
+    #[cfg(target_os = "macos")]
+    mod macos_tests {
+        use super::*;
+
+        #[test]
+        fn test_notifications_and_signing() {
+            assert_eq!(check_notifications(Ok(true)).status, CheckStatus::Pass);
+            assert_eq!(check_notifications(Ok(false)).status, CheckStatus::Warn);
+
+            let check = check_notifications(Err(NotificationError::UnsignedBinary));
+            assert!(check.hint.unwrap().contains("codesign"));
+
+            let check = check_code_signing(false, Path::new("/usr/local/bin/pomodoro"));
+            assert_eq!(check.status, CheckStatus::Warn);
+            assert!(check.hint.unwrap().ends_with("/usr/local/bin/pomodoro"));
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Showing the daemon log for `pomodoro log`.
+//!
+//! The LaunchAgent starts the daemon with `--log-file
+//! ~/.pomodoro/logs/daemon.log`. `log` prints the last lines of that file,
+//! and `log -f` keeps polling it and prints whatever is appended until
+//! Ctrl+C, like `tail -f`.
+//!
+//! A missing file is not an error: the daemon may not have written anything
+//! yet. With `-f` the file is picked up once it appears, and if it shrinks
+//! (it was truncated or replaced) it is read again from the start.
+
+use std::fs::File;
+use std::future::Future;
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+/// Default number of lines printed before following.
+pub const DEFAULT_LOG_LINES: usize = 20;
+
+/// How often a followed log is checked for new lines.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How much of the end of the file is read to find the last lines.
+const TAIL_WINDOW_BYTES: u64 = 64 * 1024;
+
+// ============================================================================
+// LogTail
+// ============================================================================
+
+/// The end of a log file, optionally followed as it grows.
+#[derive(Debug, Clone)]
+pub struct LogTail {
+    path: PathBuf,
+    poll_interval: Duration,
+}
+
+impl LogTail {
+    /// Creates a tail of the log file at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            poll_interval: POLL_INTERVAL,
+        }
+    }
+
+    /// Sets how often [`Self::follow`] checks the file.
+    #[must_use]
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Returns the log file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Prints the last `lines` lines of the log to `out`.
+    ///
+    /// Only the last 64 KiB of the file are searched, so a very long log
+    /// does not have to be read in full. If the file does not exist, a
+    /// "no logs yet" message is printed instead.
+    ///
+    /// Returns the file length, where [`Self::follow`] continues from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or `out` cannot be
+    /// written to.
+    pub fn print_last<W: Write>(&self, lines: usize, out: &mut W) -> Result<u64> {
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                writeln!(out, "ログはまだありません: {}", self.path.display())
+                    .context("出力に失敗しました")?;
+                return Ok(0);
+            }
+            Err(e) => {
+                return Err(e).with_context(|| format!("ログを開けません: {}", self.path.display()))
+            }
+        };
+
+        let len = file
+            .metadata()
+            .with_context(|| format!("ログを読み込めません: {}", self.path.display()))?
+            .len();
+        let start = len.saturating_sub(TAIL_WINDOW_BYTES);
+        let bytes = read_from(&mut file, start)
+            .with_context(|| format!("ログを読み込めません: {}", self.path.display()))?;
+        // A character cut by the window start is replaced rather than rejected
+        let text = String::from_utf8_lossy(&bytes);
+
+        // A window starting mid-file begins with a partial line
+        let mut all: Vec<&str> = text.lines().collect();
+        if start > 0 && !all.is_empty() {
+            all.remove(0);
+        }
+        for line in &all[all.len().saturating_sub(lines)..] {
+            writeln!(out, "{}", line).context("出力に失敗しました")?;
+        }
+        out.flush().context("出力に失敗しました")?;
+
+        Ok(len)
+    }
+
+    /// Prints what is appended to the log after `offset` until `shutdown`
+    /// resolves (the CLI passes Ctrl+C).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or `out` cannot be
+    /// written to.
+    pub async fn follow<W, F>(&self, mut offset: u64, out: &mut W, shutdown: F) -> Result<()>
+    where
+        W: Write,
+        F: Future,
+    {
+        let mut ticker = interval(self.poll_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        tokio::pin!(shutdown);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    offset = self.print_appended(offset, out)?;
+                }
+                _ = &mut shutdown => {
+                    // Do not lose lines written just before Ctrl+C
+                    self.print_appended(offset, out)?;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Prints the bytes after `offset` and returns the new offset.
+    fn print_appended<W: Write>(&self, offset: u64, out: &mut W) -> Result<u64> {
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(0),
+            Err(e) => {
+                return Err(e).with_context(|| format!("ログを開けません: {}", self.path.display()))
+            }
+        };
+        let len = file
+            .metadata()
+            .with_context(|| format!("ログを読み込めません: {}", self.path.display()))?
+            .len();
+
+        // Truncated or replaced: start over
+        let offset = if len < offset { 0 } else { offset };
+        if len == offset {
+            return Ok(offset);
+        }
+
+        let bytes = read_from(&mut file, offset)
+            .with_context(|| format!("ログを読み込めません: {}", self.path.display()))?;
+        out.write_all(&bytes)
+            .and_then(|()| out.flush())
+            .context("出力に失敗しました")?;
+
+        Ok(offset + bytes.len() as u64)
+    }
+}
+
+/// Reads the file from `start` to its end.
+fn read_from(file: &mut File, start: u64) -> std::io::Result<Vec<u8>> {
+    file.seek(SeekFrom::Start(start))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{self, OpenOptions};
+    use tempfile::TempDir;
+
+    fn append(path: &Path, text: &str) {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap();
+        file.write_all(text.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn test_print_last_lines() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("daemon.log");
+        fs::write(&path, "one\ntwo\nthree\n").unwrap();
+        let mut out = Vec::new();
+
+        let offset = LogTail::new(&path).print_last(2, &mut out).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "two\nthree\n");
+        assert_eq!(offset, 14);
+    }
+
+    #[test]
+    fn test_print_last_without_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("daemon.log");
+        let mut out = Vec::new();
+
+        let offset = LogTail::new(&path).print_last(20, &mut out).unwrap();
+
+        assert_eq!(offset, 0);
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .starts_with("ログはまだありません"));
+    }
+
+    #[tokio::test]
+    async fn test_follow_emits_appended_lines() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("daemon.log");
+        fs::write(&path, "old\n").unwrap();
+        let tail = LogTail::new(&path).with_poll_interval(Duration::from_millis(5));
+        let mut out = Vec::new();
+
+        let offset = tail.print_last(0, &mut out).unwrap();
+        let writer = async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            append(&path, "first\n");
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            append(&path, "second\n");
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        };
+        tail.follow(offset, &mut out, writer).await.unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "first\nsecond\n");
+    }
+
+    #[tokio::test]
+    async fn test_follow_restarts_after_truncation() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("daemon.log");
+        fs::write(&path, "a long line before rotation\n").unwrap();
+        let tail = LogTail::new(&path).with_poll_interval(Duration::from_millis(5));
+        let mut out = Vec::new();
+
+        let offset = tail.print_last(0, &mut out).unwrap();
+        let writer = async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            fs::write(&path, "new\n").unwrap();
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        };
+        tail.follow(offset, &mut out, writer).await.unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "new\n");
+    }
+}
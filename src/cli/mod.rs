@@ -4,11 +4,38 @@
 //! - `commands`: Command definitions using clap derive
 //! - `client`: IPC client for daemon communication
 //! - `display`: Output formatting and display logic
+//! - `clipboard`: Task name from the system clipboard
+//! - `completions`: Shell completion generation and installation
+//! - `stdin`: Task name piped through stdin (`--task -`)
+//! - `purge`: Confirmation flow for `uninstall --purge`
+//! - `doctor`: Environment checks for `doctor`
+//! - `status_cache`: Cached status for `status --short --cache`
+//! - `notifications`: Listing and clearing notifications (`notifications`)
+//! - `log_tail`: Showing and following the daemon log (`log`)
+//! - `stop_confirm`: Confirmation before `stop` ends a long work session
+//! - `stats`: Statistics from the session history (`stats`)
+//! - `wait`: Waiting for the work session to end (`start --wait`)
 
 pub mod client;
+pub mod clipboard;
 pub mod commands;
+pub mod completions;
 pub mod display;
+pub mod doctor;
+pub mod log_tail;
+pub mod notifications;
+pub mod purge;
+pub mod stats;
+pub mod status_cache;
+pub mod stdin;
+pub mod stop_confirm;
+pub mod wait;
 
-pub use client::IpcClient;
-pub use commands::{Cli, Commands, StartArgs};
+pub use client::{EventSubscription, IpcClient, IpcSession, StartOutcome};
+pub use commands::{
+    BreakArgs, Cli, Commands, ConfigCommand, ConfigKey, ConfigSetArgs, ConfigShowArgs, DaemonArgs,
+    InstallArgs, LogArgs, LongBreakArgs, NotificationsCommand, PauseArgs, RunArgs, SetupCommand,
+    SnoozeArgs, SoundCommand, StartArgs, StatsArgs, StatusArgs, StopArgs, UninstallArgs,
+    WatchEventsArgs,
+};
 pub use display::Display;
@@ -0,0 +1,234 @@
+//! Listing and clearing notifications for `pomodoro notifications`.
+//!
+//! This is a debugging aid: `list` shows what the notification center
+//! still holds for this app (pending requests and delivered notifications),
+//! and `clear` removes all of it. The notification center is reached
+//! through [`NotificationStore`], so the command surface can be tested with
+//! [`MockNotificationStore`]. Outside macOS there is no store and the
+//! commands report that they are unsupported.
+
+use std::io::Write;
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+
+// ============================================================================
+// NotificationEntry
+// ============================================================================
+
+/// A notification held by the notification center.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationEntry {
+    /// Request identifier (e.g. "pomodoro.work-complete")
+    pub identifier: String,
+    /// Notification title
+    pub title: String,
+    /// When it was delivered (`None` while still pending)
+    pub delivered_at: Option<SystemTime>,
+}
+
+#[cfg(target_os = "macos")]
+impl From<crate::notification::NotificationSummary> for NotificationEntry {
+    fn from(summary: crate::notification::NotificationSummary) -> Self {
+        Self {
+            identifier: summary.identifier,
+            title: summary.title,
+            delivered_at: summary.delivered_at,
+        }
+    }
+}
+
+// ============================================================================
+// NotificationStore
+// ============================================================================
+
+/// Access to the notifications of this app.
+#[allow(async_fn_in_trait)]
+pub trait NotificationStore {
+    /// Returns the notification requests that have not been delivered yet.
+    async fn pending(&self) -> Result<Vec<NotificationEntry>>;
+    /// Returns the notifications still shown in Notification Center.
+    async fn delivered(&self) -> Result<Vec<NotificationEntry>>;
+    /// Removes all pending and delivered notifications.
+    fn clear(&self);
+}
+
+/// The macOS notification center.
+#[cfg(target_os = "macos")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemNotificationStore;
+
+#[cfg(target_os = "macos")]
+impl NotificationStore for SystemNotificationStore {
+    async fn pending(&self) -> Result<Vec<NotificationEntry>> {
+        let summaries = crate::notification::pending_notifications()
+            .await
+            .context("未配信の通知を取得できませんでした")?;
+        Ok(summaries.into_iter().map(Into::into).collect())
+    }
+
+    async fn delivered(&self) -> Result<Vec<NotificationEntry>> {
+        let summaries = crate::notification::delivered_notifications()
+            .await
+            .context("配信済みの通知を取得できませんでした")?;
+        Ok(summaries.into_iter().map(Into::into).collect())
+    }
+
+    fn clear(&self) {
+        crate::notification::clear_notifications();
+    }
+}
+
+/// Notification store for testing with a fixed set of notifications.
+#[derive(Debug, Default)]
+pub struct MockNotificationStore {
+    pending: std::sync::Mutex<Vec<NotificationEntry>>,
+    delivered: std::sync::Mutex<Vec<NotificationEntry>>,
+}
+
+impl MockNotificationStore {
+    #[must_use]
+    pub fn new(pending: Vec<NotificationEntry>, delivered: Vec<NotificationEntry>) -> Self {
+        Self {
+            pending: std::sync::Mutex::new(pending),
+            delivered: std::sync::Mutex::new(delivered),
+        }
+    }
+
+    /// Returns the number of notifications left, pending and delivered.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pending.lock().unwrap().len() + self.delivered.lock().unwrap().len()
+    }
+
+    /// Returns whether no notification is left.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl NotificationStore for MockNotificationStore {
+    async fn pending(&self) -> Result<Vec<NotificationEntry>> {
+        Ok(self.pending.lock().unwrap().clone())
+    }
+
+    async fn delivered(&self) -> Result<Vec<NotificationEntry>> {
+        Ok(self.delivered.lock().unwrap().clone())
+    }
+
+    fn clear(&self) {
+        self.pending.lock().unwrap().clear();
+        self.delivered.lock().unwrap().clear();
+    }
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Prints the pending and delivered notifications (`notifications list`).
+///
+/// # Errors
+///
+/// Returns an error if the notifications cannot be queried or `out`
+/// cannot be written to.
+pub async fn list<S: NotificationStore, W: Write>(store: &S, mut out: W) -> Result<()> {
+    let pending = store.pending().await?;
+    let delivered = store.delivered().await?;
+
+    if pending.is_empty() && delivered.is_empty() {
+        writeln!(out, "通知はありません").context("出力に失敗しました")?;
+        return Ok(());
+    }
+    for entry in pending.iter().chain(&delivered) {
+        writeln!(out, "{}", format_entry(entry)).context("出力に失敗しました")?;
+    }
+    Ok(())
+}
+
+/// Removes all notifications and reports how many there were
+/// (`notifications clear`).
+///
+/// # Errors
+///
+/// Returns an error if the notifications cannot be queried.
+pub async fn clear<S: NotificationStore>(store: &S) -> Result<usize> {
+    let count = store.pending().await?.len() + store.delivered().await?.len();
+    store.clear();
+    Ok(count)
+}
+
+/// Formats one notification as a line of `notifications list`.
+fn format_entry(entry: &NotificationEntry) -> String {
+    let state = match entry.delivered_at {
+        Some(at) => DateTime::<Local>::from(at)
+            .format("配信済み %Y-%m-%d %H:%M:%S")
+            .to_string(),
+        None => "未配信".to_string(),
+    };
+    format!("{}  {}  {}", state, entry.identifier, entry.title)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn entry(identifier: &str, delivered_at: Option<SystemTime>) -> NotificationEntry {
+        NotificationEntry {
+            identifier: identifier.to_string(),
+            title: "作業完了".to_string(),
+            delivered_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_shows_pending_and_delivered() {
+        let delivered_at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let store = MockNotificationStore::new(
+            vec![entry("pomodoro.break-complete", None)],
+            vec![entry("pomodoro.work-complete", Some(delivered_at))],
+        );
+        let mut out = Vec::new();
+
+        list(&store, &mut out).await.unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("未配信  pomodoro.break-complete"));
+        let expected = DateTime::<Local>::from(delivered_at)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        assert!(lines[1].starts_with(&format!("配信済み {}", expected)));
+        assert!(lines[1].ends_with("pomodoro.work-complete  作業完了"));
+    }
+
+    #[tokio::test]
+    async fn test_list_without_notifications() {
+        let store = MockNotificationStore::default();
+        let mut out = Vec::new();
+
+        list(&store, &mut out).await.unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "通知はありません\n");
+    }
+
+    #[tokio::test]
+    async fn test_clear_removes_everything() {
+        let store = MockNotificationStore::new(
+            vec![entry("pomodoro.break-complete", None)],
+            vec![entry("pomodoro.work-complete", Some(SystemTime::now()))],
+        );
+
+        assert_eq!(clear(&store).await.unwrap(), 2);
+        assert!(store.is_empty());
+        assert_eq!(clear(&store).await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,136 @@
+//! Confirmation flow for `uninstall --purge`.
+//!
+//! The files to delete are listed and the user is asked to confirm before
+//! anything is removed, unless `--yes` was given.
+
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::launchagent::remove_app_files;
+
+// ============================================================================
+// Purge
+// ============================================================================
+
+/// Deletes the existing paths among `paths` after confirmation.
+///
+/// Without `yes`, the paths are listed on `output` and a `y`/`yes` answer is
+/// read from `input`. Returns the removed paths, or `None` if the user
+/// declined.
+///
+/// # Errors
+///
+/// Returns an error if the prompt cannot be written or read, or if a path
+/// cannot be removed.
+pub fn purge<R: BufRead, W: Write>(
+    paths: &[PathBuf],
+    yes: bool,
+    input: R,
+    output: W,
+) -> Result<Option<Vec<PathBuf>>> {
+    let existing: Vec<PathBuf> = paths.iter().filter(|p| p.exists()).cloned().collect();
+    if existing.is_empty() {
+        return Ok(Some(Vec::new()));
+    }
+
+    if !yes && !confirm(&existing, input, output)? {
+        return Ok(None);
+    }
+
+    Ok(Some(remove_app_files(&existing)?))
+}
+
+/// Lists the paths and asks whether to delete them.
+fn confirm<R: BufRead, W: Write>(paths: &[PathBuf], mut input: R, mut output: W) -> Result<bool> {
+    writeln!(output, "以下のファイルを削除します:")?;
+    for path in paths {
+        writeln!(output, "  {}", path.display())?;
+    }
+    write!(output, "よろしいですか？ [y/N]: ")?;
+    output.flush()?;
+
+    let mut answer = String::new();
+    input
+        .read_line(&mut answer)
+        .context("確認の入力を読み取れませんでした")?;
+
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::io::Cursor;
+    use tempfile::TempDir;
+
+    fn setup_files(dir: &TempDir) -> Vec<PathBuf> {
+        let state = dir.path().join("state.json");
+        let history = dir.path().join("history.jsonl");
+        fs::write(&state, "{}").unwrap();
+        fs::write(&history, "").unwrap();
+        vec![state, history, dir.path().join("pomodoro.sock")]
+    }
+
+    #[test]
+    fn test_prompts_without_yes_and_respects_no() {
+        let dir = TempDir::new().unwrap();
+        let paths = setup_files(&dir);
+        let mut output = Vec::new();
+
+        let result = purge(&paths, false, Cursor::new("n\n"), &mut output).unwrap();
+
+        let prompt = String::from_utf8(output).unwrap();
+        assert!(prompt.contains("state.json"));
+        assert!(prompt.contains("[y/N]"));
+        // Only existing files are listed
+        assert!(!prompt.contains("pomodoro.sock"));
+        assert!(result.is_none());
+        assert!(paths[0].exists());
+    }
+
+    #[test]
+    fn test_confirmed_purge_removes_files() {
+        let dir = TempDir::new().unwrap();
+        let paths = setup_files(&dir);
+
+        let removed = purge(&paths, false, Cursor::new("yes\n"), Vec::new())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(removed, paths[..2].to_vec());
+        assert!(!paths[0].exists());
+        assert!(!paths[1].exists());
+    }
+
+    #[test]
+    fn test_yes_skips_prompt() {
+        let dir = TempDir::new().unwrap();
+        let paths = setup_files(&dir);
+        let mut output = Vec::new();
+
+        let removed = purge(&paths, true, Cursor::new(""), &mut output)
+            .unwrap()
+            .unwrap();
+
+        assert!(output.is_empty());
+        assert_eq!(removed.len(), 2);
+    }
+
+    #[test]
+    fn test_empty_answer_declines() {
+        let dir = TempDir::new().unwrap();
+        let paths = setup_files(&dir);
+
+        let result = purge(&paths, false, Cursor::new(""), Vec::new()).unwrap();
+
+        assert!(result.is_none());
+        assert!(paths[1].exists());
+    }
+}
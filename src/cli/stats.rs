@@ -0,0 +1,430 @@
+//! Statistics from the session history for `pomodoro stats`.
+//!
+//! The daemon writes the history (see [`crate::daemon::history`]); `stats`
+//! only reads it, so it works whether or not the daemon is running.
+//!
+//! Without a range the counts cover today; `--since/--until` report totals
+//! and a per-day breakdown for a [`DayRange`] instead.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
+use crate::daemon::history::{DayClock, DayRange, FocusScore, HistoryEntry, HistoryRecord};
+
+// ============================================================================
+// Stats
+// ============================================================================
+
+/// Counts shown by `stats`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Work sessions completed
+    pub pomodoros_completed: usize,
+    /// Work sessions abandoned
+    pub work_abandoned: usize,
+    /// Breaks skipped
+    pub breaks_skipped: usize,
+}
+
+impl Stats {
+    /// Counts the history entries of `day`, as told by `clock`.
+    pub fn for_day(entries: &[HistoryEntry], day: NaiveDate, clock: DayClock) -> Self {
+        Self::for_range(entries, DayRange::day(day), clock)
+    }
+
+    /// Counts the history entries of the days in `range`, as told by `clock`.
+    pub fn for_range(entries: &[HistoryEntry], range: DayRange, clock: DayClock) -> Self {
+        let mut stats = Self::default();
+        for (_, entry) in range.filter(entries, clock) {
+            stats.add(&entry.record);
+        }
+        stats
+    }
+
+    /// Counts one record.
+    fn add(&mut self, record: &HistoryRecord) {
+        match record {
+            HistoryRecord::WorkCompleted { .. } => self.pomodoros_completed += 1,
+            HistoryRecord::BreakSkipped { .. } => self.breaks_skipped += 1,
+            HistoryRecord::WorkAbandoned { .. } => self.work_abandoned += 1,
+            // The pomodoro was counted when the session first completed
+            HistoryRecord::WorkExtended { .. } => {}
+        }
+    }
+
+    /// Returns the focus score of the counted work sessions.
+    pub fn focus_score(&self) -> Option<FocusScore> {
+        FocusScore::new(self.pomodoros_completed, self.work_abandoned)
+    }
+
+    /// Formats the counts for `stats`, one per line.
+    pub fn format(&self) -> String {
+        format!(
+            "今日スキップした休憩: {}回\n今日の集中度: {}",
+            self.breaks_skipped,
+            self.format_focus()
+        )
+    }
+
+    /// Formats the counts on one line, for the rows of a range report.
+    fn summary(&self) -> String {
+        format!(
+            "完了したポモドーロ {}回 / スキップした休憩 {}回 / 集中度 {}",
+            self.pomodoros_completed,
+            self.breaks_skipped,
+            self.format_focus()
+        )
+    }
+
+    /// Formats the focus score, "N/A" without any work session.
+    fn format_focus(&self) -> String {
+        self.focus_score()
+            .map_or_else(|| "N/A".to_string(), |score| score.to_string())
+    }
+}
+
+// ============================================================================
+// RangeStats
+// ============================================================================
+
+/// Totals and per-day counts, shown by `stats --since/--until`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeStats {
+    /// The days reported on
+    pub range: DayRange,
+    /// Counts over the whole range
+    pub total: Stats,
+    /// Counts per day, for every day of the range
+    pub days: BTreeMap<NaiveDate, Stats>,
+}
+
+impl RangeStats {
+    /// Counts the history entries of the days in `range`, as told by `clock`.
+    pub fn for_range(entries: &[HistoryEntry], range: DayRange, clock: DayClock) -> Self {
+        let mut total = Stats::default();
+        let mut days: BTreeMap<_, _> = range.days().map(|day| (day, Stats::default())).collect();
+        for (day, entry) in range.filter(entries, clock) {
+            total.add(&entry.record);
+            days.entry(day).or_default().add(&entry.record);
+        }
+        Self { range, total, days }
+    }
+
+    /// Formats the totals, then one line per day.
+    pub fn format(&self) -> String {
+        let mut lines = vec![
+            format!("{}の統計:", self.range),
+            format!("  合計: {}", self.total.summary()),
+        ];
+        for (day, stats) in &self.days {
+            lines.push(format!("  {}: {}", day, stats.summary()));
+        }
+        lines.join("\n")
+    }
+}
+
+// ============================================================================
+// TagStats
+// ============================================================================
+
+/// Completed pomodoros per tag, shown by `stats --by-tag`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagStats {
+    /// Count per tag, in tag order
+    pub tagged: BTreeMap<String, usize>,
+    /// Count of sessions without a tag
+    pub untagged: usize,
+}
+
+impl TagStats {
+    /// Counts the completed work sessions of `day`, as told by `clock`, by tag.
+    pub fn for_day(entries: &[HistoryEntry], day: NaiveDate, clock: DayClock) -> Self {
+        Self::for_range(entries, DayRange::day(day), clock)
+    }
+
+    /// Counts the completed work sessions of the days in `range`, as told
+    /// by `clock`, by tag.
+    pub fn for_range(entries: &[HistoryEntry], range: DayRange, clock: DayClock) -> Self {
+        let mut stats = Self::default();
+        for (_, entry) in range.filter(entries, clock) {
+            match &entry.record {
+                HistoryRecord::WorkCompleted { tag: Some(tag), .. } => {
+                    *stats.tagged.entry(tag.clone()).or_default() += 1;
+                }
+                HistoryRecord::WorkCompleted { tag: None, .. } => stats.untagged += 1,
+                HistoryRecord::WorkExtended { .. }
+                | HistoryRecord::BreakSkipped { .. }
+                | HistoryRecord::WorkAbandoned { .. } => {}
+            }
+        }
+        stats
+    }
+
+    /// Formats today's counts for `stats --by-tag`, one tag per line.
+    pub fn format(&self) -> String {
+        self.format_for("今日")
+    }
+
+    /// Formats the counts, one tag per line, headed by `period` (e.g.
+    /// "今日" or "2026-03-01〜2026-03-07に").
+    pub fn format_for(&self, period: &str) -> String {
+        if self.tagged.is_empty() && self.untagged == 0 {
+            return format!("{}完了したポモドーロはありません", period);
+        }
+        let mut lines = vec![format!("{}完了したポモドーロ（タグ別）:", period)];
+        for (tag, count) in &self.tagged {
+            lines.push(format!("  {}: {}回", tag, count));
+        }
+        if self.untagged > 0 {
+            lines.push(format!("  (タグなし): {}回", self.untagged));
+        }
+        lines.join("\n")
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, TimeZone};
+
+    /// Japan Standard Time, so day boundaries differ from UTC.
+    fn jst() -> FixedOffset {
+        FixedOffset::east_opt(9 * 3600).unwrap()
+    }
+
+    fn skipped_at(day: u32, hour: u32) -> HistoryEntry {
+        HistoryEntry {
+            at: jst().with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap(),
+            record: HistoryRecord::BreakSkipped {
+                elapsed_seconds: 60,
+            },
+        }
+    }
+
+    #[test]
+    fn test_counts_only_the_given_day() {
+        let entries = [skipped_at(1, 23), skipped_at(2, 9), skipped_at(2, 15)];
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+        let stats = Stats::for_day(&entries, day, DayClock::Local);
+
+        assert_eq!(stats.breaks_skipped, 2);
+        assert_eq!(
+            stats.format(),
+            "今日スキップした休憩: 2回\n今日の集中度: N/A"
+        );
+    }
+
+    fn completed_at(day: u32, tag: Option<&str>) -> HistoryEntry {
+        HistoryEntry {
+            at: jst().with_ymd_and_hms(2026, 3, day, 10, 0, 0).unwrap(),
+            record: HistoryRecord::WorkCompleted {
+                task_name: Some("Docs".to_string()),
+                tag: tag.map(str::to_string),
+                work_seconds: 1500,
+            },
+        }
+    }
+
+    #[test]
+    fn test_extension_does_not_count_as_another_pomodoro() {
+        let extended = HistoryEntry {
+            at: jst().with_ymd_and_hms(2026, 3, 2, 10, 30, 0).unwrap(),
+            record: HistoryRecord::WorkExtended {
+                task_name: Some("Docs".to_string()),
+                tag: Some("project-x".to_string()),
+                work_seconds: 300,
+            },
+        };
+        let entries = [completed_at(2, Some("project-x")), extended];
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+        let stats = Stats::for_day(&entries, day, DayClock::Local);
+        let tags = TagStats::for_day(&entries, day, DayClock::Local);
+
+        assert_eq!(stats.pomodoros_completed, 1);
+        assert_eq!(stats.focus_score(), FocusScore::new(1, 0));
+        assert_eq!(tags.tagged.get("project-x"), Some(&1));
+    }
+
+    #[test]
+    fn test_by_tag_counts_completed_sessions_of_the_day() {
+        let entries = [
+            completed_at(1, Some("project-x")),
+            completed_at(2, Some("project-y")),
+            completed_at(2, Some("project-x")),
+            completed_at(2, None),
+            completed_at(2, Some("project-x")),
+            skipped_at(2, 11),
+        ];
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+        let stats = TagStats::for_day(&entries, day, DayClock::Local);
+
+        assert_eq!(stats.tagged.get("project-x"), Some(&2));
+        assert_eq!(stats.tagged.get("project-y"), Some(&1));
+        assert_eq!(stats.untagged, 1);
+        assert_eq!(
+            stats.format(),
+            "今日完了したポモドーロ（タグ別）:\n  project-x: 2回\n  project-y: 1回\n  (タグなし): 1回"
+        );
+        // Completed sessions do not count as skipped breaks
+        assert_eq!(
+            Stats::for_day(&entries, day, DayClock::Local).breaks_skipped,
+            1
+        );
+    }
+
+    #[test]
+    fn test_by_tag_without_sessions() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let stats = TagStats::for_day(&[skipped_at(2, 9)], day, DayClock::Local);
+
+        assert_eq!(stats, TagStats::default());
+        assert_eq!(stats.format(), "今日完了したポモドーロはありません");
+    }
+
+    #[test]
+    fn test_empty_history() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        assert_eq!(Stats::for_day(&[], day, DayClock::Local), Stats::default());
+    }
+
+    #[test]
+    fn test_range_totals_and_per_day_breakdown() {
+        let entries = [
+            completed_at(1, None),
+            completed_at(2, Some("project-x")),
+            skipped_at(2, 11),
+            completed_at(2, None),
+            completed_at(4, Some("project-x")),
+            completed_at(5, None),
+        ];
+        let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+        let range = DayRange::new(march(2), march(4)).unwrap();
+
+        let stats = RangeStats::for_range(&entries, range, DayClock::Local);
+
+        assert_eq!(
+            stats.total,
+            Stats {
+                pomodoros_completed: 3,
+                work_abandoned: 0,
+                breaks_skipped: 1,
+            }
+        );
+        assert_eq!(stats.days.len(), 3);
+        assert_eq!(stats.days[&march(3)], Stats::default());
+        assert_eq!(
+            stats.format(),
+            "2026-03-02〜2026-03-04の統計:\n\
+             \x20 合計: 完了したポモドーロ 3回 / スキップした休憩 1回 / 集中度 100% (3/3)\n\
+             \x20 2026-03-02: 完了したポモドーロ 2回 / スキップした休憩 1回 / 集中度 100% (2/2)\n\
+             \x20 2026-03-03: 完了したポモドーロ 0回 / スキップした休憩 0回 / 集中度 N/A\n\
+             \x20 2026-03-04: 完了したポモドーロ 1回 / スキップした休憩 0回 / 集中度 100% (1/1)"
+        );
+    }
+
+    #[test]
+    fn test_focus_score_of_the_day() {
+        let abandoned_at = |hour| HistoryEntry {
+            at: jst().with_ymd_and_hms(2026, 3, 2, hour, 0, 0).unwrap(),
+            record: HistoryRecord::WorkAbandoned {
+                elapsed_seconds: 600,
+            },
+        };
+        let mut entries: Vec<_> = (0..4).map(|_| completed_at(2, None)).collect();
+        entries.push(abandoned_at(15));
+        entries.push(abandoned_at(16));
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+        let stats = Stats::for_day(&entries, day, DayClock::Local);
+
+        assert_eq!(stats.work_abandoned, 2);
+        assert_eq!(
+            stats.format(),
+            "今日スキップした休憩: 0回\n今日の集中度: 67% (4/6)"
+        );
+        // Abandoned sessions have no tag to count under
+        assert_eq!(
+            TagStats::for_day(&entries, day, DayClock::Local).untagged,
+            4
+        );
+    }
+
+    #[test]
+    fn test_range_by_tag() {
+        let entries = [
+            completed_at(1, Some("project-x")),
+            completed_at(2, Some("project-x")),
+            completed_at(3, Some("project-x")),
+            completed_at(3, None),
+        ];
+        let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+        let range = DayRange::new(march(2), march(3)).unwrap();
+
+        let stats = TagStats::for_range(&entries, range, DayClock::Local);
+
+        assert_eq!(stats.tagged.get("project-x"), Some(&2));
+        assert_eq!(stats.untagged, 1);
+        assert_eq!(
+            TagStats::default().format_for(&format!("{}に", range)),
+            "2026-03-02〜2026-03-03に完了したポモドーロはありません"
+        );
+    }
+
+    #[test]
+    fn test_range_days_follow_the_clock() {
+        // 08:00 on March 2 in JST is 23:00 on March 1 in UTC
+        let entries = [HistoryEntry {
+            at: jst().with_ymd_and_hms(2026, 3, 2, 8, 0, 0).unwrap(),
+            ..completed_at(2, None)
+        }];
+        let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+        let range = DayRange::new(march(1), march(2)).unwrap();
+
+        let local = RangeStats::for_range(&entries, range, DayClock::Local);
+        let utc = RangeStats::for_range(&entries, range, DayClock::Utc);
+
+        assert_eq!(local.days[&march(2)].pomodoros_completed, 1);
+        assert_eq!(utc.days[&march(1)].pomodoros_completed, 1);
+        assert_eq!(local.total, utc.total);
+    }
+
+    #[test]
+    fn test_late_night_session_counts_on_local_day() {
+        // 23:30 on March 1 and 00:30 on March 2 in JST, both March 1 in UTC
+        let late = HistoryEntry {
+            at: jst().with_ymd_and_hms(2026, 3, 1, 23, 30, 0).unwrap(),
+            ..skipped_at(1, 0)
+        };
+        let early = HistoryEntry {
+            at: jst().with_ymd_and_hms(2026, 3, 2, 0, 30, 0).unwrap(),
+            ..skipped_at(1, 0)
+        };
+        let entries = [late, early];
+        let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+
+        assert_eq!(
+            Stats::for_day(&entries, march(1), DayClock::Local).breaks_skipped,
+            1
+        );
+        assert_eq!(
+            Stats::for_day(&entries, march(2), DayClock::Local).breaks_skipped,
+            1
+        );
+        assert_eq!(
+            Stats::for_day(&entries, march(1), DayClock::Utc).breaks_skipped,
+            2
+        );
+        assert_eq!(
+            Stats::for_day(&entries, march(2), DayClock::Utc).breaks_skipped,
+            0
+        );
+    }
+}
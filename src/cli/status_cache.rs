@@ -0,0 +1,206 @@
+//! Client-side cache for `status --short --cache`.
+//!
+//! Shell prompts may run `status --short` on every redraw. With `--cache`,
+//! the last response is kept in `status-cache.json` (see
+//! [`crate::paths::status_cache_path`]) together
+//! with the time it was fetched, and reused while it is younger than the
+//! TTL, so the daemon is asked at most once per TTL however often the
+//! prompt is drawn.
+//!
+//! The cache is best-effort: a missing, unreadable or stale file is a miss,
+//! and a failed write is ignored.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::types::IpcResponse;
+
+use super::client::IpcClient;
+
+/// Default time a cached status is reused.
+pub const DEFAULT_STATUS_CACHE_TTL: Duration = Duration::from_secs(1);
+
+// ============================================================================
+// StatusCache
+// ============================================================================
+
+/// Contents of the cache file.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    /// When the response was fetched (milliseconds since the Unix epoch)
+    #[serde(rename = "fetchedAtMs")]
+    fetched_at_ms: u64,
+    /// The status response
+    response: IpcResponse,
+}
+
+/// Status responses cached in a file for a short time.
+#[derive(Debug, Clone)]
+pub struct StatusCache {
+    path: PathBuf,
+    ttl: Duration,
+}
+
+impl StatusCache {
+    /// Creates a cache stored at `path` whose entries live for `ttl`.
+    pub fn new(path: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            path: path.into(),
+            ttl,
+        }
+    }
+
+    /// Returns the cache file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the cached response if it was fetched less than the TTL
+    /// before `now`.
+    ///
+    /// An entry from the future (the clock was set back) counts as stale.
+    pub fn load(&self, now: SystemTime) -> Option<IpcResponse> {
+        let text = fs::read_to_string(&self.path).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&text).ok()?;
+        let fetched_at = UNIX_EPOCH + Duration::from_millis(entry.fetched_at_ms);
+        let age = now.duration_since(fetched_at).ok()?;
+        (age < self.ttl).then_some(entry.response)
+    }
+
+    /// Stores a response fetched at `now`.
+    ///
+    /// The file is written under a per-process temporary name and renamed,
+    /// so concurrent prompts never read a half-written cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn store(&self, response: &IpcResponse, now: SystemTime) -> Result<()> {
+        let fetched_at_ms = now
+            .duration_since(UNIX_EPOCH)
+            .map(|since| u64::try_from(since.as_millis()).unwrap_or(u64::MAX))
+            .unwrap_or(0);
+        let entry = CacheEntry {
+            fetched_at_ms,
+            response: response.clone(),
+        };
+        let json = serde_json::to_string(&entry).context("状態のシリアライズに失敗しました")?;
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("ディレクトリを作成できません: {}", parent.display()))?;
+        }
+        let tmp_path = self
+            .path
+            .with_extension(format!("json.{}.tmp", std::process::id()));
+        fs::write(&tmp_path, json)
+            .with_context(|| format!("キャッシュを書き込めません: {}", tmp_path.display()))?;
+        fs::rename(&tmp_path, &self.path)
+            .with_context(|| format!("キャッシュを書き込めません: {}", self.path.display()))?;
+
+        Ok(())
+    }
+
+    /// Returns the cached status, or asks the daemon and caches its answer.
+    ///
+    /// The daemon is queried with [`IpcClient::status_quick`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache misses and the daemon cannot be reached.
+    pub async fn status(&self, client: &IpcClient) -> Result<IpcResponse> {
+        if let Some(response) = self.load(SystemTime::now()) {
+            return Ok(response);
+        }
+
+        let response = client.status_quick().await?;
+        if let Err(e) = self.store(&response, SystemTime::now()) {
+            tracing::debug!("状態のキャッシュを保存できませんでした: {:#}", e);
+        }
+        Ok(response)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ResponseData;
+    use tempfile::TempDir;
+
+    fn working(remaining_seconds: u32) -> IpcResponse {
+        IpcResponse::success(
+            "",
+            Some(ResponseData {
+                state: Some("working".to_string()),
+                remaining_seconds: Some(remaining_seconds),
+                ..Default::default()
+            }),
+        )
+    }
+
+    fn cache(dir: &TempDir) -> StatusCache {
+        StatusCache::new(
+            dir.path().join("status-cache.json"),
+            DEFAULT_STATUS_CACHE_TTL,
+        )
+    }
+
+    #[test]
+    fn test_hit_within_ttl() {
+        let dir = TempDir::new().unwrap();
+        let cache = cache(&dir);
+        let fetched = UNIX_EPOCH + Duration::from_secs(1_000_000);
+
+        cache.store(&working(930), fetched).unwrap();
+        let hit = cache.load(fetched + Duration::from_millis(999)).unwrap();
+
+        assert_eq!(hit.data.unwrap().remaining_seconds, Some(930));
+    }
+
+    #[test]
+    fn test_miss_when_stale() {
+        let dir = TempDir::new().unwrap();
+        let cache = cache(&dir);
+        let fetched = UNIX_EPOCH + Duration::from_secs(1_000_000);
+
+        cache.store(&working(930), fetched).unwrap();
+
+        assert!(cache.load(fetched + Duration::from_secs(1)).is_none());
+        // The clock went backwards
+        assert!(cache.load(fetched - Duration::from_secs(5)).is_none());
+    }
+
+    #[test]
+    fn test_miss_without_or_with_broken_file() {
+        let dir = TempDir::new().unwrap();
+        let cache = cache(&dir);
+        let now = SystemTime::now();
+
+        assert!(cache.load(now).is_none());
+
+        fs::write(cache.path(), "not json").unwrap();
+        assert!(cache.load(now).is_none());
+    }
+
+    #[test]
+    fn test_store_replaces_entry() {
+        let dir = TempDir::new().unwrap();
+        let cache = cache(&dir);
+        let now = SystemTime::now();
+
+        cache.store(&working(930), now).unwrap();
+        cache.store(&working(929), now).unwrap();
+
+        let hit = cache.load(now).unwrap();
+        assert_eq!(hit.data.unwrap().remaining_seconds, Some(929));
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+}
@@ -0,0 +1,92 @@
+//! Task names piped through stdin for the `start` command.
+//!
+//! `pomodoro start --task -` reads the task name from stdin, so scripts can
+//! pipe generated names without shell escaping:
+//!
+//! ```bash
+//! echo "Fix bug #123" | pomodoro start --task -
+//! ```
+
+use std::io::{self, BufRead, IsTerminal};
+
+use super::commands::validate_task_name;
+
+/// `--task` value that means "read the task name from stdin"
+pub const STDIN_TASK: &str = "-";
+
+// ============================================================================
+// Task Name Reading
+// ============================================================================
+
+/// Reads a task name from `reader`.
+///
+/// Only the first line is used; it is trimmed and checked with the same
+/// rules as `--task`.
+///
+/// # Errors
+///
+/// Returns an error message if the input is empty, cannot be read, or is not
+/// a valid task name.
+pub fn read_task_name<R: BufRead>(mut reader: R) -> Result<String, String> {
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .map_err(|e| format!("標準入力の読み取りに失敗しました: {}", e))?;
+
+    let name = line.trim();
+    if name.is_empty() {
+        return Err("標準入力からタスク名を読み取れませんでした（入力が空です）".to_string());
+    }
+    validate_task_name(name)
+}
+
+/// Reads a task name from stdin.
+///
+/// # Errors
+///
+/// Returns an error message if stdin is a terminal rather than a pipe, or if
+/// [`read_task_name`] fails.
+pub fn read_task_from_stdin() -> Result<String, String> {
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        return Err(
+            "--task - には標準入力へのパイプが必要です（例: echo \"タスク\" | pomodoro start --task -）"
+                .to_string(),
+        );
+    }
+    read_task_name(stdin.lock())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_reads_first_line_trimmed() {
+        let task = read_task_name(Cursor::new("  Fix bug #123  \nsecond line\n"));
+        assert_eq!(task, Ok("Fix bug #123".to_string()));
+    }
+
+    #[test]
+    fn test_reads_line_without_newline() {
+        let task = read_task_name(Cursor::new("JIRA-42"));
+        assert_eq!(task, Ok("JIRA-42".to_string()));
+    }
+
+    #[test]
+    fn test_empty_input_is_error() {
+        assert!(read_task_name(Cursor::new("")).is_err());
+        assert!(read_task_name(Cursor::new("   \n")).is_err());
+    }
+
+    #[test]
+    fn test_too_long_input_is_error() {
+        let long_text = "a".repeat(101);
+        assert!(read_task_name(Cursor::new(long_text)).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//! Confirmation before `stop` ends a long work session.
+//!
+//! Stopping throws away the current session, so when a work session has run
+//! for at least [`STOP_CONFIRM_THRESHOLD_SECONDS`], `stop` asks first. The
+//! prompt is skipped with `--yes`/`--force`, and whenever stdout is not a
+//! terminal, so scripts and hooks never block on it.
+
+use std::io::{BufRead, Write};
+
+use anyhow::{Context, Result};
+
+use crate::types::ResponseData;
+
+/// Work time after which `stop` asks for confirmation.
+pub const STOP_CONFIRM_THRESHOLD_SECONDS: u32 = 5 * 60;
+
+// ============================================================================
+// StopGuard
+// ============================================================================
+
+/// Decides whether `stop` should ask before stopping.
+#[derive(Debug, Clone, Copy)]
+pub struct StopGuard {
+    /// `--yes`/`--force` was given
+    yes: bool,
+    /// stdout is a terminal
+    interactive: bool,
+}
+
+impl StopGuard {
+    /// Creates a guard for the given flag and terminal state.
+    pub fn new(yes: bool, interactive: bool) -> Self {
+        Self { yes, interactive }
+    }
+
+    /// Returns whether a prompt may be shown at all.
+    ///
+    /// When this is `false`, the CLI does not need to fetch the status.
+    pub fn is_enabled(&self) -> bool {
+        !self.yes && self.interactive
+    }
+
+    /// Asks whether to stop the session described by `data`.
+    ///
+    /// Returns `true` without prompting when the guard is disabled or the
+    /// work session is shorter than the threshold (or not a work session).
+    /// Otherwise a `y`/`yes` answer is read from `input`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the prompt cannot be written or read.
+    pub fn confirm<R: BufRead, W: Write>(
+        &self,
+        data: &ResponseData,
+        mut input: R,
+        mut output: W,
+    ) -> Result<bool> {
+        if !self.is_enabled() {
+            return Ok(true);
+        }
+        let elapsed = data.work_elapsed_seconds.unwrap_or(0);
+        if elapsed < STOP_CONFIRM_THRESHOLD_SECONDS {
+            return Ok(true);
+        }
+
+        write!(
+            output,
+            "現在の{}分の作業セッションを停止しますか？ [y/N]: ",
+            elapsed / 60
+        )?;
+        output.flush()?;
+
+        let mut answer = String::new();
+        input
+            .read_line(&mut answer)
+            .context("確認の入力を読み取れませんでした")?;
+
+        Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn working(elapsed: u32) -> ResponseData {
+        ResponseData {
+            state: Some("working".to_string()),
+            work_elapsed_seconds: Some(elapsed),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_prompts_for_long_session() {
+        let guard = StopGuard::new(false, true);
+        let mut output = Vec::new();
+
+        let stop = guard
+            .confirm(&working(23 * 60 + 10), Cursor::new("n\n"), &mut output)
+            .unwrap();
+
+        assert!(!stop);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "現在の23分の作業セッションを停止しますか？ [y/N]: "
+        );
+        assert!(guard
+            .confirm(&working(23 * 60), Cursor::new("y\n"), Vec::new())
+            .unwrap());
+    }
+
+    #[test]
+    fn test_force_skips_prompt() {
+        let guard = StopGuard::new(true, true);
+        let mut output = Vec::new();
+
+        assert!(!guard.is_enabled());
+        assert!(guard
+            .confirm(&working(23 * 60), Cursor::new(""), &mut output)
+            .unwrap());
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_non_tty_skips_prompt() {
+        let guard = StopGuard::new(false, false);
+        let mut output = Vec::new();
+
+        assert!(!guard.is_enabled());
+        assert!(guard
+            .confirm(&working(23 * 60), Cursor::new(""), &mut output)
+            .unwrap());
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_short_or_non_work_session_skips_prompt() {
+        let guard = StopGuard::new(false, true);
+        let mut output = Vec::new();
+
+        assert!(guard
+            .confirm(&working(60), Cursor::new(""), &mut output)
+            .unwrap());
+        assert!(guard
+            .confirm(&ResponseData::default(), Cursor::new(""), &mut output)
+            .unwrap());
+        assert!(output.is_empty());
+    }
+}
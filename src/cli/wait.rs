@@ -0,0 +1,123 @@
+//! Waiting for a work session to end for `start --wait`.
+//!
+//! `start --wait` subscribes to the daemon's events before starting, then
+//! prints the countdown until the work session completes, so scripts can
+//! chain a command after a pomodoro. A session stopped before it completes
+//! is reported as [`WaitOutcome::Stopped`], which the CLI turns into a
+//! non-zero exit. Ctrl+C only stops waiting; the timer keeps running.
+
+use std::future::Future;
+use std::io::Write;
+
+use anyhow::{Context, Result};
+
+use crate::cli::client::EventSubscription;
+use crate::daemon::TimerEvent;
+use crate::menubar::IconManager;
+
+// ============================================================================
+// WaitOutcome
+// ============================================================================
+
+/// How waiting for a work session ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitOutcome {
+    /// The work session ran to the end
+    Completed,
+    /// The session was stopped (or abandoned) before it completed
+    Stopped,
+    /// Waiting was given up (Ctrl+C); the timer is left running
+    Detached,
+}
+
+/// Returns how the work session ended if `event` ends it.
+pub fn work_outcome(event: &TimerEvent) -> Option<WaitOutcome> {
+    match event {
+        TimerEvent::WorkCompleted { .. } => Some(WaitOutcome::Completed),
+        TimerEvent::WorkAbandoned { .. } | TimerEvent::Stopped => Some(WaitOutcome::Stopped),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Waiting
+// ============================================================================
+
+/// Prints the countdown from `subscription` to `out` until the work session
+/// ends or `detach` resolves (the CLI passes Ctrl+C).
+///
+/// # Errors
+///
+/// Returns an error if the events cannot be received, the daemon closes
+/// the connection first, or `out` cannot be written to.
+pub async fn wait_for_work<W, F>(
+    subscription: &mut EventSubscription,
+    mut out: W,
+    detach: F,
+) -> Result<WaitOutcome>
+where
+    W: Write,
+    F: Future,
+{
+    tokio::pin!(detach);
+
+    loop {
+        let event = tokio::select! {
+            event = subscription.next_event() => event?,
+            _ = &mut detach => {
+                writeln!(out).context("出力に失敗しました")?;
+                return Ok(WaitOutcome::Detached);
+            }
+        };
+        let Some(event) = event else {
+            anyhow::bail!("デーモンとの接続が切れました");
+        };
+
+        if let TimerEvent::Tick { remaining_seconds } = event.event {
+            write!(out, "\r🍅 {}", IconManager::format_time(remaining_seconds))
+                .and_then(|()| out.flush())
+                .context("出力に失敗しました")?;
+        }
+        if let Some(outcome) = work_outcome(&event.event) {
+            writeln!(out).context("出力に失敗しました")?;
+            return Ok(outcome);
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_outcome() {
+        let completed = TimerEvent::WorkCompleted {
+            pomodoro_count: 1,
+            task_name: None,
+            tag: None,
+            work_seconds: 1500,
+            extended: false,
+        };
+        assert_eq!(work_outcome(&completed), Some(WaitOutcome::Completed));
+        assert_eq!(
+            work_outcome(&TimerEvent::WorkAbandoned {
+                elapsed_seconds: 60
+            }),
+            Some(WaitOutcome::Stopped)
+        );
+        assert_eq!(
+            work_outcome(&TimerEvent::Stopped),
+            Some(WaitOutcome::Stopped)
+        );
+        assert_eq!(
+            work_outcome(&TimerEvent::Tick {
+                remaining_seconds: 10
+            }),
+            None
+        );
+    }
+}
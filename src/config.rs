@@ -0,0 +1,752 @@
+//! Configuration file for the Pomodoro Timer.
+//!
+//! Settings are read from a TOML file, `~/.pomodoro/config.toml` on macOS
+//! and `~/.config/pomodoro/config.toml` elsewhere by default (see
+//! [`paths::config_path`]). The `--config <PATH>` flag selects another
+//! file. Every key is optional and missing keys keep their built-in values:
+//!
+//! ```toml
+//! work_minutes = 50
+//! break_minutes = 10
+//! long_break_interval = 3
+//! ```
+//!
+//! Named [`Preset`]s bundle common duration sets (`--preset deepwork`).
+//!
+//! `[profiles.<name>]` tables hold named setups. `--profile <NAME>` (or the
+//! `POMODORO_PROFILE` environment variable) merges the chosen profile over
+//! the rest of the file, key by key:
+//!
+//! ```toml
+//! work_minutes = 25
+//!
+//! [profiles.writing]
+//! work_minutes = 50
+//!
+//! [profiles.writing.notifications]
+//! countdown_tick = true
+//! ```
+//!
+//! Shell commands can be run on phase changes from the `[hooks]` table:
+//!
+//! ```toml
+//! [hooks]
+//! on_work_start = "dim-lights on"
+//! on_break_start = "dim-lights off"
+//! ```
+//!
+//! Completion notifications can be repeated until acknowledged, and are
+//! removed once their phase is over unless `auto_dismiss` is turned off, in
+//! the `[notifications]` table. `countdown_tick` plays a tick on the last
+//! few seconds of each phase, and `notification_sound` names the sound
+//! Notification Center plays with each notification (the system default
+//! if unset). The completion sound and the tick play on the system default
+//! output device unless `output_device` names another one (see
+//! `pomodoro sound devices`). The "take a break" notification suggests one of
+//! `break_activities` in turn (an empty list turns the suggestion off).
+//! The completion titles are templates where `{task}` is the current task
+//! (empty without one):
+//!
+//! ```toml
+//! [notifications]
+//! nag_interval_seconds = 120
+//! nag_max_count = 5
+//! auto_dismiss = false
+//! countdown_tick = true
+//! notification_sound = "Glass.aiff"
+//! output_device = "MacBook Pro Speakers"
+//! break_activities = ["ストレッチ", "散歩"]
+//! work_complete_title = "🍅 {task} done"
+//! break_complete_title = "☕ Back to work"
+//! ```
+//!
+//! Phase changes can be spoken with macOS `say` from the `[announce]`
+//! table (an empty phrase stays silent):
+//!
+//! ```toml
+//! [announce]
+//! enabled = true
+//! voice = "Samantha"
+//! break_start = "Time for a break"
+//! ```
+//!
+//! The daemon can shut itself down after sitting stopped, with no client
+//! connected, for a while. The LaunchAgent does not restart a clean exit,
+//! so it stays down until the next login or until it is started again.
+//! A connection whose request is not answered within
+//! `request_timeout_seconds` (10 by default) is closed. The history file
+//! is rotated once it reaches `history_max_mb` (5 by default):
+//!
+//! ```toml
+//! [daemon]
+//! idle_shutdown_minutes = 30
+//! request_timeout_seconds = 10
+//! history_max_mb = 5
+//! ```
+//!
+//! The glyphs of the menu bar title and `status --short` are chosen in the
+//! `[display]` table (`emoji`, `ascii` or `nerdfont`). Seconds can be left
+//! out of the menu bar title (which then reads "16m") separately from the
+//! live countdown of `pomodoro run`:
+//!
+//! ```toml
+//! [display]
+//! icon_style = "ascii"
+//! menu_bar_seconds = false
+//! countdown_seconds = true
+//! ```
+//!
+//! `config show --json` prints every setting in effect as an
+//! [`EffectiveConfig`].
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::focus::FocusModeConfig;
+use crate::menubar::IconStyle;
+use crate::paths;
+use crate::template::{render_notification_title, NOTIFICATION_TITLE_PLACEHOLDERS};
+use crate::types::PomodoroConfig;
+
+/// Environment variable selecting a profile when `--profile` is not given.
+pub const PROFILE_ENV: &str = "POMODORO_PROFILE";
+
+/// Table of the file holding the named profiles.
+const PROFILES_KEY: &str = "profiles";
+
+// ============================================================================
+// Config
+// ============================================================================
+
+/// Contents of the configuration file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Timer settings (top-level keys of the file)
+    #[serde(flatten)]
+    pub timer: PomodoroConfig,
+    /// Commands run on phase changes (`[hooks]` table)
+    pub hooks: HooksConfig,
+    /// Completion notification settings (`[notifications]` table)
+    pub notifications: NotificationsConfig,
+    /// Spoken phase announcements (`[announce]` table)
+    pub announce: AnnounceConfig,
+    /// Daemon process settings (`[daemon]` table)
+    pub daemon: DaemonConfig,
+    /// Title display settings (`[display]` table)
+    pub display: DisplayConfig,
+}
+
+/// Shell commands the daemon runs when a phase changes.
+///
+/// Each command is run with `sh -c`; see [`crate::daemon::hooks`] for the
+/// environment variables it receives.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Run when a work session starts
+    pub on_work_start: Option<String>,
+    /// Run when a break (short or long) starts
+    pub on_break_start: Option<String>,
+    /// Run when the timer stops
+    pub on_stop: Option<String>,
+}
+
+/// Settings for completion notifications.
+///
+/// See [`crate::daemon::nag`] for what counts as an acknowledgment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationsConfig {
+    /// Seconds between repeats (0 disables repeating)
+    pub nag_interval_seconds: u32,
+    /// Maximum number of repeats per completion (0 disables repeating)
+    pub nag_max_count: u32,
+    /// Remove a delivered completion notification once the phase it
+    /// announced has ended (macOS)
+    pub auto_dismiss: bool,
+    /// Play a short tick on each of the last seconds of a phase
+    pub countdown_tick: bool,
+    /// Sound file played by Notification Center with each notification,
+    /// looked up in the `Library/Sounds` folders (the system default
+    /// sound if `None`)
+    pub notification_sound: Option<String>,
+    /// Name of the audio output device for the completion sound and the
+    /// tick (the default device if `None` or not found)
+    pub output_device: Option<String>,
+    /// Suggestions shown in turn when a break starts (none if empty)
+    pub break_activities: Vec<String>,
+    /// Title template of the work complete notification
+    pub work_complete_title: String,
+    /// Title template of the break complete notification
+    pub break_complete_title: String,
+    /// Title template of the long break complete notification
+    pub long_break_complete_title: String,
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            nag_interval_seconds: 0,
+            nag_max_count: 3,
+            auto_dismiss: true,
+            countdown_tick: false,
+            notification_sound: None,
+            output_device: None,
+            break_activities: default_break_activities(),
+            work_complete_title: "🍅 ポモドーロタイマー".to_string(),
+            break_complete_title: "☕ ポモドーロタイマー".to_string(),
+            long_break_complete_title: "☕ ポモドーロタイマー".to_string(),
+        }
+    }
+}
+
+impl NotificationsConfig {
+    /// Checks that the title templates only use known placeholders.
+    fn validate_titles(&self) -> Result<()> {
+        let titles = [
+            ("work_complete_title", &self.work_complete_title),
+            ("break_complete_title", &self.break_complete_title),
+            ("long_break_complete_title", &self.long_break_complete_title),
+        ];
+        for (key, template) in titles {
+            render_notification_title(template, None).with_context(|| {
+                format!(
+                    "{} が不正です（使用できるプレースホルダー: {}）",
+                    key,
+                    NOTIFICATION_TITLE_PLACEHOLDERS.join(", ")
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the built-in break activity suggestions.
+fn default_break_activities() -> Vec<String> {
+    ["ストレッチ", "水を飲む", "6m先を20秒眺める", "深呼吸"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Settings for spoken phase announcements (macOS).
+///
+/// See [`crate::daemon::announce`] for when each phrase is spoken.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnnounceConfig {
+    /// Speak the phrases at all
+    pub enabled: bool,
+    /// Voice passed to `say -v` (the system voice if `None`)
+    pub voice: Option<String>,
+    /// Spoken when a work session starts
+    pub work_start: String,
+    /// Spoken when a short break starts
+    pub break_start: String,
+    /// Spoken when a long break starts
+    pub long_break_start: String,
+    /// Spoken when the timer stops
+    pub stop: String,
+}
+
+impl Default for AnnounceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            voice: None,
+            work_start: "Work time".to_string(),
+            break_start: "Break time".to_string(),
+            long_break_start: "Long break time".to_string(),
+            stop: String::new(),
+        }
+    }
+}
+
+/// Settings for the daemon process itself.
+///
+/// See [`crate::daemon::idle`] for what counts as idle.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DaemonConfig {
+    /// Shut down after this many idle minutes (never if `None`)
+    pub idle_shutdown_minutes: Option<u32>,
+    /// Seconds one IPC request may take before its connection is closed
+    /// (10 if `None`); read at startup
+    pub request_timeout_seconds: Option<u32>,
+    /// Megabytes the history file may reach before it is rotated (5 if
+    /// `None`); read at startup
+    pub history_max_mb: Option<u32>,
+}
+
+/// How the menu bar title, `status --short` and the `run` countdown are
+/// drawn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    /// Glyph set for the phase prefix (`--icon-style` overrides it)
+    pub icon_style: IconStyle,
+    /// Show seconds in the menu bar title and `status --short`
+    pub menu_bar_seconds: bool,
+    /// Show seconds in the `run` countdown (`--no-seconds` overrides it)
+    pub countdown_seconds: bool,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            icon_style: IconStyle::default(),
+            menu_bar_seconds: true,
+            countdown_seconds: true,
+        }
+    }
+}
+
+impl Config {
+    /// Parses and validates configuration from TOML text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not valid TOML or a value is out of range.
+    pub fn from_toml(text: &str) -> Result<Self> {
+        Self::from_toml_with_profile(text, None)
+    }
+
+    /// Parses and validates configuration from TOML text, with the
+    /// `[profiles.<profile>]` table merged over the rest of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not valid TOML, the profile is not
+    /// defined, or a value is out of range.
+    pub fn from_toml_with_profile(text: &str, profile: Option<&str>) -> Result<Self> {
+        let mut table: toml::Table =
+            toml::from_str(text).context("設定ファイルの形式が不正です")?;
+        let profiles = table.remove(PROFILES_KEY);
+        if let Some(name) = profile {
+            let overrides = match profiles.as_ref().and_then(|profiles| profiles.get(name)) {
+                Some(toml::Value::Table(overrides)) => overrides.clone(),
+                Some(_) => anyhow::bail!("[profiles.{}] はテーブルで指定してください", name),
+                None => anyhow::bail!("プロファイルが見つかりません: {}", name),
+            };
+            merge_table(&mut table, overrides);
+        }
+
+        let config: Self = toml::Value::Table(table)
+            .try_into()
+            .context("設定ファイルの形式が不正です")?;
+        config.timer.validate().map_err(|e| anyhow!(e))?;
+        if config.daemon.idle_shutdown_minutes == Some(0) {
+            anyhow::bail!("idle_shutdown_minutes は1以上で指定してください");
+        }
+        if config.daemon.request_timeout_seconds == Some(0) {
+            anyhow::bail!("request_timeout_seconds は1以上で指定してください");
+        }
+        if config.daemon.history_max_mb == Some(0) {
+            anyhow::bail!("history_max_mb は1以上で指定してください");
+        }
+        config.notifications.validate_titles()?;
+        Ok(config)
+    }
+}
+
+/// Overwrites the keys of `base` with `overrides`, merging nested tables.
+fn merge_table(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(value)) => merge_table(base, value),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// EffectiveConfig
+// ============================================================================
+
+/// Every setting in effect, as printed by `config show --json`.
+///
+/// The field names are part of the output format and are kept stable:
+/// `timer` holds the top-level keys of the file (after `--preset`), the
+/// other fields are the tables of the same name, and `focus` is the Focus
+/// mode shortcut setup, which is not read from the file yet. Sounds are
+/// set in `notifications` and the title glyphs in `display`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectiveConfig {
+    /// Timer settings
+    pub timer: PomodoroConfig,
+    /// Commands run on phase changes
+    pub hooks: HooksConfig,
+    /// Completion notification and sound settings
+    pub notifications: NotificationsConfig,
+    /// Spoken phase announcements
+    pub announce: AnnounceConfig,
+    /// Daemon process settings
+    pub daemon: DaemonConfig,
+    /// Menu bar title and `status --short` settings
+    pub display: DisplayConfig,
+    /// Focus mode shortcuts
+    pub focus: FocusModeConfig,
+}
+
+impl EffectiveConfig {
+    /// Resolves the settings of `config`, with `preset` applied to the timer.
+    pub fn resolve(config: Config, preset: Option<Preset>) -> Self {
+        let timer = match preset {
+            Some(preset) => preset.apply(config.timer),
+            None => config.timer,
+        };
+        Self {
+            timer,
+            hooks: config.hooks,
+            notifications: config.notifications,
+            announce: config.announce,
+            daemon: config.daemon,
+            display: config.display,
+            focus: FocusModeConfig::default(),
+        }
+    }
+}
+
+// ============================================================================
+// Preset
+// ============================================================================
+
+/// Predefined duration sets selectable with `--preset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Preset {
+    /// 25 min work, 5 min break, 15 min long break every 4 pomodoros
+    Classic,
+    /// 50 min work, 10 min break, 30 min long break every 3 pomodoros
+    #[value(name = "deepwork")]
+    DeepWork,
+    /// 15 min work, 3 min break, 10 min long break every 4 pomodoros
+    #[value(name = "shortcycle")]
+    ShortCycle,
+}
+
+impl Preset {
+    /// Returns the built-in configuration with this preset's durations.
+    pub fn config(self) -> PomodoroConfig {
+        self.apply(PomodoroConfig::default())
+    }
+
+    /// Overrides the durations and long-break interval of `config`.
+    ///
+    /// Other settings (auto-cycle, strict mode, ...) are left untouched.
+    pub fn apply(self, config: PomodoroConfig) -> PomodoroConfig {
+        let (work, brk, long_brk, interval) = match self {
+            Preset::Classic => (25, 5, 15, 4),
+            Preset::DeepWork => (50, 10, 30, 3),
+            Preset::ShortCycle => (15, 3, 10, 4),
+        };
+        config
+            .with_work_minutes(work)
+            .with_break_minutes(brk)
+            .with_long_break_minutes(long_brk)
+            .with_long_break_interval(interval)
+    }
+}
+
+// ============================================================================
+// Loading
+// ============================================================================
+
+/// Loads the configuration file, with `profile` merged over it.
+///
+/// With `path` set the file must exist. Otherwise the default location is
+/// used, and a missing file falls back to the built-in defaults.
+///
+/// # Errors
+///
+/// Returns an error if an explicitly given file is missing, if the file
+/// cannot be read, parsed or validated, or if `profile` is not defined in it.
+pub fn load_config(path: Option<&Path>, profile: Option<&str>) -> Result<Config> {
+    match path {
+        Some(path) => load_from(path, true, profile),
+        None => load_from(&paths::config_path()?, false, profile),
+    }
+}
+
+/// Returns the profile to use: `flag` (`--profile`) if given, otherwise
+/// `POMODORO_PROFILE` if set and not empty.
+pub fn resolve_profile(flag: Option<String>) -> Option<String> {
+    flag.or_else(|| {
+        std::env::var(PROFILE_ENV)
+            .ok()
+            .filter(|name| !name.is_empty())
+    })
+}
+
+fn load_from(path: &Path, explicit: bool, profile: Option<&str>) -> Result<Config> {
+    if !path.exists() {
+        if explicit {
+            anyhow::bail!("設定ファイルが見つかりません: {}", path.display());
+        }
+        // No file defines no profiles, so asking for one is still an error
+        return Config::from_toml_with_profile("", profile);
+    }
+
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("設定ファイルを読み込めません: {}", path.display()))?;
+    Config::from_toml_with_profile(&text, profile)
+        .with_context(|| format!("設定ファイル: {}", path.display()))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_explicit_missing_file_is_error() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+
+        let err = load_config(Some(&path), None).unwrap_err();
+        assert!(err.to_string().contains("設定ファイルが見つかりません"));
+    }
+
+    #[test]
+    fn test_default_missing_file_uses_defaults() {
+        let dir = TempDir::new().unwrap();
+        let config = load_from(&dir.path().join("config.toml"), false, None).unwrap();
+
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn test_explicit_file_overrides_given_keys_only() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("custom.toml");
+        fs::write(&path, "work_minutes = 50\nlong_break_interval = 3\n").unwrap();
+
+        let config = load_config(Some(&path), None).unwrap();
+
+        assert_eq!(config.timer.work_duration.as_minutes(), 50);
+        assert_eq!(config.timer.long_break_interval, 3);
+        assert_eq!(config.timer.break_duration.as_minutes(), 5);
+        assert!(config.timer.long_break_enabled);
+    }
+
+    const PROFILES: &str = "work_minutes = 25\nbreak_minutes = 5\n\
+        [notifications]\nnag_max_count = 1\n\
+        [profiles.writing]\nwork_minutes = 50\n\
+        [profiles.writing.notifications]\ncountdown_tick = true\n\
+        [profiles.coding]\nwork_minutes = 40\n";
+
+    #[test]
+    fn test_profile_overrides_base() {
+        let config = Config::from_toml_with_profile(PROFILES, Some("writing")).unwrap();
+
+        assert_eq!(config.timer.work_duration.as_minutes(), 50);
+        assert_eq!(config.timer.break_duration.as_minutes(), 5);
+        assert!(config.notifications.countdown_tick);
+        assert_eq!(config.notifications.nag_max_count, 1);
+
+        let config = Config::from_toml_with_profile(PROFILES, Some("coding")).unwrap();
+        assert_eq!(config.timer.work_duration.as_minutes(), 40);
+        assert!(!config.notifications.countdown_tick);
+
+        let config = Config::from_toml(PROFILES).unwrap();
+        assert_eq!(config.timer.work_duration.as_minutes(), 25);
+    }
+
+    #[test]
+    fn test_unknown_profile_is_error() {
+        let err = Config::from_toml_with_profile(PROFILES, Some("reading")).unwrap_err();
+        assert!(err.to_string().contains("reading"));
+
+        let dir = TempDir::new().unwrap();
+        assert!(load_from(&dir.path().join("config.toml"), false, Some("writing")).is_err());
+    }
+
+    #[test]
+    fn test_hooks_table() {
+        let config =
+            Config::from_toml("work_minutes = 30\n[hooks]\non_work_start = \"echo start\"\n")
+                .unwrap();
+
+        assert_eq!(config.timer.work_duration.as_minutes(), 30);
+        assert_eq!(config.hooks.on_work_start.as_deref(), Some("echo start"));
+        assert!(config.hooks.on_break_start.is_none());
+        assert!(config.hooks.on_stop.is_none());
+    }
+
+    #[test]
+    fn test_notifications_table() {
+        assert_eq!(Config::default().notifications.nag_interval_seconds, 0);
+
+        let config = Config::from_toml("[notifications]\nnag_interval_seconds = 120\n").unwrap();
+
+        assert_eq!(config.notifications.nag_interval_seconds, 120);
+        assert_eq!(config.notifications.nag_max_count, 3);
+        assert!(config.notifications.auto_dismiss);
+        assert!(!config.notifications.countdown_tick);
+
+        let config = Config::from_toml("[notifications]\nauto_dismiss = false\n").unwrap();
+        assert!(!config.notifications.auto_dismiss);
+
+        let config = Config::from_toml("[notifications]\ncountdown_tick = true\n").unwrap();
+        assert!(config.notifications.countdown_tick);
+        assert!(config.notifications.notification_sound.is_none());
+
+        let config =
+            Config::from_toml("[notifications]\nnotification_sound = \"Glass.aiff\"\n").unwrap();
+        assert_eq!(
+            config.notifications.notification_sound.as_deref(),
+            Some("Glass.aiff")
+        );
+        assert_eq!(config.notifications.break_activities.len(), 4);
+        assert!(config.notifications.output_device.is_none());
+
+        let config = Config::from_toml("[notifications]\noutput_device = \"AirPods\"\n").unwrap();
+        assert_eq!(
+            config.notifications.output_device.as_deref(),
+            Some("AirPods")
+        );
+
+        let config = Config::from_toml("[notifications]\nbreak_activities = []\n").unwrap();
+        assert!(config.notifications.break_activities.is_empty());
+    }
+
+    #[test]
+    fn test_notification_title_templates() {
+        let defaults = NotificationsConfig::default();
+        assert_eq!(defaults.work_complete_title, "🍅 ポモドーロタイマー");
+
+        let config =
+            Config::from_toml("[notifications]\nwork_complete_title = \"🍅 {task} done\"\n")
+                .unwrap();
+        assert_eq!(config.notifications.work_complete_title, "🍅 {task} done");
+        assert_eq!(
+            config.notifications.break_complete_title,
+            defaults.break_complete_title
+        );
+
+        let err =
+            Config::from_toml("[notifications]\nbreak_complete_title = \"{phase}\"\n").unwrap_err();
+        assert!(format!("{:#}", err).contains("break_complete_title"));
+    }
+
+    #[test]
+    fn test_announce_table() {
+        let defaults = Config::default().announce;
+        assert!(!defaults.enabled);
+        assert_eq!(defaults.break_start, "Break time");
+
+        let config =
+            Config::from_toml("[announce]\nenabled = true\nvoice = \"Kyoko\"\nstop = \"おわり\"\n")
+                .unwrap();
+
+        assert!(config.announce.enabled);
+        assert_eq!(config.announce.voice.as_deref(), Some("Kyoko"));
+        assert_eq!(config.announce.stop, "おわり");
+        assert_eq!(config.announce.work_start, "Work time");
+    }
+
+    #[test]
+    fn test_daemon_table() {
+        assert_eq!(Config::default().daemon.idle_shutdown_minutes, None);
+
+        let config = Config::from_toml("[daemon]\nidle_shutdown_minutes = 30\n").unwrap();
+        assert_eq!(config.daemon.idle_shutdown_minutes, Some(30));
+
+        assert!(Config::from_toml("[daemon]\nidle_shutdown_minutes = 0\n").is_err());
+
+        let config = Config::from_toml("[daemon]\nrequest_timeout_seconds = 3\n").unwrap();
+        assert_eq!(config.daemon.request_timeout_seconds, Some(3));
+        assert!(Config::from_toml("[daemon]\nrequest_timeout_seconds = 0\n").is_err());
+
+        let config = Config::from_toml("[daemon]\nhistory_max_mb = 20\n").unwrap();
+        assert_eq!(config.daemon.history_max_mb, Some(20));
+        assert!(Config::from_toml("[daemon]\nhistory_max_mb = 0\n").is_err());
+    }
+
+    #[test]
+    fn test_effective_config_round_trip() {
+        let config = Config::from_toml(
+            "work_minutes = 40\n[hooks]\non_work_start = \"echo start\"\n[display]\nicon_style = \"ascii\"\n",
+        )
+        .unwrap();
+        let effective = EffectiveConfig::resolve(config, Some(Preset::DeepWork));
+        assert_eq!(effective.timer.work_duration.as_minutes(), 50);
+
+        let json = serde_json::to_value(&effective).unwrap();
+        assert_eq!(json["timer"]["work_minutes"], 50);
+        assert_eq!(json["hooks"]["on_work_start"], "echo start");
+        assert_eq!(json["display"]["icon_style"], "ascii");
+        assert_eq!(json["focus"]["enable_shortcut_name"], "Enable Work Focus");
+
+        let back: EffectiveConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(back, effective);
+    }
+
+    #[test]
+    fn test_display_table() {
+        assert_eq!(Config::default().display.icon_style, IconStyle::Emoji);
+
+        let config = Config::from_toml("[display]\nicon_style = \"nerdfont\"\n").unwrap();
+        assert_eq!(config.display.icon_style, IconStyle::Nerdfont);
+
+        assert!(Config::from_toml("[display]\nicon_style = \"unicode\"\n").is_err());
+    }
+
+    #[test]
+    fn test_display_seconds() {
+        let config = Config::default();
+        assert!(config.display.menu_bar_seconds);
+        assert!(config.display.countdown_seconds);
+
+        let config = Config::from_toml("[display]\nmenu_bar_seconds = false\n").unwrap();
+        assert!(!config.display.menu_bar_seconds);
+        assert!(config.display.countdown_seconds);
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(Preset::Classic.config(), PomodoroConfig::default());
+
+        let deepwork = Preset::DeepWork.config();
+        assert_eq!(deepwork.work_duration.as_minutes(), 50);
+        assert_eq!(deepwork.break_duration.as_minutes(), 10);
+        assert_eq!(deepwork.long_break_duration.as_minutes(), 30);
+        assert_eq!(deepwork.long_break_interval, 3);
+
+        let shortcycle = Preset::ShortCycle.config();
+        assert_eq!(shortcycle.work_duration.as_minutes(), 15);
+        assert_eq!(shortcycle.break_duration.as_minutes(), 3);
+        assert_eq!(shortcycle.long_break_duration.as_minutes(), 10);
+        assert_eq!(shortcycle.long_break_interval, 4);
+
+        for preset in Preset::value_variants() {
+            assert!(preset.config().validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_preset_keeps_other_settings() {
+        let base = PomodoroConfig::default().with_strict(true);
+        let config = Preset::DeepWork.apply(base);
+
+        assert!(config.strict);
+        assert_eq!(config.work_duration.as_minutes(), 50);
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        assert!(Config::from_toml("work_minutes = 0").is_err());
+        assert!(Config::from_toml("work_minutes = \"long\"").is_err());
+        assert!(Config::from_toml("not toml").is_err());
+    }
+}
@@ -0,0 +1,250 @@
+//! Spoken phase announcements for the Pomodoro Timer daemon.
+//!
+//! With `enabled` set in the `[announce]` table (or `daemon --announce`),
+//! a phrase is spoken with macOS `say` when a phase changes:
+//! - `work_start`: [`TimerEvent::WorkStarted`]
+//! - `break_start` / `long_break_start`: [`TimerEvent::BreakStarted`]
+//! - `stop`: [`TimerEvent::Stopped`]
+//!
+//! An empty phrase stays silent. `say` runs in the background and failures
+//! are only logged, so a missing voice never holds up the timer.
+//!
+//! Commands go through [`CommandRunner`], so tests can record them with
+//! [`MockCommandRunner`] instead of speaking.
+
+use std::io;
+use std::process::Stdio;
+use std::sync::Mutex;
+
+use tokio::process::Command;
+
+use crate::config::AnnounceConfig;
+
+use super::timer::TimerEvent;
+
+/// Path of the macOS speech command.
+pub const SAY_PATH: &str = "/usr/bin/say";
+
+// ============================================================================
+// CommandRunner
+// ============================================================================
+
+/// Starts external commands without waiting for them.
+pub trait CommandRunner {
+    /// Starts `program` with `args` in the background.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command cannot be started.
+    fn spawn(&self, program: &str, args: &[String]) -> io::Result<()>;
+}
+
+/// Runs commands as child processes of the daemon.
+///
+/// Must be used inside a Tokio runtime, which reaps the finished children.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemCommandRunner;
+
+impl CommandRunner for SystemCommandRunner {
+    fn spawn(&self, program: &str, args: &[String]) -> io::Result<()> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let program = program.to_string();
+        tokio::spawn(async move {
+            match child.wait().await {
+                Ok(status) if !status.success() => {
+                    tracing::debug!(%program, %status, "読み上げが失敗しました");
+                }
+                Ok(_) => {}
+                Err(e) => tracing::debug!(%program, "読み上げの実行に失敗しました: {}", e),
+            }
+        });
+        Ok(())
+    }
+}
+
+/// Command runner for testing that records commands instead of running them.
+#[derive(Debug, Default)]
+pub struct MockCommandRunner {
+    calls: Mutex<Vec<(String, Vec<String>)>>,
+}
+
+impl MockCommandRunner {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the recorded commands as `(program, args)` pairs.
+    #[must_use]
+    pub fn calls(&self) -> Vec<(String, Vec<String>)> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+impl CommandRunner for MockCommandRunner {
+    fn spawn(&self, program: &str, args: &[String]) -> io::Result<()> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push((program.to_string(), args.to_vec()));
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Announcer
+// ============================================================================
+
+/// Speaks the configured phrase for phase-change events.
+#[derive(Debug)]
+pub struct Announcer<R> {
+    config: AnnounceConfig,
+    runner: R,
+}
+
+impl<R: CommandRunner> Announcer<R> {
+    /// Creates an announcer that starts `say` through the given runner.
+    pub fn new(config: AnnounceConfig, runner: R) -> Self {
+        Self { config, runner }
+    }
+
+    /// Replaces the settings.
+    pub fn set_config(&mut self, config: AnnounceConfig) {
+        self.config = config;
+    }
+
+    /// Returns the phrase for an event, if one should be spoken.
+    pub fn phrase_for(&self, event: &TimerEvent) -> Option<&str> {
+        if !self.config.enabled {
+            return None;
+        }
+        let phrase = match event {
+            TimerEvent::WorkStarted { .. } => &self.config.work_start,
+            TimerEvent::BreakStarted {
+                is_long_break: false,
+            } => &self.config.break_start,
+            TimerEvent::BreakStarted {
+                is_long_break: true,
+            } => &self.config.long_break_start,
+            TimerEvent::Stopped => &self.config.stop,
+            _ => return None,
+        };
+        Some(phrase.as_str()).filter(|phrase| !phrase.trim().is_empty())
+    }
+
+    /// Speaks the phrase for an event, if any.
+    ///
+    /// Returns whether `say` was started.
+    pub fn observe(&self, event: &TimerEvent) -> bool {
+        let Some(phrase) = self.phrase_for(event) else {
+            return false;
+        };
+
+        let mut args = Vec::new();
+        if let Some(voice) = &self.config.voice {
+            args.push("-v".to_string());
+            args.push(voice.clone());
+        }
+        // Keep a phrase starting with '-' from being read as an option
+        args.push("--".to_string());
+        args.push(phrase.to_string());
+
+        match self.runner.spawn(SAY_PATH, &args) {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!("読み上げを開始できませんでした: {}", e);
+                false
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> AnnounceConfig {
+        AnnounceConfig {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_phrases_for_phase_changes() {
+        let announcer = Announcer::new(enabled(), MockCommandRunner::new());
+
+        assert_eq!(
+            announcer.phrase_for(&TimerEvent::WorkStarted { task_name: None }),
+            Some("Work time")
+        );
+        assert_eq!(
+            announcer.phrase_for(&TimerEvent::BreakStarted {
+                is_long_break: false
+            }),
+            Some("Break time")
+        );
+        assert_eq!(
+            announcer.phrase_for(&TimerEvent::BreakStarted {
+                is_long_break: true
+            }),
+            Some("Long break time")
+        );
+        // The stop phrase is empty by default
+        assert_eq!(announcer.phrase_for(&TimerEvent::Stopped), None);
+        assert_eq!(
+            announcer.phrase_for(&TimerEvent::Paused { reason: None }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_observe_runs_say_with_voice() {
+        let config = AnnounceConfig {
+            voice: Some("Kyoko".to_string()),
+            break_start: "休憩です".to_string(),
+            ..enabled()
+        };
+        let announcer = Announcer::new(config, MockCommandRunner::new());
+
+        assert!(announcer.observe(&TimerEvent::BreakStarted {
+            is_long_break: false
+        }));
+
+        let calls = announcer.runner.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, SAY_PATH);
+        assert_eq!(calls[0].1, ["-v", "Kyoko", "--", "休憩です"]);
+    }
+
+    #[test]
+    fn test_disabled_is_silent() {
+        let announcer = Announcer::new(AnnounceConfig::default(), MockCommandRunner::new());
+
+        assert!(!announcer.observe(&TimerEvent::WorkStarted { task_name: None }));
+        assert!(announcer.runner.calls().is_empty());
+    }
+
+    #[test]
+    fn test_spawn_failure_is_tolerated() {
+        struct FailingRunner;
+        impl CommandRunner for FailingRunner {
+            fn spawn(&self, _program: &str, _args: &[String]) -> io::Result<()> {
+                Err(io::Error::new(io::ErrorKind::NotFound, "no say"))
+            }
+        }
+
+        let announcer = Announcer::new(enabled(), FailingRunner);
+
+        assert!(!announcer.observe(&TimerEvent::WorkStarted { task_name: None }));
+    }
+}
@@ -0,0 +1,156 @@
+//! Delayed automatic start for the Pomodoro Timer daemon.
+//!
+//! With `daemon --auto-start-after <MINUTES>`, a work session begins that
+//! many minutes after the daemon starts (e.g. two minutes after login when
+//! the LaunchAgent runs it). 0, the default, leaves the daemon idle until a
+//! client starts the timer.
+//!
+//! The start fires once. If the timer is already running or paused by
+//! then, it is left alone.
+
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::types::TimerPhase;
+
+use super::clock::{Clock, SystemClock};
+use super::timer::TimerEngine;
+
+// ============================================================================
+// AutoStart
+// ============================================================================
+
+/// One-shot timer that starts a work session after a delay on a [`Clock`].
+#[derive(Debug, Clone)]
+pub struct AutoStart<C: Clock = SystemClock> {
+    clock: C,
+    /// When to start (`None` when disabled or already fired)
+    deadline: Option<Instant>,
+}
+
+impl<C: Clock> AutoStart<C> {
+    /// Creates an auto-start `minutes` from now on `clock` (0 disables it).
+    pub fn from_minutes(minutes: u32, clock: C) -> Self {
+        if minutes == 0 {
+            return Self {
+                clock,
+                deadline: None,
+            };
+        }
+        Self::after(Duration::from_secs(u64::from(minutes) * 60), clock)
+    }
+
+    /// Creates an auto-start `delay` from now on `clock`.
+    pub fn after(delay: Duration, clock: C) -> Self {
+        let deadline = Some(clock.instant() + delay);
+        Self { clock, deadline }
+    }
+
+    /// Returns whether the start is still to come.
+    pub fn is_pending(&self) -> bool {
+        self.deadline.is_some()
+    }
+
+    /// Waits until the start is due.
+    ///
+    /// Never resolves once fired or when disabled. Cancel-safe, so it can
+    /// be polled from `tokio::select!` in a loop.
+    pub async fn wait(&self) {
+        match self.deadline {
+            Some(deadline) => self.clock.sleep_until(deadline).await,
+            None => std::future::pending().await,
+        }
+    }
+
+    /// Starts a work session with no task, unless the timer is already in use.
+    ///
+    /// Returns whether a session was started. Later calls do nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the work session cannot be started.
+    pub fn fire(&mut self, engine: &mut TimerEngine) -> Result<bool> {
+        if self.deadline.take().is_none() {
+            return Ok(false);
+        }
+        if engine.get_state().phase != TimerPhase::Stopped {
+            tracing::info!("タイマーが使用中のため自動開始をスキップしました");
+            return Ok(false);
+        }
+
+        engine.start(None)?;
+        tracing::info!("作業を自動開始しました");
+        Ok(true)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::clock::MockClock;
+    use crate::daemon::timer::TimerEvent;
+    use crate::types::PomodoroConfig;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    async fn test_work_starts_after_delay_and_not_before() {
+        let (mut engine, mut rx) = TimerEngine::with_channel(PomodoroConfig::default());
+        let clock = MockClock::default();
+        let mut auto_start = AutoStart::from_minutes(2, clock.clone());
+
+        clock.advance(Duration::from_secs(119));
+        assert!(timeout(Duration::from_millis(30), auto_start.wait())
+            .await
+            .is_err());
+        assert!(rx.try_recv().is_err());
+
+        clock.advance(Duration::from_secs(1));
+        timeout(Duration::from_secs(1), auto_start.wait())
+            .await
+            .unwrap();
+        assert!(auto_start.fire(&mut engine).unwrap());
+
+        assert_eq!(
+            rx.try_recv().unwrap().event,
+            TimerEvent::WorkStarted { task_name: None }
+        );
+        assert_eq!(engine.get_state().phase, TimerPhase::Working);
+        assert!(!auto_start.is_pending());
+    }
+
+    #[tokio::test]
+    async fn test_fires_only_once() {
+        let (mut engine, _rx) = TimerEngine::with_channel(PomodoroConfig::default());
+        let mut auto_start = AutoStart::after(Duration::ZERO, MockClock::default());
+
+        assert!(auto_start.fire(&mut engine).unwrap());
+        engine.stop().unwrap();
+
+        assert!(!auto_start.fire(&mut engine).unwrap());
+        assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+        assert!(timeout(Duration::from_millis(20), auto_start.wait())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_running_timer_is_left_alone() {
+        let (mut engine, _rx) = TimerEngine::with_channel(PomodoroConfig::default());
+        engine.start(Some("Write docs".to_string())).unwrap();
+        let mut auto_start = AutoStart::after(Duration::ZERO, MockClock::default());
+
+        assert!(!auto_start.fire(&mut engine).unwrap());
+        assert_eq!(engine.get_state().task_name.as_deref(), Some("Write docs"));
+    }
+
+    #[test]
+    fn test_zero_minutes_disables() {
+        assert!(!AutoStart::from_minutes(0, MockClock::default()).is_pending());
+        assert!(AutoStart::from_minutes(2, MockClock::default()).is_pending());
+    }
+}
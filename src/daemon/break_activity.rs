@@ -0,0 +1,96 @@
+//! Break activity suggestions for the Pomodoro Timer daemon.
+//!
+//! The "take a break" notification sent when a work session completes
+//! carries one of the `break_activities` from the `[notifications]` table.
+//! The suggestions are used in turn, so consecutive breaks get different
+//! ones. Blank entries are skipped, and an empty list sends no suggestion.
+
+// ============================================================================
+// BreakActivities
+// ============================================================================
+
+/// Rotates through the configured break activity suggestions.
+#[derive(Debug, Clone, Default)]
+pub struct BreakActivities {
+    activities: Vec<String>,
+    /// Index of the next suggestion
+    next: usize,
+}
+
+impl BreakActivities {
+    /// Creates a rotation over `activities`, ignoring blank entries.
+    pub fn new(activities: Vec<String>) -> Self {
+        Self {
+            activities: activities
+                .into_iter()
+                .filter(|activity| !activity.trim().is_empty())
+                .collect(),
+            next: 0,
+        }
+    }
+
+    /// Replaces the suggestions.
+    ///
+    /// The rotation continues from the same position, wrapped to the new
+    /// list, so a reload does not start over with the first suggestion.
+    pub fn set_activities(&mut self, activities: Vec<String>) {
+        let next = self.next;
+        *self = Self::new(activities);
+        self.next = next;
+    }
+
+    /// Returns the next suggestion and advances the rotation.
+    ///
+    /// Returns `None` if there are no suggestions.
+    pub fn next_activity(&mut self) -> Option<&str> {
+        if self.activities.is_empty() {
+            return None;
+        }
+        let index = self.next % self.activities.len();
+        self.next = index + 1;
+        Some(&self.activities[index])
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activities(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_rotation_advances_and_wraps() {
+        let mut rotation = BreakActivities::new(activities(&["Stretch", "Drink water"]));
+
+        assert_eq!(rotation.next_activity(), Some("Stretch"));
+        assert_eq!(rotation.next_activity(), Some("Drink water"));
+        assert_eq!(rotation.next_activity(), Some("Stretch"));
+    }
+
+    #[test]
+    fn test_blank_entries_and_empty_list() {
+        let mut rotation = BreakActivities::new(activities(&["", "Walk", "  "]));
+        assert_eq!(rotation.next_activity(), Some("Walk"));
+        assert_eq!(rotation.next_activity(), Some("Walk"));
+
+        let mut rotation = BreakActivities::new(Vec::new());
+        assert_eq!(rotation.next_activity(), None);
+    }
+
+    #[test]
+    fn test_set_activities_keeps_position() {
+        let mut rotation = BreakActivities::new(activities(&["a", "b", "c"]));
+        rotation.next_activity();
+
+        rotation.set_activities(activities(&["x", "y"]));
+
+        assert_eq!(rotation.next_activity(), Some("y"));
+        assert_eq!(rotation.next_activity(), Some("x"));
+    }
+}
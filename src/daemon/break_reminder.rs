@@ -0,0 +1,191 @@
+//! Reminders during long breaks.
+//!
+//! With `daemon --break-reminder <COUNT>`, a long break is split into
+//! COUNT + 1 equal parts and a "minutes of break left" notification is sent
+//! at each split, e.g. one reminder halfway through a 30-minute break with
+//! 15 minutes left. 0, the default, sends none. Short breaks never get
+//! reminders.
+//!
+//! The splits are computed from the time left when the long break starts,
+//! so a break that was already running when the daemon started is skipped.
+
+use crate::types::TimerState;
+
+use super::timer::TimerEvent;
+
+/// Largest number of reminders per long break.
+pub const MAX_BREAK_REMINDERS: u32 = 10;
+
+// ============================================================================
+// BreakReminder
+// ============================================================================
+
+/// Decides when to remind of the time left in a long break.
+#[derive(Debug, Clone, Default)]
+pub struct BreakReminder {
+    /// Reminders per long break (0: off)
+    count: u32,
+    /// The long break being followed, if any
+    current: Option<LongBreak>,
+}
+
+/// Progress through the reminders of one long break.
+#[derive(Debug, Clone, Copy)]
+struct LongBreak {
+    /// Length of the break in seconds
+    total_seconds: u32,
+    /// Reminders already sent
+    sent: u32,
+}
+
+impl BreakReminder {
+    /// Creates a reminder sending `count` reminders per long break.
+    pub fn new(count: u32) -> Self {
+        Self {
+            count: count.min(MAX_BREAK_REMINDERS),
+            current: None,
+        }
+    }
+
+    /// Returns whether any reminders are sent.
+    ///
+    /// When this is `false`, the daemon does not need to call
+    /// [`Self::observe`].
+    pub fn is_enabled(&self) -> bool {
+        self.count > 0
+    }
+
+    /// Follows a timer event, with `state` as it is after the event.
+    ///
+    /// Returns the seconds left in the break when a reminder is due. If
+    /// ticks were missed and several splits passed at once, one reminder
+    /// is returned for all of them.
+    pub fn observe(&mut self, event: &TimerEvent, state: &TimerState) -> Option<u32> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        match event {
+            TimerEvent::BreakStarted {
+                is_long_break: true,
+            } => {
+                self.current = Some(LongBreak {
+                    total_seconds: state.remaining_seconds,
+                    sent: 0,
+                });
+                None
+            }
+            TimerEvent::Tick { remaining_seconds } => {
+                let current = self.current.as_mut()?;
+                if current.total_seconds == 0 {
+                    return None;
+                }
+                let elapsed = current.total_seconds.saturating_sub(*remaining_seconds);
+                let due = (u64::from(elapsed) * u64::from(self.count + 1)
+                    / u64::from(current.total_seconds))
+                .min(u64::from(self.count)) as u32;
+                if due <= current.sent {
+                    return None;
+                }
+                current.sent = due;
+                Some(*remaining_seconds)
+            }
+            // Pausing keeps the break; anything else ends it
+            TimerEvent::Paused { .. } | TimerEvent::Resumed => None,
+            _ => {
+                self.current = None;
+                None
+            }
+        }
+    }
+}
+
+/// Returns the minutes left, rounded up, for the reminder text.
+pub fn remaining_minutes(remaining_seconds: u32) -> u32 {
+    remaining_seconds.div_ceil(60)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::timer::TimerEngine;
+    use crate::types::PomodoroConfig;
+
+    /// Runs a long break of `minutes` and returns the seconds left at each
+    /// reminder.
+    fn run_long_break(count: u32, minutes: u32) -> Vec<u32> {
+        let (mut engine, mut rx) = TimerEngine::with_channel(PomodoroConfig::default());
+        let mut reminder = BreakReminder::new(count);
+        let mut reminders = Vec::new();
+
+        engine.start_long_break(minutes).unwrap();
+        for _ in 0..minutes * 60 {
+            while let Ok(stamped) = rx.try_recv() {
+                reminders.extend(reminder.observe(&stamped.event, engine.get_state()));
+            }
+            engine.tick().unwrap();
+        }
+        while let Ok(stamped) = rx.try_recv() {
+            reminders.extend(reminder.observe(&stamped.event, engine.get_state()));
+        }
+        reminders
+    }
+
+    #[test]
+    fn test_one_reminder_at_midpoint() {
+        let reminders = run_long_break(1, 30);
+
+        assert_eq!(reminders, vec![15 * 60]);
+        assert_eq!(remaining_minutes(reminders[0]), 15);
+    }
+
+    #[test]
+    fn test_reminders_split_break_evenly() {
+        assert_eq!(run_long_break(2, 30), vec![20 * 60, 10 * 60]);
+    }
+
+    #[test]
+    fn test_off_by_default() {
+        let reminder = BreakReminder::default();
+
+        assert!(!reminder.is_enabled());
+        assert!(run_long_break(0, 30).is_empty());
+    }
+
+    #[test]
+    fn test_short_break_gets_no_reminder() {
+        let (mut engine, mut rx) = TimerEngine::with_channel(PomodoroConfig::default());
+        let mut reminder = BreakReminder::new(1);
+
+        engine.start_break_only(30).unwrap();
+        for _ in 0..30 * 60 {
+            engine.tick().unwrap();
+            while let Ok(stamped) = rx.try_recv() {
+                assert_eq!(reminder.observe(&stamped.event, engine.get_state()), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_missed_ticks_send_one_reminder() {
+        let state = TimerState::new(PomodoroConfig::default());
+        let mut reminder = BreakReminder::new(3);
+        reminder.current = Some(LongBreak {
+            total_seconds: 1200,
+            sent: 0,
+        });
+
+        let event = TimerEvent::Tick {
+            remaining_seconds: 250,
+        };
+        assert_eq!(reminder.observe(&event, &state), Some(250));
+        let event = TimerEvent::Tick {
+            remaining_seconds: 249,
+        };
+        assert_eq!(reminder.observe(&event, &state), None);
+    }
+}
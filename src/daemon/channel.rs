@@ -0,0 +1,364 @@
+//! Bounded event channel between the timer engine and its consumer.
+//!
+//! The engine must never block or fail because its consumer is slow, and the
+//! consumer must never miss a phase change. The channel therefore holds at
+//! most `capacity` events and, when full, makes room by dropping the oldest
+//! [`Tick`](super::TimerEvent::Tick): ticks only report the countdown, and
+//! the next one carries the up-to-date remaining time anyway.
+//!
+//! All other events change what the timer is doing and are never dropped.
+//! If the queue is full of them, a new one is still accepted (the queue
+//! exceeds `capacity`) while a new tick is discarded. Past `capacity` the
+//! queue only grows by phase changes, which happen a few times per minute
+//! at most, instead of by a tick every second.
+//!
+//! The API mirrors `tokio::sync::mpsc`: [`EventReceiver::recv`] is
+//! cancel-safe and returns `None` once every [`EventSender`] is dropped, and
+//! [`EventSender::send`] fails once the receiver is dropped.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::mpsc::error::{SendError, TryRecvError};
+use tokio::sync::Notify;
+
+use super::timer::TimestampedEvent;
+
+/// Default number of events buffered between the engine and its consumer.
+pub const DEFAULT_EVENT_CAPACITY: usize = 256;
+
+/// Creates a bounded event channel holding up to `capacity` events.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn event_channel(capacity: usize) -> (EventSender, EventReceiver) {
+    assert!(capacity > 0, "event channel capacity must be positive");
+
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(VecDeque::with_capacity(capacity)),
+        capacity,
+        notify: Notify::new(),
+        senders: AtomicUsize::new(1),
+        receiver_alive: AtomicBool::new(true),
+        dropped: AtomicU64::new(0),
+    });
+
+    (
+        EventSender {
+            shared: Arc::clone(&shared),
+        },
+        EventReceiver { shared },
+    )
+}
+
+/// State shared by both ends of the channel.
+#[derive(Debug)]
+struct Shared {
+    /// Buffered events, oldest first
+    queue: Mutex<VecDeque<TimestampedEvent>>,
+    /// Number of events kept before ticks are dropped
+    capacity: usize,
+    /// Wakes the receiver on a new event or when the last sender goes away
+    notify: Notify,
+    /// Number of live senders
+    senders: AtomicUsize,
+    /// Whether the receiver still exists
+    receiver_alive: AtomicBool,
+    /// Number of ticks dropped so far
+    dropped: AtomicU64,
+}
+
+impl Shared {
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<TimestampedEvent>> {
+        // A panic while holding the lock leaves the queue itself intact
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+// ============================================================================
+// EventSender
+// ============================================================================
+
+/// Sending half of the event channel.
+#[derive(Debug)]
+pub struct EventSender {
+    shared: Arc<Shared>,
+}
+
+impl EventSender {
+    /// Queues an event, dropping the oldest tick if the channel is full.
+    ///
+    /// Never waits: a full channel only ever costs ticks.
+    ///
+    /// # Errors
+    ///
+    /// Returns the event back if the receiver has been dropped.
+    pub fn send(&self, event: TimestampedEvent) -> Result<(), SendError<TimestampedEvent>> {
+        if !self.shared.receiver_alive.load(Ordering::Acquire) {
+            return Err(SendError(event));
+        }
+
+        {
+            let mut queue = self.shared.lock();
+            if queue.len() >= self.shared.capacity {
+                if let Some(oldest_tick) =
+                    queue.iter().position(|queued| queued.event.is_droppable())
+                {
+                    queue.remove(oldest_tick);
+                    self.record_drop();
+                } else if event.event.is_droppable() {
+                    self.record_drop();
+                    return Ok(());
+                }
+                // Otherwise a phase change is queued over capacity
+            }
+            queue.push_back(event);
+        }
+
+        self.shared.notify.notify_one();
+        Ok(())
+    }
+
+    /// Returns whether the receiver has been dropped.
+    pub fn is_closed(&self) -> bool {
+        !self.shared.receiver_alive.load(Ordering::Acquire)
+    }
+
+    fn record_drop(&self) {
+        let dropped = self.shared.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+        if dropped.is_power_of_two() {
+            tracing::debug!(
+                dropped,
+                "イベントの受信が遅れているため古いTickを破棄しました"
+            );
+        }
+    }
+}
+
+impl Clone for EventSender {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::AcqRel);
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl Drop for EventSender {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            // Wake the receiver so it can observe the closed channel
+            self.shared.notify.notify_one();
+        }
+    }
+}
+
+// ============================================================================
+// EventReceiver
+// ============================================================================
+
+/// Receiving half of the event channel.
+#[derive(Debug)]
+pub struct EventReceiver {
+    shared: Arc<Shared>,
+}
+
+impl EventReceiver {
+    /// Waits for the next event.
+    ///
+    /// Returns `None` once the channel is empty and every sender is dropped.
+    /// Cancel-safe: no event is lost if the future is dropped.
+    pub async fn recv(&mut self) -> Option<TimestampedEvent> {
+        let shared = Arc::clone(&self.shared);
+        loop {
+            // Register interest before checking, so a send in between is not missed
+            let notified = shared.notify.notified();
+            match self.try_recv() {
+                Ok(event) => return Some(event),
+                Err(TryRecvError::Disconnected) => return None,
+                Err(TryRecvError::Empty) => notified.await,
+            }
+        }
+    }
+
+    /// Takes the next event without waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryRecvError::Empty`] if no event is queued, or
+    /// [`TryRecvError::Disconnected`] if none ever will be.
+    pub fn try_recv(&mut self) -> Result<TimestampedEvent, TryRecvError> {
+        // Read before popping: once closed, every event is already queued
+        let closed = self.shared.senders.load(Ordering::Acquire) == 0;
+        if let Some(event) = self.shared.lock().pop_front() {
+            return Ok(event);
+        }
+        if closed {
+            Err(TryRecvError::Disconnected)
+        } else {
+            Err(TryRecvError::Empty)
+        }
+    }
+
+    /// Returns the number of queued events.
+    pub fn len(&self) -> usize {
+        self.shared.lock().len()
+    }
+
+    /// Returns whether no event is queued.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of ticks dropped because the channel was full.
+    pub fn dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for EventReceiver {
+    fn drop(&mut self) {
+        self.shared.receiver_alive.store(false, Ordering::Release);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::timer::{TimerEngine, TimerEvent};
+    use crate::types::{PhaseDuration, PomodoroConfig};
+
+    fn tick(remaining_seconds: u32) -> TimestampedEvent {
+        TimestampedEvent::now(TimerEvent::Tick { remaining_seconds })
+    }
+
+    fn drain(rx: &mut EventReceiver) -> Vec<TimerEvent> {
+        std::iter::from_fn(|| rx.try_recv().ok())
+            .map(|stamped| stamped.event)
+            .collect()
+    }
+
+    #[test]
+    fn test_full_channel_drops_oldest_tick() {
+        let (tx, mut rx) = event_channel(3);
+        for remaining in (1..=5).rev() {
+            tx.send(tick(remaining)).unwrap();
+        }
+
+        assert_eq!(rx.len(), 3);
+        assert_eq!(rx.dropped(), 2);
+        assert_eq!(
+            drain(&mut rx),
+            vec![
+                TimerEvent::Tick {
+                    remaining_seconds: 3
+                },
+                TimerEvent::Tick {
+                    remaining_seconds: 2
+                },
+                TimerEvent::Tick {
+                    remaining_seconds: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_phase_changes_are_never_dropped() {
+        let (tx, mut rx) = event_channel(2);
+        tx.send(TimestampedEvent::now(TimerEvent::Paused { reason: None }))
+            .unwrap();
+        tx.send(TimestampedEvent::now(TimerEvent::Resumed)).unwrap();
+
+        // Full of phase changes: a tick is discarded, a phase change is kept
+        tx.send(tick(10)).unwrap();
+        tx.send(TimestampedEvent::now(TimerEvent::Stopped)).unwrap();
+
+        assert_eq!(
+            drain(&mut rx),
+            vec![
+                TimerEvent::Paused { reason: None },
+                TimerEvent::Resumed,
+                TimerEvent::Stopped
+            ]
+        );
+        assert_eq!(rx.dropped(), 1);
+    }
+
+    #[test]
+    fn test_flooding_a_stalled_receiver_stays_bounded() {
+        let config = PomodoroConfig {
+            work_duration: PhaseDuration::from_minutes(1),
+            break_duration: PhaseDuration::from_minutes(1),
+            auto_cycle: true,
+            long_break_enabled: false,
+            ..PomodoroConfig::default()
+        };
+        let (tx, mut rx) = event_channel(64);
+        let mut engine = TimerEngine::new(config, tx);
+
+        // Ten work/break cycles without reading a single event
+        engine.start(None).unwrap();
+        for _ in 0..10 * 120 {
+            engine.tick().unwrap();
+            assert!(rx.len() <= 64, "queue grew to {}", rx.len());
+        }
+
+        let events = drain(&mut rx);
+        let phase_changes: Vec<_> = events
+            .iter()
+            .filter(|event| !event.is_droppable())
+            .map(TimerEvent::name)
+            .collect();
+        assert_eq!(phase_changes.len(), 1 + 10 * 4);
+        assert_eq!(phase_changes[0], "work_started");
+        assert_eq!(
+            phase_changes
+                .iter()
+                .filter(|&&name| name == "work_completed")
+                .count(),
+            10
+        );
+        assert!(rx.dropped() > 1000);
+    }
+
+    #[tokio::test]
+    async fn test_recv_ends_when_senders_are_dropped() {
+        let (tx, mut rx) = event_channel(4);
+        let tx2 = tx.clone();
+        tx.send(tick(1)).unwrap();
+        drop(tx);
+
+        assert!(rx.recv().await.is_some());
+        drop(tx2);
+        assert!(rx.recv().await.is_none());
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Disconnected)));
+    }
+
+    #[tokio::test]
+    async fn test_recv_wakes_on_send() {
+        let (tx, mut rx) = event_channel(4);
+        let handle = tokio::spawn(async move { rx.recv().await });
+
+        tokio::task::yield_now().await;
+        tx.send(TimestampedEvent::now(TimerEvent::Stopped)).unwrap();
+
+        let event = handle.await.unwrap().unwrap();
+        assert_eq!(event.event, TimerEvent::Stopped);
+    }
+
+    #[test]
+    fn test_send_after_receiver_dropped_is_error() {
+        let (tx, rx) = event_channel(4);
+        drop(rx);
+
+        assert!(tx.is_closed());
+        assert!(tx.send(tick(1)).is_err());
+    }
+}
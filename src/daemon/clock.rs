@@ -0,0 +1,210 @@
+//! Time source for the Pomodoro Timer daemon.
+//!
+//! The tick loop reads the time and waits between ticks through [`Clock`]
+//! rather than calling [`SystemTime::now`] and `tokio::time::sleep`
+//! directly, and so do the timeouts measured on the monotonic clock
+//! ([`Clock::instant`]): request deadlines, idle shutdown, delayed
+//! auto-start, nagging and notification debouncing. The daemon uses
+//! [`SystemClock`]; tests use [`MockClock`], whose time only moves when
+//! [`MockClock::advance`] is called, so a five-minute gap can be tested
+//! without waiting five minutes.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use tokio::sync::watch;
+
+// ============================================================================
+// Clock
+// ============================================================================
+
+/// Source of the current time and of delays.
+pub trait Clock: Send + Sync {
+    /// Returns the current wall-clock time.
+    fn now(&self) -> SystemTime;
+
+    /// Returns the current monotonic time, for measuring intervals.
+    fn instant(&self) -> Instant;
+
+    /// Waits until `duration` has passed on this clock.
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send;
+
+    /// Waits until this clock's [`Self::instant`] reaches `deadline`.
+    fn sleep_until(&self, deadline: Instant) -> impl Future<Output = ()> + Send {
+        self.sleep(deadline.saturating_duration_since(self.instant()))
+    }
+}
+
+/// The system clock and the tokio timer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn instant(&self) -> Instant {
+        // The tokio clock, so it agrees with `sleep` when tokio time is paused
+        tokio::time::Instant::now().into_std()
+    }
+
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
+        tokio::time::sleep(duration)
+    }
+}
+
+// ============================================================================
+// MockClock
+// ============================================================================
+
+/// Clock for testing that only moves when advanced.
+///
+/// Clones share the same time, so a test can keep one and hand another to
+/// the code under test.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<watch::Sender<SystemTime>>,
+    /// Wall-clock time the clock was created at
+    start: SystemTime,
+    /// Monotonic time standing for `start`
+    start_instant: Instant,
+}
+
+impl MockClock {
+    /// Creates a clock standing at `start`.
+    #[must_use]
+    pub fn new(start: SystemTime) -> Self {
+        Self {
+            now: Arc::new(watch::Sender::new(start)),
+            start,
+            start_instant: Instant::now(),
+        }
+    }
+
+    /// Moves the clock forward by `duration`, waking the sleeps that are due.
+    pub fn advance(&self, duration: Duration) {
+        self.now.send_modify(|now| *now += duration);
+    }
+
+    /// Returns the number of sleeps currently waiting on this clock.
+    ///
+    /// A test can wait for this to become non-zero before advancing, so
+    /// the time is not moved before the code under test starts waiting.
+    #[must_use]
+    pub fn sleepers(&self) -> usize {
+        self.now.receiver_count()
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new(SystemTime::UNIX_EPOCH)
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        *self.now.borrow()
+    }
+
+    fn instant(&self) -> Instant {
+        let advanced = self.now().duration_since(self.start).unwrap_or_default();
+        self.start_instant + advanced
+    }
+
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
+        let deadline = self.now() + duration;
+        let mut rx = self.now.subscribe();
+        async move {
+            // The sender lives as long as `self`, so this only fails once
+            // the clock is gone, and then there is nothing left to wait for
+            let _ = rx.wait_for(|now| *now >= deadline).await;
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    async fn test_mock_sleep_wakes_when_advanced_past_deadline() {
+        let clock = MockClock::default();
+        let sleep = tokio::spawn({
+            let clock = clock.clone();
+            async move { clock.sleep(Duration::from_secs(10)).await }
+        });
+        while clock.sleepers() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        clock.advance(Duration::from_secs(9));
+        tokio::task::yield_now().await;
+        assert!(!sleep.is_finished());
+
+        clock.advance(Duration::from_secs(1));
+        timeout(Duration::from_secs(1), sleep)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            clock.now(),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(10)
+        );
+        assert_eq!(clock.sleepers(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_mock_instant_moves_with_advance() {
+        let clock = MockClock::default();
+        let start = clock.instant();
+
+        clock.advance(Duration::from_secs(90));
+
+        assert_eq!(clock.instant() - start, Duration::from_secs(90));
+        assert_eq!(clock.clone().instant(), clock.instant());
+    }
+
+    #[tokio::test]
+    async fn test_mock_sleep_until_wakes_at_deadline() {
+        let clock = MockClock::default();
+        let deadline = clock.instant() + Duration::from_secs(10);
+        let sleep = tokio::spawn({
+            let clock = clock.clone();
+            async move { clock.sleep_until(deadline).await }
+        });
+        while clock.sleepers() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        clock.advance(Duration::from_secs(9));
+        tokio::task::yield_now().await;
+        assert!(!sleep.is_finished());
+
+        clock.advance(Duration::from_secs(1));
+        timeout(Duration::from_secs(1), sleep)
+            .await
+            .unwrap()
+            .unwrap();
+        // A deadline already passed does not wait
+        timeout(Duration::from_secs(1), clock.sleep_until(deadline))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_zero_sleep_returns_at_once() {
+        let clock = MockClock::default();
+
+        timeout(Duration::from_secs(1), clock.sleep(Duration::ZERO))
+            .await
+            .unwrap();
+    }
+}
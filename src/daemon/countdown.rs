@@ -0,0 +1,195 @@
+//! Countdown tick sound for the Pomodoro Timer daemon.
+//!
+//! With `countdown_tick` set in the `[notifications]` table, a short tick
+//! plays on each of the last [`COUNTDOWN_SECONDS`] seconds of a running
+//! phase. No tick plays when the countdown reaches zero, so it never
+//! overlaps the completion sound.
+//!
+//! Playback is fire-and-forget: the tick is shorter than a second and the
+//! player does not wait for it to finish.
+
+use crate::config::NotificationsConfig;
+use crate::sound::{
+    prewarm_player, try_create_player, RodioSoundPlayer, SoundPlayer, SoundSource, TICK_SOUND_NAME,
+};
+
+use super::timer::TimerEvent;
+
+/// Number of final seconds that get a tick.
+pub const COUNTDOWN_SECONDS: u32 = 3;
+
+// ============================================================================
+// Countdown
+// ============================================================================
+
+/// Plays the tick sound for the final seconds of a phase.
+pub struct Countdown<P> {
+    /// Whether `countdown_tick` is turned on
+    enabled: bool,
+    /// Player for the tick (`None` when audio is unavailable)
+    player: Option<P>,
+    /// Output device the player was opened on (`None` for the default)
+    device: Option<String>,
+}
+
+impl<P: SoundPlayer> Countdown<P> {
+    /// Creates a countdown that plays through the given player.
+    pub fn new(enabled: bool, player: Option<P>) -> Self {
+        Self {
+            enabled,
+            player,
+            device: None,
+        }
+    }
+
+    /// Plays the tick if the event is one of the final seconds.
+    ///
+    /// Returns whether a tick was played.
+    pub fn observe(&self, event: &TimerEvent) -> bool {
+        if !self.enabled || !is_countdown_tick(event) {
+            return false;
+        }
+        let Some(player) = &self.player else {
+            return false;
+        };
+
+        match player.play(&SoundSource::embedded(TICK_SOUND_NAME)) {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::debug!("カウントダウン音を再生できませんでした: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Prewarms the player so the first tick is not delayed.
+    ///
+    /// Returns whether the player was prewarmed (not when the tick is off
+    /// or audio is unavailable).
+    pub fn prewarm(&self) -> bool {
+        match &self.player {
+            Some(player) if self.enabled => prewarm_player(player),
+            _ => false,
+        }
+    }
+}
+
+impl Countdown<RodioSoundPlayer> {
+    /// Creates a countdown from the `[notifications]` settings.
+    ///
+    /// The audio device is only opened when the tick is turned on.
+    pub fn from_config(config: &NotificationsConfig) -> Self {
+        let mut countdown = Self::new(false, None);
+        countdown.set_config(config);
+        countdown
+    }
+
+    /// Replaces the settings, opening the audio device on first use.
+    ///
+    /// A different `output_device` closes the current device; it is
+    /// reopened on the new one.
+    pub fn set_config(&mut self, config: &NotificationsConfig) {
+        self.enabled = config.countdown_tick;
+        if self.device != config.output_device {
+            self.device = config.output_device.clone();
+            self.player = None;
+        }
+        if self.enabled && self.player.is_none() {
+            self.player = try_create_player(self.device.as_deref(), false);
+        }
+    }
+}
+
+/// Returns whether the event is a tick in the final seconds of a phase.
+fn is_countdown_tick(event: &TimerEvent) -> bool {
+    matches!(
+        event,
+        TimerEvent::Tick { remaining_seconds } if (1..=COUNTDOWN_SECONDS).contains(remaining_seconds)
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::channel::{event_channel, DEFAULT_EVENT_CAPACITY};
+    use crate::daemon::timer::TimerEngine;
+    use crate::sound::MockSoundPlayer;
+    use crate::types::PomodoroConfig;
+
+    /// Runs a whole work phase and returns the mock after observing it.
+    fn run_work_phase(enabled: bool) -> Countdown<MockSoundPlayer> {
+        let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+        let mut engine = TimerEngine::new(PomodoroConfig::default(), tx);
+        let countdown = Countdown::new(enabled, Some(MockSoundPlayer::new()));
+
+        engine.start(None).unwrap();
+        for _ in 0..25 * 60 {
+            engine.tick().unwrap();
+        }
+        while let Ok(event) = rx.try_recv() {
+            countdown.observe(&event.event);
+        }
+        countdown
+    }
+
+    #[test]
+    fn test_ticks_in_final_seconds() {
+        let countdown = run_work_phase(true);
+        let player = countdown.player.unwrap();
+
+        assert_eq!(player.play_count(), COUNTDOWN_SECONDS as usize);
+        assert!(player
+            .get_play_calls()
+            .iter()
+            .all(|source| source.name() == TICK_SOUND_NAME));
+    }
+
+    #[test]
+    fn test_disabled_plays_nothing() {
+        let countdown = run_work_phase(false);
+
+        assert_eq!(countdown.player.unwrap().play_count(), 0);
+    }
+
+    #[test]
+    fn test_no_tick_at_zero() {
+        let countdown = Countdown::new(true, Some(MockSoundPlayer::new()));
+
+        assert!(countdown.observe(&TimerEvent::Tick {
+            remaining_seconds: 1
+        }));
+        assert!(!countdown.observe(&TimerEvent::Tick {
+            remaining_seconds: 0
+        }));
+        assert!(!countdown.observe(&TimerEvent::Tick {
+            remaining_seconds: 4
+        }));
+    }
+
+    #[test]
+    fn test_prewarm_only_when_enabled() {
+        let countdown = Countdown::new(true, Some(MockSoundPlayer::new()));
+        assert!(countdown.prewarm());
+        assert_eq!(countdown.player.unwrap().prewarm_count(), 1);
+
+        let countdown = Countdown::new(false, Some(MockSoundPlayer::new()));
+        assert!(!countdown.prewarm());
+        assert_eq!(countdown.player.unwrap().prewarm_count(), 0);
+
+        let countdown: Countdown<MockSoundPlayer> = Countdown::new(true, None);
+        assert!(!countdown.prewarm());
+    }
+
+    #[test]
+    fn test_without_player_is_silent() {
+        let countdown: Countdown<MockSoundPlayer> = Countdown::new(true, None);
+
+        assert!(!countdown.observe(&TimerEvent::Tick {
+            remaining_seconds: 2
+        }));
+    }
+}
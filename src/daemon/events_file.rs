@@ -0,0 +1,136 @@
+//! JSONL event sink for the Pomodoro Timer daemon.
+//!
+//! With `daemon --events-file <PATH>`, every timer event is appended to the
+//! file as one JSON object per line:
+//!
+//! ```text
+//! {"at":"2026-01-01T09:00:00+09:00","event":"work_started","task_name":null}
+//! ```
+//!
+//! The file is opened in append mode and flushed after every event. It is
+//! never rotated.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use super::sink::spawn_sink;
+use super::timer::TimestampedEvent;
+
+// ============================================================================
+// EventsFile
+// ============================================================================
+
+/// Appends timer events to a JSONL file.
+#[derive(Debug)]
+pub struct EventsFile {
+    file: File,
+}
+
+impl EventsFile {
+    /// Opens `path` for appending, creating it if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("イベントファイルを開けません: {}", path.display()))?;
+        Ok(Self { file })
+    }
+
+    /// Appends one event as a JSON line and flushes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be serialized or written.
+    pub fn write(&mut self, event: &TimestampedEvent) -> Result<()> {
+        let mut line = serde_json::to_vec(event).context("Failed to serialize timer event")?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.file.flush()?;
+        Ok(())
+    }
+
+    /// Writes the events received on `events` until the channel closes.
+    ///
+    /// Write failures are logged and the event is dropped.
+    pub fn spawn(mut self, events: broadcast::Receiver<TimestampedEvent>) -> JoinHandle<()> {
+        spawn_sink("イベントファイル", events, move |event| {
+            self.write(event)
+        })
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::channel::{event_channel, DEFAULT_EVENT_CAPACITY};
+    use crate::daemon::timer::{TimerEngine, TimerEvent};
+    use crate::types::PomodoroConfig;
+    use tempfile::TempDir;
+
+    fn event_names(text: &str) -> Vec<String> {
+        text.lines()
+            .map(|line| {
+                let value: serde_json::Value = serde_json::from_str(line).unwrap();
+                assert!(value["at"].is_string());
+                value["event"].as_str().unwrap().to_string()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_start_and_stop_are_written() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let (events_tx, _) = broadcast::channel(16);
+        let handle = EventsFile::open(&path)
+            .unwrap()
+            .spawn(events_tx.subscribe());
+
+        let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+        let mut engine = TimerEngine::new(PomodoroConfig::default(), tx);
+        engine.start(Some("Write docs".to_string())).unwrap();
+        engine.stop().unwrap();
+        drop(engine);
+        while let Some(event) = rx.recv().await {
+            events_tx.send(event).unwrap();
+        }
+        drop(events_tx);
+        handle.await.unwrap();
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(event_names(&text), vec!["work_started", "stopped"]);
+        assert!(text.contains("\"task_name\":\"Write docs\""));
+    }
+
+    #[test]
+    fn test_appends_to_existing_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("events.jsonl");
+        std::fs::write(
+            &path,
+            "{\"at\":\"2026-01-01T09:00:00+09:00\",\"event\":\"paused\"}\n",
+        )
+        .unwrap();
+
+        let mut file = EventsFile::open(&path).unwrap();
+        file.write(&TimestampedEvent::now(TimerEvent::Resumed))
+            .unwrap();
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(event_names(&text), vec!["paused", "resumed"]);
+    }
+}
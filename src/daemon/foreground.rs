@@ -0,0 +1,282 @@
+//! Single work session in the foreground (`pomodoro run`).
+//!
+//! This is the daemon minus the socket: the [`TimerEngine`] runs in-process,
+//! a live countdown is printed on one line, and when the work session ends
+//! the completion sound and notification go out and the session returns.
+//! Nothing is persisted and no IPC server is started, so it works without
+//! an installed or running daemon.
+
+use std::future::Future;
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use crate::config::NotificationsConfig;
+use crate::menubar::ShowSeconds;
+#[cfg(target_os = "macos")]
+use crate::notification::{NotificationTitles, Notifier};
+use crate::sound::{get_default_sound, prewarm_player, try_create_player, RodioSoundPlayer};
+use crate::types::PomodoroConfig;
+
+#[cfg(target_os = "macos")]
+use super::break_activity::BreakActivities;
+use super::countdown::Countdown;
+use super::timer::{TimerEngine, TimerEvent};
+
+/// How long the completion sound is given to play before returning
+const COMPLETION_SOUND_LENGTH: Duration = Duration::from_secs(2);
+
+// ============================================================================
+// Options
+// ============================================================================
+
+/// Options for a foreground session.
+#[derive(Debug, Clone)]
+pub struct ForegroundOptions {
+    /// Time between timer ticks (one second outside of tests)
+    pub tick_interval: Duration,
+    /// Play the completion sound (and the countdown tick, if configured)
+    pub sound: bool,
+    /// Send the completion notification
+    pub notify: bool,
+    /// Countdown tick and notification settings (`[notifications]` table)
+    pub notifications: NotificationsConfig,
+    /// Precision of the countdown
+    pub show_seconds: ShowSeconds,
+}
+
+impl Default for ForegroundOptions {
+    fn default() -> Self {
+        Self {
+            tick_interval: Duration::from_secs(1),
+            sound: true,
+            notify: true,
+            notifications: NotificationsConfig::default(),
+            show_seconds: ShowSeconds::default(),
+        }
+    }
+}
+
+/// How a foreground session ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionOutcome {
+    /// The work session ran to the end
+    Completed,
+    /// The session was stopped before the end (Ctrl+C)
+    Interrupted,
+}
+
+// ============================================================================
+// Session
+// ============================================================================
+
+/// Runs one work session, printing the countdown to `out`.
+///
+/// The session ends when the work phase completes or when `shutdown`
+/// resolves (the CLI passes Ctrl+C), whichever comes first.
+///
+/// # Errors
+///
+/// Returns an error if the session cannot be started or `out` cannot be
+/// written to.
+pub async fn run_session<W, F>(
+    config: PomodoroConfig,
+    task_name: Option<String>,
+    options: &ForegroundOptions,
+    mut out: W,
+    shutdown: F,
+) -> Result<SessionOutcome>
+where
+    W: Write,
+    F: Future,
+{
+    let (mut engine, mut events) = TimerEngine::with_channel(config);
+    let countdown = if options.sound {
+        Countdown::from_config(&options.notifications)
+    } else {
+        Countdown::new(false, None)
+    };
+    // Opened up front so the completion sound is not delayed by the device
+    let completion_player = if options.sound {
+        try_create_player(options.notifications.output_device.as_deref(), false)
+    } else {
+        None
+    };
+    if let Some(player) = &completion_player {
+        prewarm_player(player);
+    }
+    #[cfg(target_os = "macos")]
+    let notifier = if options.notify {
+        let mut notifier = Notifier::select(false, false).await;
+        notifier.set_sound(options.notifications.notification_sound.clone());
+        notifier.set_titles(NotificationTitles::from_config(&options.notifications));
+        Some(notifier)
+    } else {
+        None
+    };
+
+    engine.start(task_name)?;
+    let state = engine.get_state();
+    write_countdown(
+        &mut out,
+        options.show_seconds,
+        state.remaining_seconds,
+        state.task_name.as_deref(),
+    )?;
+
+    let mut ticker = interval(options.tick_interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    // The first tick completes immediately
+    ticker.tick().await;
+    tokio::pin!(shutdown);
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                engine.tick()?;
+                while let Ok(stamped) = events.try_recv() {
+                    countdown.observe(&stamped.event);
+                    match &stamped.event {
+                        TimerEvent::Tick { remaining_seconds } => {
+                            let task_name = engine.get_state().task_name.as_deref();
+                            write_countdown(&mut out, options.show_seconds, *remaining_seconds, task_name)?;
+                        }
+                        TimerEvent::WorkCompleted { .. } => {
+                            writeln!(out, "\n* 作業が完了しました").context("出力に失敗しました")?;
+                            #[cfg(target_os = "macos")]
+                            if let Some(notifier) = &notifier {
+                                let mut activities = BreakActivities::new(
+                                    options.notifications.break_activities.clone(),
+                                );
+                                super::runner::notify(notifier, &stamped.event, &mut activities)
+                                    .await;
+                            }
+                            if let Some(player) = &completion_player {
+                                play_completion_sound(player).await;
+                            }
+                            return Ok(SessionOutcome::Completed);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ = &mut shutdown => {
+                engine.stop()?;
+                writeln!(out, "\n■ 中断しました").context("出力に失敗しました")?;
+                return Ok(SessionOutcome::Interrupted);
+            }
+        }
+    }
+}
+
+/// Rewrites the countdown line (e.g. "🍅 24:59 Write docs").
+fn write_countdown<W: Write>(
+    out: &mut W,
+    show_seconds: ShowSeconds,
+    remaining: u32,
+    task_name: Option<&str>,
+) -> Result<()> {
+    let mut line = format!("\r🍅 {}", show_seconds.format(remaining));
+    if let Some(task) = task_name {
+        line.push(' ');
+        line.push_str(task);
+    }
+    write!(out, "{}", line)
+        .and_then(|()| out.flush())
+        .context("出力に失敗しました")
+}
+
+/// Plays the default notification sound on `player`.
+async fn play_completion_sound(player: &RodioSoundPlayer) {
+    if let Err(e) = player.play(&get_default_sound()) {
+        tracing::warn!("完了音を再生できませんでした: {}", e);
+        return;
+    }
+    // Playback is detached, but the player owns the output stream, which
+    // must outlive the sound
+    tokio::time::sleep(COMPLETION_SOUND_LENGTH).await;
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fast_options() -> ForegroundOptions {
+        ForegroundOptions {
+            tick_interval: Duration::from_millis(1),
+            sound: false,
+            notify: false,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_countdown_line_is_rewritten() {
+        let mut out = Vec::new();
+        let config = PomodoroConfig::default().with_work_minutes(1);
+
+        let outcome = run_session(
+            config,
+            Some("Write docs".to_string()),
+            &fast_options(),
+            &mut out,
+            std::future::pending::<()>(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome, SessionOutcome::Completed);
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("\r🍅 01:00 Write docs"));
+        assert!(text.contains("\r🍅 00:01 Write docs"));
+        assert!(text.ends_with("作業が完了しました\n"));
+    }
+
+    #[tokio::test]
+    async fn test_countdown_without_seconds() {
+        let mut out = Vec::new();
+        let config = PomodoroConfig::default().with_work_minutes(1);
+        let options = ForegroundOptions {
+            show_seconds: ShowSeconds::No,
+            ..fast_options()
+        };
+
+        run_session(
+            config,
+            None,
+            &options,
+            &mut out,
+            std::future::pending::<()>(),
+        )
+        .await
+        .unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("\r🍅 1m"));
+        assert!(text.contains("\r🍅 1m\r"));
+        assert!(!text.contains("00:"));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_interrupts_session() {
+        let mut out = Vec::new();
+
+        let outcome = run_session(
+            PomodoroConfig::default(),
+            None,
+            &fast_options(),
+            &mut out,
+            std::future::ready(()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome, SessionOutcome::Interrupted);
+        assert!(String::from_utf8(out).unwrap().contains("中断しました"));
+    }
+}
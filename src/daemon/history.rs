@@ -0,0 +1,1028 @@
+//! Session history for the Pomodoro Timer daemon.
+//!
+//! The daemon appends a record to the history file (`history.jsonl` in the
+//! state directory, see [`paths::history_path`](crate::paths::history_path))
+//! for the events worth keeping, one JSON object per line:
+//!
+//! ```text
+//! {"at":"2026-01-01T08:55:00+09:00","record":"work_completed","task_name":"Docs","tag":"project-x","work_seconds":1500}
+//! {"at":"2026-01-01T09:00:00+09:00","record":"work_extended","task_name":"Docs","work_seconds":300}
+//! {"at":"2026-01-01T09:10:00+09:00","record":"break_skipped","elapsed_seconds":120}
+//! {"at":"2026-01-01T09:40:00+09:00","record":"work_abandoned","elapsed_seconds":600}
+//! ```
+//!
+//! Completed and abandoned work sessions and skipped breaks are recorded;
+//! `pomodoro stats` counts them, and rates the focus of a day by the share
+//! of its work sessions that were completed (see [`FocusScore`]). The
+//! extension of a completed session (`extend`) gets a `work_extended`
+//! record of its own, which adds work time but no second pomodoro.
+//!
+//! Once the file would grow past `history_max_mb` in the `[daemon]` table
+//! (5 MB by default), it is renamed to `history.jsonl.1`, replacing the
+//! previous one, and a new file is started. Reading covers both files.
+//!
+//! Timestamps keep the UTC offset they were recorded with, so `stats` puts
+//! a session at 23:30 local time on that day, not on the UTC day it falls
+//! on. `stats --utc` groups by UTC days instead. `stats --since/--until`
+//! reports on a [`DayRange`] rather than only today, and `stats --export`
+//! writes the entries as CSV or JSON (see [`ExportFormat`]).
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, SecondsFormat, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use super::sink::spawn_sink;
+use super::timer::{TimerEvent, TimestampedEvent};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Size the history file may reach before it is rotated (5 MB)
+pub const DEFAULT_HISTORY_MAX_BYTES: u64 = 5 * 1024 * 1024;
+
+// ============================================================================
+// HistoryRecord
+// ============================================================================
+
+/// What a history entry records.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "record", rename_all = "snake_case")]
+pub enum HistoryRecord {
+    /// A work session ran to completion
+    WorkCompleted {
+        /// Task name (if any)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        task_name: Option<String>,
+        /// Tag of the run (if any)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
+        /// Seconds of work the session took (0 when recorded without it)
+        #[serde(default)]
+        work_seconds: u32,
+    },
+    /// A completed work session ran on for its extension (`extend`); the
+    /// pomodoro itself was recorded by the session's `WorkCompleted`
+    WorkExtended {
+        /// Task name (if any)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        task_name: Option<String>,
+        /// Tag of the run (if any)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
+        /// Seconds of work the extension took
+        work_seconds: u32,
+    },
+    /// A break was skipped with `skip`
+    BreakSkipped {
+        /// Seconds of break taken before skipping
+        elapsed_seconds: u32,
+    },
+    /// A work session was stopped past the abandonment grace period
+    WorkAbandoned {
+        /// Seconds worked before stopping
+        elapsed_seconds: u32,
+    },
+}
+
+impl HistoryRecord {
+    /// Returns the record for a timer event, if it is one to keep.
+    pub fn from_event(event: &TimerEvent) -> Option<Self> {
+        match event {
+            TimerEvent::WorkCompleted {
+                task_name,
+                tag,
+                work_seconds,
+                extended: true,
+                ..
+            } => Some(HistoryRecord::WorkExtended {
+                task_name: task_name.clone(),
+                tag: tag.clone(),
+                work_seconds: *work_seconds,
+            }),
+            TimerEvent::WorkCompleted {
+                task_name,
+                tag,
+                work_seconds,
+                ..
+            } => Some(HistoryRecord::WorkCompleted {
+                task_name: task_name.clone(),
+                tag: tag.clone(),
+                work_seconds: *work_seconds,
+            }),
+            TimerEvent::BreakSkipped { elapsed_seconds } => Some(HistoryRecord::BreakSkipped {
+                elapsed_seconds: *elapsed_seconds,
+            }),
+            TimerEvent::WorkAbandoned { elapsed_seconds } => Some(HistoryRecord::WorkAbandoned {
+                elapsed_seconds: *elapsed_seconds,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the record kind (the serialized `record` tag).
+    pub fn name(&self) -> &'static str {
+        match self {
+            HistoryRecord::WorkCompleted { .. } => "work_completed",
+            HistoryRecord::WorkExtended { .. } => "work_extended",
+            HistoryRecord::BreakSkipped { .. } => "break_skipped",
+            HistoryRecord::WorkAbandoned { .. } => "work_abandoned",
+        }
+    }
+
+    /// Returns the task name the record carries, if any.
+    pub fn task_name(&self) -> Option<&str> {
+        match self {
+            HistoryRecord::WorkCompleted { task_name, .. }
+            | HistoryRecord::WorkExtended { task_name, .. } => task_name.as_deref(),
+            HistoryRecord::BreakSkipped { .. } | HistoryRecord::WorkAbandoned { .. } => None,
+        }
+    }
+
+    /// Returns how many seconds of work or break the record covers.
+    pub fn seconds(&self) -> u32 {
+        match self {
+            HistoryRecord::WorkCompleted { work_seconds, .. }
+            | HistoryRecord::WorkExtended { work_seconds, .. } => *work_seconds,
+            HistoryRecord::BreakSkipped { elapsed_seconds }
+            | HistoryRecord::WorkAbandoned { elapsed_seconds } => *elapsed_seconds,
+        }
+    }
+}
+
+/// A history record stamped with the time it happened.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// When it happened, in the local time of the recording daemon
+    pub at: DateTime<FixedOffset>,
+    /// The record itself (flattened, so its `record` tag sits next to `at`)
+    #[serde(flatten)]
+    pub record: HistoryRecord,
+}
+
+// ============================================================================
+// DayClock
+// ============================================================================
+
+/// Which clock decides the day a history entry belongs to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DayClock {
+    /// Local time (the offset the entry was recorded with)
+    #[default]
+    Local,
+    /// UTC, for reports that do not depend on where they were recorded
+    Utc,
+}
+
+impl DayClock {
+    /// Returns the clock for `stats`, UTC if `utc` is set.
+    pub fn from_utc_flag(utc: bool) -> Self {
+        if utc {
+            Self::Utc
+        } else {
+            Self::Local
+        }
+    }
+
+    /// Returns the day `at` falls on.
+    pub fn day_of(self, at: &DateTime<FixedOffset>) -> NaiveDate {
+        match self {
+            Self::Local => at.date_naive(),
+            Self::Utc => at.with_timezone(&Utc).date_naive(),
+        }
+    }
+
+    /// Returns the current day.
+    pub fn today(self) -> NaiveDate {
+        match self {
+            Self::Local => Local::now().date_naive(),
+            Self::Utc => Utc::now().date_naive(),
+        }
+    }
+}
+
+// ============================================================================
+// DayRange
+// ============================================================================
+
+/// An inclusive range of days, for `stats --since/--until`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayRange {
+    since: NaiveDate,
+    until: NaiveDate,
+}
+
+impl DayRange {
+    /// Returns the days from `since` through `until`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `since` is after `until`.
+    pub fn new(since: NaiveDate, until: NaiveDate) -> Result<Self> {
+        if since > until {
+            bail!(
+                "--since ({}) には --until ({}) 以前の日付を指定してください",
+                since,
+                until
+            );
+        }
+        Ok(Self { since, until })
+    }
+
+    /// Returns the range of a single day.
+    pub fn day(day: NaiveDate) -> Self {
+        Self {
+            since: day,
+            until: day,
+        }
+    }
+
+    /// Returns the first day.
+    pub fn since(&self) -> NaiveDate {
+        self.since
+    }
+
+    /// Returns the last day.
+    pub fn until(&self) -> NaiveDate {
+        self.until
+    }
+
+    /// Returns whether `day` is in the range.
+    pub fn contains(&self, day: NaiveDate) -> bool {
+        self.since <= day && day <= self.until
+    }
+
+    /// Returns every day of the range, in order.
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        let until = self.until;
+        self.since.iter_days().take_while(move |day| *day <= until)
+    }
+
+    /// Returns the entries that fall in the range, as told by `clock`,
+    /// each with its day.
+    pub fn filter(
+        self,
+        entries: &[HistoryEntry],
+        clock: DayClock,
+    ) -> impl Iterator<Item = (NaiveDate, &HistoryEntry)> {
+        entries
+            .iter()
+            .map(move |entry| (clock.day_of(&entry.at), entry))
+            .filter(move |(day, _)| self.contains(*day))
+    }
+}
+
+impl fmt::Display for DayRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.since == self.until {
+            write!(f, "{}", self.since)
+        } else {
+            write!(f, "{}〜{}", self.since, self.until)
+        }
+    }
+}
+
+// ============================================================================
+// FocusScore
+// ============================================================================
+
+/// The share of work sessions completed rather than abandoned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusScore {
+    /// Work sessions completed
+    pub completed: usize,
+    /// Work sessions abandoned
+    pub abandoned: usize,
+}
+
+impl FocusScore {
+    /// Returns the score of these counts, or `None` without any session.
+    pub fn new(completed: usize, abandoned: usize) -> Option<Self> {
+        (completed + abandoned > 0).then_some(Self {
+            completed,
+            abandoned,
+        })
+    }
+
+    /// Returns the share of sessions completed, from 0.0 to 1.0.
+    pub fn ratio(&self) -> f32 {
+        self.completed as f32 / (self.completed + self.abandoned) as f32
+    }
+}
+
+impl fmt::Display for FocusScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.0}% ({}/{})",
+            self.ratio() * 100.0,
+            self.completed,
+            self.completed + self.abandoned
+        )
+    }
+}
+
+/// Rates the work sessions of the days in `range`, as told by `clock`.
+///
+/// Returns `None` if no work session was completed or abandoned then.
+pub fn focus_score(
+    entries: &[HistoryEntry],
+    range: DayRange,
+    clock: DayClock,
+) -> Option<FocusScore> {
+    let (mut completed, mut abandoned) = (0, 0);
+    for (_, entry) in range.filter(entries, clock) {
+        match entry.record {
+            HistoryRecord::WorkCompleted { .. } => completed += 1,
+            HistoryRecord::WorkAbandoned { .. } => abandoned += 1,
+            HistoryRecord::WorkExtended { .. } | HistoryRecord::BreakSkipped { .. } => {}
+        }
+    }
+    FocusScore::new(completed, abandoned)
+}
+
+// ============================================================================
+// HistoryWriter
+// ============================================================================
+
+/// Appends history records to the history file, rotating it when it grows
+/// too large.
+#[derive(Debug)]
+pub struct HistoryWriter {
+    file: File,
+    path: PathBuf,
+    /// Bytes in the current file
+    size: u64,
+    /// Size the file may reach before it is rotated
+    max_bytes: u64,
+}
+
+impl HistoryWriter {
+    /// Opens `path` for appending, creating it (and its directory) if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("履歴のディレクトリを作成できません: {}", parent.display())
+            })?;
+        }
+        let file = open_append(path)?;
+        let size = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        Ok(Self {
+            file,
+            path: path.to_path_buf(),
+            size,
+            max_bytes: DEFAULT_HISTORY_MAX_BYTES,
+        })
+    }
+
+    /// Sets the size the file may reach before it is rotated.
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Moves the current file to [`rotated_path`], replacing the previous
+    /// one, and starts a new file.
+    fn rotate(&mut self) -> Result<()> {
+        let rotated = rotated_path(&self.path);
+        fs::rename(&self.path, &rotated).with_context(|| {
+            format!(
+                "履歴ファイルをローテーションできません: {}",
+                self.path.display()
+            )
+        })?;
+        self.file = open_append(&self.path)?;
+        self.size = 0;
+        tracing::info!(
+            "履歴ファイルをローテーションしました: {}",
+            rotated.display()
+        );
+        Ok(())
+    }
+
+    /// Appends the record for `event`, if it has one, and flushes it.
+    ///
+    /// Returns whether a record was written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be serialized or written.
+    pub fn write(&mut self, event: &TimestampedEvent) -> Result<bool> {
+        let Some(record) = HistoryRecord::from_event(&event.event) else {
+            return Ok(false);
+        };
+        let entry = HistoryEntry {
+            at: event.at.fixed_offset(),
+            record,
+        };
+        let mut line = serde_json::to_vec(&entry).context("Failed to serialize history entry")?;
+        line.push(b'\n');
+        // An empty file keeps even a line longer than the limit
+        if self.size > 0 && self.size + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        self.file.write_all(&line)?;
+        self.file.flush()?;
+        self.size += line.len() as u64;
+        Ok(true)
+    }
+
+    /// Records the events received on `events` until the channel closes.
+    ///
+    /// Write failures are logged and the record is dropped.
+    pub fn spawn(mut self, events: broadcast::Receiver<TimestampedEvent>) -> JoinHandle<()> {
+        spawn_sink("履歴", events, move |event| self.write(event).map(drop))
+    }
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/// Output format of `stats --export`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// One row per entry: timestamp, task, phase, minutes (RFC 4180)
+    Csv,
+    /// The entries as a JSON array, as recorded
+    Json,
+}
+
+impl ExportFormat {
+    /// Writes `entries` in this format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entries cannot be serialized.
+    pub fn export(self, entries: &[HistoryEntry]) -> Result<String> {
+        match self {
+            Self::Csv => Ok(to_csv(entries)),
+            Self::Json => {
+                let mut json = serde_json::to_string_pretty(entries)
+                    .context("Failed to serialize history entries")?;
+                json.push('\n');
+                Ok(json)
+            }
+        }
+    }
+}
+
+/// Header row of the CSV export.
+const CSV_HEADER: [&str; 4] = ["timestamp", "task", "phase", "minutes"];
+
+/// Writes `entries` as CSV, with a header row and CRLF line breaks.
+///
+/// The phase is the record kind (`work_completed`, `work_abandoned`,
+/// `break_skipped`) and the minutes are those of work or break it covers,
+/// to one decimal place.
+pub fn to_csv(entries: &[HistoryEntry]) -> String {
+    let mut csv = csv_row(CSV_HEADER);
+    for entry in entries {
+        let record = &entry.record;
+        csv.push_str(&csv_row([
+            &entry.at.to_rfc3339_opts(SecondsFormat::Secs, false),
+            record.task_name().unwrap_or_default(),
+            record.name(),
+            &format!("{:.1}", f64::from(record.seconds()) / 60.0),
+        ]));
+    }
+    csv
+}
+
+/// Formats one CSV row, quoting fields as RFC 4180 requires.
+fn csv_row<'a>(fields: impl IntoIterator<Item = &'a str>) -> String {
+    let fields: Vec<String> = fields.into_iter().map(csv_field).collect();
+    fields.join(",") + "\r\n"
+}
+
+/// Quotes a field that contains a comma, a quote or a line break, doubling
+/// its quotes.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Opens `path` for appending, creating it if needed.
+fn open_append(path: &Path) -> Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("履歴ファイルを開けません: {}", path.display()))
+}
+
+/// Returns where the history file at `path` is moved when rotated
+/// (`history.jsonl.1`).
+pub fn rotated_path(path: &Path) -> PathBuf {
+    let mut rotated = path.as_os_str().to_os_string();
+    rotated.push(".1");
+    PathBuf::from(rotated)
+}
+
+// ============================================================================
+// Reading
+// ============================================================================
+
+/// Reads the entries of the history file at `path`, after those of the
+/// file it was last rotated to, so they stay in order.
+///
+/// A missing file has no entries. Lines that cannot be parsed (e.g. written
+/// by a newer version) are skipped.
+///
+/// # Errors
+///
+/// Returns an error if a file exists but cannot be read.
+pub fn read_entries(path: &Path) -> Result<Vec<HistoryEntry>> {
+    let mut entries = Vec::new();
+    read_file_entries(&rotated_path(path), &mut entries)?;
+    read_file_entries(path, &mut entries)?;
+    Ok(entries)
+}
+
+/// Appends the entries of the history file at `path` to `entries`.
+fn read_file_entries(path: &Path, entries: &mut Vec<HistoryEntry>) -> Result<()> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => {
+            return Err(e).with_context(|| format!("履歴ファイルを開けません: {}", path.display()))
+        }
+    };
+
+    for line in BufReader::new(file).lines() {
+        let line =
+            line.with_context(|| format!("履歴ファイルを読み込めません: {}", path.display()))?;
+        match serde_json::from_str(&line) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => tracing::debug!("履歴の行を読み飛ばしました: {}", e),
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::channel::{event_channel, DEFAULT_EVENT_CAPACITY};
+    use crate::daemon::timer::TimerEngine;
+    use crate::types::PomodoroConfig;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_completed_work_and_skipped_break_are_recorded() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("state").join("history.jsonl");
+        let (events_tx, _) = broadcast::channel(64);
+        let handle = HistoryWriter::open(&path)
+            .unwrap()
+            .spawn(events_tx.subscribe());
+
+        let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+        let mut engine = TimerEngine::new(PomodoroConfig::default(), tx);
+        engine.start(Some("Docs".to_string())).unwrap();
+        engine.set_tag(Some("project-x".to_string()));
+        engine.get_state_mut().remaining_seconds = 1;
+        engine.tick().unwrap();
+        engine.tick_by(30).unwrap();
+        engine.skip_break().unwrap();
+        drop(engine);
+        while let Some(event) = rx.recv().await {
+            events_tx.send(event).unwrap();
+        }
+        drop(events_tx);
+        handle.await.unwrap();
+
+        let entries = read_entries(&path).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0].record,
+            HistoryRecord::WorkCompleted {
+                task_name: Some("Docs".to_string()),
+                tag: Some("project-x".to_string()),
+                work_seconds: 1500,
+            }
+        );
+        assert_eq!(
+            entries[1].record,
+            HistoryRecord::BreakSkipped {
+                elapsed_seconds: 30
+            }
+        );
+    }
+
+    #[test]
+    fn test_extension_is_recorded_apart_from_the_pomodoro() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let mut writer = HistoryWriter::open(&path).unwrap();
+        let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+        let mut engine = TimerEngine::new(PomodoroConfig::default(), tx);
+
+        engine.start(Some("Docs".to_string())).unwrap();
+        engine.tick_by(25 * 60).unwrap();
+        engine.extend_work(5).unwrap();
+        engine.tick_by(5 * 60).unwrap();
+        while let Ok(event) = rx.try_recv() {
+            writer.write(&event).unwrap();
+        }
+
+        let records: Vec<_> = read_entries(&path)
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.record)
+            .collect();
+        assert_eq!(
+            records,
+            vec![
+                HistoryRecord::WorkCompleted {
+                    task_name: Some("Docs".to_string()),
+                    tag: None,
+                    work_seconds: 25 * 60,
+                },
+                HistoryRecord::WorkExtended {
+                    task_name: Some("Docs".to_string()),
+                    tag: None,
+                    work_seconds: 5 * 60,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_completed_break_is_not_recorded() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let mut writer = HistoryWriter::open(&path).unwrap();
+
+        let completed = TimerEvent::BreakCompleted {
+            is_long_break: false,
+        };
+        assert!(!writer.write(&TimestampedEvent::now(completed)).unwrap());
+
+        assert!(read_entries(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_offset_is_kept_when_read_back() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let line = "{\"at\":\"2026-03-01T23:30:00+09:00\",\"record\":\"break_skipped\",\"elapsed_seconds\":5}\n";
+        fs::write(&path, line).unwrap();
+
+        let entries = read_entries(&path).unwrap();
+
+        assert_eq!(entries[0].at.to_rfc3339(), "2026-03-01T23:30:00+09:00");
+        assert_eq!(serde_json::to_string(&entries[0]).unwrap() + "\n", line);
+    }
+
+    #[test]
+    fn test_day_clock_near_midnight() {
+        let late = DateTime::parse_from_rfc3339("2026-03-01T23:30:00+09:00").unwrap();
+        let early = DateTime::parse_from_rfc3339("2026-03-02T00:30:00+09:00").unwrap();
+        let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+
+        assert_eq!(DayClock::Local.day_of(&late), march(1));
+        assert_eq!(DayClock::Local.day_of(&early), march(2));
+        // 14:30 and 15:30 UTC on March 1
+        assert_eq!(DayClock::Utc.day_of(&late), march(1));
+        assert_eq!(DayClock::Utc.day_of(&early), march(1));
+    }
+
+    #[test]
+    fn test_day_range() {
+        let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+        let range = DayRange::new(march(1), march(3)).unwrap();
+
+        assert_eq!(
+            range.days().collect::<Vec<_>>(),
+            vec![march(1), march(2), march(3)]
+        );
+        assert!(range.contains(march(3)));
+        assert!(!range.contains(march(4)));
+        assert_eq!(range.to_string(), "2026-03-01〜2026-03-03");
+        assert_eq!(DayRange::day(march(2)).to_string(), "2026-03-02");
+        assert_eq!(
+            DayRange::new(march(2), march(2)).unwrap(),
+            DayRange::day(march(2))
+        );
+    }
+
+    #[test]
+    fn test_day_range_rejects_since_after_until() {
+        let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+
+        let error = DayRange::new(march(3), march(1)).unwrap_err();
+
+        assert!(error.to_string().contains("--since (2026-03-03)"));
+    }
+
+    #[test]
+    fn test_day_range_filter_uses_the_clock() {
+        let entry = |at: &str| HistoryEntry {
+            at: DateTime::parse_from_rfc3339(at).unwrap(),
+            record: HistoryRecord::BreakSkipped { elapsed_seconds: 5 },
+        };
+        // March 2 locally, March 1 in UTC
+        let entries = [
+            entry("2026-02-28T12:00:00+09:00"),
+            entry("2026-03-02T08:00:00+09:00"),
+            entry("2026-03-04T12:00:00+09:00"),
+        ];
+        let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+        let range = DayRange::new(march(2), march(4)).unwrap();
+
+        let local: Vec<_> = range
+            .filter(&entries, DayClock::Local)
+            .map(|(day, _)| day)
+            .collect();
+        let utc: Vec<_> = range
+            .filter(&entries, DayClock::Utc)
+            .map(|(day, _)| day)
+            .collect();
+
+        assert_eq!(local, vec![march(2), march(4)]);
+        assert_eq!(utc, vec![march(4)]);
+    }
+
+    fn entry(at: &str, record: HistoryRecord) -> HistoryEntry {
+        HistoryEntry {
+            at: DateTime::parse_from_rfc3339(at).unwrap(),
+            record,
+        }
+    }
+
+    #[test]
+    fn test_csv_export() {
+        let entries = [
+            entry(
+                "2026-03-01T09:25:00+09:00",
+                HistoryRecord::WorkCompleted {
+                    task_name: Some("Docs".to_string()),
+                    tag: None,
+                    work_seconds: 1500,
+                },
+            ),
+            entry(
+                "2026-03-01T09:27:00+09:00",
+                HistoryRecord::BreakSkipped {
+                    elapsed_seconds: 90,
+                },
+            ),
+        ];
+
+        assert_eq!(
+            ExportFormat::Csv.export(&entries).unwrap(),
+            "timestamp,task,phase,minutes\r\n\
+             2026-03-01T09:25:00+09:00,Docs,work_completed,25.0\r\n\
+             2026-03-01T09:27:00+09:00,,break_skipped,1.5\r\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_export_quotes_task_names() {
+        let completed = |task: &str| {
+            entry(
+                "2026-03-01T09:25:00+09:00",
+                HistoryRecord::WorkCompleted {
+                    task_name: Some(task.to_string()),
+                    tag: None,
+                    work_seconds: 1500,
+                },
+            )
+        };
+        let entries = [
+            completed("Review, then merge"),
+            completed("Read \"Dune\""),
+            completed("line\nbreak"),
+        ];
+
+        let csv = to_csv(&entries);
+        let rows: Vec<&str> = csv.split("\r\n").collect();
+
+        assert_eq!(
+            rows[1],
+            "2026-03-01T09:25:00+09:00,\"Review, then merge\",work_completed,25.0"
+        );
+        assert_eq!(
+            rows[2],
+            "2026-03-01T09:25:00+09:00,\"Read \"\"Dune\"\"\",work_completed,25.0"
+        );
+        assert_eq!(
+            rows[3],
+            "2026-03-01T09:25:00+09:00,\"line\nbreak\",work_completed,25.0"
+        );
+    }
+
+    #[test]
+    fn test_json_export_reads_back() {
+        let entries = vec![entry(
+            "2026-03-01T09:25:00+09:00",
+            HistoryRecord::WorkCompleted {
+                task_name: Some("a, b".to_string()),
+                tag: Some("x".to_string()),
+                work_seconds: 1500,
+            },
+        )];
+
+        let json = ExportFormat::Json.export(&entries).unwrap();
+        let parsed: Vec<HistoryEntry> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, entries);
+        assert!(json.contains("\"record\": \"work_completed\""));
+    }
+
+    #[test]
+    fn test_work_seconds_defaults_for_older_entries() {
+        let line = "{\"at\":\"2026-01-01T08:55:00+09:00\",\"record\":\"work_completed\"}";
+
+        let entry: HistoryEntry = serde_json::from_str(line).unwrap();
+
+        assert_eq!(entry.record.seconds(), 0);
+    }
+
+    #[test]
+    fn test_abandoned_work_is_recorded() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let mut writer = HistoryWriter::open(&path).unwrap();
+
+        let abandoned = TimerEvent::WorkAbandoned {
+            elapsed_seconds: 600,
+        };
+        assert!(writer.write(&TimestampedEvent::now(abandoned)).unwrap());
+
+        let entries = read_entries(&path).unwrap();
+        assert_eq!(
+            entries[0].record,
+            HistoryRecord::WorkAbandoned {
+                elapsed_seconds: 600
+            }
+        );
+        assert_eq!(entries[0].record.name(), "work_abandoned");
+    }
+
+    #[test]
+    fn test_focus_score() {
+        let completed = || HistoryRecord::WorkCompleted {
+            task_name: None,
+            tag: None,
+            work_seconds: 1500,
+        };
+        let abandoned = || HistoryRecord::WorkAbandoned {
+            elapsed_seconds: 600,
+        };
+        let mut entries: Vec<_> = (0..8)
+            .map(|_| entry("2026-03-02T10:00:00+09:00", completed()))
+            .collect();
+        entries.push(entry("2026-03-02T11:00:00+09:00", abandoned()));
+        entries.push(entry("2026-03-02T12:00:00+09:00", abandoned()));
+        // Neither other days nor skipped breaks count
+        entries.push(entry("2026-03-01T12:00:00+09:00", abandoned()));
+        entries.push(entry(
+            "2026-03-02T12:30:00+09:00",
+            HistoryRecord::BreakSkipped { elapsed_seconds: 5 },
+        ));
+        let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+
+        let score = focus_score(&entries, DayRange::day(march(2)), DayClock::Local).unwrap();
+
+        assert_eq!(
+            score,
+            FocusScore {
+                completed: 8,
+                abandoned: 2
+            }
+        );
+        assert!((score.ratio() - 0.8).abs() < f32::EPSILON);
+        assert_eq!(score.to_string(), "80% (8/10)");
+        let range = DayRange::new(march(1), march(2)).unwrap();
+        assert_eq!(
+            focus_score(&entries, range, DayClock::Local)
+                .unwrap()
+                .to_string(),
+            "73% (8/11)"
+        );
+    }
+
+    #[test]
+    fn test_focus_score_without_sessions() {
+        let march = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+        let entries = [entry(
+            "2026-03-02T12:30:00+09:00",
+            HistoryRecord::BreakSkipped { elapsed_seconds: 5 },
+        )];
+
+        assert_eq!(
+            focus_score(&entries, DayRange::day(march(2)), DayClock::Local),
+            None
+        );
+        assert_eq!(FocusScore::new(0, 0), None);
+        assert_eq!(FocusScore::new(0, 3).unwrap().to_string(), "0% (0/3)");
+    }
+
+    fn skipped(elapsed_seconds: u32) -> TimestampedEvent {
+        TimestampedEvent::now(TimerEvent::BreakSkipped { elapsed_seconds })
+    }
+
+    #[test]
+    fn test_crossing_max_size_rotates() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let mut writer = HistoryWriter::open(&path).unwrap();
+        writer.write(&skipped(1)).unwrap();
+        let line_len = fs::metadata(&path).unwrap().len();
+        // Room for two lines
+        let mut writer = HistoryWriter::open(&path)
+            .unwrap()
+            .with_max_bytes(line_len * 2);
+
+        writer.write(&skipped(2)).unwrap();
+        assert!(!rotated_path(&path).exists());
+        writer.write(&skipped(3)).unwrap();
+
+        assert_eq!(rotated_path(&path), dir.path().join("history.jsonl.1"));
+        assert_eq!(
+            fs::metadata(rotated_path(&path)).unwrap().len(),
+            line_len * 2
+        );
+        assert_eq!(fs::metadata(&path).unwrap().len(), line_len);
+        let seconds: Vec<u32> = read_entries(&path)
+            .unwrap()
+            .iter()
+            .map(|entry| entry.record.seconds())
+            .collect();
+        assert_eq!(seconds, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_rotation_keeps_one_backup() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history.jsonl");
+        // Every line starts a new file
+        let mut writer = HistoryWriter::open(&path).unwrap().with_max_bytes(1);
+
+        for elapsed_seconds in 1..=3 {
+            writer.write(&skipped(elapsed_seconds)).unwrap();
+        }
+
+        let seconds: Vec<u32> = read_entries(&path)
+            .unwrap()
+            .iter()
+            .map(|entry| entry.record.seconds())
+            .collect();
+        assert_eq!(seconds, vec![2, 3]);
+        assert!(!dir.path().join("history.jsonl.2").exists());
+    }
+
+    #[test]
+    fn test_stats_count_across_rotated_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let mut writer = HistoryWriter::open(&path).unwrap().with_max_bytes(1);
+        writer.write(&skipped(1)).unwrap();
+        writer.write(&skipped(2)).unwrap();
+        assert!(rotated_path(&path).exists());
+
+        let entries = read_entries(&path).unwrap();
+        let today = entries[0].at.date_naive();
+
+        let stats = crate::cli::stats::Stats::for_day(&entries, today, DayClock::Local);
+        assert_eq!(stats.breaks_skipped, 2);
+    }
+
+    #[test]
+    fn test_read_entries_skips_unknown_lines() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history.jsonl");
+        fs::write(
+            &path,
+            "{\"at\":\"2026-01-01T09:00:00+09:00\",\"record\":\"future\"}\n\
+             {\"at\":\"2026-01-01T09:05:00+09:00\",\"record\":\"break_skipped\",\"elapsed_seconds\":5}\n",
+        )
+        .unwrap();
+
+        assert_eq!(read_entries(&path).unwrap().len(), 1);
+        assert!(read_entries(&dir.path().join("missing.jsonl"))
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,209 @@
+//! Phase-change hooks for the Pomodoro Timer daemon.
+//!
+//! Commands configured in the `[hooks]` table of the config file are run
+//! with `sh -c` when the matching event occurs:
+//! - `on_work_start`: [`TimerEvent::WorkStarted`]
+//! - `on_break_start`: [`TimerEvent::BreakStarted`]
+//! - `on_stop`: [`TimerEvent::Stopped`]
+//!
+//! The command receives the timer context as environment variables:
+//! - `POMODORO_TASK`: task name (empty if none)
+//! - `POMODORO_PHASE`: current phase (`working`, `breaking`, ...)
+//! - `POMODORO_COUNT`: completed pomodoros
+//!
+//! Hooks are fire-and-forget: they run in the background, are killed after
+//! a timeout, and failures are only logged.
+
+use std::process::Stdio;
+
+use tokio::process::Command;
+use tokio::task::JoinHandle;
+use tokio::time::{timeout, Duration};
+
+use crate::config::HooksConfig;
+use crate::types::TimerState;
+
+use super::timer::TimerEvent;
+
+/// Default time a hook may run before it is killed.
+pub const DEFAULT_HOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+// ============================================================================
+// HookRunner
+// ============================================================================
+
+/// Runs the configured hook commands for timer events.
+#[derive(Debug, Clone)]
+pub struct HookRunner {
+    hooks: HooksConfig,
+    timeout: Duration,
+}
+
+impl HookRunner {
+    /// Creates a runner for the given hooks with the default timeout.
+    pub fn new(hooks: HooksConfig) -> Self {
+        Self {
+            hooks,
+            timeout: DEFAULT_HOOK_TIMEOUT,
+        }
+    }
+
+    /// Sets the time a hook may run before it is killed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the command configured for an event, if any.
+    pub fn command_for(&self, event: &TimerEvent) -> Option<&str> {
+        let command = match event {
+            TimerEvent::WorkStarted { .. } => &self.hooks.on_work_start,
+            TimerEvent::BreakStarted { .. } => &self.hooks.on_break_start,
+            TimerEvent::Stopped => &self.hooks.on_stop,
+            _ => return None,
+        };
+        command.as_deref()
+    }
+
+    /// Spawns the hook for an event in the background.
+    ///
+    /// `state` is the timer state right after the event. Returns the handle
+    /// of the spawned task, or `None` if the event has no hook.
+    pub fn run(&self, event: &TimerEvent, state: &TimerState) -> Option<JoinHandle<()>> {
+        let command = self.command_for(event)?.to_string();
+
+        let mut child = Command::new("sh");
+        child
+            .arg("-c")
+            .arg(&command)
+            .env("POMODORO_TASK", state.task_name.as_deref().unwrap_or(""))
+            .env("POMODORO_PHASE", state.phase.as_str())
+            .env("POMODORO_COUNT", state.pomodoro_count.to_string())
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true);
+        let limit = self.timeout;
+
+        Some(tokio::spawn(async move {
+            let mut child = match child.spawn() {
+                Ok(child) => child,
+                Err(e) => {
+                    tracing::warn!(%command, "フックを起動できませんでした: {}", e);
+                    return;
+                }
+            };
+
+            match timeout(limit, child.wait()).await {
+                Ok(Ok(status)) if status.success() => {
+                    tracing::debug!(%command, "フックを実行しました");
+                }
+                Ok(Ok(status)) => tracing::warn!(%command, %status, "フックが失敗しました"),
+                Ok(Err(e)) => tracing::warn!(%command, "フックの実行に失敗しました: {}", e),
+                Err(_) => {
+                    tracing::warn!(%command, "フックがタイムアウトしたため終了しました");
+                    let _ = child.kill().await;
+                }
+            }
+        }))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PomodoroConfig, TimerPhase};
+    use std::time::Instant;
+    use tempfile::TempDir;
+
+    fn working_state() -> TimerState {
+        let mut state = TimerState::new(PomodoroConfig::default());
+        state.start_working(Some("Write docs".to_string()));
+        state.pomodoro_count = 2;
+        state
+    }
+
+    #[test]
+    fn test_command_for_matching_events() {
+        let runner = HookRunner::new(HooksConfig {
+            on_work_start: Some("echo work".to_string()),
+            on_break_start: Some("echo break".to_string()),
+            on_stop: None,
+        });
+
+        assert_eq!(
+            runner.command_for(&TimerEvent::WorkStarted { task_name: None }),
+            Some("echo work")
+        );
+        assert_eq!(
+            runner.command_for(&TimerEvent::BreakStarted {
+                is_long_break: true
+            }),
+            Some("echo break")
+        );
+        assert_eq!(runner.command_for(&TimerEvent::Stopped), None);
+        assert_eq!(
+            runner.command_for(&TimerEvent::Paused { reason: None }),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_passes_context_as_env() {
+        let dir = TempDir::new().unwrap();
+        let out = dir.path().join("hook.out");
+        let runner = HookRunner::new(HooksConfig {
+            on_work_start: Some(format!(
+                "echo \"$POMODORO_TASK|$POMODORO_PHASE|$POMODORO_COUNT\" > '{}'",
+                out.display()
+            )),
+            ..Default::default()
+        });
+
+        let state = working_state();
+        assert_eq!(state.phase, TimerPhase::Working);
+        let handle = runner
+            .run(&TimerEvent::WorkStarted { task_name: None }, &state)
+            .unwrap();
+        handle.await.unwrap();
+
+        let written = std::fs::read_to_string(&out).unwrap();
+        assert_eq!(written.trim(), "Write docs|working|2");
+    }
+
+    #[tokio::test]
+    async fn test_run_without_hook_does_nothing() {
+        let runner = HookRunner::new(HooksConfig::default());
+        assert!(runner.run(&TimerEvent::Stopped, &working_state()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failing_hook_is_ignored() {
+        let runner = HookRunner::new(HooksConfig {
+            on_stop: Some("exit 3".to_string()),
+            ..Default::default()
+        });
+
+        let handle = runner.run(&TimerEvent::Stopped, &working_state()).unwrap();
+        assert!(handle.await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_slow_hook_is_killed_after_timeout() {
+        let runner = HookRunner::new(HooksConfig {
+            on_stop: Some("sleep 5".to_string()),
+            ..Default::default()
+        })
+        .with_timeout(Duration::from_millis(100));
+
+        let started = Instant::now();
+        let handle = runner.run(&TimerEvent::Stopped, &working_state()).unwrap();
+        handle.await.unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(3));
+    }
+}
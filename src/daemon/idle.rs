@@ -0,0 +1,129 @@
+//! Idle shutdown for the Pomodoro Timer daemon.
+//!
+//! With `idle_shutdown_minutes` set in the `[daemon]` table, the daemon
+//! exits once it has been idle for that long. It is idle while:
+//! - the timer is stopped, and
+//! - no client is connected (a `Subscribe` stream keeps it busy)
+//!
+//! Every new connection restarts the idle period, so a `status` query is
+//! enough to keep the daemon around. The exit is a clean one (status 0),
+//! which the LaunchAgent does not restart (`KeepAlive` only covers failed
+//! exits), so the daemon stays down until the next login or until it is
+//! started again.
+
+use std::time::{Duration, Instant};
+
+use crate::config::DaemonConfig;
+
+// ============================================================================
+// IdleMonitor
+// ============================================================================
+
+/// Tracks how long the daemon has been idle.
+#[derive(Debug, Clone)]
+pub struct IdleMonitor {
+    /// Idle time before shutting down (`None` when disabled)
+    timeout: Option<Duration>,
+    /// Last time the daemon was busy or received a connection
+    last_activity: Instant,
+}
+
+impl IdleMonitor {
+    /// Creates a monitor from the `[daemon]` settings, idle since `now`.
+    pub fn new(config: &DaemonConfig, now: Instant) -> Self {
+        Self {
+            timeout: config
+                .idle_shutdown_minutes
+                .map(|minutes| Duration::from_secs(u64::from(minutes) * 60)),
+            last_activity: now,
+        }
+    }
+
+    /// Replaces the settings, keeping the current idle period.
+    pub fn set_config(&mut self, config: &DaemonConfig) {
+        *self = Self::new(config, self.last_activity);
+    }
+
+    /// Returns whether idle shutdown is turned on.
+    pub fn is_enabled(&self) -> bool {
+        self.timeout.is_some()
+    }
+
+    /// Restarts the idle period (a request arrived at `now`).
+    pub fn record_activity(&mut self, now: Instant) {
+        self.last_activity = now;
+    }
+
+    /// Returns whether the daemon should shut down.
+    ///
+    /// `busy` is whether the timer is running or paused, or a client is
+    /// connected; a busy daemon restarts its idle period.
+    pub fn should_shut_down(&mut self, busy: bool, now: Instant) -> bool {
+        if busy {
+            self.record_activity(now);
+            return false;
+        }
+        self.timeout
+            .is_some_and(|timeout| now.saturating_duration_since(self.last_activity) >= timeout)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::clock::{Clock, MockClock};
+
+    fn config(minutes: Option<u32>) -> DaemonConfig {
+        DaemonConfig {
+            idle_shutdown_minutes: minutes,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_shuts_down_after_timeout() {
+        let start = MockClock::default().instant();
+        let mut idle = IdleMonitor::new(&config(Some(1)), start);
+
+        assert!(!idle.should_shut_down(false, start + Duration::from_secs(59)));
+        assert!(idle.should_shut_down(false, start + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_activity_restarts_idle_period() {
+        let start = MockClock::default().instant();
+        let mut idle = IdleMonitor::new(&config(Some(1)), start);
+
+        idle.record_activity(start + Duration::from_secs(50));
+        assert!(!idle.should_shut_down(false, start + Duration::from_secs(100)));
+
+        // A running timer or open connection also counts as activity
+        assert!(!idle.should_shut_down(true, start + Duration::from_secs(200)));
+        assert!(!idle.should_shut_down(false, start + Duration::from_secs(259)));
+        assert!(idle.should_shut_down(false, start + Duration::from_secs(260)));
+    }
+
+    #[test]
+    fn test_disabled_never_shuts_down() {
+        let start = MockClock::default().instant();
+        let mut idle = IdleMonitor::new(&config(None), start);
+
+        assert!(!idle.is_enabled());
+        assert!(!idle.should_shut_down(false, start + Duration::from_secs(86_400)));
+    }
+
+    #[test]
+    fn test_set_config_keeps_idle_period() {
+        let start = MockClock::default().instant();
+        let mut idle = IdleMonitor::new(&config(None), start);
+
+        idle.set_config(&config(Some(1)));
+
+        assert!(idle.is_enabled());
+        assert!(idle.should_shut_down(false, start + Duration::from_secs(60)));
+    }
+}
@@ -4,27 +4,32 @@
 //! - Server that listens on a Unix socket
 //! - Request/response handling for timer commands
 //! - Integration with TimerEngine for command execution
+//! - JSON framing by default, with opt-in MessagePack for busy subscribers
 
+use std::future::Future;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tokio::net::{UnixListener, UnixStream};
-use tokio::sync::Mutex;
+use tokio::sync::{broadcast, Mutex};
 use tokio::time::{timeout, Duration};
 
-use crate::types::{IpcRequest, IpcResponse, ResponseData, StartParams};
+use crate::types::{
+    IpcRequest, IpcResponse, PhaseDuration, PomodoroConfig, ResponseData, StartParams, TimerState,
+    SESSION_ALREADY_RUNNING,
+};
 
-use super::timer::TimerEngine;
+use super::clock::{Clock, SystemClock};
+use super::timer::{TimerEngine, TimestampedEvent};
 
 // ============================================================================
 // Constants
 // ============================================================================
 
-/// Default socket path
-pub const DEFAULT_SOCKET_PATH: &str = "~/.pomodoro/pomodoro.sock";
-
 /// Maximum request size in bytes (4KB)
 const MAX_REQUEST_SIZE: usize = 4096;
 
@@ -35,6 +40,27 @@ const CONNECTION_TIMEOUT_SECS: u64 = 30;
 /// Read timeout in seconds
 const READ_TIMEOUT_SECS: u64 = 5;
 
+/// Default time one request may take, from reading it to sending the reply
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// Maximum snooze duration in minutes
+pub const MAX_SNOOZE_MINUTES: u32 = 30;
+
+/// Maximum extra work added to a just-completed session in minutes
+pub const MAX_EXTEND_WORK_MINUTES: u32 = 30;
+
+/// Maximum standalone break duration in minutes
+pub const MAX_BREAK_MINUTES: u32 = 60;
+
+/// Maximum number of back-to-back standalone breaks (`break --repeat`)
+pub const MAX_BREAK_REPEAT: u32 = 20;
+
+/// Size of the big-endian length prefix that precedes every IPC message
+pub const FRAME_HEADER_SIZE: usize = 4;
+
+/// First payload byte marking a MessagePack frame (JSON never starts with it)
+pub const MSGPACK_FRAME_TAG: u8 = 0x01;
+
 // ============================================================================
 // IpcError
 // ============================================================================
@@ -46,6 +72,10 @@ pub enum IpcError {
     #[error("Failed to bind socket: {0}")]
     BindError(String),
 
+    /// Another daemon is listening on the socket
+    #[error("A daemon is already running on {0}")]
+    AlreadyRunning(String),
+
     /// Connection error
     #[error("Connection error: {0}")]
     ConnectionError(String),
@@ -66,11 +96,171 @@ pub enum IpcError {
     #[error("Operation timed out")]
     Timeout,
 
+    /// A request was not answered within the request timeout
+    #[error("Request not answered within {0:?}; connection closed")]
+    DeadlineExceeded(Duration),
+
     /// Request too large
     #[error("Request too large (max {MAX_REQUEST_SIZE} bytes)")]
     RequestTooLarge,
 }
 
+// ============================================================================
+// Framing
+// ============================================================================
+
+/// Writes a single length-prefixed frame to the stream.
+///
+/// A frame is a 4-byte big-endian payload length followed by the payload itself.
+///
+/// # Errors
+///
+/// Returns an error if the payload does not fit in a frame or writing fails.
+pub async fn write_frame<W>(writer: &mut W, payload: &[u8]) -> std::io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let len = u32::try_from(payload.len()).map_err(|_| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "frame payload too large")
+    })?;
+
+    writer.write_all(&len.to_be_bytes()).await?;
+    writer.write_all(payload).await?;
+    writer.flush().await
+}
+
+/// Reads a single length-prefixed frame from the stream.
+///
+/// Keeps reading until the whole frame has arrived, so payloads split across
+/// several writes are reassembled. Callers are expected to bound the call with
+/// a timeout.
+///
+/// # Errors
+///
+/// Returns `IpcError::ConnectionError` if the peer closed the connection before
+/// or during the frame, and `IpcError::RequestTooLarge` if the announced length
+/// exceeds `max_size`.
+pub async fn read_frame<R>(reader: &mut R, max_size: usize) -> Result<Vec<u8>, IpcError>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut header = [0u8; FRAME_HEADER_SIZE];
+    reader
+        .read_exact(&mut header)
+        .await
+        .map_err(map_frame_read_error)?;
+
+    let len = u32::from_be_bytes(header) as usize;
+    if len > max_size {
+        return Err(IpcError::RequestTooLarge);
+    }
+
+    let mut payload = vec![0u8; len];
+    reader
+        .read_exact(&mut payload)
+        .await
+        .map_err(map_frame_read_error)?;
+
+    Ok(payload)
+}
+
+/// Payload encoding of a frame.
+///
+/// JSON frames carry the bare JSON text. MessagePack frames start with
+/// [`MSGPACK_FRAME_TAG`] followed by the MessagePack data. A client opts in
+/// by sending its request as MessagePack; the server then answers, and
+/// streams events, in the same encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Encoding {
+    /// JSON text (the default, for interoperability)
+    #[default]
+    Json,
+    /// Compact MessagePack with named fields
+    MessagePack,
+}
+
+impl Encoding {
+    /// Detects the encoding of a frame payload from its first byte.
+    pub fn detect(payload: &[u8]) -> Self {
+        match payload.first() {
+            Some(&MSGPACK_FRAME_TAG) => Encoding::MessagePack,
+            _ => Encoding::Json,
+        }
+    }
+
+    /// Serializes a value into a frame payload in this encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IpcError::SerializationError` if the value cannot be encoded.
+    pub fn encode<T: Serialize>(self, value: &T) -> Result<Vec<u8>, IpcError> {
+        match self {
+            Encoding::Json => {
+                serde_json::to_vec(value).map_err(|e| IpcError::SerializationError(e.to_string()))
+            }
+            Encoding::MessagePack => {
+                let mut payload = vec![MSGPACK_FRAME_TAG];
+                rmp_serde::encode::write_named(&mut payload, value)
+                    .map_err(|e| IpcError::SerializationError(e.to_string()))?;
+                Ok(payload)
+            }
+        }
+    }
+
+    /// Deserializes a frame payload, detecting its encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IpcError::SerializationError` if the payload cannot be decoded.
+    pub fn decode<T: DeserializeOwned>(payload: &[u8]) -> Result<(T, Self), IpcError> {
+        let encoding = Self::detect(payload);
+        let value = match encoding {
+            Encoding::Json => serde_json::from_slice(payload)
+                .map_err(|e| IpcError::SerializationError(e.to_string()))?,
+            Encoding::MessagePack => rmp_serde::from_slice(&payload[1..])
+                .map_err(|e| IpcError::SerializationError(e.to_string()))?,
+        };
+        Ok((value, encoding))
+    }
+}
+
+/// Returns whether a failed read just means the client is done with the
+/// connection: it closed it, or stayed idle past the read timeout after
+/// `served` requests.
+///
+/// A close before any request is a liveness probe
+/// (`IpcClient::is_daemon_running`), not a failure.
+fn is_end_of_session(error: &anyhow::Error, served: u32) -> bool {
+    match error.downcast_ref::<IpcError>() {
+        Some(IpcError::ConnectionError(_)) => true,
+        Some(IpcError::Timeout) => served > 0,
+        _ => false,
+    }
+}
+
+/// Runs `future` until `deadline` on `clock`, like [`tokio::time::timeout_at`].
+///
+/// Returns `None` if the deadline passes first.
+async fn timeout_at<C: Clock, F: Future>(
+    clock: &C,
+    deadline: std::time::Instant,
+    future: F,
+) -> Option<F::Output> {
+    tokio::select! {
+        biased;
+        output = future => Some(output),
+        () = clock.sleep_until(deadline) => None,
+    }
+}
+
+fn map_frame_read_error(e: std::io::Error) -> IpcError {
+    if e.kind() == std::io::ErrorKind::UnexpectedEof {
+        IpcError::ConnectionError("Connection closed by client".to_string())
+    } else {
+        IpcError::ReadError(e.to_string())
+    }
+}
+
 // ============================================================================
 // IpcServer
 // ============================================================================
@@ -113,6 +303,23 @@ impl IpcServer {
         })
     }
 
+    /// Creates a new IPC server, refusing to take over a live socket.
+    ///
+    /// Unlike [`Self::new`], an existing socket file is only removed if
+    /// nothing accepts a connection on it (it was left by a daemon that is
+    /// gone). If another daemon is listening, the socket is left alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IpcError::AlreadyRunning`] if a daemon is listening on the
+    /// socket, or an error if the socket cannot be bound.
+    pub fn new_exclusive(socket_path: &Path) -> Result<Self> {
+        if std::os::unix::net::UnixStream::connect(socket_path).is_ok() {
+            return Err(IpcError::AlreadyRunning(socket_path.display().to_string()).into());
+        }
+        Self::new(socket_path)
+    }
+
     /// Accepts an incoming client connection.
     ///
     /// # Errors
@@ -129,34 +336,40 @@ impl IpcServer {
 
     /// Receives and deserializes an IPC request from the stream.
     ///
-    /// Applies a read timeout to prevent blocking indefinitely.
+    /// Applies a read timeout to prevent blocking indefinitely. A frame that is
+    /// still incomplete when the timeout fires yields `IpcError::Timeout`.
     ///
     /// # Errors
     ///
     /// Returns an error if reading or deserialization fails.
     pub async fn receive_request(stream: &mut UnixStream) -> Result<IpcRequest> {
-        let mut buffer = vec![0u8; MAX_REQUEST_SIZE];
+        Ok(Self::receive_request_with_encoding(stream).await?.0)
+    }
 
+    /// Like [`receive_request`](Self::receive_request), but also returns the
+    /// encoding the client used, so the reply can be sent in kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading or deserialization fails.
+    pub async fn receive_request_with_encoding(
+        stream: &mut UnixStream,
+    ) -> Result<(IpcRequest, Encoding)> {
         let read_result = timeout(
             Duration::from_secs(READ_TIMEOUT_SECS),
-            stream.read(&mut buffer),
+            read_frame(stream, MAX_REQUEST_SIZE),
         )
         .await;
 
-        let n = match read_result {
-            Ok(Ok(n)) => n,
-            Ok(Err(e)) => return Err(IpcError::ReadError(e.to_string()).into()),
+        let payload = match read_result {
+            Ok(result) => result?,
             Err(_) => return Err(IpcError::Timeout.into()),
         };
 
-        if n == 0 {
-            anyhow::bail!("Connection closed by client");
-        }
-
-        let request: IpcRequest = serde_json::from_slice(&buffer[..n])
-            .with_context(|| "Failed to deserialize IPC request")?;
+        let decoded =
+            Encoding::decode(&payload).with_context(|| "Failed to deserialize IPC request")?;
 
-        Ok(request)
+        Ok(decoded)
     }
 
     /// Serializes and sends an IPC response to the stream.
@@ -165,17 +378,150 @@ impl IpcServer {
     ///
     /// Returns an error if serialization or writing fails.
     pub async fn send_response(stream: &mut UnixStream, response: &IpcResponse) -> Result<()> {
-        let json = serde_json::to_vec(response).context("Failed to serialize IPC response")?;
+        Self::send_response_encoded(stream, response, Encoding::Json).await
+    }
 
-        stream
-            .write_all(&json)
+    /// Serializes and sends an IPC response in the given encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or writing fails.
+    pub async fn send_response_encoded(
+        stream: &mut UnixStream,
+        response: &IpcResponse,
+        encoding: Encoding,
+    ) -> Result<()> {
+        let payload = encoding
+            .encode(response)
+            .context("Failed to serialize IPC response")?;
+
+        write_frame(stream, &payload)
             .await
             .context("Failed to write response")?;
-        stream.flush().await.context("Failed to flush response")?;
 
         Ok(())
     }
 
+    /// Acknowledges a `Subscribe` request and streams events until the
+    /// client disconnects or the event source is closed.
+    ///
+    /// Each event is sent as its own frame in `encoding`. Events missed
+    /// because the client fell behind are skipped with a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the acknowledgement cannot be sent.
+    pub async fn stream_events(
+        stream: &mut UnixStream,
+        mut events: broadcast::Receiver<TimestampedEvent>,
+        encoding: Encoding,
+    ) -> Result<()> {
+        Self::send_response_encoded(
+            stream,
+            &IpcResponse::success("イベントの配信を開始しました", None),
+            encoding,
+        )
+        .await?;
+
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("{}件のイベントを配信できませんでした", skipped);
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            };
+
+            let payload = encoding
+                .encode(&event)
+                .context("Failed to serialize timer event")?;
+            if let Err(e) = write_frame(stream, &payload).await {
+                tracing::debug!("購読者が切断しました: {}", e);
+                return Ok(());
+            }
+        }
+    }
+
+    /// Serves the requests of one client connection.
+    ///
+    /// Requests are answered in order until the client closes the
+    /// connection, so a client can send several requests over one
+    /// connection (see `IpcClient::session`). One-shot clients simply close
+    /// after their reply, and probes close without sending anything. A
+    /// connection left idle for the read timeout after its first request is
+    /// closed quietly. A `Subscribe` request turns the
+    /// connection into an event stream for the rest of its life.
+    ///
+    /// Reading a request, handling it and sending the reply must fit in the
+    /// handler's request timeout; otherwise the connection is closed, so a
+    /// stuck request cannot hold its task forever.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no first request arrives in time, if a request
+    /// cannot be decoded or a response cannot be sent, or if the request
+    /// timeout expires ([`IpcError::DeadlineExceeded`]).
+    pub async fn serve_connection(stream: UnixStream, handler: &RequestHandler) -> Result<()> {
+        Self::serve_connection_with_clock(stream, handler, &SystemClock).await
+    }
+
+    /// Serves a connection like [`Self::serve_connection`], with the request
+    /// timeout measured on `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`Self::serve_connection`].
+    pub async fn serve_connection_with_clock<C: Clock>(
+        mut stream: UnixStream,
+        handler: &RequestHandler,
+        clock: &C,
+    ) -> Result<()> {
+        let mut served = 0u32;
+        let limit = handler.request_timeout();
+        let expired = || IpcError::DeadlineExceeded(limit);
+
+        loop {
+            let deadline = clock.instant() + limit;
+            let received = timeout_at(
+                clock,
+                deadline,
+                Self::receive_request_with_encoding(&mut stream),
+            )
+            .await
+            .ok_or_else(expired)?;
+            let (request, encoding) = match received {
+                Ok(received) => received,
+                Err(e) if is_end_of_session(&e, served) => return Ok(()),
+                Err(e) => return Err(e),
+            };
+            // The request itself may hold task names; `handle` logs it redacted
+            tracing::debug!(
+                command = request.command_name(),
+                ?encoding,
+                "リクエスト受信"
+            );
+
+            if matches!(request, IpcRequest::Subscribe) {
+                if let Some(events) = handler.subscribe() {
+                    return Self::stream_events(&mut stream, events, encoding).await;
+                }
+            }
+
+            let response = timeout_at(clock, deadline, handler.handle(request))
+                .await
+                .ok_or_else(expired)?;
+            timeout_at(
+                clock,
+                deadline,
+                Self::send_response_encoded(&mut stream, &response, encoding),
+            )
+            .await
+            .ok_or_else(expired)??;
+            served += 1;
+        }
+    }
+
     /// Returns the socket path.
     pub fn socket_path(&self) -> &Path {
         &self.socket_path
@@ -197,22 +543,116 @@ impl Drop for IpcServer {
 pub struct RequestHandler {
     /// Shared reference to the timer engine
     engine: Arc<Mutex<TimerEngine>>,
+    /// Broadcast of timer events for subscribers (None disables Subscribe)
+    events: Option<broadcast::Sender<TimestampedEvent>>,
+    /// Time one request may take on a connection (see `serve_connection`)
+    request_timeout: Duration,
+    /// Whether task names are written to the request log as they are
+    log_task_names: bool,
 }
 
 impl RequestHandler {
     /// Creates a new request handler with the given timer engine.
     pub fn new(engine: Arc<Mutex<TimerEngine>>) -> Self {
-        Self { engine }
+        Self {
+            engine,
+            events: None,
+            request_timeout: Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS),
+            log_task_names: false,
+        }
+    }
+
+    /// Writes task names to the request log instead of redacting them.
+    pub fn with_log_task_names(mut self, log_task_names: bool) -> Self {
+        self.log_task_names = log_task_names;
+        self
+    }
+
+    /// Sets the time one request may take, from reading it to sending the
+    /// reply, before its connection is closed.
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Returns the time one request may take on a connection.
+    pub fn request_timeout(&self) -> Duration {
+        self.request_timeout
+    }
+
+    /// Enables `Subscribe` requests, fed from the given event broadcast.
+    pub fn with_events(mut self, events: broadcast::Sender<TimestampedEvent>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Returns a new receiver of timer events, if subscriptions are enabled.
+    pub fn subscribe(&self) -> Option<broadcast::Receiver<TimestampedEvent>> {
+        self.events.as_ref().map(broadcast::Sender::subscribe)
     }
 
     /// Handles an IPC request and returns the appropriate response.
+    ///
+    /// Each request is logged at debug level with its outcome. Task names
+    /// are replaced by their length unless [`Self::with_log_task_names`]
+    /// turned them on.
     pub async fn handle(&self, request: IpcRequest) -> IpcResponse {
+        let command = request.command_name();
+        let tasks = self.logged_tasks(&request);
+        let response = self.dispatch(request).await;
+        tracing::debug!(
+            command,
+            tasks = tasks.as_deref(),
+            status = %response.status,
+            message = %response.message,
+            "リクエストを処理しました"
+        );
+        response
+    }
+
+    /// Returns the task names of a request as written to the request log.
+    fn logged_tasks(&self, request: &IpcRequest) -> Option<String> {
+        let IpcRequest::Start { params } = request else {
+            return None;
+        };
+        let tasks: Vec<String> = params
+            .task_name
+            .iter()
+            .chain(params.task_queue.iter().flatten())
+            .map(|task| {
+                if self.log_task_names {
+                    task.clone()
+                } else {
+                    format!("<{}文字>", task.chars().count())
+                }
+            })
+            .collect();
+        (!tasks.is_empty()).then(|| tasks.join(", "))
+    }
+
+    /// Runs the handler for the request.
+    async fn dispatch(&self, request: IpcRequest) -> IpcResponse {
         match request {
             IpcRequest::Start { params } => self.handle_start(params).await,
-            IpcRequest::Pause => self.handle_pause().await,
+            IpcRequest::Pause { reason } => self.handle_pause(reason).await,
             IpcRequest::Resume => self.handle_resume().await,
             IpcRequest::Stop => self.handle_stop().await,
             IpcRequest::Status => self.handle_status().await,
+            IpcRequest::Break { minutes, repeat } => self.handle_break(minutes, repeat).await,
+            IpcRequest::LongBreak { minutes } => self.handle_long_break(minutes).await,
+            IpcRequest::Snooze { minutes } => self.handle_snooze(minutes).await,
+            IpcRequest::ExtendWork { minutes } => self.handle_extend_work(minutes).await,
+            IpcRequest::Reset => self.handle_reset().await,
+            IpcRequest::Skip => self.handle_skip().await,
+            IpcRequest::Suspend => self.handle_suspend().await,
+            IpcRequest::Unsuspend => self.handle_unsuspend().await,
+            IpcRequest::GetConfig => self.handle_get_config().await,
+            IpcRequest::SetConfig { config } => self.handle_set_config(config).await,
+            IpcRequest::Unsupported => IpcResponse::unsupported_command(),
+            // Streaming needs the connection itself; see `IpcServer::stream_events`
+            IpcRequest::Subscribe => {
+                IpcResponse::error("このリクエストはイベント配信に対応していません")
+            }
         }
     }
 
@@ -220,55 +660,167 @@ impl RequestHandler {
     async fn handle_start(&self, params: StartParams) -> IpcResponse {
         let mut engine = self.engine.lock().await;
 
-        // Apply custom configuration if provided
-        if params.work_minutes.is_some()
-            || params.break_minutes.is_some()
-            || params.long_break_minutes.is_some()
-            || params.auto_cycle.is_some()
-            || params.focus_mode.is_some()
+        // With `prompt_break`, a plain `start` confirms the break the timer
+        // waits for. One asking for another session must not start it instead.
+        if engine.get_state().awaiting_break {
+            if Self::requests_other_session(engine.get_state(), &params) {
+                return IpcResponse::error(
+                    "休憩の開始待ちのため、新しいセッションは開始できません。\
+                     休憩を始めるにはオプションなしで start を、作業に戻るには skip を実行してください",
+                );
+            }
+            return match engine.start_awaited_break() {
+                Ok(()) => IpcResponse::success(
+                    "休憩を開始しました",
+                    Some(ResponseData::from_timer_state(engine.get_state())),
+                ),
+                Err(e) => IpcResponse::error(e.to_string()),
+            };
+        }
+
+        // With `if_not_running`, repeating the start of the running session is a no-op
+        let state = engine.get_state();
+        if params.if_not_running == Some(true)
+            && state.is_running()
+            && Self::is_same_session(state, &params)
         {
-            let state = engine.get_state();
-            let mut config = state.config.clone();
+            return IpcResponse::success(
+                SESSION_ALREADY_RUNNING,
+                Some(ResponseData::from_timer_state(state)),
+            );
+        }
 
-            if let Some(work) = params.work_minutes {
-                config.work_minutes = work;
-            }
-            if let Some(brk) = params.break_minutes {
-                config.break_minutes = brk;
+        // Enforce the daemon's session length cap regardless of the request
+        let config = &engine.get_state().config;
+        let work_minutes = params
+            .work_minutes
+            .unwrap_or(config.work_duration.as_minutes());
+        if work_minutes > config.max_work_minutes {
+            return IpcResponse::error(format!(
+                "作業時間が上限の{}分を超えています（{}分）",
+                config.max_work_minutes, work_minutes
+            ));
+        }
+
+        // Apply custom configuration if provided
+        let config = params.apply_to(&engine.get_state().config);
+        if let Some(config) = &config {
+            // Validate configuration
+            if let Err(e) = config.validate() {
+                return IpcResponse::error(e);
             }
-            if let Some(long_brk) = params.long_break_minutes {
-                config.long_break_minutes = long_brk;
+            if config.work_duration < PhaseDuration::from_minutes(config.min_work_minutes) {
+                return IpcResponse::error(format!(
+                    "作業時間が下限の{}分を下回っています（{}分）",
+                    config.min_work_minutes,
+                    config.work_duration.as_minutes()
+                ));
             }
-            if let Some(auto) = params.auto_cycle {
-                config.auto_cycle = auto;
+        }
+
+        // Kept so a config reload does not undo them during this run
+        let overrides = config.is_some().then(|| params.clone());
+
+        // With `force`, the running session makes way for the new one; strict
+        // mode refuses, as that would also cut a break short of its floor
+        if params.force == Some(true) && engine.get_state().is_running() {
+            if engine.get_state().config.strict {
+                return IpcResponse::error("厳格モードでは実行中のセッションを置き換えられません");
             }
-            if let Some(focus) = params.focus_mode {
-                config.focus_mode = focus;
+            if let Err(e) = engine.stop() {
+                return IpcResponse::error(e.to_string());
             }
+        }
 
-            // Validate configuration
-            if let Err(e) = config.validate() {
-                return IpcResponse::error(e);
+        if let Some(config) = config {
+            // Never change the configuration of a running session
+            if !engine.get_state().is_running() {
+                engine.set_config(config);
             }
         }
 
-        match engine.start(params.task_name) {
+        // The first queued task replaces the task name
+        let (task_name, task_queue) = match params.task_queue {
+            Some(mut queue) if !queue.is_empty() => {
+                let first = queue.remove(0);
+                (Some(first), Some(queue))
+            }
+            _ => (params.task_name, None),
+        };
+
+        let paused = params.paused.unwrap_or(false);
+        let result = if paused {
+            engine.start_paused(task_name)
+        } else {
+            engine.start(task_name)
+        };
+
+        match result {
             Ok(()) => {
+                if let Some(queue) = task_queue {
+                    engine.set_task_queue(queue);
+                }
+                engine.set_tag(params.tag);
+                engine.set_run_overrides(overrides);
                 let state = engine.get_state();
-                IpcResponse::success(
-                    "タイマーを開始しました",
-                    Some(ResponseData::from_timer_state(state)),
-                )
+                let message = if paused {
+                    "タイマーを一時停止状態で準備しました"
+                } else {
+                    "タイマーを開始しました"
+                };
+                IpcResponse::success(message, Some(ResponseData::from_timer_state(state)))
             }
             Err(e) => IpcResponse::error(e.to_string()),
         }
     }
 
+    /// Returns whether `params` would start the session `state` is running:
+    /// the same task, tag and timer configuration.
+    fn is_same_session(state: &TimerState, params: &StartParams) -> bool {
+        let task_name = match &params.task_queue {
+            Some(queue) if !queue.is_empty() => queue.first(),
+            _ => params.task_name.as_ref(),
+        };
+        state.task_name.as_ref() == task_name
+            && state.tag == params.tag
+            && match params.apply_to(&state.config) {
+                Some(config) => config == state.config,
+                None => true,
+            }
+    }
+
+    /// Returns whether `params` ask for something the break awaited by
+    /// `state` cannot give: a task, tag, queue, paused start or a different
+    /// timer configuration (`start --work`, `--preset`, ...).
+    ///
+    /// A setting equal to the run's is not a different configuration, and
+    /// the cycle limit is ignored.
+    fn requests_other_session(state: &TimerState, params: &StartParams) -> bool {
+        let has_tasks = params
+            .task_queue
+            .as_ref()
+            .is_some_and(|queue| !queue.is_empty());
+        if params.task_name.is_some()
+            || params.tag.is_some()
+            || has_tasks
+            || params.paused == Some(true)
+        {
+            return true;
+        }
+        params.apply_to(&state.config).is_some_and(|mut config| {
+            config.max_cycles = state.config.max_cycles;
+            config != state.config
+        })
+    }
+
     /// Handles the pause command.
-    async fn handle_pause(&self) -> IpcResponse {
+    async fn handle_pause(&self, reason: Option<String>) -> IpcResponse {
         let mut engine = self.engine.lock().await;
+        let reason = reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
 
-        match engine.pause() {
+        match engine.pause_with_reason(reason) {
             Ok(()) => {
                 let state = engine.get_state();
                 IpcResponse::success(
@@ -312,6 +864,216 @@ impl RequestHandler {
         }
     }
 
+    /// Handles the snooze command.
+    async fn handle_snooze(&self, minutes: u32) -> IpcResponse {
+        if !(1..=MAX_SNOOZE_MINUTES).contains(&minutes) {
+            return IpcResponse::error(format!(
+                "スヌーズ時間は1-{}分の範囲で指定してください",
+                MAX_SNOOZE_MINUTES
+            ));
+        }
+
+        let mut engine = self.engine.lock().await;
+
+        match engine.snooze(minutes) {
+            Ok(added) => {
+                let state = engine.get_state();
+                let message = if added < minutes.saturating_mul(60) {
+                    format!(
+                        "上限の{}分に達したため、延長は{}秒までに制限されました",
+                        state.config.max_work_minutes, added
+                    )
+                } else {
+                    format!("休憩を{}分延長しました", minutes)
+                };
+                IpcResponse::success(message, Some(ResponseData::from_timer_state(state)))
+            }
+            Err(e) => IpcResponse::error(e.to_string()),
+        }
+    }
+
+    /// Handles the extendWork command (the "+5分" notification action).
+    async fn handle_extend_work(&self, minutes: u32) -> IpcResponse {
+        if !(1..=MAX_EXTEND_WORK_MINUTES).contains(&minutes) {
+            return IpcResponse::error(format!(
+                "延長時間は1-{}分の範囲で指定してください",
+                MAX_EXTEND_WORK_MINUTES
+            ));
+        }
+
+        let mut engine = self.engine.lock().await;
+
+        match engine.extend_work(minutes) {
+            Ok(()) => IpcResponse::success(
+                format!("作業を{}分延長しました", minutes),
+                Some(ResponseData::from_timer_state(engine.get_state())),
+            ),
+            Err(e) => IpcResponse::error(e.to_string()),
+        }
+    }
+
+    /// Handles the break command (a standalone break, or `repeat` of them
+    /// back to back).
+    async fn handle_break(&self, minutes: Option<u32>, repeat: Option<u32>) -> IpcResponse {
+        let mut engine = self.engine.lock().await;
+        let minutes = minutes.unwrap_or(engine.get_state().config.break_duration.as_minutes());
+
+        if !(1..=MAX_BREAK_MINUTES).contains(&minutes) {
+            return IpcResponse::error(format!(
+                "休憩時間は1-{}分の範囲で指定してください",
+                MAX_BREAK_MINUTES
+            ));
+        }
+
+        let repeat = repeat.unwrap_or(1);
+        if !(1..=MAX_BREAK_REPEAT).contains(&repeat) {
+            return IpcResponse::error(format!(
+                "繰り返し回数は1-{}回の範囲で指定してください",
+                MAX_BREAK_REPEAT
+            ));
+        }
+
+        match engine.start_break_series(minutes, repeat) {
+            Ok(()) => {
+                let message = if repeat > 1 {
+                    format!("{}分の休憩を{}回開始しました", minutes, repeat)
+                } else {
+                    format!("{}分の休憩を開始しました", minutes)
+                };
+                IpcResponse::success(
+                    message,
+                    Some(ResponseData::from_timer_state(engine.get_state())),
+                )
+            }
+            Err(e) => IpcResponse::error(e.to_string()),
+        }
+    }
+
+    /// Handles the long break command (a standalone long break).
+    async fn handle_long_break(&self, minutes: Option<u32>) -> IpcResponse {
+        let mut engine = self.engine.lock().await;
+        let minutes = minutes.unwrap_or(engine.get_state().config.long_break_duration.as_minutes());
+
+        if !(1..=MAX_BREAK_MINUTES).contains(&minutes) {
+            return IpcResponse::error(format!(
+                "長い休憩時間は1-{}分の範囲で指定してください",
+                MAX_BREAK_MINUTES
+            ));
+        }
+
+        match engine.start_long_break(minutes) {
+            Ok(()) => IpcResponse::success(
+                format!("{}分の長い休憩を開始しました", minutes),
+                Some(ResponseData::from_timer_state(engine.get_state())),
+            ),
+            Err(e) => IpcResponse::error(e.to_string()),
+        }
+    }
+
+    /// Handles the skip command.
+    async fn handle_skip(&self) -> IpcResponse {
+        let mut engine = self.engine.lock().await;
+
+        match engine.skip_break() {
+            Ok(()) => IpcResponse::success(
+                "休憩をスキップして作業を開始しました",
+                Some(ResponseData::from_timer_state(engine.get_state())),
+            ),
+            Err(e) => IpcResponse::error(e.to_string()),
+        }
+    }
+
+    /// Handles the suspend command.
+    async fn handle_suspend(&self) -> IpcResponse {
+        let mut engine = self.engine.lock().await;
+
+        match engine.suspend() {
+            Ok(()) => IpcResponse::success(
+                "セッションを中断しました",
+                Some(ResponseData::from_timer_state(engine.get_state())),
+            ),
+            Err(e) => IpcResponse::error(e.to_string()),
+        }
+    }
+
+    /// Handles the getConfig command.
+    async fn handle_get_config(&self) -> IpcResponse {
+        let engine = self.engine.lock().await;
+        let state = engine.get_state();
+
+        IpcResponse::success(
+            "",
+            Some(ResponseData {
+                config: Some(state.config.clone()),
+                ..ResponseData::from_timer_state(state)
+            }),
+        )
+    }
+
+    /// Handles the setConfig command.
+    ///
+    /// The running phase keeps its length; the new durations apply from the
+    /// next phase. A cycle limit given with `start --cycles` belongs to the
+    /// current run and is kept. The configuration file is not written, so a
+    /// reload (SIGHUP) or restart goes back to it.
+    async fn handle_set_config(&self, mut config: PomodoroConfig) -> IpcResponse {
+        if let Err(e) = config.validate() {
+            return IpcResponse::error(e);
+        }
+        if config.work_duration > PhaseDuration::from_minutes(config.max_work_minutes) {
+            return IpcResponse::error(format!(
+                "作業時間が上限の{}分を超えています（{}分）",
+                config.max_work_minutes,
+                config.work_duration.as_minutes()
+            ));
+        }
+        if config.work_duration < PhaseDuration::from_minutes(config.min_work_minutes) {
+            return IpcResponse::error(format!(
+                "作業時間が下限の{}分を下回っています（{}分）",
+                config.min_work_minutes,
+                config.work_duration.as_minutes()
+            ));
+        }
+
+        let mut engine = self.engine.lock().await;
+        config.max_cycles = engine.get_state().config.max_cycles;
+        engine.set_config(config);
+        tracing::info!("設定を変更しました");
+
+        let state = engine.get_state();
+        IpcResponse::success(
+            "設定を変更しました（次のフェーズから適用されます）",
+            Some(ResponseData {
+                config: Some(state.config.clone()),
+                ..ResponseData::from_timer_state(state)
+            }),
+        )
+    }
+
+    /// Handles the unsuspend command.
+    async fn handle_unsuspend(&self) -> IpcResponse {
+        let mut engine = self.engine.lock().await;
+
+        match engine.unsuspend() {
+            Ok(()) => IpcResponse::success(
+                "中断したセッションを再開しました",
+                Some(ResponseData::from_timer_state(engine.get_state())),
+            ),
+            Err(e) => IpcResponse::error(e.to_string()),
+        }
+    }
+
+    /// Handles the reset command.
+    async fn handle_reset(&self) -> IpcResponse {
+        let mut engine = self.engine.lock().await;
+        engine.reset();
+
+        IpcResponse::success(
+            "ポモドーロ数をリセットしました",
+            Some(ResponseData::from_timer_state(engine.get_state())),
+        )
+    }
+
     /// Handles the status command.
     async fn handle_status(&self) -> IpcResponse {
         let engine = self.engine.lock().await;
@@ -328,10 +1090,10 @@ impl RequestHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tokio::sync::mpsc;
+    use crate::daemon::channel::{event_channel, EventReceiver, DEFAULT_EVENT_CAPACITY};
 
     use crate::daemon::timer::TimerEvent;
-    use crate::types::PomodoroConfig;
+    use crate::types::{PomodoroConfig, TimerPhase, PROTOCOL_VERSION};
 
     // ------------------------------------------------------------------------
     // Helper functions
@@ -345,8 +1107,8 @@ mod tests {
         path
     }
 
-    fn create_engine() -> (Arc<Mutex<TimerEngine>>, mpsc::UnboundedReceiver<TimerEvent>) {
-        let (tx, rx) = mpsc::unbounded_channel();
+    fn create_engine() -> (Arc<Mutex<TimerEngine>>, EventReceiver) {
+        let (tx, rx) = event_channel(DEFAULT_EVENT_CAPACITY);
         let config = PomodoroConfig::default();
         let engine = TimerEngine::new(config, tx);
         (Arc::new(Mutex::new(engine)), rx)
@@ -383,6 +1145,41 @@ mod tests {
             assert!(server.is_ok());
         }
 
+        #[tokio::test]
+        async fn test_new_exclusive_reclaims_stale_socket() {
+            let socket_path = create_temp_socket_path();
+            // A socket file nobody listens on, as left by a crashed daemon
+            drop(std::os::unix::net::UnixListener::bind(&socket_path).unwrap());
+            assert!(socket_path.exists());
+
+            let server = IpcServer::new_exclusive(&socket_path);
+
+            assert!(server.is_ok());
+            assert!(UnixStream::connect(&socket_path).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn test_new_exclusive_refuses_live_daemon() {
+            let socket_path = create_temp_socket_path();
+            let running = IpcServer::new(&socket_path).unwrap();
+
+            let Err(error) = IpcServer::new_exclusive(&socket_path) else {
+                panic!("a second server must not take the socket");
+            };
+
+            assert!(matches!(
+                error.downcast_ref::<IpcError>(),
+                Some(IpcError::AlreadyRunning(_))
+            ));
+            // The running daemon keeps its socket
+            let client = tokio::spawn({
+                let socket_path = socket_path.clone();
+                async move { UnixStream::connect(&socket_path).await }
+            });
+            assert!(running.accept().await.is_ok());
+            assert!(client.await.unwrap().is_ok());
+        }
+
         #[tokio::test]
         async fn test_server_creates_parent_directory() {
             let dir = tempfile::tempdir().unwrap();
@@ -414,7 +1211,37 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn test_receive_request_status() {
+        async fn test_stream_events() {
+            let socket_path = create_temp_socket_path();
+            let server = IpcServer::new(&socket_path).unwrap();
+            let (events_tx, _) = broadcast::channel(16);
+            let events_rx = events_tx.subscribe();
+
+            let client_path = socket_path.clone();
+            let client_handle = tokio::spawn(async move {
+                let mut stream = UnixStream::connect(&client_path).await.unwrap();
+                let ack: IpcResponse =
+                    serde_json::from_slice(&read_frame(&mut stream, 4096).await.unwrap()).unwrap();
+                let event: TimestampedEvent =
+                    serde_json::from_slice(&read_frame(&mut stream, 4096).await.unwrap()).unwrap();
+                (ack, event)
+            });
+
+            let mut stream = server.accept().await.unwrap();
+            let sent = TimestampedEvent::now(TimerEvent::Paused { reason: None });
+            events_tx.send(sent.clone()).unwrap();
+            drop(events_tx);
+            IpcServer::stream_events(&mut stream, events_rx, Encoding::Json)
+                .await
+                .unwrap();
+
+            let (ack, event) = client_handle.await.unwrap();
+            assert_eq!(ack.status, "success");
+            assert_eq!(event, sent);
+        }
+
+        #[tokio::test]
+        async fn test_receive_request_status() {
             let socket_path = create_temp_socket_path();
             let server = IpcServer::new(&socket_path).unwrap();
 
@@ -424,8 +1251,7 @@ mod tests {
                 tokio::time::sleep(Duration::from_millis(50)).await;
                 let mut stream = UnixStream::connect(&client_path).await.unwrap();
                 let request = r#"{"command":"status"}"#;
-                stream.write_all(request.as_bytes()).await.unwrap();
-                stream.flush().await.unwrap();
+                write_frame(&mut stream, request.as_bytes()).await.unwrap();
             });
 
             let mut stream = server.accept().await.unwrap();
@@ -447,8 +1273,7 @@ mod tests {
                 tokio::time::sleep(Duration::from_millis(50)).await;
                 let mut stream = UnixStream::connect(&client_path).await.unwrap();
                 let request = r#"{"command":"start","taskName":"Test Task"}"#;
-                stream.write_all(request.as_bytes()).await.unwrap();
-                stream.flush().await.unwrap();
+                write_frame(&mut stream, request.as_bytes()).await.unwrap();
             });
 
             let mut stream = server.accept().await.unwrap();
@@ -474,111 +1299,1068 @@ mod tests {
                 tokio::time::sleep(Duration::from_millis(50)).await;
                 let mut stream = UnixStream::connect(&client_path).await.unwrap();
 
-                // Read response
-                let mut buffer = vec![0u8; 4096];
-                let n = stream.read(&mut buffer).await.unwrap();
-                let response: IpcResponse = serde_json::from_slice(&buffer[..n]).unwrap();
-                response
-            });
+                // Read response
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                let response: IpcResponse = serde_json::from_slice(&frame).unwrap();
+                response
+            });
+
+            let mut stream = server.accept().await.unwrap();
+            let response = IpcResponse::success("Test message", None);
+            IpcServer::send_response(&mut stream, &response)
+                .await
+                .unwrap();
+
+            let received = client_handle.await.unwrap();
+            assert_eq!(received.status, "success");
+            assert_eq!(received.message, "Test message");
+        }
+
+        #[tokio::test]
+        async fn test_receive_request_invalid_json() {
+            let socket_path = create_temp_socket_path();
+            let server = IpcServer::new(&socket_path).unwrap();
+
+            let client_path = socket_path.clone();
+            let _client_handle = tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                let mut stream = UnixStream::connect(&client_path).await.unwrap();
+                let invalid_json = "not valid json";
+                write_frame(&mut stream, invalid_json.as_bytes())
+                    .await
+                    .unwrap();
+            });
+
+            let mut stream = server.accept().await.unwrap();
+            let request = IpcServer::receive_request(&mut stream).await;
+
+            assert!(request.is_err());
+        }
+
+        #[tokio::test]
+        async fn test_receive_request_split_across_writes() {
+            let socket_path = create_temp_socket_path();
+            let server = IpcServer::new(&socket_path).unwrap();
+
+            let client_path = socket_path.clone();
+            let client_handle = tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                let mut stream = UnixStream::connect(&client_path).await.unwrap();
+
+                let payload = br#"{"command":"start","taskName":"Chunked"}"#;
+                let mut frame = (payload.len() as u32).to_be_bytes().to_vec();
+                frame.extend_from_slice(payload);
+                let (first, second) = frame.split_at(10);
+
+                stream.write_all(first).await.unwrap();
+                stream.flush().await.unwrap();
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                stream.write_all(second).await.unwrap();
+                stream.flush().await.unwrap();
+            });
+
+            let mut stream = server.accept().await.unwrap();
+            let request = IpcServer::receive_request(&mut stream).await.unwrap();
+
+            if let IpcRequest::Start { params } = request {
+                assert_eq!(params.task_name, Some("Chunked".to_string()));
+            } else {
+                panic!("Expected Start request");
+            }
+
+            client_handle.await.unwrap();
+        }
+
+        #[tokio::test]
+        async fn test_receive_request_incomplete_frame_times_out() {
+            let socket_path = create_temp_socket_path();
+            let server = IpcServer::new(&socket_path).unwrap();
+
+            let client_path = socket_path.clone();
+            let client_handle = tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                let mut stream = UnixStream::connect(&client_path).await.unwrap();
+
+                // Announce 100 bytes but only send a few, keeping the connection open
+                stream.write_all(&100u32.to_be_bytes()).await.unwrap();
+                stream.write_all(br#"{"comm"#).await.unwrap();
+                stream.flush().await.unwrap();
+                tokio::time::sleep(Duration::from_secs(READ_TIMEOUT_SECS + 1)).await;
+            });
+
+            let mut stream = server.accept().await.unwrap();
+            let result = IpcServer::receive_request(&mut stream).await;
+
+            let err = result.unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<IpcError>(),
+                Some(IpcError::Timeout)
+            ));
+
+            client_handle.abort();
+        }
+
+        #[tokio::test]
+        async fn test_socket_path_getter() {
+            let socket_path = create_temp_socket_path();
+            let server = IpcServer::new(&socket_path).unwrap();
+
+            assert_eq!(server.socket_path(), socket_path);
+        }
+
+        #[tokio::test]
+        async fn test_server_drop_cleanup() {
+            let socket_path = create_temp_socket_path();
+
+            {
+                let _server = IpcServer::new(&socket_path).unwrap();
+                assert!(socket_path.exists());
+            }
+
+            // Socket file should be removed after drop
+            assert!(!socket_path.exists());
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // RequestHandler Tests
+    // ------------------------------------------------------------------------
+
+    mod request_handler_tests {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_get_config_after_set_config() {
+            let (engine, _rx) = create_engine();
+            engine.lock().await.start(None).unwrap();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let response = handler
+                .handle(IpcRequest::SetConfig {
+                    config: PomodoroConfig::default().with_work_minutes(50),
+                })
+                .await;
+            assert_eq!(response.status, "success");
+            // The running session keeps its length
+            assert_eq!(response.data.unwrap().remaining_seconds, Some(25 * 60));
+
+            let response = handler.handle(IpcRequest::GetConfig).await;
+            assert_eq!(response.status, "success");
+            assert_eq!(
+                response
+                    .data
+                    .unwrap()
+                    .config
+                    .unwrap()
+                    .work_duration
+                    .as_minutes(),
+                50
+            );
+
+            engine.lock().await.stop().unwrap();
+            engine.lock().await.start(None).unwrap();
+            assert_eq!(engine.lock().await.get_state().remaining_seconds, 50 * 60);
+        }
+
+        #[tokio::test]
+        async fn test_set_config_rejects_invalid() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let response = handler
+                .handle(IpcRequest::SetConfig {
+                    config: PomodoroConfig::default().with_work_minutes(0),
+                })
+                .await;
+            assert_eq!(response.status, "error");
+
+            let response = handler
+                .handle(IpcRequest::SetConfig {
+                    config: PomodoroConfig::default()
+                        .with_max_work_minutes(30)
+                        .with_work_minutes(45),
+                })
+                .await;
+            assert_eq!(response.status, "error");
+
+            let response = handler.handle(IpcRequest::GetConfig).await;
+            assert_eq!(
+                response.data.unwrap().config,
+                Some(PomodoroConfig::default())
+            );
+        }
+
+        #[tokio::test]
+        async fn test_handle_status() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let response = handler.handle(IpcRequest::Status).await;
+
+            assert_eq!(response.status, "success");
+            assert!(response.data.is_some());
+
+            let data = response.data.unwrap();
+            assert_eq!(data.phase(), Some(TimerPhase::Stopped));
+            assert_eq!(data.remaining_seconds, Some(0));
+            assert_eq!(data.pomodoro_count, Some(0));
+        }
+
+        #[tokio::test]
+        async fn test_handle_break() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let response = handler
+                .handle(IpcRequest::Break {
+                    minutes: Some(10),
+                    repeat: None,
+                })
+                .await;
+
+            assert_eq!(response.status, "success");
+            let data = response.data.unwrap();
+            assert_eq!(data.phase(), Some(TimerPhase::Breaking));
+            assert_eq!(data.remaining_seconds, Some(10 * 60));
+            assert_eq!(data.pomodoro_count, Some(0));
+        }
+
+        #[tokio::test]
+        async fn test_handle_break_defaults_to_configured_length() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let response = handler
+                .handle(IpcRequest::Break {
+                    minutes: None,
+                    repeat: None,
+                })
+                .await;
+
+            assert_eq!(response.data.unwrap().remaining_seconds, Some(5 * 60));
+        }
+
+        #[tokio::test]
+        async fn test_handle_break_out_of_range() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let response = handler
+                .handle(IpcRequest::Break {
+                    minutes: Some(61),
+                    repeat: None,
+                })
+                .await;
+
+            assert_eq!(response.status, "error");
+        }
+
+        #[tokio::test]
+        async fn test_handle_extend_work() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine.clone());
+
+            let response = handler.handle(IpcRequest::ExtendWork { minutes: 5 }).await;
+            assert_eq!(response.status, "error");
+
+            {
+                let mut engine = engine.lock().await;
+                engine.start(None).unwrap();
+                engine.tick_by(25 * 60).unwrap();
+            }
+            let request: IpcRequest =
+                serde_json::from_str(r#"{"command":"extendWork","minutes":5}"#).unwrap();
+            let response = handler.handle(request).await;
+
+            assert_eq!(response.status, "success");
+            assert_eq!(response.message, "作業を5分延長しました");
+            let data = response.data.unwrap();
+            assert_eq!(data.phase(), Some(TimerPhase::Working));
+            assert_eq!(data.remaining_seconds, Some(5 * 60));
+            assert_eq!(data.pomodoro_count, Some(1));
+
+            let response = handler.handle(IpcRequest::ExtendWork { minutes: 31 }).await;
+            assert_eq!(response.status, "error");
+        }
+
+        #[tokio::test]
+        async fn test_handle_break_repeat() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let request: IpcRequest =
+                serde_json::from_str(r#"{"command":"break","minutes":2,"repeat":5}"#).unwrap();
+            let response = handler.handle(request).await;
+
+            assert_eq!(response.status, "success");
+            assert_eq!(response.message, "2分の休憩を5回開始しました");
+            assert_eq!(response.data.unwrap().remaining_seconds, Some(2 * 60));
+
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+            let response = handler
+                .handle(IpcRequest::Break {
+                    minutes: Some(2),
+                    repeat: Some(0),
+                })
+                .await;
+            assert_eq!(response.status, "error");
+        }
+
+        #[tokio::test]
+        async fn test_handle_long_break() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let request: IpcRequest =
+                serde_json::from_str(r#"{"command":"longBreak","minutes":30}"#).unwrap();
+            let response = handler.handle(request).await;
+
+            assert_eq!(response.status, "success");
+            assert_eq!(response.message, "30分の長い休憩を開始しました");
+            let data = response.data.unwrap();
+            assert_eq!(data.phase(), Some(TimerPhase::LongBreaking));
+            assert_eq!(data.remaining_seconds, Some(30 * 60));
+
+            // Already on a break
+            let response = handler
+                .handle(IpcRequest::LongBreak { minutes: None })
+                .await;
+            assert_eq!(response.status, "error");
+        }
+
+        #[tokio::test]
+        async fn test_handle_long_break_defaults_and_range() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let response = handler
+                .handle(IpcRequest::LongBreak { minutes: Some(61) })
+                .await;
+            assert_eq!(response.status, "error");
+
+            let response = handler
+                .handle(IpcRequest::LongBreak { minutes: None })
+                .await;
+            assert_eq!(response.data.unwrap().remaining_seconds, Some(15 * 60));
+        }
+
+        #[tokio::test]
+        async fn test_handle_snooze_during_break() {
+            let (engine, _rx) = create_engine();
+            engine.lock().await.get_state_mut().start_breaking();
+            let handler = RequestHandler::new(engine);
+
+            let response = handler.handle(IpcRequest::Snooze { minutes: 5 }).await;
+
+            assert_eq!(response.status, "success");
+            let data = response.data.unwrap();
+            assert_eq!(data.phase(), Some(TimerPhase::Breaking));
+            assert_eq!(data.remaining_seconds, Some(10 * 60));
+        }
+
+        #[tokio::test]
+        async fn test_handle_snooze_clamps_to_cap() {
+            let (engine, _rx) = create_engine();
+            {
+                let mut engine = engine.lock().await;
+                engine.set_config(PomodoroConfig::default().with_max_work_minutes(7));
+                engine.get_state_mut().start_breaking();
+            }
+            let handler = RequestHandler::new(engine);
+
+            let response = handler.handle(IpcRequest::Snooze { minutes: 5 }).await;
+
+            assert_eq!(response.status, "success");
+            assert_eq!(
+                response.message,
+                "上限の7分に達したため、延長は120秒までに制限されました"
+            );
+            assert_eq!(response.data.unwrap().remaining_seconds, Some(7 * 60));
+        }
+
+        #[tokio::test]
+        async fn test_handle_snooze_not_in_break() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let response = handler.handle(IpcRequest::Snooze { minutes: 5 }).await;
+
+            assert_eq!(response.status, "error");
+            assert!(response.message.contains("休憩中ではありません"));
+        }
+
+        #[tokio::test]
+        async fn test_handle_unknown_command() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let request: IpcRequest = serde_json::from_str(r#"{"command":"frobnicate"}"#).unwrap();
+            let response = handler.handle(request).await;
+
+            assert_eq!(response.status, "error");
+            assert!(response.is_unsupported_command());
+            assert_eq!(response.protocol_version, PROTOCOL_VERSION);
+        }
+
+        #[tokio::test]
+        async fn test_handle_skip_respects_min_break() {
+            let (engine, _rx) = create_engine();
+            {
+                let mut engine = engine.lock().await;
+                engine.set_config(PomodoroConfig::default().with_min_break_seconds(60));
+                engine.start_break_only(5).unwrap();
+            }
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let response = handler.handle(IpcRequest::Skip).await;
+            assert_eq!(response.status, "error");
+            assert!(response.message.contains("あと60秒"));
+
+            for _ in 0..60 {
+                engine.lock().await.tick().unwrap();
+            }
+            let response = handler.handle(IpcRequest::Skip).await;
+            assert_eq!(response.status, "success");
+            assert_eq!(response.phase(), Some(TimerPhase::Working));
+        }
+
+        #[tokio::test]
+        async fn test_start_and_skip_confirm_awaited_break() {
+            let (engine, _rx) = create_engine();
+            engine.lock().await.get_state_mut().await_break();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let status = handler.handle(IpcRequest::Status).await;
+            assert_eq!(status.phase(), Some(TimerPhase::Stopped));
+            assert_eq!(status.data.unwrap().awaiting_break, Some(true));
+
+            let request = IpcRequest::Start {
+                params: StartParams::default(),
+            };
+            let response = handler.handle(request).await;
+            assert_eq!(response.status, "success");
+            assert_eq!(response.message, "休憩を開始しました");
+            assert_eq!(response.phase(), Some(TimerPhase::Breaking));
+
+            engine.lock().await.stop().unwrap();
+            engine.lock().await.get_state_mut().await_break();
+            let response = handler.handle(IpcRequest::Skip).await;
+            assert_eq!(response.status, "success");
+            assert_eq!(response.phase(), Some(TimerPhase::Working));
+        }
+
+        #[tokio::test]
+        async fn test_start_with_session_options_does_not_confirm_awaited_break() {
+            let (engine, _rx) = create_engine();
+            engine.lock().await.get_state_mut().await_break();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+            let requests = [
+                StartParams {
+                    task_name: Some("Docs".to_string()),
+                    ..Default::default()
+                },
+                // `start --work 50` (or a preset with other durations)
+                StartParams {
+                    work_minutes: Some(50),
+                    ..Default::default()
+                },
+                StartParams {
+                    task_queue: Some(vec!["A".to_string(), "B".to_string()]),
+                    ..Default::default()
+                },
+            ];
+
+            for params in requests {
+                let response = handler.handle(IpcRequest::Start { params }).await;
+
+                assert_eq!(response.status, "error");
+                assert!(response.message.contains("休憩の開始待ち"));
+                let state = engine.lock().await.get_state().clone();
+                assert!(state.awaiting_break);
+                assert_eq!(state.phase, TimerPhase::Stopped);
+            }
+        }
+
+        #[tokio::test]
+        async fn test_start_with_run_config_confirms_awaited_break() {
+            let (engine, _rx) = create_engine();
+            engine.lock().await.get_state_mut().await_break();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+            // What the CLI sends for a plain `start`: the resolved configuration
+            let config = PomodoroConfig::default();
+            let params = StartParams {
+                work_minutes: Some(config.work_duration.as_minutes()),
+                break_minutes: Some(config.break_duration.as_minutes()),
+                long_break_minutes: Some(config.long_break_duration.as_minutes()),
+                long_break_interval: Some(config.long_break_interval),
+                auto_cycle: Some(config.auto_cycle),
+                focus_mode: Some(config.focus_mode),
+                strict: Some(config.strict),
+                long_break_enabled: Some(config.long_break_enabled),
+                ..Default::default()
+            };
+
+            let response = handler.handle(IpcRequest::Start { params }).await;
+
+            assert_eq!(response.status, "success");
+            assert_eq!(response.message, "休憩を開始しました");
+            assert_eq!(response.phase(), Some(TimerPhase::Breaking));
+        }
+
+        #[tokio::test]
+        async fn test_handle_snooze_out_of_range() {
+            let (engine, _rx) = create_engine();
+            engine.lock().await.get_state_mut().start_breaking();
+            let handler = RequestHandler::new(engine);
+
+            let response = handler.handle(IpcRequest::Snooze { minutes: 31 }).await;
+
+            assert_eq!(response.status, "error");
+        }
+
+        #[tokio::test]
+        async fn test_subscribe_requires_events() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+            assert!(handler.subscribe().is_none());
+
+            let (events_tx, _) = broadcast::channel(16);
+            let handler = RequestHandler::new(engine).with_events(events_tx);
+            assert!(handler.subscribe().is_some());
+        }
+
+        #[tokio::test]
+        async fn test_handle_suspend_and_unsuspend() {
+            let (engine, _rx) = create_engine();
+            {
+                let mut engine = engine.lock().await;
+                engine.start(Some("Write docs".to_string())).unwrap();
+                engine.get_state_mut().remaining_seconds = 300;
+            }
+            let handler = RequestHandler::new(engine);
+
+            let response = handler.handle(IpcRequest::Suspend).await;
+            assert_eq!(response.status, "success");
+            assert_eq!(response.phase(), Some(TimerPhase::Stopped));
+
+            let response = handler.handle(IpcRequest::Unsuspend).await;
+            assert_eq!(response.status, "success");
+            assert_eq!(response.phase(), Some(TimerPhase::Working));
+            let data = response.data.unwrap();
+            assert_eq!(data.remaining_seconds, Some(300));
+            assert_eq!(data.task_name.as_deref(), Some("Write docs"));
+
+            let response = handler.handle(IpcRequest::Unsuspend).await;
+            assert_eq!(response.status, "error");
+        }
+
+        #[tokio::test]
+        async fn test_handle_reset() {
+            let (engine, _rx) = create_engine();
+            {
+                let mut engine = engine.lock().await;
+                engine.get_state_mut().pomodoro_count = 2;
+                engine.get_state_mut().total_completed_pomodoros = 6;
+            }
+            let handler = RequestHandler::new(engine);
+
+            let response = handler.handle(IpcRequest::Reset).await;
+
+            assert_eq!(response.status, "success");
+            let data = response.data.unwrap();
+            assert_eq!(data.pomodoro_count, Some(0));
+            assert_eq!(data.pomodoros_until_long_break, Some(4));
+        }
+
+        #[tokio::test]
+        async fn test_handle_start() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    task_name: Some("Test Task".to_string()),
+                    ..Default::default()
+                },
+            };
+
+            let response = handler.handle(request).await;
+
+            assert_eq!(response.status, "success");
+            assert_eq!(response.message, "タイマーを開始しました");
+            assert!(response.data.is_some());
+
+            let data = response.data.unwrap();
+            assert_eq!(data.phase(), Some(TimerPhase::Working));
+            assert_eq!(data.remaining_seconds, Some(25 * 60));
+            assert_eq!(data.task_name, Some("Test Task".to_string()));
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_strict_disables_pause() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    strict: Some(true),
+                    ..Default::default()
+                },
+            };
+            assert_eq!(handler.handle(request).await.status, "success");
+
+            let response = handler.handle(IpcRequest::Pause { reason: None }).await;
+            assert_eq!(response.status, "error");
+
+            let response = handler.handle(IpcRequest::Stop).await;
+            assert_eq!(response.status, "success");
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_overrides_survive_config_reload() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    work_minutes: Some(50),
+                    strict: Some(true),
+                    ..Default::default()
+                },
+            };
+            assert_eq!(handler.handle(request).await.status, "success");
+
+            let mut engine = engine.lock().await;
+            engine.reload_config(PomodoroConfig::default().with_work_minutes(30));
+            let config = &engine.get_state().config;
+            assert_eq!(config.work_duration.as_minutes(), 50);
+            assert!(config.strict);
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_applies_long_break_interval() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    long_break_interval: Some(3),
+                    ..Default::default()
+                },
+            };
+            assert_eq!(handler.handle(request).await.status, "success");
+            assert_eq!(
+                engine.lock().await.get_state().config.long_break_interval,
+                3
+            );
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_without_long_break() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    long_break_enabled: Some(false),
+                    ..Default::default()
+                },
+            };
+            assert_eq!(handler.handle(request).await.status, "success");
+            assert!(!engine.lock().await.get_state().config.long_break_enabled);
+
+            let data = handler.handle(IpcRequest::Status).await.data.unwrap();
+            assert_eq!(data.pomodoros_until_long_break, None);
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_max_cycles_applies_to_one_run() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    auto_cycle: Some(true),
+                    max_cycles: Some(2),
+                    ..Default::default()
+                },
+            };
+            handler.handle(request).await;
+            assert_eq!(engine.lock().await.get_state().config.max_cycles, Some(2));
+
+            handler.handle(IpcRequest::Stop).await;
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    auto_cycle: Some(true),
+                    ..Default::default()
+                },
+            };
+            handler.handle(request).await;
+            assert_eq!(engine.lock().await.get_state().config.max_cycles, None);
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_rejects_zero_cycles() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    max_cycles: Some(0),
+                    ..Default::default()
+                },
+            };
+
+            assert_eq!(handler.handle(request).await.status, "error");
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_rejects_work_over_cap() {
+            let (engine, _rx) = create_engine();
+            engine
+                .lock()
+                .await
+                .set_config(PomodoroConfig::default().with_max_work_minutes(50));
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    work_minutes: Some(60),
+                    ..Default::default()
+                },
+            };
+            let response = handler.handle(request).await;
+
+            assert_eq!(response.status, "error");
+            assert_eq!(
+                response.message,
+                "作業時間が上限の50分を超えています（60分）"
+            );
+            assert!(!engine.lock().await.get_state().is_running());
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_rejects_work_below_floor() {
+            let (engine, _rx) = create_engine();
+            engine
+                .lock()
+                .await
+                .set_config(PomodoroConfig::default().with_min_work_minutes(15));
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    work_minutes: Some(5),
+                    ..Default::default()
+                },
+            };
+            let response = handler.handle(request).await;
+
+            assert_eq!(response.status, "error");
+            assert_eq!(
+                response.message,
+                "作業時間が下限の15分を下回っています（5分）"
+            );
+            assert!(!engine.lock().await.get_state().is_running());
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_accepts_work_at_floor() {
+            let (engine, _rx) = create_engine();
+            engine
+                .lock()
+                .await
+                .set_config(PomodoroConfig::default().with_min_work_minutes(15));
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    work_minutes: Some(15),
+                    ..Default::default()
+                },
+            };
+            let response = handler.handle(request).await;
+
+            assert_eq!(response.status, "success");
+            assert_eq!(response.data.unwrap().remaining_seconds, Some(15 * 60));
+        }
+
+        fn idempotent_start(task: &str, work_minutes: u32) -> IpcRequest {
+            IpcRequest::Start {
+                params: StartParams {
+                    work_minutes: Some(work_minutes),
+                    task_name: Some(task.to_string()),
+                    if_not_running: Some(true),
+                    ..Default::default()
+                },
+            }
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_if_not_running_matching_session() {
+            let (engine, mut rx) = create_engine();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+
+            let response = handler.handle(idempotent_start("Write docs", 30)).await;
+            assert_eq!(response.message, "タイマーを開始しました");
+            engine.lock().await.tick_by(60).unwrap();
+            while rx.try_recv().is_ok() {}
+
+            let response = handler.handle(idempotent_start("Write docs", 30)).await;
+
+            assert_eq!(response.status, "success");
+            assert_eq!(response.message, SESSION_ALREADY_RUNNING);
+            // The running session carries on untouched
+            assert_eq!(response.data.unwrap().remaining_seconds, Some(29 * 60));
+            assert!(rx.try_recv().is_err());
+        }
+
+        #[tokio::test]
+        async fn test_handle_start_if_not_running_different_session() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+            handler.handle(idempotent_start("Write docs", 30)).await;
+
+            let response = handler.handle(idempotent_start("Review", 30)).await;
+            assert_eq!(response.status, "error");
+
+            let response = handler.handle(idempotent_start("Write docs", 45)).await;
+            assert_eq!(response.status, "error");
+            assert_eq!(
+                engine.lock().await.get_state().task_name.as_deref(),
+                Some("Write docs")
+            );
+        }
+
+        /// Log output captured by a test subscriber.
+        #[derive(Clone, Default)]
+        struct LogBuffer(Arc<std::sync::Mutex<Vec<u8>>>);
+
+        impl std::io::Write for LogBuffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        /// Starts a session with secret task names and returns the log.
+        async fn logged_start(handler: &RequestHandler) -> String {
+            let logs = LogBuffer::default();
+            let subscriber = tracing_subscriber::fmt()
+                .with_max_level(tracing::Level::DEBUG)
+                .with_ansi(false)
+                .with_writer({
+                    let logs = logs.clone();
+                    move || logs.clone()
+                })
+                .finish();
+            let _guard = tracing::subscriber::set_default(subscriber);
+
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    task_queue: Some(vec!["Secret plan".to_string(), "Payroll".to_string()]),
+                    ..Default::default()
+                },
+            };
+            handler.handle(request).await;
+
+            let bytes = logs.0.lock().unwrap().clone();
+            String::from_utf8(bytes).unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_request_log_redacts_task_names() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+
+            let log = logged_start(&handler).await;
+
+            assert!(log.contains("リクエストを処理しました"));
+            assert!(log.contains("command=\"start\""));
+            assert!(log.contains("status=success"));
+            assert!(log.contains("tasks=\"<11文字>, <7文字>\""));
+            assert!(!log.contains("Secret plan"));
+            assert!(!log.contains("Payroll"));
+        }
+
+        #[tokio::test]
+        async fn test_request_log_shows_task_names_when_enabled() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine).with_log_task_names(true);
 
-            let mut stream = server.accept().await.unwrap();
-            let response = IpcResponse::success("Test message", None);
-            IpcServer::send_response(&mut stream, &response)
-                .await
-                .unwrap();
+            let log = logged_start(&handler).await;
 
-            let received = client_handle.await.unwrap();
-            assert_eq!(received.status, "success");
-            assert_eq!(received.message, "Test message");
+            assert!(log.contains("tasks=\"Secret plan, Payroll\""));
         }
 
         #[tokio::test]
-        async fn test_receive_request_invalid_json() {
-            let socket_path = create_temp_socket_path();
-            let server = IpcServer::new(&socket_path).unwrap();
+        async fn test_handle_start_tag_flows_into_state() {
+            let (engine, mut rx) = create_engine();
+            let handler = RequestHandler::new(Arc::clone(&engine));
 
-            let client_path = socket_path.clone();
-            let _client_handle = tokio::spawn(async move {
-                tokio::time::sleep(Duration::from_millis(50)).await;
-                let mut stream = UnixStream::connect(&client_path).await.unwrap();
-                let invalid_json = "not valid json";
-                stream.write_all(invalid_json.as_bytes()).await.unwrap();
-                stream.flush().await.unwrap();
-            });
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    task_name: Some("Docs".to_string()),
+                    tag: Some("project-x".to_string()),
+                    ..Default::default()
+                },
+            };
+            let response = handler.handle(request).await;
 
-            let mut stream = server.accept().await.unwrap();
-            let request = IpcServer::receive_request(&mut stream).await;
+            assert_eq!(response.status, "success");
+            let data = response.data.unwrap();
+            assert_eq!(data.task_name.as_deref(), Some("Docs"));
+            assert_eq!(data.tag.as_deref(), Some("project-x"));
 
-            assert!(request.is_err());
+            // The completion carries the tag into the history
+            {
+                let mut engine = engine.lock().await;
+                engine.get_state_mut().remaining_seconds = 1;
+                engine.tick().unwrap();
+            }
+            rx.try_recv().unwrap();
+            rx.try_recv().unwrap();
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::WorkCompleted {
+                    pomodoro_count: 1,
+                    task_name: Some("Docs".to_string()),
+                    tag: Some("project-x".to_string()),
+                    work_seconds: 1500,
+                    extended: false,
+                }
+            );
+
+            handler.handle(IpcRequest::Stop).await;
+            assert_eq!(engine.lock().await.get_state().tag, None);
         }
 
         #[tokio::test]
-        async fn test_socket_path_getter() {
-            let socket_path = create_temp_socket_path();
-            let server = IpcServer::new(&socket_path).unwrap();
+        async fn test_handle_start_if_not_running_different_tag() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+            handler.handle(idempotent_start("Write docs", 30)).await;
 
-            assert_eq!(server.socket_path(), socket_path);
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    work_minutes: Some(30),
+                    task_name: Some("Write docs".to_string()),
+                    tag: Some("project-x".to_string()),
+                    if_not_running: Some(true),
+                    ..Default::default()
+                },
+            };
+            let response = handler.handle(request).await;
+
+            assert_eq!(response.status, "error");
         }
 
         #[tokio::test]
-        async fn test_server_drop_cleanup() {
-            let socket_path = create_temp_socket_path();
+        async fn test_handle_start_force_replaces_running_session() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(Arc::clone(&engine));
+            handler.handle(idempotent_start("Write docs", 30)).await;
 
-            {
-                let _server = IpcServer::new(&socket_path).unwrap();
-                assert!(socket_path.exists());
-            }
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    work_minutes: Some(45),
+                    task_name: Some("Review".to_string()),
+                    if_not_running: Some(true),
+                    force: Some(true),
+                    ..Default::default()
+                },
+            };
+            let response = handler.handle(request).await;
 
-            // Socket file should be removed after drop
-            assert!(!socket_path.exists());
+            assert_eq!(response.status, "success");
+            assert_eq!(response.message, "タイマーを開始しました");
+            let data = response.data.unwrap();
+            assert_eq!(data.task_name.as_deref(), Some("Review"));
+            assert_eq!(data.remaining_seconds, Some(45 * 60));
         }
-    }
 
-    // ------------------------------------------------------------------------
-    // RequestHandler Tests
-    // ------------------------------------------------------------------------
+        #[tokio::test]
+        async fn test_handle_start_force_refused_in_strict_mode() {
+            let (engine, _rx) = create_engine();
+            {
+                let mut engine = engine.lock().await;
+                engine.set_config(
+                    PomodoroConfig::default()
+                        .with_strict(true)
+                        .with_min_break_seconds(60),
+                );
+                engine.start_break_only(5).unwrap();
+            }
+            let handler = RequestHandler::new(Arc::clone(&engine));
 
-    mod request_handler_tests {
-        use super::*;
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    force: Some(true),
+                    ..Default::default()
+                },
+            };
+            let response = handler.handle(request).await;
+
+            assert_eq!(response.status, "error");
+            assert!(response.message.contains("厳格モード"));
+            assert_eq!(engine.lock().await.get_state().phase, TimerPhase::Breaking);
+        }
 
         #[tokio::test]
-        async fn test_handle_status() {
+        async fn test_handle_start_paused() {
             let (engine, _rx) = create_engine();
             let handler = RequestHandler::new(engine);
 
-            let response = handler.handle(IpcRequest::Status).await;
+            let request = IpcRequest::Start {
+                params: StartParams {
+                    work_minutes: Some(30),
+                    paused: Some(true),
+                    ..Default::default()
+                },
+            };
+            let response = handler.handle(request).await;
 
             assert_eq!(response.status, "success");
-            assert!(response.data.is_some());
-
+            assert_eq!(response.message, "タイマーを一時停止状態で準備しました");
             let data = response.data.unwrap();
-            assert_eq!(data.state, Some("stopped".to_string()));
-            assert_eq!(data.remaining_seconds, Some(0));
-            assert_eq!(data.pomodoro_count, Some(0));
+            assert_eq!(data.phase(), Some(TimerPhase::Paused));
+            assert_eq!(data.remaining_seconds, Some(30 * 60));
+
+            let response = handler.handle(IpcRequest::Resume).await;
+            assert_eq!(response.phase(), Some(TimerPhase::Working));
+            assert_eq!(response.data.unwrap().remaining_seconds, Some(30 * 60));
         }
 
         #[tokio::test]
-        async fn test_handle_start() {
+        async fn test_handle_start_task_queue() {
             let (engine, _rx) = create_engine();
             let handler = RequestHandler::new(engine);
 
             let request = IpcRequest::Start {
                 params: StartParams {
-                    task_name: Some("Test Task".to_string()),
+                    task_name: Some("ignored".to_string()),
+                    auto_cycle: Some(true),
+                    task_queue: Some(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
                     ..Default::default()
                 },
             };
-
             let response = handler.handle(request).await;
 
             assert_eq!(response.status, "success");
-            assert_eq!(response.message, "タイマーを開始しました");
-            assert!(response.data.is_some());
-
             let data = response.data.unwrap();
-            assert_eq!(data.state, Some("working".to_string()));
-            assert_eq!(data.remaining_seconds, Some(25 * 60));
-            assert_eq!(data.task_name, Some("Test Task".to_string()));
+            assert_eq!(data.task_name, Some("a".to_string()));
+            assert_eq!(
+                data.task_queue,
+                Some(vec!["b".to_string(), "c".to_string()])
+            );
+
+            let response = handler.handle(IpcRequest::Status).await;
+            assert_eq!(response.data.unwrap().task_queue.unwrap().len(), 2);
         }
 
         #[tokio::test]
@@ -611,13 +2393,45 @@ mod tests {
                 })
                 .await;
 
-            let response = handler.handle(IpcRequest::Pause).await;
+            let response = handler.handle(IpcRequest::Pause { reason: None }).await;
 
             assert_eq!(response.status, "success");
             assert_eq!(response.message, "タイマーを一時停止しました");
 
             let data = response.data.unwrap();
-            assert_eq!(data.state, Some("paused".to_string()));
+            assert_eq!(data.phase(), Some(TimerPhase::Paused));
+            assert_eq!(data.pause_reason, None);
+        }
+
+        #[tokio::test]
+        async fn test_handle_pause_with_reason() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+            handler
+                .handle(IpcRequest::Start {
+                    params: StartParams::default(),
+                })
+                .await;
+
+            let response = handler
+                .handle(IpcRequest::Pause {
+                    reason: Some(" meeting ".to_string()),
+                })
+                .await;
+            assert_eq!(response.status, "success");
+            assert_eq!(
+                response.data.unwrap().pause_reason.as_deref(),
+                Some("meeting")
+            );
+
+            let status = handler.handle(IpcRequest::Status).await;
+            assert_eq!(
+                status.data.unwrap().pause_reason.as_deref(),
+                Some("meeting")
+            );
+
+            let resumed = handler.handle(IpcRequest::Resume).await;
+            assert_eq!(resumed.data.unwrap().pause_reason, None);
         }
 
         #[tokio::test]
@@ -625,7 +2439,7 @@ mod tests {
             let (engine, _rx) = create_engine();
             let handler = RequestHandler::new(engine);
 
-            let response = handler.handle(IpcRequest::Pause).await;
+            let response = handler.handle(IpcRequest::Pause { reason: None }).await;
 
             assert_eq!(response.status, "error");
             assert!(response.message.contains("実行されていません"));
@@ -642,7 +2456,7 @@ mod tests {
                     params: StartParams::default(),
                 })
                 .await;
-            handler.handle(IpcRequest::Pause).await;
+            handler.handle(IpcRequest::Pause { reason: None }).await;
 
             let response = handler.handle(IpcRequest::Resume).await;
 
@@ -650,7 +2464,7 @@ mod tests {
             assert_eq!(response.message, "タイマーを再開しました");
 
             let data = response.data.unwrap();
-            assert_eq!(data.state, Some("working".to_string()));
+            assert_eq!(data.phase(), Some(TimerPhase::Working));
         }
 
         #[tokio::test]
@@ -682,7 +2496,7 @@ mod tests {
             assert_eq!(response.message, "タイマーを停止しました");
 
             let data = response.data.unwrap();
-            assert_eq!(data.state, Some("stopped".to_string()));
+            assert_eq!(data.phase(), Some(TimerPhase::Stopped));
         }
 
         #[tokio::test]
@@ -709,6 +2523,7 @@ mod tests {
                     auto_cycle: Some(true),
                     focus_mode: Some(true),
                     task_name: Some("Custom".to_string()),
+                    ..Default::default()
                 },
             };
 
@@ -719,7 +2534,7 @@ mod tests {
             let data = response.data.unwrap();
             // Note: The remaining seconds still use the original config
             // because we don't recreate the engine with new config
-            assert_eq!(data.state, Some("working".to_string()));
+            assert_eq!(data.phase(), Some(TimerPhase::Working));
         }
 
         #[tokio::test]
@@ -747,6 +2562,7 @@ mod tests {
 
     mod integration_tests {
         use super::*;
+        use crate::daemon::clock::MockClock;
 
         #[tokio::test]
         async fn test_full_ipc_flow() {
@@ -763,13 +2579,11 @@ mod tests {
 
                 // Send start request
                 let request = r#"{"command":"start","taskName":"Integration Test"}"#;
-                stream.write_all(request.as_bytes()).await.unwrap();
-                stream.flush().await.unwrap();
+                write_frame(&mut stream, request.as_bytes()).await.unwrap();
 
                 // Read response
-                let mut buffer = vec![0u8; 4096];
-                let n = stream.read(&mut buffer).await.unwrap();
-                let response: IpcResponse = serde_json::from_slice(&buffer[..n]).unwrap();
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                let response: IpcResponse = serde_json::from_slice(&frame).unwrap();
                 response
             });
 
@@ -788,7 +2602,7 @@ mod tests {
             assert!(client_response.data.is_some());
 
             let data = client_response.data.unwrap();
-            assert_eq!(data.state, Some("working".to_string()));
+            assert_eq!(data.phase(), Some(TimerPhase::Working));
             assert_eq!(data.task_name, Some("Integration Test".to_string()));
         }
 
@@ -805,11 +2619,9 @@ mod tests {
                 tokio::time::sleep(Duration::from_millis(50)).await;
                 let mut stream = UnixStream::connect(&client_path).await.unwrap();
                 let request = r#"{"command":"start"}"#;
-                stream.write_all(request.as_bytes()).await.unwrap();
-                stream.flush().await.unwrap();
-                let mut buf = vec![0u8; 4096];
-                let n = stream.read(&mut buf).await.unwrap();
-                serde_json::from_slice::<IpcResponse>(&buf[..n]).unwrap()
+                write_frame(&mut stream, request.as_bytes()).await.unwrap();
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                serde_json::from_slice::<IpcResponse>(&frame).unwrap()
             });
 
             let mut stream1 = server.accept().await.unwrap();
@@ -828,11 +2640,9 @@ mod tests {
                 tokio::time::sleep(Duration::from_millis(50)).await;
                 let mut stream = UnixStream::connect(&client_path).await.unwrap();
                 let request = r#"{"command":"status"}"#;
-                stream.write_all(request.as_bytes()).await.unwrap();
-                stream.flush().await.unwrap();
-                let mut buf = vec![0u8; 4096];
-                let n = stream.read(&mut buf).await.unwrap();
-                serde_json::from_slice::<IpcResponse>(&buf[..n]).unwrap()
+                write_frame(&mut stream, request.as_bytes()).await.unwrap();
+                let frame = read_frame(&mut stream, 4096).await.unwrap();
+                serde_json::from_slice::<IpcResponse>(&frame).unwrap()
             });
 
             let mut stream2 = server.accept().await.unwrap();
@@ -845,7 +2655,137 @@ mod tests {
             let result2 = client2.await.unwrap();
             assert_eq!(result2.status, "success");
             let data = result2.data.unwrap();
-            assert_eq!(data.state, Some("working".to_string()));
+            assert_eq!(data.phase(), Some(TimerPhase::Working));
+        }
+
+        #[tokio::test]
+        async fn test_serve_connection_answers_several_requests() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+            let (mut client, server_side) = UnixStream::pair().unwrap();
+            let server =
+                tokio::spawn(
+                    async move { IpcServer::serve_connection(server_side, &handler).await },
+                );
+
+            let mut states = Vec::new();
+            for request in [r#"{"command":"start"}"#, r#"{"command":"pause"}"#] {
+                write_frame(&mut client, request.as_bytes()).await.unwrap();
+                let frame = read_frame(&mut client, 4096).await.unwrap();
+                let response: IpcResponse = serde_json::from_slice(&frame).unwrap();
+                states.push(response.data.unwrap().state);
+            }
+            drop(client);
+
+            assert_eq!(
+                states,
+                vec![Some("working".to_string()), Some("paused".to_string())]
+            );
+            assert!(server.await.unwrap().is_ok());
+        }
+
+        #[tokio::test]
+        async fn test_serve_connection_one_shot_client() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+            let (mut client, server_side) = UnixStream::pair().unwrap();
+            let server =
+                tokio::spawn(
+                    async move { IpcServer::serve_connection(server_side, &handler).await },
+                );
+
+            write_frame(&mut client, br#"{"command":"status"}"#)
+                .await
+                .unwrap();
+            let frame = read_frame(&mut client, 4096).await.unwrap();
+            drop(client);
+
+            let response: IpcResponse = serde_json::from_slice(&frame).unwrap();
+            assert_eq!(response.status, "success");
+            assert!(server.await.unwrap().is_ok());
+        }
+
+        #[tokio::test]
+        async fn test_serve_connection_probe_without_request() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+            let (client, server_side) = UnixStream::pair().unwrap();
+
+            drop(client);
+
+            assert!(IpcServer::serve_connection(server_side, &handler)
+                .await
+                .is_ok());
+        }
+
+        #[tokio::test]
+        async fn test_idle_connection_expires_on_the_clock() {
+            let (engine, _rx) = create_engine();
+            let handler = RequestHandler::new(engine);
+            let limit = handler.request_timeout();
+            let clock = MockClock::default();
+            let (_client, server_side) = UnixStream::pair().unwrap();
+            let server = tokio::spawn({
+                let clock = clock.clone();
+                async move {
+                    IpcServer::serve_connection_with_clock(server_side, &handler, &clock).await
+                }
+            });
+            while clock.sleepers() == 0 {
+                tokio::task::yield_now().await;
+            }
+
+            clock.advance(limit);
+
+            let error = server.await.unwrap().unwrap_err();
+            assert!(matches!(
+                error.downcast_ref::<IpcError>(),
+                Some(IpcError::DeadlineExceeded(d)) if *d == limit
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_request_past_deadline_closes_connection() {
+            let socket_path = create_temp_socket_path();
+            let server = IpcServer::new(&socket_path).unwrap();
+            let (engine, _rx) = create_engine();
+            let handler = Arc::new(
+                RequestHandler::new(Arc::clone(&engine))
+                    .with_request_timeout(Duration::from_millis(100)),
+            );
+            let accept_loop = tokio::spawn(async move {
+                loop {
+                    let stream = server.accept().await.unwrap();
+                    let handler = Arc::clone(&handler);
+                    tokio::spawn(async move {
+                        let _ = IpcServer::serve_connection(stream, &handler).await;
+                    });
+                }
+            });
+
+            // The engine is busy, so the request cannot be handled in time;
+            // the connection is closed well before the read timeout
+            let busy = engine.lock().await;
+            let mut stuck = UnixStream::connect(&socket_path).await.unwrap();
+            write_frame(&mut stuck, br#"{"command":"status"}"#)
+                .await
+                .unwrap();
+            let closed = timeout(Duration::from_secs(2), read_frame(&mut stuck, 4096))
+                .await
+                .expect("connection should be closed at the deadline");
+            assert!(closed.is_err());
+            drop(busy);
+
+            // The server still accepts and answers new connections
+            let mut stream = UnixStream::connect(&socket_path).await.unwrap();
+            write_frame(&mut stream, br#"{"command":"status"}"#)
+                .await
+                .unwrap();
+            let frame = read_frame(&mut stream, 4096).await.unwrap();
+            let response: IpcResponse = serde_json::from_slice(&frame).unwrap();
+            assert_eq!(response.status, "success");
+
+            accept_loop.abort();
         }
 
         #[tokio::test]
@@ -881,6 +2821,144 @@ mod tests {
         }
     }
 
+    // ------------------------------------------------------------------------
+    // Encoding Tests
+    // ------------------------------------------------------------------------
+
+    mod encoding_tests {
+        use super::*;
+
+        fn sample_events() -> Vec<TimestampedEvent> {
+            [
+                TimerEvent::WorkStarted {
+                    task_name: Some("Task".to_string()),
+                },
+                TimerEvent::WorkCompleted {
+                    pomodoro_count: 3,
+                    task_name: None,
+                    tag: None,
+                    work_seconds: 1500,
+                    extended: false,
+                },
+                TimerEvent::BreakStarted {
+                    is_long_break: true,
+                },
+                TimerEvent::Paused { reason: None },
+                TimerEvent::Tick {
+                    remaining_seconds: 42,
+                },
+            ]
+            .into_iter()
+            .map(TimestampedEvent::now)
+            .collect()
+        }
+
+        fn sample_response() -> IpcResponse {
+            IpcResponse::success(
+                "",
+                Some(ResponseData {
+                    state: Some("working".to_string()),
+                    remaining_seconds: Some(1500),
+                    progress: Some(0.25),
+                    ..Default::default()
+                }),
+            )
+        }
+
+        #[test]
+        fn test_round_trip_both_encodings() {
+            for encoding in [Encoding::Json, Encoding::MessagePack] {
+                for event in sample_events() {
+                    let payload = encoding.encode(&event).unwrap();
+                    let (decoded, detected): (TimestampedEvent, _) =
+                        Encoding::decode(&payload).unwrap();
+                    assert_eq!(decoded, event);
+                    assert_eq!(detected, encoding);
+                }
+
+                let response = sample_response();
+                let payload = encoding.encode(&response).unwrap();
+                let (decoded, _): (IpcResponse, _) = Encoding::decode(&payload).unwrap();
+                assert_eq!(decoded.status, response.status);
+                assert_eq!(decoded.data, response.data);
+
+                let payload = encoding.encode(&IpcRequest::Snooze { minutes: 5 }).unwrap();
+                let (decoded, _): (IpcRequest, _) = Encoding::decode(&payload).unwrap();
+                assert!(matches!(decoded, IpcRequest::Snooze { minutes: 5 }));
+            }
+        }
+
+        #[test]
+        fn test_messagepack_is_tagged_and_smaller() {
+            let event = TimestampedEvent::now(TimerEvent::Tick {
+                remaining_seconds: 1499,
+            });
+            let json = Encoding::Json.encode(&event).unwrap();
+            let msgpack = Encoding::MessagePack.encode(&event).unwrap();
+
+            assert_eq!(json[0], b'{');
+            assert_eq!(msgpack[0], MSGPACK_FRAME_TAG);
+            assert!(msgpack.len() < json.len());
+        }
+
+        #[test]
+        fn test_invalid_payload_is_serialization_error() {
+            let result: Result<(IpcRequest, _), _> = Encoding::decode(&[MSGPACK_FRAME_TAG, 0xc1]);
+            assert!(matches!(result, Err(IpcError::SerializationError(_))));
+        }
+
+        #[tokio::test]
+        async fn test_server_replies_in_client_encoding() {
+            let socket_path = create_temp_socket_path();
+            let server = IpcServer::new(&socket_path).unwrap();
+
+            let client_path = socket_path.clone();
+            let client_handle = tokio::spawn(async move {
+                let mut stream = UnixStream::connect(&client_path).await.unwrap();
+                let request = Encoding::MessagePack.encode(&IpcRequest::Status).unwrap();
+                write_frame(&mut stream, &request).await.unwrap();
+                read_frame(&mut stream, 4096).await.unwrap()
+            });
+
+            let mut stream = server.accept().await.unwrap();
+            let (request, encoding) = IpcServer::receive_request_with_encoding(&mut stream)
+                .await
+                .unwrap();
+            assert!(matches!(request, IpcRequest::Status));
+            assert_eq!(encoding, Encoding::MessagePack);
+            IpcServer::send_response_encoded(&mut stream, &sample_response(), encoding)
+                .await
+                .unwrap();
+
+            let reply = client_handle.await.unwrap();
+            assert_eq!(Encoding::detect(&reply), Encoding::MessagePack);
+            let (response, _): (IpcResponse, _) = Encoding::decode(&reply).unwrap();
+            assert_eq!(response.data, sample_response().data);
+        }
+
+        #[tokio::test]
+        async fn test_json_request_gets_json_reply() {
+            let socket_path = create_temp_socket_path();
+            let server = IpcServer::new(&socket_path).unwrap();
+
+            let client_path = socket_path.clone();
+            let client_handle = tokio::spawn(async move {
+                let mut stream = UnixStream::connect(&client_path).await.unwrap();
+                write_frame(&mut stream, br#"{"command":"status"}"#)
+                    .await
+                    .unwrap();
+            });
+
+            let mut stream = server.accept().await.unwrap();
+            let (_, encoding) = IpcServer::receive_request_with_encoding(&mut stream)
+                .await
+                .unwrap();
+            assert_eq!(encoding, Encoding::Json);
+
+            client_handle.await.unwrap();
+        }
+    }
+
     // ------------------------------------------------------------------------
     // Error Handling Tests
     // ------------------------------------------------------------------------
@@ -907,6 +2985,27 @@ mod tests {
             assert!(result.is_err());
         }
 
+        #[tokio::test]
+        async fn test_read_frame_rejects_oversized_length() {
+            let (mut client, mut server) = tokio::io::duplex(64);
+            client
+                .write_all(&(MAX_REQUEST_SIZE as u32 + 1).to_be_bytes())
+                .await
+                .unwrap();
+
+            let result = read_frame(&mut server, MAX_REQUEST_SIZE).await;
+            assert!(matches!(result, Err(IpcError::RequestTooLarge)));
+        }
+
+        #[tokio::test]
+        async fn test_write_then_read_frame_round_trip() {
+            let (mut client, mut server) = tokio::io::duplex(64);
+            write_frame(&mut client, b"hello").await.unwrap();
+
+            let payload = read_frame(&mut server, MAX_REQUEST_SIZE).await.unwrap();
+            assert_eq!(payload, b"hello");
+        }
+
         #[tokio::test]
         async fn test_ipc_error_display() {
             let err = IpcError::BindError("test error".to_string());
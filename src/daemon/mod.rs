@@ -2,10 +2,46 @@
 //!
 //! This module contains the core daemon functionality:
 //! - `timer`: Timer engine with state transitions and countdown logic
+//! - `clock`: Time source of the timer loop, with a mock for tests
+//! - `channel`: Bounded event channel from the timer engine
 //! - `ipc`: Unix Domain Socket IPC server for client communication
+//! - `runner`: Daemon main loop tying the server and timer together
+//! - `store`: State persisted across daemon restarts
+//! - `hooks`: Shell commands run on phase changes
+//! - `nag`: Repeats of unacknowledged completion notifications
+//! - `countdown`: Tick sound for the last seconds of a phase
+//! - `announce`: Spoken phase announcements (macOS `say`)
+//! - `break_activity`: Rotating suggestions for what to do on a break
+//! - `break_reminder`: Reminders of the time left in a long break
+//! - `foreground`: Single session without the daemon (`pomodoro run`)
+//! - `sink`: Tasks feeding the event broadcast to the file sinks
+//! - `events_file`: JSONL file sink for timer events
+//! - `history`: Session history records (skipped breaks)
+//! - `reload`: Configuration reload on `SIGHUP`
+//! - `idle`: Shutdown after a configurable idle period
+//! - `autostart`: Work session started a while after the daemon starts
 
+pub mod announce;
+pub mod autostart;
+pub mod break_activity;
+pub mod break_reminder;
+pub mod channel;
+pub mod clock;
+pub mod countdown;
+pub mod events_file;
+pub mod foreground;
+pub mod history;
+pub mod hooks;
+pub mod idle;
 pub mod ipc;
+pub mod nag;
+pub mod reload;
+pub mod runner;
+pub mod sink;
+pub mod store;
 pub mod timer;
 
-pub use ipc::{IpcError, IpcServer, RequestHandler, DEFAULT_SOCKET_PATH};
-pub use timer::{TimerEngine, TimerEvent};
+pub use channel::{event_channel, EventReceiver, EventSender, DEFAULT_EVENT_CAPACITY};
+pub use clock::{Clock, MockClock, SystemClock};
+pub use ipc::{Encoding, IpcError, IpcServer, RequestHandler};
+pub use timer::{TimerEngine, TimerEvent, TimestampedEvent};
@@ -0,0 +1,349 @@
+//! Repeated completion notifications for the Pomodoro Timer daemon.
+//!
+//! With `nag_interval_seconds` set in the `[notifications]` table, the
+//! completion notification is re-sent every interval until the user
+//! acknowledges it, up to `nag_max_count` times. A notification counts as
+//! acknowledged when:
+//! - the user acts on it (action button, click or dismiss), or
+//! - the user moves the timer on (starts a phase, pauses or stops it)
+//!
+//! The phase the engine starts on its own right after a completion (the
+//! break after work, or the next work with `auto_cycle`) does not count.
+
+use std::time::{Duration, Instant};
+
+use crate::config::NotificationsConfig;
+#[cfg(target_os = "macos")]
+use crate::notification::{NotificationActionEvent, NotificationSender};
+
+use super::timer::{TimerEvent, TimestampedEvent};
+
+// ============================================================================
+// Nagger
+// ============================================================================
+
+/// Tracks the last completion notification and when to repeat it.
+#[derive(Debug, Clone)]
+pub struct Nagger {
+    /// Time between repeats (`None` when nagging is disabled)
+    interval: Option<Duration>,
+    /// Maximum number of repeats per completion
+    max_count: u32,
+    /// Completion awaiting acknowledgment
+    pending: Option<Pending>,
+}
+
+/// A completion notification that has not been acknowledged yet.
+#[derive(Debug, Clone)]
+struct Pending {
+    /// The completion event to notify again
+    event: TimerEvent,
+    /// Number of repeats sent so far
+    sent: u32,
+    /// When the next repeat is due
+    next_at: Instant,
+}
+
+impl Nagger {
+    /// Creates a nagger from the `[notifications]` settings.
+    pub fn new(config: &NotificationsConfig) -> Self {
+        Self {
+            interval: (config.nag_interval_seconds > 0 && config.nag_max_count > 0)
+                .then(|| Duration::from_secs(u64::from(config.nag_interval_seconds))),
+            max_count: config.nag_max_count,
+            pending: None,
+        }
+    }
+
+    /// Replaces the settings, keeping any completion awaiting acknowledgment.
+    pub fn set_config(&mut self, config: &NotificationsConfig) {
+        let pending = self.pending.take();
+        *self = Self::new(config);
+        if self.interval.is_some() {
+            self.pending = pending;
+        }
+    }
+
+    /// Returns whether a completion is waiting for acknowledgment.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Updates the tracking for a timer event that occurred at `now`.
+    ///
+    /// Completions start nagging; phase changes, pauses and stops
+    /// acknowledge the pending completion, unless the engine made them as
+    /// the follow-up to the completion.
+    pub fn observe(&mut self, stamped: &TimestampedEvent, now: Instant) {
+        let event = &stamped.event;
+        match event {
+            TimerEvent::WorkCompleted { .. } | TimerEvent::BreakCompleted { .. } => {
+                self.pending = self.interval.map(|interval| Pending {
+                    event: event.clone(),
+                    sent: 0,
+                    next_at: now + interval,
+                });
+            }
+            TimerEvent::WorkStarted { .. }
+            | TimerEvent::BreakStarted { .. }
+            | TimerEvent::Paused { .. }
+            | TimerEvent::Stopped
+                if !stamped.follows_completion =>
+            {
+                self.acknowledge();
+            }
+            _ => {}
+        }
+    }
+
+    /// Stops nagging about the pending completion.
+    pub fn acknowledge(&mut self) {
+        if self.pending.take().is_some() {
+            tracing::debug!("完了通知が確認されました");
+        }
+    }
+
+    /// Returns the completion to notify again if a repeat is due at `now`.
+    ///
+    /// Each call that returns an event counts as one repeat; nagging ends
+    /// after `nag_max_count` repeats.
+    pub fn due(&mut self, now: Instant) -> Option<TimerEvent> {
+        let interval = self.interval?;
+        let pending = self.pending.as_mut()?;
+        if now < pending.next_at {
+            return None;
+        }
+
+        let event = pending.event.clone();
+        pending.sent += 1;
+        pending.next_at = now + interval;
+        if pending.sent >= self.max_count {
+            self.pending = None;
+        }
+        Some(event)
+    }
+
+    /// Drains the notification action events and returns them.
+    ///
+    /// Any action on a notification acknowledges the pending completion.
+    #[cfg(target_os = "macos")]
+    pub fn drain_actions<N: NotificationSender>(
+        &mut self,
+        notifier: &N,
+    ) -> Vec<NotificationActionEvent> {
+        let actions: Vec<_> = std::iter::from_fn(|| notifier.try_recv_action()).collect();
+        if !actions.is_empty() {
+            self.acknowledge();
+        }
+        actions
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::channel::{event_channel, EventReceiver, DEFAULT_EVENT_CAPACITY};
+    use crate::daemon::clock::{Clock, MockClock};
+    use crate::daemon::timer::TimerEngine;
+    use crate::types::{PomodoroConfig, TimerPhase};
+
+    fn config(interval: u32, max_count: u32) -> NotificationsConfig {
+        NotificationsConfig {
+            nag_interval_seconds: interval,
+            nag_max_count: max_count,
+            ..Default::default()
+        }
+    }
+
+    fn stamped(event: TimerEvent) -> TimestampedEvent {
+        TimestampedEvent::now(event)
+    }
+
+    fn observe_all(nagger: &mut Nagger, rx: &mut EventReceiver, now: Instant) {
+        while let Ok(event) = rx.try_recv() {
+            nagger.observe(&event, now);
+        }
+    }
+
+    fn work_completed() -> TimerEvent {
+        TimerEvent::WorkCompleted {
+            pomodoro_count: 1,
+            task_name: None,
+            tag: None,
+            work_seconds: 1500,
+            extended: false,
+        }
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let mut nagger = Nagger::new(&NotificationsConfig::default());
+        let now = MockClock::default().instant();
+
+        nagger.observe(&stamped(work_completed()), now);
+
+        assert!(!nagger.is_pending());
+        assert!(nagger.due(now + Duration::from_secs(3600)).is_none());
+    }
+
+    #[test]
+    fn test_repeats_every_interval_up_to_max() {
+        let mut nagger = Nagger::new(&config(60, 2));
+        let now = MockClock::default().instant();
+        nagger.observe(&stamped(work_completed()), now);
+
+        assert!(nagger.due(now + Duration::from_secs(59)).is_none());
+        assert_eq!(
+            nagger.due(now + Duration::from_secs(60)),
+            Some(work_completed())
+        );
+        assert!(nagger.due(now + Duration::from_secs(90)).is_none());
+        assert_eq!(
+            nagger.due(now + Duration::from_secs(120)),
+            Some(work_completed())
+        );
+
+        assert!(!nagger.is_pending());
+        assert!(nagger.due(now + Duration::from_secs(180)).is_none());
+    }
+
+    #[test]
+    fn test_break_after_completed_work_keeps_nagging() {
+        let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+        let mut engine = TimerEngine::new(PomodoroConfig::default(), tx);
+        let mut nagger = Nagger::new(&config(60, 3));
+        let now = MockClock::default().instant();
+
+        engine.start(None).unwrap();
+        engine.tick_by(25 * 60).unwrap();
+        observe_all(&mut nagger, &mut rx, now);
+
+        assert!(engine.get_state().is_in_break());
+        assert!(nagger.is_pending());
+        assert_eq!(
+            nagger.due(now + Duration::from_secs(60)),
+            Some(work_completed())
+        );
+    }
+
+    #[test]
+    fn test_next_work_after_completed_break_keeps_nagging() {
+        let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+        let timer_config = PomodoroConfig {
+            auto_cycle: true,
+            ..Default::default()
+        };
+        let mut engine = TimerEngine::new(timer_config, tx);
+        let mut nagger = Nagger::new(&config(60, 3));
+        let now = MockClock::default().instant();
+
+        engine.start(None).unwrap();
+        engine.tick_by(25 * 60).unwrap();
+        engine.tick_by(5 * 60).unwrap();
+        observe_all(&mut nagger, &mut rx, now);
+
+        assert_eq!(engine.get_state().phase, TimerPhase::Working);
+        assert!(nagger.is_pending());
+    }
+
+    #[test]
+    fn test_skipping_the_break_acknowledges() {
+        let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+        let mut engine = TimerEngine::new(PomodoroConfig::default(), tx);
+        let mut nagger = Nagger::new(&config(60, 3));
+        let now = MockClock::default().instant();
+
+        engine.start(None).unwrap();
+        engine.tick_by(25 * 60).unwrap();
+        engine.skip_break().unwrap();
+        observe_all(&mut nagger, &mut rx, now);
+
+        assert!(!nagger.is_pending());
+        assert!(nagger.due(now + Duration::from_secs(60)).is_none());
+    }
+
+    #[test]
+    fn test_stop_acknowledges() {
+        let mut nagger = Nagger::new(&config(60, 3));
+        let now = MockClock::default().instant();
+        nagger.observe(
+            &stamped(TimerEvent::BreakCompleted {
+                is_long_break: true,
+            }),
+            now,
+        );
+
+        nagger.observe(&stamped(TimerEvent::Stopped), now);
+
+        assert!(!nagger.is_pending());
+    }
+
+    #[test]
+    fn test_set_config_keeps_pending_completion() {
+        let mut nagger = Nagger::new(&config(60, 3));
+        let now = MockClock::default().instant();
+        nagger.observe(&stamped(work_completed()), now);
+
+        nagger.set_config(&config(30, 3));
+        assert!(nagger.is_pending());
+
+        nagger.set_config(&config(0, 3));
+        assert!(!nagger.is_pending());
+    }
+
+    #[test]
+    fn test_ticks_do_not_acknowledge() {
+        let mut nagger = Nagger::new(&config(60, 3));
+        let now = MockClock::default().instant();
+        nagger.observe(&stamped(work_completed()), now);
+
+        nagger.observe(
+            &stamped(TimerEvent::Tick {
+                remaining_seconds: 0,
+            }),
+            now,
+        );
+
+        assert!(nagger.is_pending());
+    }
+
+    #[cfg(target_os = "macos")]
+    mod action_tests {
+        use super::*;
+        use crate::notification::MockNotificationSender;
+
+        #[test]
+        fn test_action_event_stops_nagging() {
+            let mock = MockNotificationSender::new();
+            let mut nagger = Nagger::new(&config(60, 3));
+            let now = MockClock::default().instant();
+            nagger.observe(&stamped(work_completed()), now);
+
+            let first = now + Duration::from_secs(60);
+            nagger.drain_actions(&mock);
+            assert_eq!(nagger.due(first), Some(work_completed()));
+
+            mock.inject_action_event(NotificationActionEvent::Dismiss);
+            nagger.drain_actions(&mock);
+            assert!(nagger.due(first + Duration::from_secs(60)).is_none());
+            assert!(!nagger.is_pending());
+        }
+
+        #[test]
+        fn test_no_action_keeps_nagging() {
+            let mock = MockNotificationSender::new();
+            let mut nagger = Nagger::new(&config(60, 3));
+            let now = MockClock::default().instant();
+            nagger.observe(&stamped(work_completed()), now);
+
+            nagger.drain_actions(&mock);
+            assert!(nagger.due(now + Duration::from_secs(60)).is_some());
+            nagger.drain_actions(&mock);
+            assert!(nagger.due(now + Duration::from_secs(120)).is_some());
+        }
+    }
+}
@@ -0,0 +1,298 @@
+//! Configuration reload for the Pomodoro Timer daemon.
+//!
+//! Sending `SIGHUP` to the daemon re-reads the configuration file without
+//! losing the current session:
+//! - `[hooks]`, `[notifications]`, `[announce]` and `[daemon]` take effect
+//!   immediately
+//! - Timer settings are applied to the engine, but durations only take
+//!   effect from the next phase (the running countdown is left alone).
+//!   Settings the current run was started with (`start --work`,
+//!   `--preset`, `--strict`, ...) are kept until the run ends
+//!
+//! If the file cannot be read or parsed, the old configuration is kept.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use tokio::sync::mpsc;
+
+use crate::config::{load_config, Config};
+
+use super::hooks::HookRunner;
+use super::nag::Nagger;
+use super::timer::TimerEngine;
+
+// ============================================================================
+// ConfigReloader
+// ============================================================================
+
+/// Loads the configuration again whenever a reload is requested.
+///
+/// Reloads are requested through the channel returned by [`Self::sender`];
+/// the `SIGHUP` handler is one such sender.
+#[derive(Debug)]
+pub struct ConfigReloader {
+    /// Configuration file given with `--config` (default location if `None`)
+    path: Option<PathBuf>,
+    /// Profile merged over the file (`--profile`)
+    profile: Option<String>,
+    requests_tx: mpsc::UnboundedSender<()>,
+    requests_rx: mpsc::UnboundedReceiver<()>,
+}
+
+impl ConfigReloader {
+    /// Creates a reloader for the given configuration file and profile.
+    pub fn new(path: Option<PathBuf>, profile: Option<String>) -> Self {
+        let (requests_tx, requests_rx) = mpsc::unbounded_channel();
+        Self {
+            path,
+            profile,
+            requests_tx,
+            requests_rx,
+        }
+    }
+
+    /// Returns a sender that requests a reload.
+    pub fn sender(&self) -> mpsc::UnboundedSender<()> {
+        self.requests_tx.clone()
+    }
+
+    /// Waits for the next reload request and loads the configuration.
+    pub async fn next(&mut self) -> Result<Config> {
+        // The reloader holds a sender itself, so the channel never closes
+        let _ = self.requests_rx.recv().await;
+        load_config(self.path.as_deref(), self.profile.as_deref())
+    }
+}
+
+// ============================================================================
+// Applying
+// ============================================================================
+
+/// Applies a reloaded configuration and returns the sections that changed.
+///
+/// Timer settings given with `start` for the current run, including a cycle
+/// limit from `start --cycles`, are kept; see [`TimerEngine::reload_config`].
+pub fn apply_config(
+    current: &mut Config,
+    reloaded: Config,
+    engine: &mut TimerEngine,
+    hooks: &mut HookRunner,
+    nagger: &mut Nagger,
+) -> Vec<&'static str> {
+    let mut changed = Vec::new();
+
+    if reloaded.timer != current.timer {
+        changed.push("timer");
+    }
+    engine.reload_config(reloaded.timer.clone());
+
+    if reloaded.hooks != current.hooks {
+        *hooks = HookRunner::new(reloaded.hooks.clone());
+        changed.push("hooks");
+    }
+    if reloaded.notifications != current.notifications {
+        nagger.set_config(&reloaded.notifications);
+        changed.push("notifications");
+    }
+    // Applied by the runner, which owns the announcer and idle monitor
+    if reloaded.announce != current.announce {
+        changed.push("announce");
+    }
+    if reloaded.daemon != current.daemon {
+        changed.push("daemon");
+    }
+
+    *current = reloaded;
+    changed
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::StartArgs;
+    use crate::daemon::channel::{event_channel, EventReceiver, DEFAULT_EVENT_CAPACITY};
+    use crate::daemon::timer::TimerEvent;
+    use crate::types::{PomodoroConfig, StartParams, TimerPhase};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_engine() -> (TimerEngine, EventReceiver) {
+        let (tx, rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+        (TimerEngine::new(PomodoroConfig::default(), tx), rx)
+    }
+
+    #[tokio::test]
+    async fn test_reload_request_loads_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "work_minutes = 30\n").unwrap();
+        let mut reloader = ConfigReloader::new(Some(path.clone()), None);
+
+        reloader.sender().send(()).unwrap();
+        let config = reloader.next().await.unwrap();
+        assert_eq!(config.timer.work_duration.as_minutes(), 30);
+
+        fs::write(&path, "work_minutes = 45\n").unwrap();
+        reloader.sender().send(()).unwrap();
+        let config = reloader.next().await.unwrap();
+        assert_eq!(config.timer.work_duration.as_minutes(), 45);
+    }
+
+    #[tokio::test]
+    async fn test_reload_with_invalid_file_is_error() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "work_minutes = 0\n").unwrap();
+        let mut reloader = ConfigReloader::new(Some(path), None);
+
+        reloader.sender().send(()).unwrap();
+        assert!(reloader.next().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reload_keeps_profile() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "[profiles.writing]\nwork_minutes = 50\n").unwrap();
+        let mut reloader = ConfigReloader::new(Some(path), Some("writing".to_string()));
+
+        reloader.sender().send(()).unwrap();
+        let config = reloader.next().await.unwrap();
+        assert_eq!(config.timer.work_duration.as_minutes(), 50);
+    }
+
+    #[test]
+    fn test_apply_defers_durations_to_next_phase() {
+        let mut current = Config::default();
+        let (mut engine, _rx) = create_engine();
+        let mut hooks = HookRunner::new(current.hooks.clone());
+        let mut nagger = Nagger::new(&current.notifications);
+        engine.start(None).unwrap();
+
+        let reloaded = Config::from_toml("work_minutes = 50\n").unwrap();
+        let changed = apply_config(&mut current, reloaded, &mut engine, &mut hooks, &mut nagger);
+
+        assert_eq!(changed, vec!["timer"]);
+        let state = engine.get_state();
+        assert_eq!(state.phase, TimerPhase::Working);
+        assert_eq!(state.remaining_seconds, 25 * 60);
+        assert_eq!(state.config.work_duration.as_minutes(), 50);
+        assert_eq!(current.timer.work_duration.as_minutes(), 50);
+    }
+
+    #[test]
+    fn test_apply_replaces_hooks_and_keeps_cycle_limit() {
+        let mut current = Config::default();
+        let (mut engine, _rx) = create_engine();
+        engine.set_config(PomodoroConfig::default().with_max_cycles(2));
+        let mut hooks = HookRunner::new(current.hooks.clone());
+        let mut nagger = Nagger::new(&current.notifications);
+
+        let reloaded = Config::from_toml("[hooks]\non_stop = \"echo bye\"\n").unwrap();
+        let changed = apply_config(&mut current, reloaded, &mut engine, &mut hooks, &mut nagger);
+
+        assert_eq!(changed, vec!["hooks"]);
+        assert_eq!(hooks.command_for(&TimerEvent::Stopped), Some("echo bye"));
+        assert_eq!(engine.get_state().config.max_cycles, Some(2));
+    }
+
+    #[test]
+    fn test_apply_keeps_start_overrides_during_run() {
+        let mut current = Config::default();
+        let (mut engine, _rx) = create_engine();
+        let mut hooks = HookRunner::new(current.hooks.clone());
+        let mut nagger = Nagger::new(&current.notifications);
+        // As if started with `start --work 50 --strict`
+        let overrides = StartParams {
+            work_minutes: Some(50),
+            strict: Some(true),
+            ..Default::default()
+        };
+        engine.set_config(overrides.apply_to(&current.timer).unwrap());
+        engine.start(None).unwrap();
+        engine.set_run_overrides(Some(overrides));
+
+        let reloaded = Config::from_toml(
+            "work_minutes = 30
+break_minutes = 10
+",
+        )
+        .unwrap();
+        let changed = apply_config(&mut current, reloaded, &mut engine, &mut hooks, &mut nagger);
+
+        assert_eq!(changed, vec!["timer"]);
+        let config = &engine.get_state().config;
+        assert_eq!(config.work_duration.as_minutes(), 50);
+        assert!(config.strict);
+        // Settings the run did not override follow the file
+        assert_eq!(config.break_duration.as_minutes(), 10);
+        assert_eq!(engine.get_state().remaining_seconds, 50 * 60);
+    }
+
+    #[test]
+    fn test_apply_follows_file_for_settings_the_client_did_not_set() {
+        let mut current = Config::default();
+        let (mut engine, _rx) = create_engine();
+        let mut hooks = HookRunner::new(current.hooks.clone());
+        let mut nagger = Nagger::new(&current.notifications);
+        // The parameters `IpcClient::start` sends for `start --work 50`
+        let overrides = StartArgs {
+            work: Some(50),
+            ..Default::default()
+        }
+        .start_params();
+        engine.set_config(overrides.apply_to(&current.timer).unwrap());
+        engine.start(None).unwrap();
+        engine.set_run_overrides(Some(overrides));
+
+        let reloaded = Config::from_toml(
+            "break_minutes = 10
+long_break_minutes = 20
+long_break_interval = 2
+auto_cycle = true
+strict = true
+",
+        )
+        .unwrap();
+        apply_config(&mut current, reloaded, &mut engine, &mut hooks, &mut nagger);
+
+        let config = &engine.get_state().config;
+        assert_eq!(config.work_duration.as_minutes(), 50);
+        assert_eq!(config.break_duration.as_minutes(), 10);
+        assert_eq!(config.long_break_duration.as_minutes(), 20);
+        assert_eq!(config.long_break_interval, 2);
+        assert!(config.auto_cycle);
+        assert!(config.strict);
+    }
+
+    #[test]
+    fn test_apply_drops_start_overrides_after_run() {
+        let mut current = Config::default();
+        let (mut engine, _rx) = create_engine();
+        let mut hooks = HookRunner::new(current.hooks.clone());
+        let mut nagger = Nagger::new(&current.notifications);
+        let overrides = StartParams {
+            work_minutes: Some(50),
+            ..Default::default()
+        };
+        engine.set_config(overrides.apply_to(&current.timer).unwrap());
+        engine.start(None).unwrap();
+        engine.set_run_overrides(Some(overrides));
+        engine.stop().unwrap();
+
+        let reloaded = Config::from_toml(
+            "work_minutes = 30
+",
+        )
+        .unwrap();
+        apply_config(&mut current, reloaded, &mut engine, &mut hooks, &mut nagger);
+
+        assert_eq!(engine.get_state().config.work_duration.as_minutes(), 30);
+        assert!(engine.run_overrides().is_none());
+    }
+}
@@ -0,0 +1,520 @@
+//! Daemon runner for the Pomodoro Timer.
+//!
+//! This module wires the daemon components together:
+//! - Loads the configuration file (`--config` or the default location)
+//! - Binds the IPC server and serves client requests
+//! - Prints `READY socket=<path>` to stdout once it accepts clients
+//! - Drives the timer engine once per second, catching up after sleep
+//! - Restores and persists state across restarts
+//! - Logs timer events and forwards them to `Subscribe` clients
+//! - Appends timer events to a JSONL file (`--events-file`)
+//! - Runs the configured phase-change hooks
+//! - Sends completion notifications (macOS), falling back to terminal alerts
+//! - Suggests a break activity in the "take a break" notification, if configured
+//! - Repeats completion notifications until acknowledged, if configured
+//! - Removes completion notifications once their phase is over (macOS)
+//! - Plays a tick on the last seconds of a phase, if configured
+//! - Reminds of the time left during long breaks (`--break-reminder`)
+//! - Speaks phase changes with `say`, if configured (macOS)
+//! - Applies the notification action buttons (pause/resume/stop) to the timer
+//! - Starts a work session a while after launch (`--auto-start-after`)
+//! - Reloads the configuration on SIGHUP
+//! - Shuts down after an idle period, if configured
+//! - Shuts down cleanly on Ctrl+C or SIGTERM
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::{broadcast, Mutex};
+use tokio::time::{interval, Duration};
+
+use crate::config::load_config;
+#[cfg(target_os = "macos")]
+use crate::config::AnnounceConfig;
+#[cfg(target_os = "macos")]
+use crate::notification::{
+    NotificationActionEvent, NotificationSender, NotificationTitles, NotificationType, Notifier,
+};
+use crate::paths;
+use crate::types::TimerPhase;
+
+#[cfg(target_os = "macos")]
+use super::announce::{Announcer, SystemCommandRunner};
+use super::autostart::AutoStart;
+#[cfg(target_os = "macos")]
+use super::break_activity::BreakActivities;
+#[cfg(target_os = "macos")]
+use super::break_reminder::remaining_minutes;
+use super::break_reminder::BreakReminder;
+use super::channel::DEFAULT_EVENT_CAPACITY;
+use super::clock::{Clock, SystemClock};
+use super::countdown::Countdown;
+use super::events_file::EventsFile;
+use super::history::{HistoryWriter, DEFAULT_HISTORY_MAX_BYTES};
+use super::hooks::HookRunner;
+use super::idle::IdleMonitor;
+use super::ipc::{IpcServer, RequestHandler, DEFAULT_REQUEST_TIMEOUT_SECS};
+use super::nag::Nagger;
+use super::reload::{apply_config, ConfigReloader};
+use super::timer::{run_shared, TimerEngine, TimerEvent, TimestampedEvent};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Number of events buffered per subscriber before older ones are dropped
+const EVENT_BUFFER_SIZE: usize = 256;
+
+// ============================================================================
+// RunOptions
+// ============================================================================
+
+/// Options controlling daemon behavior.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Alert on the terminal instead of trying the notification center
+    pub terminal_notifications: bool,
+    /// Silence terminal alerts and the readiness line
+    pub quiet: bool,
+    /// Configuration file given with `--config` (default location if `None`)
+    pub config_path: Option<PathBuf>,
+    /// Profile merged over the configuration file (`--profile`)
+    pub profile: Option<String>,
+    /// JSONL file every timer event is appended to (`--events-file`)
+    pub events_file: Option<PathBuf>,
+    /// Timer events buffered before ticks are dropped (`--event-capacity`,
+    /// [`DEFAULT_EVENT_CAPACITY`] if `None`)
+    pub event_capacity: Option<usize>,
+    /// Speak phase changes even if `[announce]` is not enabled (`--announce`)
+    pub announce: bool,
+    /// Voice for announcements, overriding `[announce]` (`--voice`)
+    pub voice: Option<String>,
+    /// Minutes after startup to begin a work session
+    /// (`--auto-start-after`, 0 to not start)
+    pub auto_start_after: u32,
+    /// Reminders of the time left per long break
+    /// (`--break-reminder`, 0 for none)
+    pub break_reminders: u32,
+    /// Refuse to start if another daemon is listening on the socket
+    /// (`--exclusive`) instead of taking the socket over
+    pub exclusive: bool,
+    /// Write task names to the request log instead of redacting them
+    /// (`--log-task-names`)
+    pub log_task_names: bool,
+}
+
+// ============================================================================
+// Daemon
+// ============================================================================
+
+/// Runs the daemon until Ctrl+C or SIGTERM is received, or until it has
+/// been idle for `idle_shutdown_minutes`.
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be loaded, the state path
+/// cannot be resolved, the events file cannot be opened or the IPC server
+/// cannot be started.
+pub async fn run(socket_path: &Path, options: &RunOptions) -> Result<()> {
+    run_with_clock(socket_path, options, SystemClock).await
+}
+
+/// Runs the daemon like [`run`], with the timer driven by `clock`.
+///
+/// # Errors
+///
+/// Returns an error in the same cases as [`run`].
+pub async fn run_with_clock<C: Clock + Clone + 'static>(
+    socket_path: &Path,
+    options: &RunOptions,
+    clock: C,
+) -> Result<()> {
+    let mut config = load_config(options.config_path.as_deref(), options.profile.as_deref())?;
+    let mut hooks = HookRunner::new(config.hooks.clone());
+    let (engine, mut event_rx) = TimerEngine::with_channel_capacity(
+        config.timer.clone(),
+        options.event_capacity.unwrap_or(DEFAULT_EVENT_CAPACITY),
+    );
+    let engine = Arc::new(Mutex::new(engine.with_state_path(paths::state_path()?)));
+    let (events_tx, _) = broadcast::channel(EVENT_BUFFER_SIZE);
+    if let Some(path) = &options.events_file {
+        EventsFile::open(path)?.spawn(events_tx.subscribe());
+    }
+    // The timer works without a history, so a failure is not fatal
+    let history_max_bytes = config
+        .daemon
+        .history_max_mb
+        .map_or(DEFAULT_HISTORY_MAX_BYTES, |mb| u64::from(mb) * 1024 * 1024);
+    match HistoryWriter::open(&paths::history_path()?) {
+        Ok(history) => {
+            history
+                .with_max_bytes(history_max_bytes)
+                .spawn(events_tx.subscribe());
+        }
+        Err(e) => tracing::warn!("履歴を記録できません: {:#}", e),
+    }
+    let request_timeout = config
+        .daemon
+        .request_timeout_seconds
+        .map_or(DEFAULT_REQUEST_TIMEOUT_SECS, u64::from);
+    let handler = Arc::new(
+        RequestHandler::new(Arc::clone(&engine))
+            .with_events(events_tx.clone())
+            .with_request_timeout(Duration::from_secs(request_timeout))
+            .with_log_task_names(options.log_task_names),
+    );
+    let server = if options.exclusive {
+        IpcServer::new_exclusive(socket_path)?
+    } else {
+        IpcServer::new(socket_path)?
+    };
+    let mut sigterm = signal(SignalKind::terminate()).context("Failed to listen for SIGTERM")?;
+    let mut sighup = signal(SignalKind::hangup()).context("Failed to listen for SIGHUP")?;
+    let mut reloader = ConfigReloader::new(options.config_path.clone(), options.profile.clone());
+    let reload_tx = reloader.sender();
+    let sighup_handle = tokio::spawn(async move {
+        while sighup.recv().await.is_some() {
+            let _ = reload_tx.send(());
+        }
+    });
+
+    tracing::info!(socket = %socket_path.display(), "Daemonを起動しました");
+
+    let tick_engine = Arc::clone(&engine);
+    let tick_clock = clock.clone();
+    let ticker_handle = tokio::spawn(async move {
+        // Catches up on the ticks skipped while the Mac was asleep
+        if let Err(e) = run_shared(&tick_engine, &tick_clock).await {
+            tracing::error!("タイマーの更新に失敗しました: {}", e);
+        }
+    });
+
+    // The notifier must stay on the main thread, so events are handled in
+    // the main loop rather than a spawned task
+    #[cfg(target_os = "macos")]
+    let mut notifier = Notifier::select(options.terminal_notifications, options.quiet).await;
+    #[cfg(target_os = "macos")]
+    {
+        notifier.set_sound(config.notifications.notification_sound.clone());
+        notifier.set_titles(NotificationTitles::from_config(&config.notifications));
+        notifier.set_prompt_break(config.timer.prompt_break);
+    }
+    #[cfg(target_os = "macos")]
+    let mut activities = BreakActivities::new(config.notifications.break_activities.clone());
+    #[cfg(target_os = "macos")]
+    let mut announcer = Announcer::new(
+        announce_config(&config.announce, options),
+        SystemCommandRunner,
+    );
+    let mut nagger = Nagger::new(&config.notifications);
+    let mut countdown = Countdown::from_config(&config.notifications);
+    countdown.prewarm();
+    let mut break_reminder = BreakReminder::new(options.break_reminders);
+    let mut notification_ticker = interval(Duration::from_secs(1));
+    let mut idle = IdleMonitor::new(&config.daemon, clock.instant());
+    let mut idle_ticker = interval(Duration::from_secs(1));
+    let connections = Arc::new(AtomicUsize::new(0));
+    let mut auto_start = AutoStart::from_minutes(options.auto_start_after, clock.clone());
+
+    if !options.quiet {
+        announce_ready(socket_path);
+    }
+
+    loop {
+        tokio::select! {
+            Some(event) = event_rx.recv() => {
+                log_event(&event);
+                countdown.observe(&event.event);
+                if break_reminder.is_enabled() {
+                    let due = break_reminder.observe(&event.event, engine.lock().await.get_state());
+                    if let Some(remaining) = due {
+                        tracing::info!(remaining_seconds = remaining, "休憩の残り時間をお知らせします");
+                        #[cfg(target_os = "macos")]
+                        if let Err(e) = notifier.send_break_reminder(remaining_minutes(remaining)).await {
+                            tracing::warn!("通知の送信に失敗しました: {}", e);
+                        }
+                    }
+                }
+                if hooks.command_for(&event.event).is_some() {
+                    hooks.run(&event.event, engine.lock().await.get_state());
+                }
+                #[cfg(target_os = "macos")]
+                {
+                    if config.notifications.auto_dismiss {
+                        dismiss_stale(&notifier, &event.event);
+                    }
+                    notify(&notifier, &event.event, &mut activities).await;
+                    update_actions(&notifier, &event.event);
+                    announcer.observe(&event.event);
+                }
+                nagger.observe(&event, clock.instant());
+                // No subscribers is not an error
+                let _ = events_tx.send(event);
+            }
+            // Action buttons only exist on macOS
+            _ = notification_ticker.tick(), if cfg!(target_os = "macos") || nagger.is_pending() => {
+                #[cfg(target_os = "macos")]
+                for action in nagger.drain_actions(&notifier) {
+                    apply_action(&handler, &action).await;
+                }
+                if let Some(event) = nagger.due(clock.instant()) {
+                    tracing::info!(?event, "未確認の完了通知を再送します");
+                    #[cfg(target_os = "macos")]
+                    notify(&notifier, &event, &mut activities).await;
+                }
+            }
+            reloaded = reloader.next() => match reloaded {
+                Ok(reloaded) => {
+                    let changed = apply_config(
+                        &mut config,
+                        reloaded,
+                        &mut *engine.lock().await,
+                        &mut hooks,
+                        &mut nagger,
+                    );
+                    countdown.set_config(&config.notifications);
+                    countdown.prewarm();
+                    idle.set_config(&config.daemon);
+                    #[cfg(target_os = "macos")]
+                    {
+                        announcer.set_config(announce_config(&config.announce, options));
+                        notifier.set_sound(config.notifications.notification_sound.clone());
+                        notifier.set_titles(NotificationTitles::from_config(&config.notifications));
+                        notifier.set_prompt_break(config.timer.prompt_break);
+                        activities.set_activities(config.notifications.break_activities.clone());
+                    }
+                    tracing::info!(?changed, "設定を再読み込みしました");
+                }
+                Err(e) => tracing::error!("設定の再読み込みに失敗したため現在の設定を維持します: {:#}", e),
+            },
+            () = auto_start.wait() => {
+                if let Err(e) = auto_start.fire(&mut *engine.lock().await) {
+                    tracing::warn!("作業を自動開始できませんでした: {}", e);
+                }
+            }
+            _ = idle_ticker.tick(), if idle.is_enabled() => {
+                let busy = connections.load(Ordering::Acquire) > 0
+                    || auto_start.is_pending()
+                    || engine.lock().await.get_state().phase != TimerPhase::Stopped;
+                if idle.should_shut_down(busy, clock.instant()) {
+                    tracing::info!("一定時間操作がなかったためDaemonを終了します");
+                    break;
+                }
+            }
+            accepted = server.accept() => match accepted {
+                Ok(stream) => {
+                    idle.record_activity(clock.instant());
+                    let handler = Arc::clone(&handler);
+                    let connections = Arc::clone(&connections);
+                    let clock = clock.clone();
+                    connections.fetch_add(1, Ordering::AcqRel);
+                    tokio::spawn(async move {
+                        if let Err(e) = IpcServer::serve_connection_with_clock(stream, &handler, &clock).await {
+                            tracing::warn!("リクエストの処理に失敗しました: {}", e);
+                        }
+                        connections.fetch_sub(1, Ordering::AcqRel);
+                    });
+                }
+                Err(e) => tracing::warn!("接続の受け付けに失敗しました: {}", e),
+            },
+            _ = tokio::signal::ctrl_c() => {
+                tracing::info!("Daemonを終了します");
+                break;
+            }
+            _ = sigterm.recv() => {
+                tracing::info!("Daemonを終了します");
+                break;
+            }
+        }
+    }
+
+    ticker_handle.abort();
+    sighup_handle.abort();
+
+    Ok(())
+}
+
+/// Applies the `--announce` and `--voice` flags on top of `[announce]`.
+#[cfg(target_os = "macos")]
+fn announce_config(config: &AnnounceConfig, options: &RunOptions) -> AnnounceConfig {
+    AnnounceConfig {
+        enabled: config.enabled || options.announce,
+        voice: options.voice.clone().or_else(|| config.voice.clone()),
+        ..config.clone()
+    }
+}
+
+/// Tells a supervising script that clients can connect now.
+///
+/// The line is `READY socket=<path>`, printed once to stdout.
+fn announce_ready(socket_path: &Path) {
+    let mut stdout = std::io::stdout();
+    if let Err(e) =
+        writeln!(stdout, "READY socket={}", socket_path.display()).and_then(|()| stdout.flush())
+    {
+        tracing::warn!("起動完了の通知を出力できませんでした: {}", e);
+    }
+}
+
+/// Logs a timer event with the time it occurred.
+fn log_event(stamped: &TimestampedEvent) {
+    let at = stamped.at.to_rfc3339();
+    let event = &stamped.event;
+    match event {
+        TimerEvent::Tick { .. } => tracing::trace!(%at, ?event, "タイマーイベント"),
+        _ => tracing::info!(%at, ?event, "タイマーイベント"),
+    }
+}
+
+/// Sends the notification for a timer event, if it has one.
+///
+/// The "take a break" notification carries the next of `activities`.
+#[cfg(target_os = "macos")]
+pub(super) async fn notify<N: NotificationSender>(
+    notifier: &N,
+    event: &TimerEvent,
+    activities: &mut BreakActivities,
+) {
+    let result = match event {
+        TimerEvent::WorkCompleted { task_name, .. } => {
+            let activity = activities.next_activity();
+            notifier
+                .send_work_complete(task_name.as_deref(), activity)
+                .await
+        }
+        TimerEvent::BreakCompleted {
+            is_long_break: true,
+        } => notifier.send_long_break_complete(None).await,
+        TimerEvent::BreakCompleted {
+            is_long_break: false,
+        } => notifier.send_break_complete(None).await,
+        _ => return,
+    };
+
+    if let Err(e) = result {
+        tracing::warn!("通知の送信に失敗しました: {}", e);
+    }
+}
+
+/// Returns the completion notifications that an event makes stale.
+///
+/// The "take a break" notification is stale once the break is over, and
+/// the "back to work" ones once work has started.
+#[cfg(target_os = "macos")]
+fn stale_notifications(event: &TimerEvent) -> &'static [NotificationType] {
+    match event {
+        TimerEvent::BreakCompleted { .. } => &[NotificationType::WorkComplete],
+        TimerEvent::WorkStarted { .. } => &[
+            NotificationType::BreakComplete,
+            NotificationType::LongBreakComplete,
+        ],
+        _ => &[],
+    }
+}
+
+/// Removes the delivered notifications made stale by a timer event.
+#[cfg(target_os = "macos")]
+fn dismiss_stale<N: NotificationSender>(notifier: &N, event: &TimerEvent) {
+    for notification_type in stale_notifications(event) {
+        notifier.remove_delivered(*notification_type);
+    }
+}
+
+/// Shows the action buttons matching the timer state after an event.
+#[cfg(target_os = "macos")]
+fn update_actions<N: NotificationSender>(notifier: &N, event: &TimerEvent) {
+    match event {
+        TimerEvent::Paused { .. } => notifier.update_actions(true),
+        TimerEvent::Resumed
+        | TimerEvent::WorkStarted { .. }
+        | TimerEvent::BreakStarted { .. }
+        | TimerEvent::Stopped => notifier.update_actions(false),
+        _ => {}
+    }
+}
+
+/// Applies a notification action to the timer.
+#[cfg(target_os = "macos")]
+async fn apply_action(handler: &RequestHandler, action: &NotificationActionEvent) {
+    tracing::info!(?action, "通知アクションを受信");
+    let Some(request) = action.to_request() else {
+        return;
+    };
+
+    let response = handler.handle(request).await;
+    if response.status != "success" {
+        tracing::warn!("通知アクションを実行できませんでした: {}", response.message);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(all(test, target_os = "macos"))]
+mod tests {
+    use super::*;
+    use crate::notification::MockNotificationSender;
+
+    #[test]
+    fn test_work_started_dismisses_break_notifications() {
+        let notifier = MockNotificationSender::new();
+
+        dismiss_stale(&notifier, &TimerEvent::WorkStarted { task_name: None });
+
+        assert_eq!(
+            notifier.removed_identifiers(),
+            vec![
+                NotificationType::BreakComplete.identifier(),
+                NotificationType::LongBreakComplete.identifier(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_break_completed_dismisses_work_notification() {
+        for is_long_break in [false, true] {
+            let notifier = MockNotificationSender::new();
+
+            dismiss_stale(&notifier, &TimerEvent::BreakCompleted { is_long_break });
+
+            assert_eq!(
+                notifier.removed_identifiers(),
+                vec![NotificationType::WorkComplete.identifier()]
+            );
+        }
+    }
+
+    #[test]
+    fn test_other_events_dismiss_nothing() {
+        let events = [
+            TimerEvent::WorkCompleted {
+                pomodoro_count: 1,
+                task_name: None,
+                tag: None,
+                work_seconds: 1500,
+                extended: false,
+            },
+            TimerEvent::BreakStarted {
+                is_long_break: false,
+            },
+            TimerEvent::Paused { reason: None },
+            TimerEvent::Resumed,
+            TimerEvent::Stopped,
+            TimerEvent::Tick {
+                remaining_seconds: 10,
+            },
+        ];
+        let notifier = MockNotificationSender::new();
+
+        for event in &events {
+            dismiss_stale(&notifier, event);
+        }
+
+        assert!(notifier.removed_identifiers().is_empty());
+    }
+}
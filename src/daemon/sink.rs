@@ -0,0 +1,119 @@
+//! Consumers of the daemon's event broadcast.
+//!
+//! The runner broadcasts every timer event to the sinks that record them
+//! (`events_file`, `history`). Each sink reads its copy in a task of its
+//! own: a sink that falls behind loses the oldest events, which is logged,
+//! and a write failure only costs the event at hand.
+
+use anyhow::Result;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use super::timer::TimestampedEvent;
+
+/// Passes the events received on `events` to `write` until the channel closes.
+///
+/// `name` names the sink in log messages.
+pub fn spawn_sink<F>(
+    name: &'static str,
+    mut events: broadcast::Receiver<TimestampedEvent>,
+    mut write: F,
+) -> JoinHandle<()>
+where
+    F: FnMut(&TimestampedEvent) -> Result<()> + Send + 'static,
+{
+    tokio::spawn(async move {
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("{}に{}件のイベントを記録できませんでした", name, skipped);
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => return,
+            };
+
+            if let Err(e) = write(&event) {
+                tracing::warn!("{}への記録に失敗しました: {:#}", name, e);
+            }
+        }
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::timer::TimerEvent;
+    use std::sync::{Arc, Mutex};
+
+    fn recording_sink(
+        events: broadcast::Receiver<TimestampedEvent>,
+    ) -> (JoinHandle<()>, Arc<Mutex<Vec<TimerEvent>>>) {
+        let written = Arc::new(Mutex::new(Vec::new()));
+        let sink_written = Arc::clone(&written);
+        let handle = spawn_sink("テスト", events, move |event| {
+            sink_written.lock().unwrap().push(event.event.clone());
+            Ok(())
+        });
+        (handle, written)
+    }
+
+    #[tokio::test]
+    async fn test_writes_events_until_the_channel_closes() {
+        let (tx, _) = broadcast::channel(16);
+        let (handle, written) = recording_sink(tx.subscribe());
+
+        tx.send(TimestampedEvent::now(TimerEvent::Resumed)).unwrap();
+        tx.send(TimestampedEvent::now(TimerEvent::Stopped)).unwrap();
+        drop(tx);
+        handle.await.unwrap();
+
+        assert_eq!(
+            *written.lock().unwrap(),
+            vec![TimerEvent::Resumed, TimerEvent::Stopped]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lagging_skips_to_the_retained_events() {
+        let (tx, _) = broadcast::channel(2);
+        let events = tx.subscribe();
+        // Sent before the sink reads anything: the first one is overwritten
+        tx.send(TimestampedEvent::now(TimerEvent::Paused { reason: None }))
+            .unwrap();
+        tx.send(TimestampedEvent::now(TimerEvent::Resumed)).unwrap();
+        tx.send(TimestampedEvent::now(TimerEvent::Stopped)).unwrap();
+        drop(tx);
+
+        let (handle, written) = recording_sink(events);
+        handle.await.unwrap();
+
+        assert_eq!(
+            *written.lock().unwrap(),
+            vec![TimerEvent::Resumed, TimerEvent::Stopped]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_failure_keeps_the_sink_running() {
+        let (tx, _) = broadcast::channel(16);
+        let written = Arc::new(Mutex::new(Vec::new()));
+        let sink_written = Arc::clone(&written);
+        let handle = spawn_sink("テスト", tx.subscribe(), move |event| {
+            anyhow::ensure!(event.event != TimerEvent::Resumed, "disk full");
+            sink_written.lock().unwrap().push(event.event.clone());
+            Ok(())
+        });
+
+        tx.send(TimestampedEvent::now(TimerEvent::Resumed)).unwrap();
+        tx.send(TimestampedEvent::now(TimerEvent::Stopped)).unwrap();
+        drop(tx);
+        handle.await.unwrap();
+
+        assert_eq!(*written.lock().unwrap(), vec![TimerEvent::Stopped]);
+    }
+}
@@ -0,0 +1,234 @@
+//! Persisted daemon state for the Pomodoro Timer.
+//!
+//! Holds the values that must survive daemon restarts: the total number of
+//! completed pomodoros, which drives the long-break cadence, and the session
+//! set aside with `suspend`. The state is stored as JSON at
+//! `paths::state_path()`.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{TimerPhase, TimerState};
+
+// ============================================================================
+// PersistedState
+// ============================================================================
+
+/// State persisted across daemon restarts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedState {
+    /// Completed pomodoros across sessions
+    #[serde(default)]
+    pub total_completed_pomodoros: u32,
+    /// Session set aside with `suspend`, waiting for `unsuspend`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suspended: Option<SuspendedSession>,
+}
+
+/// A session set aside with `suspend`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuspendedSession {
+    /// Phase that was running (never `Paused` or `Stopped`)
+    pub phase: TimerPhase,
+    /// Seconds that were left in the phase
+    pub remaining_seconds: u32,
+    /// Full length of the phase (0 in files written before it was kept)
+    #[serde(default)]
+    pub phase_seconds: u32,
+    /// Task of the session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_name: Option<String>,
+    /// Tasks that were queued after it
+    #[serde(default, skip_serializing_if = "VecDeque::is_empty")]
+    pub task_queue: VecDeque<String>,
+}
+
+impl SuspendedSession {
+    /// Captures the running or paused session of a timer state.
+    ///
+    /// Returns `None` if the timer is stopped.
+    pub fn capture(state: &TimerState) -> Option<Self> {
+        Some(Self {
+            phase: state.active_phase()?,
+            remaining_seconds: state.remaining_seconds,
+            phase_seconds: state.phase_seconds,
+            task_name: state.task_name.clone(),
+            task_queue: state.task_queue.clone(),
+        })
+    }
+
+    /// Puts the session back into a timer state, running.
+    pub fn restore_to(self, state: &mut TimerState) {
+        state.phase = self.phase;
+        state.remaining_seconds = self.remaining_seconds;
+        state.phase_seconds = match self.phase_seconds {
+            0 => state.configured_duration(self.phase).as_secs(),
+            seconds => seconds,
+        };
+        state.task_name = self.task_name;
+        state.task_queue = self.task_queue;
+    }
+}
+
+impl PersistedState {
+    /// Captures the persisted fields of a timer state.
+    ///
+    /// The suspended session is owned by the engine and left empty.
+    pub fn from_timer_state(state: &TimerState) -> Self {
+        Self {
+            total_completed_pomodoros: state.total_completed_pomodoros,
+            suspended: None,
+        }
+    }
+
+    /// Applies the persisted fields to a timer state.
+    pub fn apply_to(&self, state: &mut TimerState) {
+        state.total_completed_pomodoros = self.total_completed_pomodoros;
+    }
+}
+
+// ============================================================================
+// Load / Save
+// ============================================================================
+
+/// Loads the persisted state, returning the default if the file is missing.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but cannot be read or parsed.
+pub fn load(path: &Path) -> Result<PersistedState> {
+    if !path.exists() {
+        return Ok(PersistedState::default());
+    }
+
+    let json = fs::read_to_string(path)
+        .with_context(|| format!("状態ファイルを読み込めません: {}", path.display()))?;
+    serde_json::from_str(&json)
+        .with_context(|| format!("状態ファイルの形式が不正です: {}", path.display()))
+}
+
+/// Saves the persisted state, creating the parent directory if needed.
+///
+/// The file is written to a temporary path first and then renamed so that
+/// a crash never leaves a half-written state file behind.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn save(path: &Path, state: &PersistedState) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("ディレクトリを作成できません: {}", parent.display()))?;
+    }
+
+    let json = serde_json::to_string_pretty(state).context("状態のシリアライズに失敗しました")?;
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, json)
+        .with_context(|| format!("状態ファイルを書き込めません: {}", tmp_path.display()))?;
+    fs::rename(&tmp_path, path)
+        .with_context(|| format!("状態ファイルを書き込めません: {}", path.display()))?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PomodoroConfig;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_missing_file_returns_default() {
+        let dir = TempDir::new().unwrap();
+        let state = load(&dir.path().join("state.json")).unwrap();
+        assert_eq!(state, PersistedState::default());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested/state.json");
+        let state = PersistedState {
+            total_completed_pomodoros: 7,
+            suspended: Some(SuspendedSession {
+                phase: TimerPhase::Working,
+                remaining_seconds: 600,
+                phase_seconds: 1500,
+                task_name: Some("Write docs".to_string()),
+                task_queue: VecDeque::from(["Review".to_string()]),
+            }),
+        };
+
+        save(&path, &state).unwrap();
+
+        assert_eq!(load(&path).unwrap(), state);
+        assert!(!path.with_extension("json.tmp").exists());
+    }
+
+    #[test]
+    fn test_load_file_without_suspended_session() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("state.json");
+        fs::write(&path, r#"{"total_completed_pomodoros":2}"#).unwrap();
+
+        let state = load(&path).unwrap();
+
+        assert_eq!(state.total_completed_pomodoros, 2);
+        assert!(state.suspended.is_none());
+    }
+
+    #[test]
+    fn test_restore_keeps_custom_phase_length() {
+        let mut state = TimerState::new(PomodoroConfig::default());
+        state.start_break_only(15);
+        state.remaining_seconds = 10 * 60;
+        let session = SuspendedSession::capture(&state).unwrap();
+        state.stop();
+
+        session.restore_to(&mut state);
+
+        assert_eq!(state.phase_seconds, 15 * 60);
+    }
+
+    #[test]
+    fn test_restore_without_phase_length_uses_config() {
+        let mut state = TimerState::new(PomodoroConfig::default());
+        let session: SuspendedSession =
+            serde_json::from_str(r#"{"phase":"working","remaining_seconds":600}"#).unwrap();
+
+        session.restore_to(&mut state);
+
+        assert_eq!(state.phase_seconds, 25 * 60);
+    }
+
+    #[test]
+    fn test_load_corrupted_file_is_error() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("state.json");
+        fs::write(&path, "not json").unwrap();
+
+        assert!(load(&path).is_err());
+    }
+
+    #[test]
+    fn test_apply_to_timer_state() {
+        let mut timer_state = TimerState::new(PomodoroConfig::default());
+        let state = PersistedState {
+            total_completed_pomodoros: 3,
+            suspended: None,
+        };
+
+        state.apply_to(&mut timer_state);
+
+        assert_eq!(timer_state.total_completed_pomodoros, 3);
+        assert_eq!(PersistedState::from_timer_state(&timer_state), state);
+    }
+}
@@ -2,23 +2,70 @@
 //!
 //! This module provides the core timer functionality:
 //! - State transitions (Working → Breaking → Stopped)
-//! - Countdown with tokio::time::interval
+//! - Countdown on a [`Clock`], caught up to the wall clock after the Mac
+//!   sleeps (see [`WallClock`])
 //! - Event firing for notifications and sounds
 //! - Auto-cycle feature
 //! - Long break after 4 pomodoros
+//!
+//! # Embedding
+//!
+//! The engine does not need the daemon. Create it with
+//! [`TimerEngine::with_channel`], drive it with [`TimerEngine::tick`] (or
+//! spawn [`TimerEngine::run`]) and read the events from the receiver:
+//!
+//! ```rust
+//! use pomodoro::daemon::{TimerEngine, TimerEvent};
+//! use pomodoro::PomodoroConfig;
+//!
+//! let config = PomodoroConfig::default().with_work_minutes(1);
+//! let (mut engine, mut events) = TimerEngine::with_channel(config);
+//!
+//! engine.start(Some("Embedded".to_string())).unwrap();
+//! for _ in 0..60 {
+//!     engine.tick().unwrap();
+//! }
+//!
+//! let mut completed = false;
+//! while let Ok(stamped) = events.try_recv() {
+//!     completed |= matches!(stamped.event, TimerEvent::WorkCompleted { .. });
+//! }
+//! assert!(completed);
+//! ```
+//!
+//! Keep the receiver alive for as long as the engine is used: sending an
+//! event to a closed channel is an error. In async code the receiver can
+//! be awaited with `recv()`. The channel is bounded: a receiver that falls
+//! behind loses old ticks, never phase changes (see [`super::channel`]).
+
+use std::path::PathBuf;
+use std::time::SystemTime;
 
 use anyhow::{Context, Result};
-use tokio::sync::mpsc;
-use tokio::time::{interval, Duration, MissedTickBehavior};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tokio::time::Duration;
 
-use crate::types::{PomodoroConfig, TimerPhase, TimerState};
+use crate::types::{PhaseDuration, PomodoroConfig, StartParams, TimerPhase, TimerState};
+
+use super::channel::{event_channel, EventReceiver, EventSender, DEFAULT_EVENT_CAPACITY};
+use super::clock::{Clock, SystemClock};
+use super::store::{self, PersistedState, SuspendedSession};
+
+/// Time between two runs of the timer loop.
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Seconds a completed cycle is shown before reverting to "stopped"
+pub const CYCLE_COMPLETED_DISPLAY_SECONDS: u32 = 10;
 
 // ============================================================================
 // TimerEvent
 // ============================================================================
 
 /// Timer events for notifications and external integrations.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
 pub enum TimerEvent {
     /// Work session started
     WorkStarted {
@@ -31,6 +78,16 @@ pub enum TimerEvent {
         pomodoro_count: u32,
         /// Task name (if any)
         task_name: Option<String>,
+        /// Tag of the run (if any)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
+        /// Seconds of work the session took (0 from older daemons)
+        #[serde(default)]
+        work_seconds: u32,
+        /// Whether the session extended one that had already completed
+        /// (`extend`), whose pomodoro was counted then
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        extended: bool,
     },
     /// Break session started
     BreakStarted {
@@ -43,11 +100,27 @@ pub enum TimerEvent {
         is_long_break: bool,
     },
     /// Timer paused
-    Paused,
+    Paused {
+        /// Why the timer was paused (`pause --reason`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
     /// Timer resumed
     Resumed,
     /// Timer stopped
     Stopped,
+    /// Work session stopped early, past the abandonment grace period
+    WorkAbandoned {
+        /// Seconds worked before stopping
+        elapsed_seconds: u32,
+    },
+    /// Break (or the wait for one) ended early with `skip`
+    BreakSkipped {
+        /// Seconds of break taken before skipping
+        elapsed_seconds: u32,
+    },
+    /// The run ended on its own after its last break (the timer stopped)
+    CycleCompleted,
     /// One second elapsed (tick)
     Tick {
         /// Remaining seconds
@@ -55,6 +128,88 @@ pub enum TimerEvent {
     },
 }
 
+impl TimerEvent {
+    /// Names of all event kinds, as used in the serialized `event` tag.
+    pub const NAMES: [&'static str; 11] = [
+        "work_started",
+        "work_completed",
+        "break_started",
+        "break_completed",
+        "paused",
+        "resumed",
+        "stopped",
+        "work_abandoned",
+        "break_skipped",
+        "cycle_completed",
+        "tick",
+    ];
+
+    /// Returns whether the event may be dropped when consumers fall behind.
+    ///
+    /// Only ticks qualify: the next tick carries the current remaining time,
+    /// while every other event changes what the timer is doing.
+    pub fn is_droppable(&self) -> bool {
+        matches!(self, TimerEvent::Tick { .. })
+    }
+
+    /// Returns the name of this event kind (the serialized `event` tag).
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimerEvent::WorkStarted { .. } => "work_started",
+            TimerEvent::WorkCompleted { .. } => "work_completed",
+            TimerEvent::BreakStarted { .. } => "break_started",
+            TimerEvent::BreakCompleted { .. } => "break_completed",
+            TimerEvent::Paused { .. } => "paused",
+            TimerEvent::Resumed => "resumed",
+            TimerEvent::Stopped => "stopped",
+            TimerEvent::WorkAbandoned { .. } => "work_abandoned",
+            TimerEvent::BreakSkipped { .. } => "break_skipped",
+            TimerEvent::CycleCompleted => "cycle_completed",
+            TimerEvent::Tick { .. } => "tick",
+        }
+    }
+}
+
+// ============================================================================
+// TimestampedEvent
+// ============================================================================
+
+/// A timer event stamped with the time it occurred.
+///
+/// Serializes as the event's fields plus an ISO-8601 `at` field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimestampedEvent {
+    /// When the event occurred
+    pub at: DateTime<Local>,
+    /// The event itself (flattened, so its `event` tag sits next to `at`)
+    #[serde(flatten)]
+    pub event: TimerEvent,
+    /// Whether the engine sent the event on its own as the follow-up to a
+    /// phase completion, like the break that starts after work (not
+    /// serialized)
+    #[serde(skip)]
+    pub follows_completion: bool,
+}
+
+impl TimestampedEvent {
+    /// Stamps an event with the current time.
+    pub fn now(event: TimerEvent) -> Self {
+        Self {
+            at: Local::now(),
+            event,
+            follows_completion: false,
+        }
+    }
+
+    /// Stamps an event that follows a phase completion with the current time.
+    pub fn following_completion(event: TimerEvent) -> Self {
+        Self {
+            follows_completion: true,
+            ..Self::now(event)
+        }
+    }
+}
+
 // ============================================================================
 // TimerEngine
 // ============================================================================
@@ -64,16 +219,94 @@ pub struct TimerEngine {
     /// Current timer state
     state: TimerState,
     /// Event sender channel
-    event_tx: mpsc::UnboundedSender<TimerEvent>,
+    event_tx: EventSender,
+    /// Break phase that just ended and stopped the timer (for snooze)
+    ended_break: Option<TimerPhase>,
+    /// Where to persist state across restarts (None disables persistence)
+    state_path: Option<PathBuf>,
+    /// Work sessions completed since the last `start` (for `max_cycles`)
+    cycles_completed: u32,
+    /// Whether the current break was started with `start_break_only`
+    break_only: bool,
+    /// Standalone breaks still to run after the current one (`break --repeat`)
+    break_repeats: u32,
+    /// Length of the repeated standalone break in minutes
+    break_only_minutes: u32,
+    /// Whether the current break follows a work session that can still be
+    /// extended (`extend_work`)
+    work_extendable: bool,
+    /// Seconds the current break has run (for `min_break_seconds`)
+    break_elapsed: u32,
+    /// Whether this run works through a task queue (stops once it is empty)
+    queued: bool,
+    /// Phase generation whose completion has been handled
+    completed_generation: Option<u64>,
+    /// Phase generation of the work session started by `extend_work`
+    extended_generation: Option<u64>,
+    /// Session set aside with `suspend` (persisted)
+    suspended: Option<SuspendedSession>,
+    /// Seconds left to show the completed cycle
+    cycle_completed_left: u32,
+    /// Timer settings given with `start` for the current run
+    run_overrides: Option<StartParams>,
+    /// Whether the paused work session was armed with `start_paused` and
+    /// has not begun yet
+    armed: bool,
 }
 
 impl TimerEngine {
     /// Creates a new TimerEngine with the given configuration and event channel.
-    pub fn new(config: PomodoroConfig, event_tx: mpsc::UnboundedSender<TimerEvent>) -> Self {
+    pub fn new(config: PomodoroConfig, event_tx: EventSender) -> Self {
         Self {
             state: TimerState::new(config),
             event_tx,
+            ended_break: None,
+            state_path: None,
+            cycles_completed: 0,
+            break_only: false,
+            break_repeats: 0,
+            break_only_minutes: 0,
+            work_extendable: false,
+            break_elapsed: 0,
+            queued: false,
+            completed_generation: None,
+            extended_generation: None,
+            suspended: None,
+            cycle_completed_left: 0,
+            run_overrides: None,
+            armed: false,
+        }
+    }
+
+    /// Creates a new TimerEngine together with the receiving end of its
+    /// event channel, buffering up to [`DEFAULT_EVENT_CAPACITY`] events.
+    pub fn with_channel(config: PomodoroConfig) -> (Self, EventReceiver) {
+        Self::with_channel_capacity(config, DEFAULT_EVENT_CAPACITY)
+    }
+
+    /// Like [`Self::with_channel`], buffering up to `capacity` events.
+    ///
+    /// When the consumer falls behind, the oldest ticks are dropped; see
+    /// [`super::channel`].
+    pub fn with_channel_capacity(config: PomodoroConfig, capacity: usize) -> (Self, EventReceiver) {
+        let (event_tx, event_rx) = event_channel(capacity);
+        (Self::new(config, event_tx), event_rx)
+    }
+
+    /// Enables persistence at the given path, restoring any saved state.
+    ///
+    /// A missing file starts from scratch; an unreadable one is logged and ignored.
+    pub fn with_state_path(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        match store::load(&path) {
+            Ok(persisted) => {
+                persisted.apply_to(&mut self.state);
+                self.suspended = persisted.suspended;
+            }
+            Err(e) => tracing::warn!("保存された状態を復元できませんでした: {:#}", e),
         }
+        self.state_path = Some(path);
+        self
     }
 
     /// Runs the timer loop.
@@ -81,71 +314,219 @@ impl TimerEngine {
     /// This method runs an infinite loop that ticks every second.
     /// It should be spawned as a separate tokio task.
     pub async fn run(&mut self) -> Result<()> {
-        let mut ticker = interval(Duration::from_secs(1));
-        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        self.run_with_clock(&SystemClock).await
+    }
+
+    /// Runs the timer loop on `clock` instead of the system clock.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an event cannot be sent.
+    pub async fn run_with_clock<C: Clock>(&mut self, clock: &C) -> Result<()> {
+        let mut wall = WallClock::new(clock.now());
 
         loop {
-            ticker.tick().await;
+            clock.sleep(TICK_INTERVAL).await;
+            self.tick_by(wall.elapsed_seconds(clock.now()))?;
+        }
+    }
+
+    /// Advances the timer by one second.
+    ///
+    /// Does nothing unless the timer is running. Fires a `Tick` event and
+    /// handles the phase transition when the countdown reaches zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an event cannot be sent.
+    pub fn tick(&mut self) -> Result<()> {
+        self.tick_by(1)
+    }
 
-            if !self.state.is_running() {
-                continue;
+    /// Advances the timer by `seconds` at once, e.g. to catch up after the
+    /// Mac slept through some ticks.
+    ///
+    /// Time left over when a phase completes carries over into the phase
+    /// that follows, so a long gap can complete several phases, each firing
+    /// its usual events. Once the timer stops (a break ends without
+    /// auto-cycle), the rest of the time is dropped. One `Tick` event is
+    /// fired per phase, with the remaining time after the jump.
+    ///
+    /// Does nothing unless the timer is running, apart from clearing a
+    /// completed cycle once it has been shown for
+    /// [`CYCLE_COMPLETED_DISPLAY_SECONDS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an event cannot be sent.
+    pub fn tick_by(&mut self, seconds: u32) -> Result<()> {
+        if self.state.cycle_completed {
+            self.cycle_completed_left = self.cycle_completed_left.saturating_sub(seconds);
+            if self.cycle_completed_left == 0 {
+                self.state.cycle_completed = false;
             }
+        }
 
-            let completed = self.state.tick();
+        let mut left = seconds;
+
+        while left > 0 && self.state.is_running() {
+            let generation = self.state.phase_generation();
+            // A countdown already at zero still takes a second to complete
+            let step = left.min(self.state.remaining_seconds).max(1);
+            left -= step;
+
+            if self.state.is_in_break() {
+                self.break_elapsed += step;
+            }
+            self.state.remaining_seconds = self.state.remaining_seconds.saturating_sub(step);
 
-            // Send tick event
             self.event_tx
-                .send(TimerEvent::Tick {
+                .send(TimestampedEvent::now(TimerEvent::Tick {
                     remaining_seconds: self.state.remaining_seconds,
-                })
+                }))
                 .context("Failed to send tick event")?;
 
-            if completed {
-                self.handle_timer_complete()?;
+            if self.state.remaining_seconds == 0 {
+                self.complete_phase(generation)?;
             }
         }
+
+        Ok(())
     }
 
-    /// Handles timer completion (phase transitions).
+    /// Completes the current phase right away (for tests).
+    #[cfg(test)]
     fn handle_timer_complete(&mut self) -> Result<()> {
+        self.complete_phase(self.state.phase_generation())
+    }
+
+    /// Handles timer completion (phase transitions) of the phase of the
+    /// given generation.
+    ///
+    /// `generation` is the phase generation the countdown ran out in. A
+    /// completion for a phase that has since been replaced, or that was
+    /// already completed, is ignored, so a phase never completes twice.
+    fn complete_phase(&mut self, generation: u64) -> Result<()> {
+        if generation != self.state.phase_generation()
+            || self.completed_generation == Some(generation)
+        {
+            tracing::debug!(
+                "古いフェーズの完了を無視しました (generation {})",
+                generation
+            );
+            return Ok(());
+        }
+        self.completed_generation = Some(generation);
+
         match self.state.phase {
             TimerPhase::Working => {
-                // Work completed - increment pomodoro count
-                self.state.increment_pomodoro_count();
+                // Work completed - increment pomodoro count, unless an
+                // extension's pomodoro was already counted
+                let work_seconds = self.state.work_elapsed_seconds().unwrap_or_default();
+                let extended = self.extended_generation == Some(generation);
+                if !extended {
+                    self.state.increment_pomodoro_count();
+                    self.persist();
+                    self.cycles_completed += 1;
+                }
 
                 self.event_tx
-                    .send(TimerEvent::WorkCompleted {
+                    .send(TimestampedEvent::now(TimerEvent::WorkCompleted {
                         pomodoro_count: self.state.pomodoro_count,
                         task_name: self.state.task_name.clone(),
-                    })
+                        tag: self.state.tag.clone(),
+                        work_seconds,
+                        extended,
+                    }))
                     .context("Failed to send work completed event")?;
 
+                // Wait for the user to start the break, if configured
+                self.break_elapsed = 0;
+                self.work_extendable = true;
+                if self.state.config.prompt_break {
+                    self.state.await_break();
+                    return Ok(());
+                }
+
                 // Start break
                 self.state.start_breaking();
+                self.break_elapsed = 0;
                 let is_long_break = self.state.phase == TimerPhase::LongBreaking;
 
                 self.event_tx
-                    .send(TimerEvent::BreakStarted { is_long_break })
+                    .send(TimestampedEvent::following_completion(
+                        TimerEvent::BreakStarted { is_long_break },
+                    ))
                     .context("Failed to send break started event")?;
             }
             TimerPhase::Breaking | TimerPhase::LongBreaking => {
                 let is_long_break = self.state.phase == TimerPhase::LongBreaking;
 
                 self.event_tx
-                    .send(TimerEvent::BreakCompleted { is_long_break })
+                    .send(TimestampedEvent::now(TimerEvent::BreakCompleted {
+                        is_long_break,
+                    }))
                     .context("Failed to send break completed event")?;
+                self.work_extendable = false;
+
+                // The next break of a `break --repeat` series
+                if self.break_only && self.break_repeats > 0 {
+                    self.break_repeats -= 1;
+                    if is_long_break {
+                        self.state.start_long_break_only(self.break_only_minutes);
+                    } else {
+                        self.state.start_break_only(self.break_only_minutes);
+                    }
+                    self.break_elapsed = 0;
 
-                // Auto-cycle or stop
-                if self.state.config.auto_cycle {
-                    self.state.start_working(self.state.task_name.clone());
+                    self.event_tx
+                        .send(TimestampedEvent::following_completion(
+                            TimerEvent::BreakStarted { is_long_break },
+                        ))
+                        .context("Failed to send break started event")?;
+                    return Ok(());
+                }
+
+                // Auto-cycle (until the cycle limit or the end of the queue) or stop
+                let limit_reached = self
+                    .state
+                    .config
+                    .max_cycles
+                    .is_some_and(|max| self.cycles_completed >= max);
+                let queue_exhausted = self.queued && self.state.task_queue.is_empty();
+
+                if self.state.config.auto_cycle
+                    && !limit_reached
+                    && !queue_exhausted
+                    && !self.break_only
+                {
+                    let task_name = if self.queued {
+                        self.state.task_queue.pop_front()
+                    } else {
+                        self.state.task_name.clone()
+                    };
+                    self.state.start_working(task_name);
 
                     self.event_tx
-                        .send(TimerEvent::WorkStarted {
-                            task_name: self.state.task_name.clone(),
-                        })
+                        .send(TimestampedEvent::following_completion(
+                            TimerEvent::WorkStarted {
+                                task_name: self.state.task_name.clone(),
+                            },
+                        ))
                         .context("Failed to send work started event")?;
                 } else {
-                    self.state.stop();
+                    self.ended_break = Some(self.state.phase);
+                    self.state.complete_cycle();
+                    self.cycle_completed_left = CYCLE_COMPLETED_DISPLAY_SECONDS;
+
+                    if self.state.config.auto_cycle {
+                        self.event_tx
+                            .send(TimestampedEvent::following_completion(TimerEvent::Stopped))
+                            .context("Failed to send stopped event")?;
+                    }
+                    self.event_tx
+                        .send(TimestampedEvent::now(TimerEvent::CycleCompleted))
+                        .context("Failed to send cycle completed event")?;
                 }
             }
             _ => {}
@@ -160,15 +541,168 @@ impl TimerEngine {
     ///
     /// Returns an error if the timer is already running.
     pub fn start(&mut self, task_name: Option<String>) -> Result<()> {
+        self.arm_work(task_name.clone())?;
+
+        self.event_tx
+            .send(TimestampedEvent::now(TimerEvent::WorkStarted { task_name }))
+            .context("Failed to send work started event")?;
+
+        Ok(())
+    }
+
+    /// Starts a new work session in the paused state.
+    ///
+    /// The session is armed with its full duration and begins counting down
+    /// on the next [`Self::resume`]. Unlike [`Self::pause`], this is allowed
+    /// in strict mode, since no running session is interrupted.
+    ///
+    /// Only [`TimerEvent::Paused`] is fired here; [`TimerEvent::WorkStarted`]
+    /// follows on the resume that begins the work, in place of
+    /// [`TimerEvent::Resumed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer is already running.
+    pub fn start_paused(&mut self, task_name: Option<String>) -> Result<()> {
+        self.arm_work(task_name)?;
+        self.state.pause();
+        self.armed = true;
+
+        self.event_tx
+            .send(TimestampedEvent::now(TimerEvent::Paused { reason: None }))
+            .context("Failed to send paused event")?;
+
+        Ok(())
+    }
+
+    /// Sets up a new work session without firing any event.
+    fn arm_work(&mut self, task_name: Option<String>) -> Result<()> {
         if self.state.is_running() {
             anyhow::bail!("タイマーは既に実行中です");
         }
 
-        self.state.start_working(task_name.clone());
+        self.state.start_working(task_name);
+        self.state.task_queue.clear();
+        self.ended_break = None;
+        self.cycles_completed = 0;
+        self.break_only = false;
+        self.break_repeats = 0;
+        self.work_extendable = false;
+        self.queued = false;
+        self.armed = false;
+        Ok(())
+    }
+
+    /// Queues tasks for the following work sessions of this run.
+    ///
+    /// Call after [`Self::start`] with the tasks that come after the current
+    /// one. Each auto-cycled work session takes the next task, and once the
+    /// queue is empty the timer stops after the current session's break.
+    pub fn set_task_queue(&mut self, tasks: impl IntoIterator<Item = String>) {
+        self.state.task_queue = tasks.into_iter().collect();
+        self.queued = true;
+    }
+
+    /// Tags the current run (`start --tag`).
+    ///
+    /// Call after [`Self::start`]. The tag stays for the auto-cycled
+    /// sessions that follow and is cleared when the timer stops.
+    pub fn set_tag(&mut self, tag: Option<String>) {
+        self.state.tag = tag;
+    }
+
+    /// Starts a standalone short break of `minutes`, without a work session.
+    ///
+    /// When the break completes the timer stops, even with auto-cycle
+    /// enabled, and the pomodoro count is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer is already running.
+    pub fn start_break_only(&mut self, minutes: u32) -> Result<()> {
+        self.begin_break_only(minutes, false)
+    }
+
+    /// Runs `repeat` standalone short breaks of `minutes` back to back.
+    ///
+    /// Each break ends with its own `BreakCompleted` (so the completion
+    /// chime sounds between them) and the next starts right away; the timer
+    /// stops after the last one. A `repeat` of 0 or 1 is a single break.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer is already running.
+    pub fn start_break_series(&mut self, minutes: u32, repeat: u32) -> Result<()> {
+        self.begin_break_only(minutes, false)?;
+        self.break_repeats = repeat.saturating_sub(1);
+        Ok(())
+    }
+
+    /// Starts a standalone long break of `minutes`, without a work session.
+    ///
+    /// The break is a `LongBreaking` phase whatever the pomodoro count, and
+    /// ends with `BreakCompleted { is_long_break: true }` before the timer
+    /// stops, as with [`Self::start_break_only`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer is already running.
+    pub fn start_long_break(&mut self, minutes: u32) -> Result<()> {
+        self.begin_break_only(minutes, true)
+    }
+
+    /// Starts a standalone short or long break.
+    fn begin_break_only(&mut self, minutes: u32, is_long_break: bool) -> Result<()> {
+        if self.state.is_running() {
+            anyhow::bail!("タイマーは既に実行中です");
+        }
+
+        if is_long_break {
+            self.state.start_long_break_only(minutes);
+        } else {
+            self.state.start_break_only(minutes);
+        }
+        self.state.task_queue.clear();
+        self.ended_break = None;
+        self.break_only = true;
+        self.break_repeats = 0;
+        self.work_extendable = false;
+        self.break_only_minutes = minutes;
+        self.queued = false;
+        self.break_elapsed = 0;
 
         self.event_tx
-            .send(TimerEvent::WorkStarted { task_name })
-            .context("Failed to send work started event")?;
+            .send(TimestampedEvent::now(TimerEvent::BreakStarted {
+                is_long_break,
+            }))
+            .context("Failed to send break started event")?;
+
+        Ok(())
+    }
+
+    /// Starts the break a completed work session is waiting for
+    /// (`prompt_break`).
+    ///
+    /// The break is long or short as it would have been without the prompt,
+    /// and the run continues as usual once it completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no break is awaited.
+    pub fn start_awaited_break(&mut self) -> Result<()> {
+        if !self.state.awaiting_break {
+            anyhow::bail!("開始を待っている休憩はありません");
+        }
+
+        self.state.start_breaking();
+        self.break_elapsed = 0;
+        let is_long_break = self.state.phase == TimerPhase::LongBreaking;
+
+        self.event_tx
+            .send(TimestampedEvent::now(TimerEvent::BreakStarted {
+                is_long_break,
+            }))
+            .context("Failed to send break started event")?;
 
         Ok(())
     }
@@ -177,16 +711,32 @@ impl TimerEngine {
     ///
     /// # Errors
     ///
-    /// Returns an error if the timer is not running.
+    /// Returns an error if the timer is not running or strict mode is enabled.
     pub fn pause(&mut self) -> Result<()> {
+        self.pause_with_reason(None)
+    }
+
+    /// Pauses the timer, noting why.
+    ///
+    /// The reason is kept in the state until the timer resumes or stops,
+    /// and is carried by the `Paused` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer is not running or strict mode is enabled.
+    pub fn pause_with_reason(&mut self, reason: Option<String>) -> Result<()> {
         if !self.state.is_running() {
             anyhow::bail!("タイマーは実行されていません");
         }
+        if self.state.config.strict {
+            anyhow::bail!("厳格モードでは一時停止できません");
+        }
 
         self.state.pause();
+        self.state.pause_reason = reason.clone();
 
         self.event_tx
-            .send(TimerEvent::Paused)
+            .send(TimestampedEvent::now(TimerEvent::Paused { reason }))
             .context("Failed to send paused event")?;
 
         Ok(())
@@ -204,8 +754,16 @@ impl TimerEngine {
 
         self.state.resume();
 
+        // A session armed with `start_paused` begins only now
+        let event = if std::mem::take(&mut self.armed) {
+            TimerEvent::WorkStarted {
+                task_name: self.state.task_name.clone(),
+            }
+        } else {
+            TimerEvent::Resumed
+        };
         self.event_tx
-            .send(TimerEvent::Resumed)
+            .send(TimestampedEvent::now(event))
             .context("Failed to send resumed event")?;
 
         Ok(())
@@ -213,96 +771,449 @@ impl TimerEngine {
 
     /// Stops the timer.
     ///
+    /// Stopping a work session later than `abandon_grace_seconds` into it
+    /// fires [`TimerEvent::WorkAbandoned`] before [`TimerEvent::Stopped`].
+    ///
+    /// A break awaited with `prompt_break` can be stopped as well.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the timer is not running or paused.
+    /// Returns an error if the timer is not running, paused or awaiting a
+    /// break.
     pub fn stop(&mut self) -> Result<()> {
-        if !self.state.is_running() && !self.state.is_paused() {
+        if !self.state.is_running() && !self.state.is_paused() && !self.state.awaiting_break {
             anyhow::bail!("タイマーは実行されていません");
         }
 
+        // Stopping an extension abandons nothing: its pomodoro completed
+        let grace = self.state.config.abandon_grace_seconds;
+        let extension = self.extended_generation == Some(self.state.phase_generation());
+        let abandoned = self
+            .state
+            .work_elapsed_seconds()
+            .filter(|&elapsed| elapsed > grace && !extension);
+
         self.state.stop();
+        self.ended_break = None;
+        self.armed = false;
+        self.break_only = false;
+        self.break_repeats = 0;
+        self.work_extendable = false;
+
+        if let Some(elapsed_seconds) = abandoned {
+            self.event_tx
+                .send(TimestampedEvent::now(TimerEvent::WorkAbandoned {
+                    elapsed_seconds,
+                }))
+                .context("Failed to send work abandoned event")?;
+        }
 
         self.event_tx
-            .send(TimerEvent::Stopped)
+            .send(TimestampedEvent::now(TimerEvent::Stopped))
             .context("Failed to send stopped event")?;
 
         Ok(())
     }
 
-    /// Returns a reference to the current timer state.
-    pub fn get_state(&self) -> &TimerState {
-        &self.state
-    }
+    /// Snoozes the current break by the given number of minutes.
+    ///
+    /// During a break the remaining time is extended. If a break has just
+    /// ended and stopped the timer, the same kind of break is re-entered
+    /// for `minutes`. The remaining time never grows past `max_work_minutes`.
+    ///
+    /// Returns the number of seconds actually added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer is not in (or just out of) a break.
+    pub fn snooze(&mut self, minutes: u32) -> Result<u32> {
+        let cap = PhaseDuration::from_minutes(self.state.config.max_work_minutes).as_secs();
+        let added = match self.state.phase {
+            TimerPhase::Breaking | TimerPhase::LongBreaking => {
+                let before = self.state.remaining_seconds;
+                self.state.remaining_seconds = before
+                    .saturating_add(PhaseDuration::from_minutes(minutes).as_secs())
+                    .min(cap.max(before));
+                self.state.remaining_seconds - before
+            }
+            TimerPhase::Stopped if self.ended_break.is_some() => {
+                let phase = self.ended_break.take().unwrap_or(TimerPhase::Breaking);
+                self.state.phase = phase;
+                self.state.cycle_completed = false;
+                self.state.remaining_seconds =
+                    PhaseDuration::from_minutes(minutes).as_secs().min(cap);
+                self.state.phase_seconds = self.state.remaining_seconds;
 
-    /// Returns a mutable reference to the timer state (for testing).
-    #[cfg(any(test, feature = "test-utils"))]
-    pub fn get_state_mut(&mut self) -> &mut TimerState {
-        &mut self.state
+                self.event_tx
+                    .send(TimestampedEvent::now(TimerEvent::BreakStarted {
+                        is_long_break: phase == TimerPhase::LongBreaking,
+                    }))
+                    .context("Failed to send break started event")?;
+                self.state.remaining_seconds
+            }
+            _ => anyhow::bail!("休憩中ではありません"),
+        };
+
+        Ok(added)
     }
-}
 
-// ============================================================================
-// Tests
-// ============================================================================
+    /// Goes back to the work session that just completed, for `minutes` more.
+    ///
+    /// Available during (or while waiting for) the break that follows a work
+    /// session, once it has run for `min_break_seconds`. The pomodoro stays
+    /// counted from its first completion; the extension's own completion
+    /// ([`TimerEvent::WorkCompleted`] with `extended`) adds no second one,
+    /// and stopping it abandons nothing. The extension is a phase of its
+    /// own length, which never grows past `max_work_minutes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no work session has just completed, or if the
+    /// minimum break has not elapsed yet.
+    pub fn extend_work(&mut self, minutes: u32) -> Result<()> {
+        let in_break = self.state.is_in_break() || self.state.awaiting_break;
+        if !self.work_extendable || !in_break {
+            anyhow::bail!("延長できる作業セッションがありません");
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let floor = self.state.config.min_break_seconds;
+        if self.break_elapsed < floor {
+            anyhow::bail!(
+                "最低休憩時間が経過していないため作業を延長できません（あと{}秒）",
+                floor - self.break_elapsed
+            );
+        }
 
-    // ------------------------------------------------------------------------
-    // TimerEvent Tests
-    // ------------------------------------------------------------------------
+        let cap = PhaseDuration::from_minutes(self.state.config.max_work_minutes).as_secs();
+        let task_name = self.state.task_name.clone();
+        self.state.start_working(task_name.clone());
+        self.state.phase_seconds = PhaseDuration::from_minutes(minutes).as_secs().min(cap);
+        self.state.remaining_seconds = self.state.phase_seconds;
+        self.extended_generation = Some(self.state.phase_generation());
+        self.work_extendable = false;
+        self.break_elapsed = 0;
 
-    mod timer_event_tests {
-        use super::*;
+        self.event_tx
+            .send(TimestampedEvent::now(TimerEvent::WorkStarted { task_name }))
+            .context("Failed to send work started event")?;
 
-        #[test]
-        fn test_work_started_event() {
-            let event = TimerEvent::WorkStarted {
-                task_name: Some("Test Task".to_string()),
-            };
-            assert_eq!(
-                event,
-                TimerEvent::WorkStarted {
-                    task_name: Some("Test Task".to_string())
-                }
-            );
-        }
+        Ok(())
+    }
 
-        #[test]
-        fn test_work_started_event_no_task() {
-            let event = TimerEvent::WorkStarted { task_name: None };
-            assert_eq!(event, TimerEvent::WorkStarted { task_name: None });
+    /// Ends the current break early and starts the next work session.
+    ///
+    /// A paused break, or one awaited with `prompt_break`, can be skipped
+    /// too. The break must have run for at least `min_break_seconds`;
+    /// paused time does not count.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer is not in (or waiting for) a break, or
+    /// if the minimum break has not elapsed yet.
+    pub fn skip_break(&mut self) -> Result<()> {
+        if !self.state.is_in_break() && !self.state.awaiting_break {
+            anyhow::bail!("休憩中ではありません");
         }
 
-        #[test]
-        fn test_work_completed_event() {
-            let event = TimerEvent::WorkCompleted {
-                pomodoro_count: 5,
-                task_name: Some("Coding".to_string()),
-            };
-            assert_eq!(
-                event,
-                TimerEvent::WorkCompleted {
-                    pomodoro_count: 5,
-                    task_name: Some("Coding".to_string())
-                }
+        let floor = self.state.config.min_break_seconds;
+        if self.break_elapsed < floor {
+            anyhow::bail!(
+                "最低休憩時間が経過していないため休憩をスキップできません（あと{}秒）",
+                floor - self.break_elapsed
             );
         }
 
-        #[test]
-        fn test_break_started_event_short() {
-            let event = TimerEvent::BreakStarted {
-                is_long_break: false,
-            };
-            assert_eq!(
-                event,
-                TimerEvent::BreakStarted {
-                    is_long_break: false
-                }
-            );
-        }
+        let task_name = self.state.task_name.clone();
+        self.state.start_working(task_name.clone());
+        self.break_only = false;
+        self.break_repeats = 0;
+        self.work_extendable = false;
+
+        self.event_tx
+            .send(TimestampedEvent::now(TimerEvent::BreakSkipped {
+                elapsed_seconds: self.break_elapsed,
+            }))
+            .context("Failed to send break skipped event")?;
+        self.event_tx
+            .send(TimestampedEvent::now(TimerEvent::WorkStarted { task_name }))
+            .context("Failed to send work started event")?;
+
+        Ok(())
+    }
+
+    /// Sets the running or paused session aside and stops the timer.
+    ///
+    /// Unlike [`Self::stop`], the phase, remaining time and tasks are kept
+    /// in the persisted state so [`Self::unsuspend`] can pick the session up
+    /// again, even after a daemon restart. The pomodoro counts are untouched
+    /// and no [`TimerEvent::WorkAbandoned`] is fired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer is not running or paused, or if a
+    /// session is already suspended.
+    pub fn suspend(&mut self) -> Result<()> {
+        if self.suspended.is_some() {
+            anyhow::bail!("既に中断中のセッションがあります");
+        }
+        let Some(session) = SuspendedSession::capture(&self.state) else {
+            anyhow::bail!("タイマーは実行されていません");
+        };
+
+        self.state.stop();
+        self.ended_break = None;
+        self.break_only = false;
+        self.break_repeats = 0;
+        self.work_extendable = false;
+        self.suspended = Some(session);
+        self.persist();
+
+        self.event_tx
+            .send(TimestampedEvent::now(TimerEvent::Stopped))
+            .context("Failed to send stopped event")?;
+
+        Ok(())
+    }
+
+    /// Resumes the session set aside with [`Self::suspend`].
+    ///
+    /// The phase continues with the remaining time it had, and
+    /// [`TimerEvent::WorkStarted`] or [`TimerEvent::BreakStarted`] is fired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer is not stopped or no session is
+    /// suspended.
+    pub fn unsuspend(&mut self) -> Result<()> {
+        if self.state.phase != TimerPhase::Stopped {
+            anyhow::bail!("タイマーは既に実行中です");
+        }
+        let Some(session) = self.suspended.take() else {
+            anyhow::bail!("中断中のセッションはありません");
+        };
+
+        self.queued = !session.task_queue.is_empty();
+        self.break_elapsed = 0;
+        self.ended_break = None;
+        self.work_extendable = false;
+        session.restore_to(&mut self.state);
+        self.persist();
+
+        let event = match self.state.phase {
+            TimerPhase::Working => TimerEvent::WorkStarted {
+                task_name: self.state.task_name.clone(),
+            },
+            phase => TimerEvent::BreakStarted {
+                is_long_break: phase == TimerPhase::LongBreaking,
+            },
+        };
+        self.event_tx
+            .send(TimestampedEvent::now(event))
+            .context("Failed to send phase started event")?;
+
+        Ok(())
+    }
+
+    /// Returns the session set aside with [`Self::suspend`], if any.
+    pub fn suspended(&self) -> Option<&SuspendedSession> {
+        self.suspended.as_ref()
+    }
+
+    /// Replaces the timer configuration used from the next phase on.
+    pub fn set_config(&mut self, config: PomodoroConfig) {
+        self.state.config = config;
+    }
+
+    /// Records the timer settings the current run was started with.
+    ///
+    /// [`Self::reload_config`] keeps them until the run ends.
+    pub fn set_run_overrides(&mut self, overrides: Option<StartParams>) {
+        self.run_overrides = overrides;
+    }
+
+    /// Returns the timer settings the current run was started with, if any.
+    pub fn run_overrides(&self) -> Option<&StartParams> {
+        self.run_overrides.as_ref()
+    }
+
+    /// Replaces the timer configuration with a reloaded one.
+    ///
+    /// During a run, the settings it was started with (`start --work`,
+    /// `--preset`, `--strict`, ...) and its cycle limit are kept; the
+    /// reloaded values apply to everything else. While stopped, the
+    /// reloaded configuration replaces the run's settings, except the
+    /// cycle limit.
+    pub fn reload_config(&mut self, mut config: PomodoroConfig) {
+        let in_run = self.state.phase != TimerPhase::Stopped || self.state.awaiting_break;
+        if !in_run {
+            self.run_overrides = None;
+        }
+        if let Some(overrides) = &self.run_overrides {
+            config = overrides.apply_to(&config).unwrap_or(config);
+        }
+        config.max_cycles = self.state.config.max_cycles;
+        self.state.config = config;
+    }
+
+    /// Clears the completed pomodoro counts, restarting the long-break cadence.
+    pub fn reset(&mut self) {
+        self.state.reset_counts();
+        self.persist();
+    }
+
+    /// Saves the persisted part of the state, if persistence is enabled.
+    ///
+    /// Failures are logged rather than returned so the timer keeps running.
+    fn persist(&self) {
+        let Some(path) = &self.state_path else {
+            return;
+        };
+
+        let persisted = PersistedState {
+            suspended: self.suspended.clone(),
+            ..PersistedState::from_timer_state(&self.state)
+        };
+        if let Err(e) = store::save(path, &persisted) {
+            tracing::warn!("状態の保存に失敗しました: {:#}", e);
+        }
+    }
+
+    /// Returns a reference to the current timer state.
+    pub fn get_state(&self) -> &TimerState {
+        &self.state
+    }
+
+    /// Returns a mutable reference to the timer state (for testing).
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn get_state_mut(&mut self) -> &mut TimerState {
+        &mut self.state
+    }
+}
+
+/// Runs the timer loop for an engine shared with the IPC handler.
+///
+/// Works like [`TimerEngine::run_with_clock`], but the engine is only
+/// locked while a tick is applied, so requests are served in between.
+///
+/// # Errors
+///
+/// Returns an error if an event cannot be sent.
+pub async fn run_shared<C: Clock>(engine: &Mutex<TimerEngine>, clock: &C) -> Result<()> {
+    let mut wall = WallClock::new(clock.now());
+
+    loop {
+        clock.sleep(TICK_INTERVAL).await;
+        let seconds = wall.elapsed_seconds(clock.now());
+        engine.lock().await.tick_by(seconds)?;
+    }
+}
+
+// ============================================================================
+// WallClock
+// ============================================================================
+
+/// Measures whole seconds of wall-clock time between ticks.
+///
+/// `Instant` does not advance while a Mac sleeps, so the one-second sleep
+/// of the timer loop stretches over the whole nap and counting ticks loses
+/// the time spent asleep. The elapsed time is taken from [`SystemTime`] instead and
+/// fed to [`TimerEngine::tick_by`]. Fractions of a second carry over to
+/// the next call. A clock set backwards counts as no time passing.
+#[derive(Debug, Clone, Copy)]
+pub struct WallClock {
+    /// Time up to which seconds have been handed out
+    last: SystemTime,
+}
+
+impl WallClock {
+    /// Starts measuring at `now`.
+    pub fn new(now: SystemTime) -> Self {
+        Self { last: now }
+    }
+
+    /// Returns the whole seconds elapsed since the previous call (or `new`).
+    pub fn elapsed_seconds(&mut self, now: SystemTime) -> u32 {
+        match now.duration_since(self.last) {
+            Ok(elapsed) => {
+                let seconds = elapsed.as_secs();
+                self.last += Duration::from_secs(seconds);
+                u32::try_from(seconds).unwrap_or(u32::MAX)
+            }
+            Err(_) => {
+                self.last = now;
+                0
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ------------------------------------------------------------------------
+    // TimerEvent Tests
+    // ------------------------------------------------------------------------
+
+    mod timer_event_tests {
+        use super::*;
+
+        #[test]
+        fn test_work_started_event() {
+            let event = TimerEvent::WorkStarted {
+                task_name: Some("Test Task".to_string()),
+            };
+            assert_eq!(
+                event,
+                TimerEvent::WorkStarted {
+                    task_name: Some("Test Task".to_string())
+                }
+            );
+        }
+
+        #[test]
+        fn test_work_started_event_no_task() {
+            let event = TimerEvent::WorkStarted { task_name: None };
+            assert_eq!(event, TimerEvent::WorkStarted { task_name: None });
+        }
+
+        #[test]
+        fn test_work_completed_event() {
+            let event = TimerEvent::WorkCompleted {
+                pomodoro_count: 5,
+                task_name: Some("Coding".to_string()),
+                tag: None,
+                work_seconds: 1500,
+                extended: false,
+            };
+            assert_eq!(
+                event,
+                TimerEvent::WorkCompleted {
+                    pomodoro_count: 5,
+                    task_name: Some("Coding".to_string()),
+                    tag: None,
+                    work_seconds: 1500,
+                    extended: false,
+                }
+            );
+        }
+
+        #[test]
+        fn test_break_started_event_short() {
+            let event = TimerEvent::BreakStarted {
+                is_long_break: false,
+            };
+            assert_eq!(
+                event,
+                TimerEvent::BreakStarted {
+                    is_long_break: false
+                }
+            );
+        }
 
         #[test]
         fn test_break_started_event_long() {
@@ -332,8 +1243,8 @@ mod tests {
 
         #[test]
         fn test_paused_event() {
-            let event = TimerEvent::Paused;
-            assert_eq!(event, TimerEvent::Paused);
+            let event = TimerEvent::Paused { reason: None };
+            assert_eq!(event, TimerEvent::Paused { reason: None });
         }
 
         #[test]
@@ -361,6 +1272,57 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_event_name_matches_serialized_tag() {
+            let events = [
+                TimerEvent::WorkStarted { task_name: None },
+                TimerEvent::WorkCompleted {
+                    pomodoro_count: 1,
+                    task_name: None,
+                    tag: None,
+                    work_seconds: 1500,
+                    extended: false,
+                },
+                TimerEvent::BreakStarted {
+                    is_long_break: false,
+                },
+                TimerEvent::BreakCompleted {
+                    is_long_break: false,
+                },
+                TimerEvent::Paused { reason: None },
+                TimerEvent::Resumed,
+                TimerEvent::Stopped,
+                TimerEvent::WorkAbandoned { elapsed_seconds: 1 },
+                TimerEvent::BreakSkipped { elapsed_seconds: 1 },
+                TimerEvent::CycleCompleted,
+                TimerEvent::Tick {
+                    remaining_seconds: 1,
+                },
+            ];
+
+            for (event, name) in events.iter().zip(TimerEvent::NAMES) {
+                let json = serde_json::to_value(event).unwrap();
+                assert_eq!(json["event"], event.name());
+                assert_eq!(event.name(), name);
+            }
+        }
+
+        #[test]
+        fn test_timestamped_event_serialization() {
+            let stamped = TimestampedEvent::now(TimerEvent::BreakStarted {
+                is_long_break: true,
+            });
+            let json = serde_json::to_value(&stamped).unwrap();
+
+            assert_eq!(json["event"], "break_started");
+            assert_eq!(json["is_long_break"], true);
+            let at = DateTime::parse_from_rfc3339(json["at"].as_str().unwrap()).unwrap();
+            assert_eq!(at, stamped.at);
+
+            let parsed: TimestampedEvent = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, stamped);
+        }
+
         #[test]
         fn test_event_clone() {
             let event = TimerEvent::WorkStarted {
@@ -372,9 +1334,9 @@ mod tests {
 
         #[test]
         fn test_event_debug() {
-            let event = TimerEvent::Paused;
+            let event = TimerEvent::Paused { reason: None };
             let debug_str = format!("{:?}", event);
-            assert_eq!(debug_str, "Paused");
+            assert_eq!(debug_str, "Paused { reason: None }");
         }
     }
 
@@ -385,17 +1347,15 @@ mod tests {
     mod timer_engine_tests {
         use super::*;
 
-        fn create_engine() -> (TimerEngine, mpsc::UnboundedReceiver<TimerEvent>) {
-            let (tx, rx) = mpsc::unbounded_channel();
+        fn create_engine() -> (TimerEngine, EventReceiver) {
+            let (tx, rx) = event_channel(DEFAULT_EVENT_CAPACITY);
             let config = PomodoroConfig::default();
             let engine = TimerEngine::new(config, tx);
             (engine, rx)
         }
 
-        fn create_engine_with_config(
-            config: PomodoroConfig,
-        ) -> (TimerEngine, mpsc::UnboundedReceiver<TimerEvent>) {
-            let (tx, rx) = mpsc::unbounded_channel();
+        fn create_engine_with_config(config: PomodoroConfig) -> (TimerEngine, EventReceiver) {
+            let (tx, rx) = event_channel(DEFAULT_EVENT_CAPACITY);
             let engine = TimerEngine::new(config, tx);
             (engine, rx)
         }
@@ -411,144 +1371,1155 @@ mod tests {
         }
 
         #[test]
-        fn test_start() {
+        fn test_events_are_stamped_when_sent() {
             let (mut engine, mut rx) = create_engine();
 
-            engine.start(Some("Test Task".to_string())).unwrap();
+            let before = Local::now();
+            engine.start(None).unwrap();
+            let after = Local::now();
 
-            let state = engine.get_state();
-            assert_eq!(state.phase, TimerPhase::Working);
-            assert_eq!(state.remaining_seconds, 25 * 60);
-            assert_eq!(state.task_name, Some("Test Task".to_string()));
+            let stamped = rx.try_recv().unwrap();
+            assert!(before <= stamped.at && stamped.at <= after);
+            assert_eq!(stamped.event, TimerEvent::WorkStarted { task_name: None });
+        }
+
+        #[test]
+        fn test_tick_ignored_when_stopped() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.tick().unwrap();
+
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+            assert!(rx.try_recv().is_err());
+        }
+
+        #[test]
+        fn test_tick_completes_work_session() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(None).unwrap();
+            let _ = rx.try_recv(); // WorkStarted
+
+            engine.get_state_mut().remaining_seconds = 1;
+            engine.tick().unwrap();
 
-            // Check event was sent
-            let event = rx.try_recv().unwrap();
             assert_eq!(
-                event,
-                TimerEvent::WorkStarted {
-                    task_name: Some("Test Task".to_string())
+                rx.try_recv().unwrap().event,
+                TimerEvent::Tick {
+                    remaining_seconds: 0
                 }
             );
+            assert!(matches!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::WorkCompleted { .. }
+            ));
+            assert_eq!(engine.get_state().phase, TimerPhase::Breaking);
         }
 
         #[test]
-        fn test_start_no_task() {
+        fn test_tick_by_within_phase() {
             let (mut engine, mut rx) = create_engine();
-
             engine.start(None).unwrap();
+            let _ = rx.try_recv(); // WorkStarted
 
-            let state = engine.get_state();
-            assert_eq!(state.phase, TimerPhase::Working);
-            assert_eq!(state.task_name, None);
+            engine.tick_by(90).unwrap();
 
-            let event = rx.try_recv().unwrap();
-            assert_eq!(event, TimerEvent::WorkStarted { task_name: None });
+            assert_eq!(engine.get_state().remaining_seconds, 25 * 60 - 90);
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::Tick {
+                    remaining_seconds: 25 * 60 - 90
+                }
+            );
+            assert!(rx.try_recv().is_err());
         }
 
         #[test]
-        fn test_start_already_running() {
-            let (mut engine, _rx) = create_engine();
-
+        fn test_tick_by_carries_over_into_break() {
+            let (mut engine, mut rx) = create_engine();
             engine.start(None).unwrap();
-            let result = engine.start(None);
+            engine.get_state_mut().remaining_seconds = 30;
 
-            assert!(result.is_err());
-            assert!(result.unwrap_err().to_string().contains("既に実行中"));
+            engine.tick_by(100).unwrap();
+
+            // 30s finish the work session, the other 70s come off the break
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Breaking);
+            assert_eq!(state.remaining_seconds, 5 * 60 - 70);
+            assert_eq!(state.pomodoro_count, 1);
+
+            let events: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok())
+                .map(|stamped| stamped.event.name())
+                .collect();
+            assert_eq!(
+                events,
+                [
+                    "work_started",
+                    "tick",
+                    "work_completed",
+                    "break_started",
+                    "tick"
+                ]
+            );
         }
 
         #[test]
-        fn test_pause() {
-            let (mut engine, mut rx) = create_engine();
-
+        fn test_tick_by_completes_several_phases_with_auto_cycle() {
+            let config = PomodoroConfig {
+                work_duration: PhaseDuration::from_minutes(1),
+                break_duration: PhaseDuration::from_minutes(1),
+                auto_cycle: true,
+                long_break_enabled: false,
+                ..PomodoroConfig::default()
+            };
+            let (mut engine, _rx) = create_engine_with_config(config);
             engine.start(None).unwrap();
-            let _ = rx.try_recv(); // consume WorkStarted
 
-            engine.pause().unwrap();
+            // Work, break, work, then 10s into the second break
+            engine.tick_by(3 * 60 + 10).unwrap();
 
             let state = engine.get_state();
-            assert_eq!(state.phase, TimerPhase::Paused);
+            assert_eq!(state.phase, TimerPhase::Breaking);
+            assert_eq!(state.remaining_seconds, 50);
+            assert_eq!(state.pomodoro_count, 2);
+        }
+
+        #[test]
+        fn test_tick_by_drops_time_once_stopped() {
+            let (mut engine, _rx) = create_engine();
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 10;
 
-            let event = rx.try_recv().unwrap();
-            assert_eq!(event, TimerEvent::Paused);
+            // Without auto-cycle the timer stops at the end of the break
+            engine.tick_by(10 + 5 * 60 + 600).unwrap();
+
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+            assert_eq!(engine.get_state().pomodoro_count, 1);
         }
 
         #[test]
-        fn test_pause_not_running() {
+        fn test_tick_by_counts_towards_min_break() {
             let (mut engine, _rx) = create_engine();
+            engine.set_config(PomodoroConfig::default().with_min_break_seconds(60));
+            engine.start_break_only(5).unwrap();
 
-            let result = engine.pause();
+            engine.tick_by(60).unwrap();
 
-            assert!(result.is_err());
-            assert!(result
-                .unwrap_err()
-                .to_string()
-                .contains("実行されていません"));
+            assert!(engine.skip_break().is_ok());
         }
 
         #[test]
-        fn test_resume() {
+        fn test_tick_by_ignored_unless_running() {
             let (mut engine, mut rx) = create_engine();
+            engine.tick_by(100).unwrap();
+            assert!(rx.try_recv().is_err());
 
             engine.start(None).unwrap();
-            let _ = rx.try_recv(); // consume WorkStarted
             engine.pause().unwrap();
-            let _ = rx.try_recv(); // consume Paused
+            let remaining = engine.get_state().remaining_seconds;
+            engine.tick_by(100).unwrap();
+            assert_eq!(engine.get_state().remaining_seconds, remaining);
+        }
 
-            engine.resume().unwrap();
+        #[test]
+        fn test_phase_completes_only_once() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(None).unwrap();
+            let generation = engine.get_state().phase_generation();
+            engine.get_state_mut().remaining_seconds = 1;
 
-            let state = engine.get_state();
-            assert_eq!(state.phase, TimerPhase::Working);
+            engine.tick().unwrap();
+            // A late second completion of the same work session
+            engine.complete_phase(generation).unwrap();
 
-            let event = rx.try_recv().unwrap();
-            assert_eq!(event, TimerEvent::Resumed);
+            let events: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok())
+                .map(|stamped| stamped.event.name())
+                .collect();
+            assert_eq!(
+                events,
+                ["work_started", "tick", "work_completed", "break_started"]
+            );
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Breaking);
+            assert_eq!(state.pomodoro_count, 1);
         }
 
         #[test]
-        fn test_resume_not_paused() {
-            let (mut engine, _rx) = create_engine();
-
-            let result = engine.resume();
+        fn test_completion_of_replaced_phase_is_ignored() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(None).unwrap();
+            let generation = engine.get_state().phase_generation();
+            engine.stop().unwrap();
+            engine.start(None).unwrap();
+            while rx.try_recv().is_ok() {}
+
+            engine.complete_phase(generation).unwrap();
+
+            assert!(rx.try_recv().is_err());
+            assert_eq!(engine.get_state().phase, TimerPhase::Working);
+            assert_eq!(engine.get_state().pomodoro_count, 0);
+        }
+
+        #[test]
+        fn test_start() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start(Some("Test Task".to_string())).unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Working);
+            assert_eq!(state.remaining_seconds, 25 * 60);
+            assert_eq!(state.task_name, Some("Test Task".to_string()));
+
+            // Check event was sent
+            let event = rx.try_recv().unwrap().event;
+            assert_eq!(
+                event,
+                TimerEvent::WorkStarted {
+                    task_name: Some("Test Task".to_string())
+                }
+            );
+        }
+
+        #[test]
+        fn test_start_no_task() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start(None).unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Working);
+            assert_eq!(state.task_name, None);
+
+            let event = rx.try_recv().unwrap().event;
+            assert_eq!(event, TimerEvent::WorkStarted { task_name: None });
+        }
+
+        #[test]
+        fn test_start_already_running() {
+            let (mut engine, _rx) = create_engine();
+
+            engine.start(None).unwrap();
+            let result = engine.start(None);
+
+            assert!(result.is_err());
+            assert!(result.unwrap_err().to_string().contains("既に実行中"));
+        }
+
+        #[test]
+        fn test_with_channel_delivers_events() {
+            let (mut engine, mut rx) = TimerEngine::with_channel(PomodoroConfig::default());
+
+            engine.start(None).unwrap();
+            engine.tick().unwrap();
+
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::WorkStarted { task_name: None }
+            );
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::Tick {
+                    remaining_seconds: 25 * 60 - 1
+                }
+            );
+        }
+
+        #[test]
+        fn test_start_paused_resumes_from_full_duration() {
+            let (mut engine, mut rx) =
+                create_engine_with_config(PomodoroConfig::default().with_strict(true));
+
+            engine.start_paused(Some("Later".to_string())).unwrap();
+            engine.tick().unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Paused);
+            assert_eq!(state.remaining_seconds, 25 * 60);
+            assert_eq!(state.task_name, Some("Later".to_string()));
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::Paused { reason: None }
+            );
+            assert!(rx.try_recv().is_err());
+
+            engine.resume().unwrap();
+            assert_eq!(engine.get_state().phase, TimerPhase::Working);
+            engine.tick().unwrap();
+            assert_eq!(engine.get_state().remaining_seconds, 25 * 60 - 1);
+        }
+
+        #[test]
+        fn test_start_paused_fires_work_started_on_first_resume() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start_paused(Some("Later".to_string())).unwrap();
+            engine.resume().unwrap();
+            engine.pause().unwrap();
+            engine.resume().unwrap();
+
+            let events: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok())
+                .map(|stamped| stamped.event)
+                .collect();
+            assert_eq!(
+                events,
+                vec![
+                    TimerEvent::Paused { reason: None },
+                    TimerEvent::WorkStarted {
+                        task_name: Some("Later".to_string())
+                    },
+                    TimerEvent::Paused { reason: None },
+                    TimerEvent::Resumed,
+                ]
+            );
+        }
+
+        #[test]
+        fn test_stopping_armed_session_never_starts_work() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start_paused(None).unwrap();
+            engine.stop().unwrap();
+            engine.start_break_only(5).unwrap();
+            engine.pause().unwrap();
+            engine.resume().unwrap();
+
+            let events: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok())
+                .map(|stamped| stamped.event)
+                .collect();
+            assert!(!events
+                .iter()
+                .any(|event| matches!(event, TimerEvent::WorkStarted { .. })));
+            assert_eq!(events.last(), Some(&TimerEvent::Resumed));
+        }
+
+        #[test]
+        fn test_pause() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start(None).unwrap();
+            let _ = rx.try_recv(); // consume WorkStarted
+
+            engine.pause().unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Paused);
+
+            let event = rx.try_recv().unwrap().event;
+            assert_eq!(event, TimerEvent::Paused { reason: None });
+            assert_eq!(state.pause_reason, None);
+        }
+
+        #[test]
+        fn test_pause_with_reason() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(None).unwrap();
+            let _ = rx.try_recv(); // consume WorkStarted
+
+            engine.pause_with_reason(Some("lunch".to_string())).unwrap();
+
+            assert_eq!(engine.get_state().pause_reason.as_deref(), Some("lunch"));
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::Paused {
+                    reason: Some("lunch".to_string())
+                }
+            );
+
+            engine.resume().unwrap();
+            assert_eq!(engine.get_state().pause_reason, None);
+
+            engine.pause_with_reason(Some("call".to_string())).unwrap();
+            engine.stop().unwrap();
+            assert_eq!(engine.get_state().pause_reason, None);
+        }
+
+        #[test]
+        fn test_paused_event_serialization() {
+            let json = serde_json::to_string(&TimerEvent::Paused {
+                reason: Some("meeting".to_string()),
+            })
+            .unwrap();
+            assert_eq!(json, r#"{"event":"paused","reason":"meeting"}"#);
+
+            let json = serde_json::to_string(&TimerEvent::Paused { reason: None }).unwrap();
+            assert_eq!(json, r#"{"event":"paused"}"#);
+            let event: TimerEvent = serde_json::from_str(&json).unwrap();
+            assert_eq!(event, TimerEvent::Paused { reason: None });
+        }
+
+        #[test]
+        fn test_pause_not_running() {
+            let (mut engine, _rx) = create_engine();
+
+            let result = engine.pause();
+
+            assert!(result.is_err());
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("実行されていません"));
+        }
+
+        #[test]
+        fn test_pause_rejected_in_strict_mode() {
+            let config = PomodoroConfig::default().with_strict(true);
+            let (mut engine, mut rx) = create_engine_with_config(config);
+
+            engine.start(None).unwrap();
+            let _ = rx.try_recv(); // consume WorkStarted
+
+            let result = engine.pause();
+
+            assert!(result.unwrap_err().to_string().contains("厳格モード"));
+            assert_eq!(engine.get_state().phase, TimerPhase::Working);
+            assert!(rx.try_recv().is_err());
+        }
+
+        #[test]
+        fn test_resume_and_stop_allowed_in_strict_mode() {
+            let config = PomodoroConfig::default().with_strict(true);
+            let (mut engine, _rx) = create_engine_with_config(config);
+
+            engine.start(None).unwrap();
+            engine.get_state_mut().pause();
+            engine.resume().unwrap();
+            assert_eq!(engine.get_state().phase, TimerPhase::Working);
+
+            engine.stop().unwrap();
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+        }
+
+        #[test]
+        fn test_resume() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start(None).unwrap();
+            let _ = rx.try_recv(); // consume WorkStarted
+            engine.pause().unwrap();
+            let _ = rx.try_recv(); // consume Paused
+
+            engine.resume().unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Working);
+
+            let event = rx.try_recv().unwrap().event;
+            assert_eq!(event, TimerEvent::Resumed);
+        }
+
+        #[test]
+        fn test_resume_not_paused() {
+            let (mut engine, _rx) = create_engine();
+
+            let result = engine.resume();
+
+            assert!(result.is_err());
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("一時停止していません"));
+        }
+
+        #[test]
+        fn test_snooze_extends_break() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+            while rx.try_recv().is_ok() {}
+
+            engine.get_state_mut().remaining_seconds = 60;
+            engine.snooze(5).unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Breaking);
+            assert_eq!(state.remaining_seconds, 60 + 5 * 60);
+        }
+
+        #[test]
+        fn test_snooze_is_clamped_to_max_work_minutes() {
+            let (mut engine, mut rx) = create_engine();
+            engine.set_config(PomodoroConfig::default().with_max_work_minutes(10));
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+            while rx.try_recv().is_ok() {}
+
+            engine.get_state_mut().remaining_seconds = 8 * 60;
+            assert_eq!(engine.snooze(5).unwrap(), 2 * 60);
+            assert_eq!(engine.get_state().remaining_seconds, 10 * 60);
+
+            assert_eq!(engine.snooze(5).unwrap(), 0);
+            assert_eq!(engine.get_state().remaining_seconds, 10 * 60);
+        }
+
+        #[test]
+        fn test_snooze_near_u32_max_does_not_overflow() {
+            let (mut engine, _rx) = create_engine();
+            engine.set_config(PomodoroConfig::default().with_max_work_minutes(u32::MAX));
+            engine.start_break_only(1).unwrap();
+
+            engine.get_state_mut().remaining_seconds = u32::MAX - 10;
+            assert_eq!(engine.snooze(u32::MAX).unwrap(), 0);
+            assert_eq!(engine.get_state().remaining_seconds, u32::MAX - 10);
+
+            engine.get_state_mut().remaining_seconds = crate::types::MAX_PHASE_SECONDS - 60;
+            assert_eq!(engine.snooze(u32::MAX).unwrap(), 60);
+            assert_eq!(
+                engine.get_state().remaining_seconds,
+                crate::types::MAX_PHASE_SECONDS
+            );
+        }
+
+        #[test]
+        fn test_snooze_reenters_just_ended_break() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap(); // Work -> Break
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap(); // Break -> Stopped
+            while rx.try_recv().is_ok() {}
+
+            engine.snooze(3).unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Breaking);
+            assert_eq!(state.remaining_seconds, 3 * 60);
+            assert_eq!(state.progress_fraction(), 0.0);
+            assert_eq!(state.phase_seconds, 3 * 60);
+            assert!(!state.cycle_completed);
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::BreakStarted {
+                    is_long_break: false
+                }
+            );
+        }
+
+        #[test]
+        fn test_snooze_reenters_ended_break_only_once() {
+            let (mut engine, _rx) = create_engine();
+            engine.start_break_only(1).unwrap();
+            engine.tick_by(60).unwrap();
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+
+            engine.snooze(3).unwrap();
+            engine.stop().unwrap();
+
+            assert!(engine.snooze(3).is_err());
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+        }
+
+        #[test]
+        fn test_cycle_completed_is_shown_then_reverts() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start_break_only(1).unwrap();
+            for _ in 0..60 {
+                engine.tick().unwrap();
+            }
+
+            let events: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok())
+                .map(|stamped| stamped.event)
+                .collect();
+            assert_eq!(events.last(), Some(&TimerEvent::CycleCompleted));
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Stopped);
+            assert!(state.cycle_completed);
+            assert_eq!(
+                crate::types::ResponseData::from_timer_state(state).cycle_completed,
+                Some(true)
+            );
+
+            engine.tick_by(CYCLE_COMPLETED_DISPLAY_SECONDS - 1).unwrap();
+            assert!(engine.get_state().cycle_completed);
+            engine.tick().unwrap();
+            let state = engine.get_state();
+            assert!(!state.cycle_completed);
+            assert_eq!(state.phase, TimerPhase::Stopped);
+            assert_eq!(
+                crate::types::ResponseData::from_timer_state(state).cycle_completed,
+                None
+            );
+            assert!(rx.try_recv().is_err());
+        }
+
+        #[test]
+        fn test_cycle_completed_cleared_by_start_or_stop() {
+            let (mut engine, _rx) = create_engine();
+            engine.start_break_only(1).unwrap();
+            engine.tick_by(60).unwrap();
+            assert!(engine.get_state().cycle_completed);
+
+            engine.start(None).unwrap();
+            assert!(!engine.get_state().cycle_completed);
+            engine.stop().unwrap();
+            assert!(!engine.get_state().cycle_completed);
+        }
+
+        #[test]
+        fn test_stop_is_not_a_completed_cycle() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(None).unwrap();
+            engine.stop().unwrap();
+
+            assert!(!engine.get_state().cycle_completed);
+            assert!(std::iter::from_fn(|| rx.try_recv().ok())
+                .all(|stamped| stamped.event != TimerEvent::CycleCompleted));
+        }
+
+        #[test]
+        fn test_snooze_not_in_break() {
+            let (mut engine, _rx) = create_engine();
+
+            let result = engine.snooze(5);
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("休憩中ではありません"));
+
+            engine.start(None).unwrap();
+            let result = engine.snooze(5);
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("休憩中ではありません"));
+        }
+
+        #[test]
+        fn test_extend_work_reenters_working_without_double_count() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(Some("Task".to_string())).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap(); // Work -> Break
+            assert_eq!(engine.get_state().pomodoro_count, 1);
+            while rx.try_recv().is_ok() {}
+
+            engine.extend_work(5).unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Working);
+            assert_eq!(state.remaining_seconds, 5 * 60);
+            assert_eq!(state.pomodoro_count, 1);
+            assert_eq!(state.total_completed_pomodoros, 1);
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::WorkStarted {
+                    task_name: Some("Task".to_string()),
+                }
+            );
+
+            engine.tick_by(5 * 60).unwrap();
+            assert_eq!(engine.get_state().pomodoro_count, 1);
+            assert_eq!(engine.get_state().total_completed_pomodoros, 1);
+            assert_eq!(engine.get_state().phase, TimerPhase::Breaking);
+            let completed = std::iter::from_fn(|| rx.try_recv().ok())
+                .map(|stamped| stamped.event)
+                .find(|event| matches!(event, TimerEvent::WorkCompleted { .. }));
+            assert_eq!(
+                completed,
+                Some(TimerEvent::WorkCompleted {
+                    pomodoro_count: 1,
+                    task_name: Some("Task".to_string()),
+                    tag: None,
+                    work_seconds: 5 * 60,
+                    extended: true,
+                })
+            );
+        }
+
+        #[test]
+        fn test_extend_work_is_a_phase_of_its_own_length() {
+            let (mut engine, _rx) = create_engine();
+            engine.start(None).unwrap();
+            engine.tick_by(25 * 60).unwrap();
+
+            engine.extend_work(5).unwrap();
+            engine.tick_by(150).unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase_total_seconds(state.phase), 5 * 60);
+            assert!((state.progress_fraction() - 0.5).abs() < f32::EPSILON);
+            assert_eq!(state.work_elapsed_seconds(), Some(150));
+        }
+
+        #[test]
+        fn test_stop_after_extend_abandons_nothing() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(None).unwrap();
+            engine.tick_by(25 * 60).unwrap();
+            engine.extend_work(5).unwrap();
+            engine.tick_by(4 * 60).unwrap();
+            while rx.try_recv().is_ok() {}
+
+            engine.stop().unwrap();
+
+            assert_eq!(rx.try_recv().unwrap().event, TimerEvent::Stopped);
+            assert_eq!(engine.get_state().total_completed_pomodoros, 1);
+        }
+
+        #[test]
+        fn test_extend_work_respects_min_break() {
+            let config = PomodoroConfig::default().with_min_break_seconds(120);
+            let (mut engine, _rx) = create_engine_with_config(config);
+            engine.start(None).unwrap();
+            engine.tick_by(25 * 60).unwrap();
+            engine.tick_by(60).unwrap();
+
+            let err = engine.extend_work(5).unwrap_err();
+            assert!(err.to_string().contains("あと60秒"));
+            assert!(engine.get_state().is_in_break());
+
+            engine.tick_by(60).unwrap();
+            engine.extend_work(5).unwrap();
+            assert_eq!(engine.get_state().phase, TimerPhase::Working);
+        }
+
+        #[test]
+        fn test_extend_work_while_awaiting_break() {
+            let config = PomodoroConfig::default().with_prompt_break(true);
+            let (mut engine, _rx) = create_engine_with_config(config);
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+
+            engine.extend_work(5).unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Working);
+            assert!(!state.awaiting_break);
+            assert_eq!(state.pomodoro_count, 1);
+        }
+
+        #[test]
+        fn test_extend_work_only_after_work_session() {
+            let (mut engine, _rx) = create_engine();
+            assert!(engine.extend_work(5).is_err());
+
+            engine.start_break_only(5).unwrap();
+            assert!(engine.extend_work(5).is_err());
+            engine.stop().unwrap();
+
+            // Only once per completed session
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+            engine.extend_work(5).unwrap();
+            assert!(engine.extend_work(5).is_err());
+            assert_eq!(engine.get_state().remaining_seconds, 5 * 60);
+        }
+
+        #[test]
+        fn test_skip_break_starts_work() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(Some("Task".to_string())).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+            while rx.try_recv().is_ok() {}
+
+            engine.tick_by(90).unwrap();
+            while rx.try_recv().is_ok() {}
+
+            engine.skip_break().unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Working);
+            assert_eq!(state.task_name, Some("Task".to_string()));
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::BreakSkipped {
+                    elapsed_seconds: 90
+                }
+            );
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::WorkStarted {
+                    task_name: Some("Task".to_string()),
+                }
+            );
+        }
+
+        #[test]
+        fn test_break_completion_is_not_a_skip() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 1;
+            engine.tick().unwrap();
+            engine.tick_by(5 * 60).unwrap();
+
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+            let skipped = std::iter::from_fn(|| rx.try_recv().ok())
+                .any(|stamped| matches!(stamped.event, TimerEvent::BreakSkipped { .. }));
+            assert!(!skipped);
+        }
+
+        #[test]
+        fn test_skip_break_rejected_before_min_break() {
+            let config = PomodoroConfig::default().with_min_break_seconds(3);
+            let (mut engine, _rx) = create_engine_with_config(config);
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+
+            engine.tick().unwrap();
+            engine.tick().unwrap();
+            let result = engine.skip_break();
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("最低休憩時間が経過していない"));
+            assert_eq!(engine.get_state().phase, TimerPhase::Breaking);
+
+            engine.tick().unwrap();
+            engine.skip_break().unwrap();
+            assert_eq!(engine.get_state().phase, TimerPhase::Working);
+        }
+
+        #[test]
+        fn test_skip_break_paused_time_does_not_count() {
+            let config = PomodoroConfig::default().with_min_break_seconds(1);
+            let (mut engine, _rx) = create_engine_with_config(config);
+            engine.start_break_only(5).unwrap();
+            engine.pause().unwrap();
+
+            engine.tick().unwrap();
+            assert!(engine.skip_break().is_err());
+
+            engine.resume().unwrap();
+            engine.tick().unwrap();
+            assert!(engine.skip_break().is_ok());
+        }
+
+        #[test]
+        fn test_skip_break_not_in_break() {
+            let (mut engine, _rx) = create_engine();
+            assert!(engine.skip_break().is_err());
+
+            engine.start(None).unwrap();
+            let result = engine.skip_break();
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("休憩中ではありません"));
+        }
+
+        #[test]
+        fn test_prompt_break_waits_for_confirmation() {
+            let config = PomodoroConfig::default().with_prompt_break(true);
+            let (mut engine, mut rx) = create_engine_with_config(config);
+            engine.start(Some("Task".to_string())).unwrap();
+            while rx.try_recv().is_ok() {}
+
+            engine.tick_by(25 * 60 + 600).unwrap();
+
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::Tick {
+                    remaining_seconds: 0
+                }
+            );
+            assert!(matches!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::WorkCompleted { .. }
+            ));
+            assert!(rx.try_recv().is_err());
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Stopped);
+            assert!(state.awaiting_break);
+            assert_eq!(state.task_name.as_deref(), Some("Task"));
+
+            // Nothing happens until the break is confirmed
+            engine.tick_by(60).unwrap();
+            assert!(rx.try_recv().is_err());
+
+            engine.start_awaited_break().unwrap();
+
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::BreakStarted {
+                    is_long_break: false
+                }
+            );
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Breaking);
+            assert_eq!(state.remaining_seconds, 5 * 60);
+            assert!(!state.awaiting_break);
+        }
+
+        #[test]
+        fn test_skip_awaited_break_starts_work() {
+            let config = PomodoroConfig::default().with_prompt_break(true);
+            let (mut engine, mut rx) = create_engine_with_config(config);
+            engine.start(Some("Task".to_string())).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+            while rx.try_recv().is_ok() {}
+
+            engine.skip_break().unwrap();
+
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::BreakSkipped { elapsed_seconds: 0 }
+            );
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::WorkStarted {
+                    task_name: Some("Task".to_string()),
+                }
+            );
+            assert!(!engine.get_state().awaiting_break);
+            assert!(engine.start_awaited_break().is_err());
+        }
+
+        #[test]
+        fn test_stop_awaited_break() {
+            let config = PomodoroConfig::default().with_prompt_break(true);
+            let (mut engine, mut rx) = create_engine_with_config(config);
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+            while rx.try_recv().is_ok() {}
+
+            engine.stop().unwrap();
+
+            assert_eq!(rx.try_recv().unwrap().event, TimerEvent::Stopped);
+            assert!(!engine.get_state().awaiting_break);
+            assert!(engine.start_awaited_break().is_err());
+        }
+
+        #[test]
+        fn test_start_awaited_break_without_prompt() {
+            let (mut engine, _rx) = create_engine();
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+
+            assert_eq!(engine.get_state().phase, TimerPhase::Breaking);
+            assert!(engine
+                .start_awaited_break()
+                .unwrap_err()
+                .to_string()
+                .contains("開始を待っている休憩はありません"));
+        }
+
+        #[test]
+        fn test_stop_from_working() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start(None).unwrap();
+            let _ = rx.try_recv(); // consume WorkStarted
+
+            engine.stop().unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Stopped);
+            assert_eq!(state.remaining_seconds, 0);
+
+            let event = rx.try_recv().unwrap().event;
+            assert_eq!(event, TimerEvent::Stopped);
+        }
+
+        #[test]
+        fn test_stop_from_paused() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start(None).unwrap();
+            let _ = rx.try_recv();
+            engine.pause().unwrap();
+            let _ = rx.try_recv();
+
+            engine.stop().unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Stopped);
+
+            let event = rx.try_recv().unwrap().event;
+            assert_eq!(event, TimerEvent::Stopped);
+        }
+
+        #[test]
+        fn test_stop_within_grace_is_not_abandoned() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start(None).unwrap();
+            let _ = rx.try_recv(); // consume WorkStarted
+            engine.get_state_mut().remaining_seconds -= 60;
+
+            engine.stop().unwrap();
+
+            assert_eq!(rx.try_recv().unwrap().event, TimerEvent::Stopped);
+            assert!(rx.try_recv().is_err());
+        }
+
+        #[test]
+        fn test_stop_past_grace_is_abandoned() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start(None).unwrap();
+            let _ = rx.try_recv(); // consume WorkStarted
+            engine.get_state_mut().remaining_seconds -= 61;
+            engine.pause().unwrap();
+            let _ = rx.try_recv(); // consume Paused
+
+            engine.stop().unwrap();
+
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::WorkAbandoned {
+                    elapsed_seconds: 61
+                }
+            );
+            assert_eq!(rx.try_recv().unwrap().event, TimerEvent::Stopped);
+        }
+
+        #[test]
+        fn test_stop_during_break_is_not_abandoned() {
+            let config = PomodoroConfig::default().with_abandon_grace_seconds(0);
+            let (mut engine, mut rx) = create_engine_with_config(config);
+
+            engine.start(None).unwrap();
+            engine.get_state_mut().start_breaking();
+            engine.get_state_mut().remaining_seconds -= 120;
+            while rx.try_recv().is_ok() {}
+
+            engine.stop().unwrap();
+
+            assert_eq!(rx.try_recv().unwrap().event, TimerEvent::Stopped);
+            assert!(rx.try_recv().is_err());
+        }
+
+        #[test]
+        fn test_start_break_only() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start_break_only(10).unwrap();
+
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Breaking);
+            assert_eq!(state.remaining_seconds, 10 * 60);
+            assert_eq!(state.pomodoro_count, 0);
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::BreakStarted {
+                    is_long_break: false
+                }
+            );
+        }
+
+        #[test]
+        fn test_break_only_completes_and_stops_despite_auto_cycle() {
+            let config = PomodoroConfig {
+                auto_cycle: true,
+                ..Default::default()
+            };
+            let (mut engine, mut rx) = create_engine_with_config(config);
+
+            engine.start_break_only(1).unwrap();
+            let _ = rx.try_recv(); // consume BreakStarted
+            engine.get_state_mut().remaining_seconds = 1;
+            engine.tick().unwrap();
+
+            let _ = rx.try_recv(); // Tick
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::BreakCompleted {
+                    is_long_break: false
+                }
+            );
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+            assert_eq!(engine.get_state().pomodoro_count, 0);
+        }
+
+        #[test]
+        fn test_break_series_repeats_then_stops() {
+            let (mut engine, mut rx) = create_engine();
+
+            engine.start_break_series(2, 3).unwrap();
+
+            let mut completed = 0;
+            let mut started = 0;
+            for _ in 0..10 {
+                if engine.get_state().phase == TimerPhase::Stopped {
+                    break;
+                }
+                assert_eq!(engine.get_state().remaining_seconds, 2 * 60);
+                engine.tick_by(2 * 60).unwrap();
+            }
+            while let Ok(stamped) = rx.try_recv() {
+                match stamped.event {
+                    TimerEvent::BreakCompleted { .. } => completed += 1,
+                    TimerEvent::BreakStarted { .. } => started += 1,
+                    _ => {}
+                }
+            }
+
+            assert_eq!(completed, 3);
+            assert_eq!(started, 3);
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+            assert_eq!(engine.get_state().pomodoro_count, 0);
+        }
+
+        #[test]
+        fn test_stop_ends_break_series() {
+            let (mut engine, _rx) = create_engine();
+            engine.start_break_series(1, 5).unwrap();
+            engine.stop().unwrap();
+
+            engine.start_break_only(1).unwrap();
+            engine.tick_by(60).unwrap();
 
-            assert!(result.is_err());
-            assert!(result
-                .unwrap_err()
-                .to_string()
-                .contains("一時停止していません"));
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
         }
 
         #[test]
-        fn test_stop_from_working() {
+        fn test_start_long_break_uses_given_minutes() {
             let (mut engine, mut rx) = create_engine();
 
-            engine.start(None).unwrap();
-            let _ = rx.try_recv(); // consume WorkStarted
-
-            engine.stop().unwrap();
+            engine.start_long_break(30).unwrap();
 
             let state = engine.get_state();
-            assert_eq!(state.phase, TimerPhase::Stopped);
-            assert_eq!(state.remaining_seconds, 0);
-
-            let event = rx.try_recv().unwrap();
-            assert_eq!(event, TimerEvent::Stopped);
+            assert_eq!(state.phase, TimerPhase::LongBreaking);
+            assert_eq!(state.remaining_seconds, 30 * 60);
+            assert_eq!(state.pomodoro_count, 0);
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::BreakStarted {
+                    is_long_break: true
+                }
+            );
         }
 
         #[test]
-        fn test_stop_from_paused() {
-            let (mut engine, mut rx) = create_engine();
+        fn test_long_break_completes_and_stops() {
+            let config = PomodoroConfig {
+                auto_cycle: true,
+                ..Default::default()
+            };
+            let (mut engine, mut rx) = create_engine_with_config(config);
 
-            engine.start(None).unwrap();
-            let _ = rx.try_recv();
-            engine.pause().unwrap();
-            let _ = rx.try_recv();
+            engine.start_long_break(1).unwrap();
+            let _ = rx.try_recv(); // consume BreakStarted
+            engine.get_state_mut().remaining_seconds = 1;
+            engine.tick().unwrap();
 
-            engine.stop().unwrap();
+            let _ = rx.try_recv(); // Tick
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::BreakCompleted {
+                    is_long_break: true
+                }
+            );
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+            assert_eq!(engine.get_state().total_completed_pomodoros, 0);
+        }
 
-            let state = engine.get_state();
-            assert_eq!(state.phase, TimerPhase::Stopped);
+        #[test]
+        fn test_start_break_only_while_running_fails() {
+            let (mut engine, _rx) = create_engine();
+            engine.start(None).unwrap();
 
-            let event = rx.try_recv().unwrap();
-            assert_eq!(event, TimerEvent::Stopped);
+            assert!(engine.start_break_only(5).is_err());
+            assert_eq!(engine.get_state().phase, TimerPhase::Working);
         }
 
         #[test]
@@ -583,16 +2554,19 @@ mod tests {
             assert_eq!(state.pomodoro_count, 1);
 
             // Check events
-            let event = rx.try_recv().unwrap();
+            let event = rx.try_recv().unwrap().event;
             assert_eq!(
                 event,
                 TimerEvent::WorkCompleted {
                     pomodoro_count: 1,
-                    task_name: Some("Task".to_string())
+                    task_name: Some("Task".to_string()),
+                    tag: None,
+                    work_seconds: 1500,
+                    extended: false,
                 }
             );
 
-            let event = rx.try_recv().unwrap();
+            let event = rx.try_recv().unwrap().event;
             assert_eq!(
                 event,
                 TimerEvent::BreakStarted {
@@ -610,6 +2584,7 @@ mod tests {
 
             // Set pomodoro count to 3 (will become 4 after work completion)
             engine.get_state_mut().pomodoro_count = 3;
+            engine.get_state_mut().total_completed_pomodoros = 3;
             engine.get_state_mut().remaining_seconds = 0;
 
             engine.handle_timer_complete().unwrap();
@@ -620,7 +2595,7 @@ mod tests {
 
             // Check events
             let _ = rx.try_recv(); // WorkCompleted
-            let event = rx.try_recv().unwrap();
+            let event = rx.try_recv().unwrap().event;
             assert_eq!(
                 event,
                 TimerEvent::BreakStarted {
@@ -649,7 +2624,7 @@ mod tests {
             let state = engine.get_state();
             assert_eq!(state.phase, TimerPhase::Stopped);
 
-            let event = rx.try_recv().unwrap();
+            let event = rx.try_recv().unwrap().event;
             assert_eq!(
                 event,
                 TimerEvent::BreakCompleted {
@@ -684,7 +2659,7 @@ mod tests {
             assert_eq!(state.task_name, Some("Auto Task".to_string()));
 
             // Check events
-            let event = rx.try_recv().unwrap();
+            let event = rx.try_recv().unwrap().event;
             assert_eq!(
                 event,
                 TimerEvent::BreakCompleted {
@@ -692,7 +2667,7 @@ mod tests {
                 }
             );
 
-            let event = rx.try_recv().unwrap();
+            let event = rx.try_recv().unwrap().event;
             assert_eq!(
                 event,
                 TimerEvent::WorkStarted {
@@ -701,18 +2676,161 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_auto_cycle_stops_after_max_cycles() {
+            let config = PomodoroConfig {
+                auto_cycle: true,
+                ..PomodoroConfig::default()
+            }
+            .with_max_cycles(2);
+            let (mut engine, mut rx) = create_engine_with_config(config);
+
+            engine.start(None).unwrap();
+
+            // First work + break: auto-cycles into the second work session
+            for _ in 0..2 {
+                engine.get_state_mut().remaining_seconds = 0;
+                engine.handle_timer_complete().unwrap();
+            }
+            assert_eq!(engine.get_state().phase, TimerPhase::Working);
+
+            // Second work + break: stops instead of starting a third
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+            while rx.try_recv().is_ok() {}
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::BreakCompleted {
+                    is_long_break: false
+                }
+            );
+            assert_eq!(rx.try_recv().unwrap().event, TimerEvent::Stopped);
+            assert_eq!(rx.try_recv().unwrap().event, TimerEvent::CycleCompleted);
+            assert!(rx.try_recv().is_err());
+        }
+
+        #[test]
+        fn test_start_resets_cycle_count() {
+            let config = PomodoroConfig {
+                auto_cycle: true,
+                ..PomodoroConfig::default()
+            }
+            .with_max_cycles(1);
+            let (mut engine, _rx) = create_engine_with_config(config);
+
+            for _ in 0..2 {
+                engine.start(None).unwrap();
+                engine.get_state_mut().remaining_seconds = 0;
+                engine.handle_timer_complete().unwrap();
+                engine.get_state_mut().remaining_seconds = 0;
+                engine.handle_timer_complete().unwrap();
+
+                assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+            }
+        }
+
+        #[test]
+        fn test_task_queue_advances_with_each_work_session() {
+            let config = PomodoroConfig {
+                auto_cycle: true,
+                ..PomodoroConfig::default()
+            };
+            let (mut engine, mut rx) = create_engine_with_config(config);
+
+            engine.start(Some("a".to_string())).unwrap();
+            engine.set_task_queue(["b".to_string(), "c".to_string()]);
+            assert_eq!(engine.get_state().task_queue, ["b", "c"]);
+
+            // Work + break: the next session takes the next task
+            for _ in 0..2 {
+                engine.get_state_mut().remaining_seconds = 0;
+                engine.handle_timer_complete().unwrap();
+            }
+            let state = engine.get_state();
+            assert_eq!(state.phase, TimerPhase::Working);
+            assert_eq!(state.task_name, Some("b".to_string()));
+            assert_eq!(state.task_queue, ["c"]);
+
+            let started: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok())
+                .filter_map(|stamped| match stamped.event {
+                    TimerEvent::WorkStarted { task_name } => task_name,
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(started, ["a", "b"]);
+        }
+
+        #[test]
+        fn test_task_queue_exhaustion_stops_auto_cycle() {
+            let config = PomodoroConfig {
+                auto_cycle: true,
+                ..PomodoroConfig::default()
+            };
+            let (mut engine, mut rx) = create_engine_with_config(config);
+
+            engine.start(Some("a".to_string())).unwrap();
+            engine.set_task_queue(["b".to_string()]);
+
+            // "a" then "b", then stop instead of a third session
+            for _ in 0..3 {
+                engine.get_state_mut().remaining_seconds = 0;
+                engine.handle_timer_complete().unwrap();
+            }
+            assert_eq!(engine.get_state().task_name, Some("b".to_string()));
+            assert!(engine.get_state().task_queue.is_empty());
+            while rx.try_recv().is_ok() {}
+
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+            assert_eq!(
+                rx.try_recv().unwrap().event,
+                TimerEvent::BreakCompleted {
+                    is_long_break: false
+                }
+            );
+            assert_eq!(rx.try_recv().unwrap().event, TimerEvent::Stopped);
+        }
+
+        #[test]
+        fn test_start_discards_task_queue() {
+            let config = PomodoroConfig {
+                auto_cycle: true,
+                ..PomodoroConfig::default()
+            };
+            let (mut engine, _rx) = create_engine_with_config(config);
+            engine.start(Some("a".to_string())).unwrap();
+            engine.set_task_queue(["b".to_string()]);
+            engine.stop().unwrap();
+            assert!(engine.get_state().task_queue.is_empty());
+
+            // A plain start keeps the task and cycles without a queue
+            engine.start(Some("x".to_string())).unwrap();
+            for _ in 0..4 {
+                engine.get_state_mut().remaining_seconds = 0;
+                engine.handle_timer_complete().unwrap();
+            }
+            assert_eq!(engine.get_state().phase, TimerPhase::Working);
+            assert_eq!(engine.get_state().task_name, Some("x".to_string()));
+        }
+
         #[test]
         fn test_get_state() {
             let config = PomodoroConfig {
-                work_minutes: 30,
-                break_minutes: 10,
+                work_duration: PhaseDuration::from_minutes(30),
+                break_duration: PhaseDuration::from_minutes(10),
                 ..PomodoroConfig::default()
             };
             let (engine, _rx) = create_engine_with_config(config);
 
             let state = engine.get_state();
-            assert_eq!(state.config.work_minutes, 30);
-            assert_eq!(state.config.break_minutes, 10);
+            assert_eq!(state.config.work_duration.as_minutes(), 30);
+            assert_eq!(state.config.break_duration.as_minutes(), 10);
         }
 
         #[test]
@@ -782,7 +2900,7 @@ mod tests {
 
             // Test at 4, 8, 12 pomodoros
             for count in [4, 8, 12] {
-                engine.get_state_mut().pomodoro_count = count - 1;
+                engine.get_state_mut().total_completed_pomodoros = count - 1;
                 engine.get_state_mut().phase = TimerPhase::Working;
                 engine.get_state_mut().remaining_seconds = 0;
 
@@ -805,7 +2923,7 @@ mod tests {
 
             // Test at 1, 2, 3, 5, 6, 7 pomodoros
             for count in [1, 2, 3, 5, 6, 7] {
-                engine.get_state_mut().pomodoro_count = count - 1;
+                engine.get_state_mut().total_completed_pomodoros = count - 1;
                 engine.get_state_mut().phase = TimerPhase::Working;
                 engine.get_state_mut().remaining_seconds = 0;
 
@@ -819,6 +2937,313 @@ mod tests {
                 );
             }
         }
+
+        /// Runs one work session to completion and returns the resulting phase.
+        fn complete_work(engine: &mut TimerEngine) -> TimerPhase {
+            engine.start(None).unwrap();
+            engine.get_state_mut().remaining_seconds = 0;
+            engine.handle_timer_complete().unwrap();
+            engine.get_state().phase
+        }
+
+        #[test]
+        fn test_stop_then_start_preserves_cadence() {
+            let (mut engine, _rx) = create_engine();
+
+            for _ in 0..3 {
+                assert_eq!(complete_work(&mut engine), TimerPhase::Breaking);
+                engine.stop().unwrap();
+            }
+
+            assert_eq!(complete_work(&mut engine), TimerPhase::LongBreaking);
+            assert_eq!(engine.get_state().total_completed_pomodoros, 4);
+        }
+
+        #[test]
+        fn test_cadence_persists_across_restart() {
+            let dir = tempfile::TempDir::new().unwrap();
+            let path = dir.path().join("state.json");
+
+            let (tx, _rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+            let mut engine = TimerEngine::new(PomodoroConfig::default(), tx).with_state_path(&path);
+            for _ in 0..3 {
+                complete_work(&mut engine);
+                engine.stop().unwrap();
+            }
+            drop(engine);
+
+            let (tx, _rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+            let mut engine = TimerEngine::new(PomodoroConfig::default(), tx).with_state_path(&path);
+            assert_eq!(engine.get_state().total_completed_pomodoros, 3);
+            assert_eq!(engine.get_state().pomodoro_count, 0);
+            assert_eq!(complete_work(&mut engine), TimerPhase::LongBreaking);
+        }
+
+        #[test]
+        fn test_reset_clears_and_persists_cadence() {
+            let dir = tempfile::TempDir::new().unwrap();
+            let path = dir.path().join("state.json");
+
+            let (tx, _rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+            let mut engine = TimerEngine::new(PomodoroConfig::default(), tx).with_state_path(&path);
+            for _ in 0..3 {
+                complete_work(&mut engine);
+                engine.stop().unwrap();
+            }
+
+            engine.reset();
+
+            assert_eq!(engine.get_state().total_completed_pomodoros, 0);
+            assert_eq!(engine.get_state().pomodoro_count, 0);
+            assert_eq!(store::load(&path).unwrap().total_completed_pomodoros, 0);
+            assert_eq!(complete_work(&mut engine), TimerPhase::Breaking);
+        }
+
+        #[test]
+        fn test_suspend_then_unsuspend_restores_session() {
+            let (mut engine, mut rx) = create_engine();
+            engine.start(Some("Write docs".to_string())).unwrap();
+            engine.set_task_queue(["Review".to_string()]);
+            engine.get_state_mut().remaining_seconds = 777;
+            let before = engine.get_state().clone();
+
+            engine.suspend().unwrap();
+
+            assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+            assert_eq!(engine.get_state().task_name, None);
+            assert_eq!(engine.suspended().unwrap().remaining_seconds, 777);
+
+            engine.unsuspend().unwrap();
+
+            let after = engine.get_state();
+            assert_eq!(after.phase, TimerPhase::Working);
+            assert_eq!(after.remaining_seconds, 777);
+            assert_eq!(after.task_name.as_deref(), Some("Write docs"));
+            assert_eq!(after.task_queue, before.task_queue);
+            assert_eq!(after.pomodoro_count, before.pomodoro_count);
+            assert!(engine.suspended().is_none());
+
+            let events: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok())
+                .map(|stamped| stamped.event)
+                .collect();
+            assert_eq!(
+                events,
+                vec![
+                    TimerEvent::WorkStarted {
+                        task_name: Some("Write docs".to_string())
+                    },
+                    TimerEvent::Stopped,
+                    TimerEvent::WorkStarted {
+                        task_name: Some("Write docs".to_string())
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn test_suspend_paused_break_restores_break() {
+            let (mut engine, _rx) = create_engine();
+            assert_eq!(complete_work(&mut engine), TimerPhase::Breaking);
+            engine.get_state_mut().remaining_seconds = 120;
+            engine.pause().unwrap();
+
+            engine.suspend().unwrap();
+            engine.unsuspend().unwrap();
+
+            assert_eq!(engine.get_state().phase, TimerPhase::Breaking);
+            assert_eq!(engine.get_state().remaining_seconds, 120);
+            assert_eq!(engine.get_state().pomodoro_count, 1);
+        }
+
+        #[test]
+        fn test_suspended_session_survives_restart() {
+            let dir = tempfile::TempDir::new().unwrap();
+            let path = dir.path().join("state.json");
+
+            let (tx, _rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+            let mut engine = TimerEngine::new(PomodoroConfig::default(), tx).with_state_path(&path);
+            engine.start(Some("Write docs".to_string())).unwrap();
+            engine.get_state_mut().remaining_seconds = 600;
+            engine.suspend().unwrap();
+            drop(engine);
+
+            let (tx, _rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+            let mut engine = TimerEngine::new(PomodoroConfig::default(), tx).with_state_path(&path);
+            engine.unsuspend().unwrap();
+
+            assert_eq!(engine.get_state().phase, TimerPhase::Working);
+            assert_eq!(engine.get_state().remaining_seconds, 600);
+            assert_eq!(engine.get_state().task_name.as_deref(), Some("Write docs"));
+            assert!(store::load(&path).unwrap().suspended.is_none());
+        }
+
+        #[test]
+        fn test_suspend_and_unsuspend_errors() {
+            let (mut engine, _rx) = create_engine();
+
+            assert!(engine.suspend().is_err());
+            assert!(engine.unsuspend().is_err());
+
+            engine.start(None).unwrap();
+            engine.suspend().unwrap();
+            engine.start(None).unwrap();
+            // A second session cannot be suspended over the first, and the
+            // first cannot be resumed while the timer runs
+            assert!(engine.suspend().is_err());
+            assert!(engine.unsuspend().is_err());
+            assert!(engine.suspended().is_some());
+        }
+
+        #[test]
+        fn test_corrupted_state_file_starts_fresh() {
+            let dir = tempfile::TempDir::new().unwrap();
+            let path = dir.path().join("state.json");
+            std::fs::write(&path, "{broken").unwrap();
+
+            let (tx, _rx) = event_channel(DEFAULT_EVENT_CAPACITY);
+            let engine = TimerEngine::new(PomodoroConfig::default(), tx).with_state_path(&path);
+
+            assert_eq!(engine.get_state().total_completed_pomodoros, 0);
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // WallClock Tests
+    // ------------------------------------------------------------------------
+
+    mod wall_clock_tests {
+        use super::*;
+        use std::time::UNIX_EPOCH;
+
+        #[test]
+        fn test_whole_seconds_and_carried_fraction() {
+            let start = UNIX_EPOCH + Duration::from_secs(1_000_000);
+            let mut clock = WallClock::new(start);
+
+            assert_eq!(clock.elapsed_seconds(start + Duration::from_millis(999)), 0);
+            assert_eq!(
+                clock.elapsed_seconds(start + Duration::from_millis(1500)),
+                1
+            );
+            // The half second left over counts towards the next one
+            assert_eq!(
+                clock.elapsed_seconds(start + Duration::from_millis(2000)),
+                1
+            );
+        }
+
+        #[test]
+        fn test_gap_after_sleep() {
+            let start = UNIX_EPOCH + Duration::from_secs(1_000_000);
+            let mut clock = WallClock::new(start);
+
+            assert_eq!(clock.elapsed_seconds(start + Duration::from_secs(600)), 600);
+        }
+
+        #[test]
+        fn test_clock_set_backwards() {
+            let start = UNIX_EPOCH + Duration::from_secs(1_000_000);
+            let mut clock = WallClock::new(start);
+            let earlier = start - Duration::from_secs(60);
+
+            assert_eq!(clock.elapsed_seconds(earlier), 0);
+            assert_eq!(clock.elapsed_seconds(earlier + Duration::from_secs(1)), 1);
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // MockClock Tests
+    // ------------------------------------------------------------------------
+
+    mod mock_clock_tests {
+        use super::*;
+        use crate::daemon::clock::MockClock;
+        use std::sync::Arc;
+
+        /// Advances `clock` once the timer loop is waiting on it.
+        async fn advance(clock: &MockClock, duration: Duration) {
+            while clock.sleepers() == 0 {
+                tokio::task::yield_now().await;
+            }
+            clock.advance(duration);
+        }
+
+        #[tokio::test]
+        async fn test_run_ticks_once_per_second_of_clock_time() {
+            let (mut engine, mut rx) = TimerEngine::with_channel(PomodoroConfig::default());
+            engine.start(None).unwrap();
+            rx.try_recv().unwrap();
+            let clock = MockClock::default();
+            let handle = tokio::spawn({
+                let clock = clock.clone();
+                async move { engine.run_with_clock(&clock).await }
+            });
+
+            for remaining in [25 * 60 - 1, 25 * 60 - 2] {
+                advance(&clock, Duration::from_secs(1)).await;
+                assert_eq!(
+                    rx.recv().await.unwrap().event,
+                    TimerEvent::Tick {
+                        remaining_seconds: remaining
+                    }
+                );
+            }
+            handle.abort();
+        }
+
+        #[tokio::test]
+        async fn test_run_catches_up_after_a_gap() {
+            let (mut engine, mut rx) = TimerEngine::with_channel(PomodoroConfig::default());
+            engine.start(None).unwrap();
+            rx.try_recv().unwrap();
+            let clock = MockClock::default();
+            let handle = tokio::spawn({
+                let clock = clock.clone();
+                async move { engine.run_with_clock(&clock).await }
+            });
+
+            // Like a Mac waking from a five-minute sleep
+            advance(&clock, Duration::from_secs(5 * 60)).await;
+
+            assert_eq!(
+                rx.recv().await.unwrap().event,
+                TimerEvent::Tick {
+                    remaining_seconds: 20 * 60
+                }
+            );
+            handle.abort();
+        }
+
+        #[tokio::test]
+        async fn test_run_shared_releases_engine_between_ticks() {
+            let (mut engine, mut rx) = TimerEngine::with_channel(PomodoroConfig::default());
+            engine.start(None).unwrap();
+            rx.try_recv().unwrap();
+            let engine = Arc::new(Mutex::new(engine));
+            let clock = MockClock::default();
+            let handle = tokio::spawn({
+                let engine = Arc::clone(&engine);
+                let clock = clock.clone();
+                async move { run_shared(&engine, &clock).await }
+            });
+
+            advance(&clock, Duration::from_secs(1)).await;
+            assert_eq!(rx.recv().await.unwrap().event.name(), "tick");
+            // Another task can use the engine while the loop waits
+            engine.lock().await.pause().unwrap();
+            engine.lock().await.resume().unwrap();
+            assert_eq!(rx.recv().await.unwrap().event.name(), "paused");
+            assert_eq!(rx.recv().await.unwrap().event.name(), "resumed");
+            advance(&clock, Duration::from_secs(1)).await;
+
+            assert_eq!(
+                rx.recv().await.unwrap().event,
+                TimerEvent::Tick {
+                    remaining_seconds: 25 * 60 - 2
+                }
+            );
+            handle.abort();
+        }
     }
 
     // ------------------------------------------------------------------------
@@ -831,7 +3256,7 @@ mod tests {
 
         #[tokio::test]
         async fn test_engine_run_tick_event() {
-            let (tx, mut rx) = mpsc::unbounded_channel();
+            let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
             let config = PomodoroConfig::default();
             let mut engine = TimerEngine::new(config, tx);
 
@@ -845,9 +3270,9 @@ mod tests {
             // Wait for at least one tick event
             let result = timeout(Duration::from_secs(2), async {
                 loop {
-                    if let Ok(event) = rx.try_recv() {
-                        if matches!(event, TimerEvent::Tick { .. }) {
-                            return event;
+                    if let Ok(stamped) = rx.try_recv() {
+                        if matches!(stamped.event, TimerEvent::Tick { .. }) {
+                            return stamped.event;
                         }
                     }
                     tokio::time::sleep(Duration::from_millis(100)).await;
@@ -865,7 +3290,7 @@ mod tests {
 
         #[tokio::test]
         async fn test_engine_run_skips_when_not_running() {
-            let (tx, mut rx) = mpsc::unbounded_channel();
+            let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
             let config = PomodoroConfig::default();
             let engine = TimerEngine::new(config, tx);
 
@@ -893,7 +3318,7 @@ mod tests {
 
         #[tokio::test]
         async fn test_engine_run_paused_no_ticks() {
-            let (tx, mut rx) = mpsc::unbounded_channel();
+            let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
             let config = PomodoroConfig::default();
             let mut engine = TimerEngine::new(config, tx);
 
@@ -923,7 +3348,7 @@ mod tests {
 
         #[tokio::test]
         async fn test_timer_precision() {
-            let (tx, mut rx) = mpsc::unbounded_channel();
+            let (tx, mut rx) = event_channel(DEFAULT_EVENT_CAPACITY);
             let config = PomodoroConfig::default();
             let mut engine = TimerEngine::new(config, tx);
 
@@ -941,8 +3366,8 @@ mod tests {
 
             // Count tick events
             let mut tick_count = 0;
-            while let Ok(event) = rx.try_recv() {
-                if matches!(event, TimerEvent::Tick { .. }) {
+            while let Ok(stamped) = rx.try_recv() {
+                if matches!(stamped.event, TimerEvent::Tick { .. }) {
                     tick_count += 1;
                 }
             }
@@ -0,0 +1,178 @@
+//! Verification of the installed plist for `install --check`.
+//!
+//! After the binary is moved or reinstalled elsewhere, the installed plist
+//! still starts the old path. The check reads the binary path back from
+//! `ProgramArguments` and compares it with the binary found in PATH.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::error::{LaunchAgentError, Result};
+use super::plist::PomodoroLaunchAgent;
+use super::status::get_plist_path;
+
+// ============================================================================
+// InstallCheck
+// ============================================================================
+
+/// Result of comparing the installed plist with the current binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallCheck {
+    /// No plist is installed
+    NotInstalled,
+    /// The plist starts the current binary
+    UpToDate {
+        /// The binary path in the plist
+        path: String,
+    },
+    /// The plist starts a different binary
+    Stale {
+        /// The binary path in the plist
+        installed: String,
+        /// The binary found in PATH
+        current: String,
+    },
+}
+
+impl InstallCheck {
+    /// Returns whether the plist starts the current binary.
+    pub fn is_up_to_date(&self) -> bool {
+        matches!(self, Self::UpToDate { .. })
+    }
+}
+
+/// Checks the installed plist against the pomodoro binary in PATH.
+///
+/// # Errors
+///
+/// Returns an error if the home directory or the binary cannot be found,
+/// or if the installed plist cannot be read.
+pub fn check_install() -> Result<InstallCheck> {
+    let plist_path = get_plist_path().ok_or(LaunchAgentError::HomeDirectoryNotFound)?;
+    let current = super::resolve_binary_path()?;
+    check_plist(&plist_path, &current)
+}
+
+/// Checks the plist at `plist_path` against `current_binary`.
+///
+/// The paths match if they are equal or resolve to the same file, so a
+/// plist written through a symlink is not reported as stale.
+///
+/// # Errors
+///
+/// Returns an error if the plist exists but cannot be read or parsed.
+pub fn check_plist(plist_path: &Path, current_binary: &str) -> Result<InstallCheck> {
+    let bytes = match fs::read(plist_path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(InstallCheck::NotInstalled),
+        Err(e) => return Err(LaunchAgentError::PlistRead(e)),
+    };
+    let installed = PomodoroLaunchAgent::from_xml(&bytes)?
+        .binary_path()
+        .unwrap_or_default()
+        .to_string();
+
+    if same_binary(&installed, current_binary) {
+        Ok(InstallCheck::UpToDate { path: installed })
+    } else {
+        Ok(InstallCheck::Stale {
+            installed,
+            current: current_binary.to_string(),
+        })
+    }
+}
+
+/// Returns whether two binary paths refer to the same file.
+fn same_binary(installed: &str, current: &str) -> bool {
+    if installed == current {
+        return true;
+    }
+    match (fs::canonicalize(installed), fs::canonicalize(current)) {
+        (Ok(installed), Ok(current)) => installed == current,
+        _ => false,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_plist(dir: &TempDir, binary_path: &str) -> std::path::PathBuf {
+        let path = dir.path().join("com.example.pomodoro.plist");
+        let xml = PomodoroLaunchAgent::new(binary_path, "/Users/test/.pomodoro/logs")
+            .to_xml()
+            .unwrap();
+        fs::write(&path, xml).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_wrong_path_is_stale() {
+        let dir = TempDir::new().unwrap();
+        let plist = write_plist(&dir, "/old/bin/pomodoro");
+
+        let check = check_plist(&plist, "/usr/local/bin/pomodoro").unwrap();
+
+        assert_eq!(
+            check,
+            InstallCheck::Stale {
+                installed: "/old/bin/pomodoro".to_string(),
+                current: "/usr/local/bin/pomodoro".to_string(),
+            }
+        );
+        assert!(!check.is_up_to_date());
+    }
+
+    #[test]
+    fn test_matching_path_is_up_to_date() {
+        let dir = TempDir::new().unwrap();
+        let plist = write_plist(&dir, "/usr/local/bin/pomodoro");
+
+        let check = check_plist(&plist, "/usr/local/bin/pomodoro").unwrap();
+
+        assert!(check.is_up_to_date());
+    }
+
+    #[test]
+    fn test_symlinked_binary_is_up_to_date() {
+        let dir = TempDir::new().unwrap();
+        let binary = dir.path().join("pomodoro");
+        fs::write(&binary, "").unwrap();
+        let link = dir.path().join("pomodoro-link");
+        std::os::unix::fs::symlink(&binary, &link).unwrap();
+        let plist = write_plist(&dir, link.to_str().unwrap());
+
+        let check = check_plist(&plist, binary.to_str().unwrap()).unwrap();
+
+        assert!(check.is_up_to_date());
+    }
+
+    #[test]
+    fn test_missing_plist_is_not_installed() {
+        let dir = TempDir::new().unwrap();
+        let plist = dir.path().join("missing.plist");
+
+        assert_eq!(
+            check_plist(&plist, "/usr/local/bin/pomodoro").unwrap(),
+            InstallCheck::NotInstalled
+        );
+    }
+
+    #[test]
+    fn test_unparsable_plist_is_an_error() {
+        let dir = TempDir::new().unwrap();
+        let plist = dir.path().join("broken.plist");
+        fs::write(&plist, "not a plist").unwrap();
+
+        assert!(matches!(
+            check_plist(&plist, "/usr/local/bin/pomodoro"),
+            Err(LaunchAgentError::PlistParse(_))
+        ));
+    }
+}
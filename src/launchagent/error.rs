@@ -22,14 +22,32 @@ pub enum LaunchAgentError {
     #[error("Failed to write plist file: {0}")]
     PlistWrite(#[source] io::Error),
 
+    /// Failed to read plist file.
+    #[error("Failed to read plist file: {0}")]
+    PlistRead(#[source] io::Error),
+
     /// Failed to remove plist file.
     #[error("Failed to remove plist file: {0}")]
     PlistRemove(#[source] io::Error),
 
+    /// Failed to remove an app data file during purge.
+    #[error("Failed to remove {}: {source}", path.display())]
+    PurgeRemove {
+        /// The file or directory that could not be removed
+        path: std::path::PathBuf,
+        /// The underlying error
+        #[source]
+        source: io::Error,
+    },
+
     /// Failed to serialize plist.
     #[error("Failed to serialize plist: {0}")]
     PlistSerialize(#[source] plist::Error),
 
+    /// Failed to parse plist.
+    #[error("Failed to parse plist: {0}")]
+    PlistParse(#[source] plist::Error),
+
     /// Failed to convert plist to UTF-8 string.
     #[error("Failed to convert plist to UTF-8: {0}")]
     PlistUtf8(#[source] std::string::FromUtf8Error),
@@ -22,17 +22,23 @@
 //! # Ok::<(), launchagent::error::LaunchAgentError>(())
 //! ```
 
+pub mod check;
 pub mod error;
 pub mod launchctl;
 pub mod plist;
+pub mod purge;
 pub mod status;
 
 use std::fs;
 use std::os::unix::fs::PermissionsExt;
 use std::process::Command;
 
+use crate::paths;
+
+pub use check::{check_install, check_plist, InstallCheck};
 pub use error::{LaunchAgentError, Result};
-pub use plist::PomodoroLaunchAgent;
+pub use plist::{KeepAlive, PomodoroLaunchAgent};
+pub use purge::remove_app_files;
 pub use status::{
     get_log_dir, get_plist_path, get_status, is_installed, is_running, ServiceStatus,
 };
@@ -77,7 +83,7 @@ pub fn install() -> Result<()> {
     let home_dir = dirs::home_dir().ok_or(LaunchAgentError::HomeDirectoryNotFound)?;
 
     // 3. Create log directory
-    let log_dir = home_dir.join(".pomodoro/logs");
+    let log_dir = paths::log_dir().map_err(|_| LaunchAgentError::HomeDirectoryNotFound)?;
     fs::create_dir_all(&log_dir).map_err(LaunchAgentError::DirectoryCreation)?;
 
     // 4. Generate plist
@@ -23,9 +23,9 @@ pub struct PomodoroLaunchAgent {
     #[serde(rename = "RunAtLoad")]
     pub run_at_load: bool,
 
-    /// Whether to automatically restart if the process terminates
+    /// When to automatically restart the process after it exits
     #[serde(rename = "KeepAlive")]
-    pub keep_alive: bool,
+    pub keep_alive: KeepAlive,
 
     /// Path to stdout log file
     #[serde(rename = "StandardOutPath")]
@@ -65,9 +65,14 @@ impl PomodoroLaunchAgent {
 
         Self {
             label: Self::LABEL.to_string(),
-            program_arguments: vec![binary_path, "daemon".to_string()],
+            program_arguments: vec![
+                binary_path,
+                "daemon".to_string(),
+                "--log-file".to_string(),
+                format!("{}/daemon.log", log_dir),
+            ],
             run_at_load: true,
-            keep_alive: true,
+            keep_alive: KeepAlive::ON_FAILURE,
             standard_out_path: format!("{}/stdout.log", log_dir),
             standard_error_path: format!("{}/stderr.log", log_dir),
             working_directory: None,
@@ -88,6 +93,19 @@ impl PomodoroLaunchAgent {
         String::from_utf8(buf).map_err(LaunchAgentError::PlistUtf8)
     }
 
+    /// Parses a plist written by [`Self::to_xml`].
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid plist of this shape.
+    pub fn from_xml(bytes: &[u8]) -> Result<Self> {
+        plist::from_bytes(bytes).map_err(LaunchAgentError::PlistParse)
+    }
+
+    /// Returns the path of the binary the service runs.
+    pub fn binary_path(&self) -> Option<&str> {
+        self.program_arguments.first().map(String::as_str)
+    }
+
     /// Sets the working directory.
     pub fn with_working_directory(mut self, dir: impl Into<String>) -> Self {
         self.working_directory = Some(dir.into());
@@ -104,11 +122,35 @@ impl PomodoroLaunchAgent {
 
     /// Disables automatic restart on exit.
     pub fn without_keep_alive(mut self) -> Self {
-        self.keep_alive = false;
+        self.keep_alive = KeepAlive::Flag(false);
         self
     }
 }
 
+/// The `KeepAlive` value: a plain flag or a dictionary of conditions.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum KeepAlive {
+    /// Restart after every exit (`true`) or never (`false`)
+    Flag(bool),
+    /// Restart depending on how the process exited
+    Conditions {
+        /// Restart only after an exit whose success matches this value
+        #[serde(rename = "SuccessfulExit")]
+        successful_exit: bool,
+    },
+}
+
+impl KeepAlive {
+    /// Restarts after a crash or a non-zero exit, but not after a clean one.
+    ///
+    /// The daemon exits with 0 when it shuts down on purpose (e.g. after
+    /// idling), and should then stay down.
+    pub const ON_FAILURE: Self = Self::Conditions {
+        successful_exit: false,
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,10 +163,15 @@ mod tests {
         assert_eq!(plist.label, "com.example.pomodoro");
         assert_eq!(
             plist.program_arguments,
-            vec!["/usr/local/bin/pomodoro", "daemon"]
+            vec![
+                "/usr/local/bin/pomodoro",
+                "daemon",
+                "--log-file",
+                "/Users/test/.pomodoro/logs/daemon.log"
+            ]
         );
         assert!(plist.run_at_load);
-        assert!(plist.keep_alive);
+        assert_eq!(plist.keep_alive, KeepAlive::ON_FAILURE);
         assert_eq!(
             plist.standard_out_path,
             "/Users/test/.pomodoro/logs/stdout.log"
@@ -214,7 +261,35 @@ mod tests {
             PomodoroLaunchAgent::new("/usr/local/bin/pomodoro", "/Users/test/.pomodoro/logs")
                 .without_keep_alive();
 
-        assert!(!plist.keep_alive);
+        assert_eq!(plist.keep_alive, KeepAlive::Flag(false));
+    }
+
+    #[test]
+    fn test_keep_alive_restarts_only_after_failure() {
+        let plist =
+            PomodoroLaunchAgent::new("/usr/local/bin/pomodoro", "/Users/test/.pomodoro/logs");
+
+        let xml = plist.to_xml().expect("Failed to generate XML");
+
+        let keep_alive = xml
+            .split("<key>KeepAlive</key>")
+            .nth(1)
+            .expect("KeepAlive should be set");
+        let keep_alive = &keep_alive[..keep_alive.find("</dict>").unwrap()];
+        assert!(keep_alive.contains("<key>SuccessfulExit</key>"));
+        assert!(keep_alive.contains("<false/>"));
+    }
+
+    #[test]
+    fn test_keep_alive_flag_roundtrip() {
+        let plist =
+            PomodoroLaunchAgent::new("/usr/local/bin/pomodoro", "/Users/test/.pomodoro/logs")
+                .without_keep_alive();
+
+        let xml = plist.to_xml().expect("Failed to generate XML");
+        let parsed = PomodoroLaunchAgent::from_xml(xml.as_bytes()).expect("Failed to parse XML");
+
+        assert_eq!(parsed.keep_alive, KeepAlive::Flag(false));
     }
 
     #[test]
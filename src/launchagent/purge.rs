@@ -0,0 +1,115 @@
+//! Removal of the app's runtime files for `uninstall --purge`.
+//!
+//! Only the paths listed by [`crate::paths::app_data_paths`] are touched, so
+//! user files such as `config.toml` survive a purge.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::error::{LaunchAgentError, Result};
+
+/// Removes the given app data paths and returns the ones that existed.
+///
+/// Directories (the log directory) are removed recursively; symlinks are
+/// removed without following them. Missing paths are skipped.
+///
+/// # Errors
+///
+/// Returns an error if an existing path cannot be removed. Paths removed
+/// before the failure stay removed.
+pub fn remove_app_files(paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+
+    for path in paths {
+        if remove_path(path).map_err(|source| LaunchAgentError::PurgeRemove {
+            path: path.clone(),
+            source,
+        })? {
+            tracing::info!(path = %path.display(), "削除しました");
+            removed.push(path.clone());
+        }
+    }
+
+    Ok(removed)
+}
+
+/// Removes a single path, returning whether it existed.
+fn remove_path(path: &Path) -> io::Result<bool> {
+    let metadata = match fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e),
+    };
+
+    if metadata.is_dir() {
+        fs::remove_dir_all(path)?;
+    } else {
+        fs::remove_file(path)?;
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths::{resolve_app_data_paths, Layout};
+    use tempfile::TempDir;
+
+    /// Lays out `~/.pomodoro` under a temporary HOME and returns the app paths.
+    fn setup_home(home: &TempDir) -> Vec<PathBuf> {
+        let home_path = home.path().to_string_lossy().to_string();
+        let lookup = move |key: &str| (key == "HOME").then(|| home_path.clone());
+        let paths = resolve_app_data_paths(Layout::Home, &lookup).unwrap();
+
+        let base = home.path().join(".pomodoro");
+        fs::create_dir_all(base.join("logs")).unwrap();
+        fs::write(base.join("state.json"), "{}").unwrap();
+        fs::write(base.join("history.jsonl"), "").unwrap();
+        fs::write(base.join("history.jsonl.1"), "").unwrap();
+        fs::write(base.join("logs/daemon.log"), "log").unwrap();
+        fs::write(base.join("config.toml"), "work_minutes = 30").unwrap();
+
+        paths
+    }
+
+    #[test]
+    fn test_removes_existing_app_files() {
+        let home = TempDir::new().unwrap();
+        let paths = setup_home(&home);
+        let base = home.path().join(".pomodoro");
+
+        let removed = remove_app_files(&paths).unwrap();
+
+        assert_eq!(
+            removed,
+            vec![
+                base.join("state.json"),
+                base.join("history.jsonl.1"),
+                base.join("history.jsonl"),
+                base.join("logs"),
+            ]
+        );
+        assert!(!base.join("state.json").exists());
+        assert!(!base.join("history.jsonl.1").exists());
+        assert!(!base.join("logs").exists());
+    }
+
+    #[test]
+    fn test_keeps_config_file() {
+        let home = TempDir::new().unwrap();
+        let paths = setup_home(&home);
+
+        remove_app_files(&paths).unwrap();
+
+        assert!(home.path().join(".pomodoro/config.toml").exists());
+    }
+
+    #[test]
+    fn test_missing_files_are_skipped() {
+        let home = TempDir::new().unwrap();
+        let missing = vec![home.path().join("state.json")];
+
+        assert!(remove_app_files(&missing).unwrap().is_empty());
+    }
+}
@@ -166,7 +166,7 @@ pub fn get_plist_path() -> Option<std::path::PathBuf> {
 /// # Returns
 /// The full path to the log directory, or None if home directory cannot be determined.
 pub fn get_log_dir() -> Option<std::path::PathBuf> {
-    dirs::home_dir().map(|home| home.join(".pomodoro/logs"))
+    crate::paths::log_dir().ok()
 }
 
 #[cfg(test)]
@@ -267,7 +267,7 @@ mod tests {
         if dirs::home_dir().is_some() {
             assert!(path.is_some());
             let path = path.unwrap();
-            assert!(path.to_string_lossy().contains(".pomodoro/logs"));
+            assert_eq!(path, crate::paths::state_dir().unwrap().join("logs"));
         }
     }
 
@@ -6,6 +6,9 @@
 //! - IPC server/client for daemon-CLI communication
 //! - CLI command parsing and display utilities
 //! - Type definitions for configuration and state
+//! - Shared filesystem paths (socket, config, logs)
+//! - Configuration file loading (`config.toml`)
+//! - Text templates for `status --format` and notification titles
 //! - Native macOS notification system (macOS only)
 //! - Menu bar UI with tray icon (macOS only)
 //! - Sound playback for timer notifications
@@ -13,11 +16,14 @@
 //! - LaunchAgent management for auto-start at login (macOS only)
 
 pub mod cli;
+pub mod config;
 pub mod daemon;
 pub mod focus;
 pub mod launchagent;
 pub mod menubar;
+pub mod paths;
 pub mod sound;
+pub mod template;
 pub mod types;
 
 // macOS-specific notification system
@@ -33,13 +39,13 @@ pub use types::{
 #[cfg(target_os = "macos")]
 pub use notification::{
     MockNotificationSender, NotificationActionEvent, NotificationError, NotificationManager,
-    NotificationSender, NotificationType,
+    NotificationSender, NotificationType, Notifier, TerminalNotifier,
 };
 
 // Re-export menubar types
 pub use menubar::{
-    EventHandler, IconManager, MenuAction, MenuBuilder, MenuConfig, MenuItemConfig, MenuItemId,
-    TrayIconManager, TrayUpdate,
+    EventHandler, IconManager, IconStyle, MenuAction, MenuBuilder, MenuConfig, MenuItemConfig,
+    MenuItemId, ShowSeconds, TrayIconManager, TrayUpdate, TrayUpdateFanout,
 };
 
 // Re-export sound types
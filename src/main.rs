@@ -5,24 +5,51 @@
 //! - 5 minutes of short break
 //! - 15-30 minutes of long break after 4 pomodoros
 
-use anyhow::Result;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
 use clap::{CommandFactory, Parser};
 
 pub mod cli;
+pub mod config;
 pub mod daemon;
+pub mod focus;
+pub mod launchagent;
+pub mod menubar;
+#[cfg(target_os = "macos")]
+pub mod notification;
+pub mod paths;
+pub mod sound;
+pub mod template;
 pub mod types;
 
-use cli::{Cli, Commands, Display, IpcClient};
+use cli::log_tail::LogTail;
+use cli::status_cache::StatusCache;
+use cli::stop_confirm::StopGuard;
+use cli::wait::WaitOutcome;
+use cli::{
+    Cli, Commands, ConfigCommand, Display, IpcClient, NotificationsCommand, SetupCommand,
+    SoundCommand, StartOutcome, StatusArgs,
+};
 
 /// Main entry point
 #[tokio::main(flavor = "current_thread")]
 async fn main() {
-    // Initialize logging
-    init_tracing();
-
     // Parse command line arguments
     let cli = Cli::parse();
 
+    // Initialize logging
+    let log_file = match &cli.command {
+        Some(Commands::Daemon(args)) => args.log_file.as_deref(),
+        _ => None,
+    };
+    if let Err(e) = init_tracing(log_file) {
+        Display::show_error(&format!("{:#}", e));
+        std::process::exit(1);
+    }
+
     // Execute command
     if let Err(e) = execute(cli).await {
         Display::show_error(&e.to_string());
@@ -31,16 +58,56 @@ async fn main() {
 }
 
 /// Initializes the tracing subscriber for logging.
-fn init_tracing() {
+///
+/// Logs go to stderr unless `log_file` is given, in which case they are
+/// appended to that file (with timestamps and without ANSI colors).
+fn init_tracing(log_file: Option<&Path>) -> Result<()> {
     use tracing_subscriber::{fmt, EnvFilter};
 
     let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn"));
 
-    fmt()
+    match log_file {
+        Some(path) => {
+            use tracing_subscriber::util::SubscriberInitExt;
+
+            file_subscriber(path, filter)?.init();
+        }
+        None => {
+            fmt()
+                .with_env_filter(filter)
+                .with_target(false)
+                .without_time()
+                .init();
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds a subscriber that appends log lines to `path`.
+///
+/// The parent directory is created if needed. Lines carry timestamps and no
+/// ANSI colors.
+fn file_subscriber(
+    path: &Path,
+    filter: tracing_subscriber::EnvFilter,
+) -> Result<impl tracing::Subscriber + Send + Sync + 'static> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("ログディレクトリを作成できません: {}", parent.display()))?;
+    }
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("ログファイルを開けません: {}", path.display()))?;
+
+    Ok(tracing_subscriber::fmt()
         .with_env_filter(filter)
         .with_target(false)
-        .without_time()
-        .init();
+        .with_ansi(false)
+        .with_writer(std::sync::Mutex::new(file))
+        .finish())
 }
 
 /// Executes the CLI command.
@@ -49,16 +116,70 @@ async fn execute(cli: Cli) -> Result<()> {
     if cli.verbose {
         tracing::info!("Verbose mode enabled");
     }
+    let profile = config::resolve_profile(cli.profile.clone());
 
     match cli.command {
-        Some(Commands::Start(args)) => {
+        Some(Commands::Start(mut args)) => {
+            if args.task.as_deref() == Some(cli::stdin::STDIN_TASK) {
+                args.task = Some(cli::stdin::read_task_from_stdin().map_err(anyhow::Error::msg)?);
+            }
+            args.task = cli::clipboard::resolve_task_name(
+                args.task.take(),
+                args.task_from_clipboard,
+                cli::clipboard::read_clipboard,
+            );
+            let config = config::load_config(cli.config.as_deref(), profile.as_deref())?;
+            Display::show_config_warnings(&args.resolve_config(&config.timer));
             let client = IpcClient::new()?;
-            let response = client.start(&args).await?;
-            Display::show_start_success(&response);
+            // Subscribe first so no event of the new session is missed
+            let mut subscription = if args.wait {
+                Some(client.subscribe().await?)
+            } else {
+                None
+            };
+            if args.resume_if_paused {
+                match client.start_or_resume(&args).await? {
+                    StartOutcome::Started(response) => Display::show_start_success(&response),
+                    StartOutcome::Resumed(response) => Display::show_resume_success(&response),
+                }
+            } else {
+                let response = client.start(&args).await?;
+                Display::show_start_success(&response);
+            }
+            if let Some(subscription) = &mut subscription {
+                let detach = tokio::signal::ctrl_c();
+                match cli::wait::wait_for_work(subscription, std::io::stdout(), detach).await? {
+                    WaitOutcome::Completed => println!("* 作業が完了しました"),
+                    WaitOutcome::Stopped => anyhow::bail!("作業が完了する前に停止されました"),
+                    WaitOutcome::Detached => {
+                        println!("* 待機を終了しました（タイマーは動作中です）")
+                    }
+                }
+            }
         }
-        Some(Commands::Pause) => {
+        Some(Commands::Run(args)) => {
+            let config = config::load_config(cli.config.as_deref(), profile.as_deref())?;
+            let options = daemon::foreground::ForegroundOptions {
+                tick_interval: args.tick_interval(),
+                sound: !args.no_sound,
+                notify: true,
+                notifications: config.notifications.clone(),
+                show_seconds: (config.display.countdown_seconds && !args.no_seconds).into(),
+            };
+            let timer = args.resolve_config(&config.timer);
+            Display::show_config_warnings(&timer);
+            daemon::foreground::run_session(
+                timer,
+                args.task.clone(),
+                &options,
+                std::io::stdout(),
+                tokio::signal::ctrl_c(),
+            )
+            .await?;
+        }
+        Some(Commands::Pause(args)) => {
             let client = IpcClient::new()?;
-            let response = client.pause().await?;
+            let response = client.pause_with_reason(args.reason).await?;
             Display::show_pause_success(&response);
         }
         Some(Commands::Resume) => {
@@ -66,34 +187,255 @@ async fn execute(cli: Cli) -> Result<()> {
             let response = client.resume().await?;
             Display::show_resume_success(&response);
         }
-        Some(Commands::Stop) => {
+        Some(Commands::Stop(args)) => {
             let client = IpcClient::new()?;
+            let guard = StopGuard::new(args.yes, std::io::stdout().is_terminal());
+            if guard.is_enabled() {
+                let status = client.status().await?;
+                let data = status.data.unwrap_or_default();
+                let stdin = std::io::stdin();
+                if !guard.confirm(&data, stdin.lock(), std::io::stdout())? {
+                    println!("停止を取りやめました");
+                    return Ok(());
+                }
+            }
             let response = client.stop().await?;
             Display::show_stop_success(&response);
         }
-        Some(Commands::Status) => {
+        Some(Commands::Status(StatusArgs {
+            format: Some(format),
+            ..
+        })) => {
             let client = IpcClient::new()?;
             let response = client.status().await?;
-            Display::show_status(&response);
+            let data = response.data.unwrap_or_default();
+            println!("{}", template::render_status(&format, &data)?);
         }
-        Some(Commands::Daemon) => {
-            // Daemon mode will be implemented in a future issue
-            eprintln!("Daemonモードはまだ実装されていません");
-            eprintln!("今後のリリースで対応予定です");
-            std::process::exit(1);
+        Some(Commands::Status(args)) if args.short => {
+            // Prints nothing when the daemon is unreachable so shell prompts keep working
+            let client = IpcClient::new()?;
+            if !client.is_daemon_running().await {
+                return Ok(());
+            }
+            let response = if args.cache {
+                let ttl = Duration::from_millis(args.cache_ttl);
+                StatusCache::new(paths::status_cache_path()?, ttl)
+                    .status(&client)
+                    .await
+            } else {
+                client.status_quick().await
+            };
+            if let Ok(response) = response {
+                // A broken config file must not break the prompt either
+                let display = config::load_config(cli.config.as_deref(), profile.as_deref())
+                    .map(|config| config.display)
+                    .unwrap_or_default();
+                let style = args.icon_style.unwrap_or(display.icon_style);
+                Display::show_status_short(&response, style, display.menu_bar_seconds.into());
+            }
+        }
+        Some(Commands::Status(_)) => {
+            let client = IpcClient::new()?;
+            let response = client.status().await?;
+            Display::show_status(&response, cli.color.enabled());
+        }
+        Some(Commands::Break(args)) => {
+            let client = IpcClient::new()?;
+            let response = client.start_break(args.minutes, args.repeat).await?;
+            Display::show_break_success(&response);
+        }
+        Some(Commands::LongBreak(args)) => {
+            let client = IpcClient::new()?;
+            let response = client.start_long_break(args.minutes).await?;
+            Display::show_break_success(&response);
+        }
+        Some(Commands::Snooze(args)) => {
+            let client = IpcClient::new()?;
+            let response = client.snooze(args.minutes).await?;
+            Display::show_snooze_success(&response);
         }
-        Some(Commands::Install) => {
+        Some(Commands::Skip) => {
+            let client = IpcClient::new()?;
+            let response = client.skip().await?;
+            Display::show_skip_success(&response);
+        }
+        Some(Commands::Suspend) => {
+            let client = IpcClient::new()?;
+            let response = client.suspend().await?;
+            Display::show_suspend_success(&response);
+        }
+        Some(Commands::Unsuspend) => {
+            let client = IpcClient::new()?;
+            let response = client.unsuspend().await?;
+            Display::show_unsuspend_success(&response);
+        }
+        Some(Commands::Reset) => {
+            let client = IpcClient::new()?;
+            let response = client.reset().await?;
+            Display::show_reset_success(&response);
+        }
+        Some(Commands::Stats(args)) => {
+            let entries = daemon::history::read_entries(&paths::history_path()?)?;
+            let clock = daemon::history::DayClock::from_utc_flag(args.utc);
+            let today = clock.today();
+            let range = args.range(today)?;
+            if let Some(format) = args.export {
+                let entries: Vec<_> = match range {
+                    Some(range) => range
+                        .filter(&entries, clock)
+                        .map(|(_, entry)| entry.clone())
+                        .collect(),
+                    None => entries,
+                };
+                print!("{}", format.export(&entries)?);
+            } else {
+                match range {
+                    Some(range) if args.by_tag => println!(
+                        "{}",
+                        cli::stats::TagStats::for_range(&entries, range, clock)
+                            .format_for(&format!("{}に", range))
+                    ),
+                    Some(range) => println!(
+                        "{}",
+                        cli::stats::RangeStats::for_range(&entries, range, clock).format()
+                    ),
+                    None if args.by_tag => println!(
+                        "{}",
+                        cli::stats::TagStats::for_day(&entries, today, clock).format()
+                    ),
+                    None => println!(
+                        "{}",
+                        cli::stats::Stats::for_day(&entries, today, clock).format()
+                    ),
+                }
+            }
+        }
+        Some(Commands::WatchEvents(args)) => {
+            let client = IpcClient::new()?;
+            let watch = client.watch_events(|event| {
+                if args.shows(&event.event) {
+                    Display::show_event(&event);
+                }
+            });
+            tokio::select! {
+                result = watch => result?,
+                _ = tokio::signal::ctrl_c() => {}
+            }
+        }
+        Some(Commands::Daemon(args)) => {
+            let socket_path = paths::socket_path()?;
+            let options = daemon::runner::RunOptions {
+                terminal_notifications: args.terminal_notifications,
+                quiet: args.quiet,
+                config_path: cli.config.clone(),
+                profile: profile.clone(),
+                events_file: args.events_file.clone(),
+                event_capacity: args.event_capacity.map(|capacity| capacity as usize),
+                announce: args.announce,
+                voice: args.voice.clone(),
+                auto_start_after: args.auto_start_after,
+                break_reminders: args.break_reminder,
+                exclusive: args.exclusive,
+                log_task_names: args.log_task_names,
+            };
+            daemon::runner::run(&socket_path, &options).await?;
+        }
+        Some(Commands::Install(args)) if args.check => {
+            let check = launchagent::check_install()?;
+            Display::show_install_check(&check);
+            if !check.is_up_to_date() {
+                anyhow::bail!("LaunchAgentが現在のバイナリを指していません");
+            }
+        }
+        Some(Commands::Install(_)) => {
             // LaunchAgent installation will be implemented in Issue #10
             Display::show_install_success();
             eprintln!("注意: LaunchAgentのインストールは今後のリリースで対応予定です");
         }
-        Some(Commands::Uninstall) => {
+        Some(Commands::Uninstall(args)) => {
             // LaunchAgent uninstallation will be implemented in Issue #10
             Display::show_uninstall_success();
             eprintln!("注意: LaunchAgentのアンインストールは今後のリリースで対応予定です");
+
+            if args.purge {
+                let paths = paths::app_data_paths()?;
+                let stdin = std::io::stdin();
+                match cli::purge::purge(&paths, args.yes, stdin.lock(), std::io::stdout())? {
+                    Some(removed) => Display::show_purge_result(&removed),
+                    None => println!("削除を中止しました"),
+                }
+            }
+        }
+        Some(Commands::Doctor) => {
+            let checks = cli::doctor::run_checks(&IpcClient::new()?).await;
+            Display::show_doctor(&checks);
+            if cli::doctor::has_failures(&checks) {
+                anyhow::bail!("必須の項目に問題があります");
+            }
         }
-        Some(Commands::Completions { shell }) => {
-            generate_completions(shell);
+        Some(Commands::Config(ConfigCommand::Show(args))) => {
+            let config = config::load_config(cli.config.as_deref(), profile.as_deref())?;
+            let effective = config::EffectiveConfig::resolve(config, args.preset);
+            if args.json {
+                let json = serde_json::to_string_pretty(&effective)
+                    .context("設定のシリアライズに失敗しました")?;
+                println!("{}", json);
+            } else {
+                Display::show_config(&effective.timer);
+            }
+            Display::show_config_warnings(&effective.timer);
+        }
+        Some(Commands::Config(ConfigCommand::Get)) => {
+            let client = IpcClient::new()?;
+            let response = client.get_config().await?;
+            Display::show_daemon_config(&response);
+        }
+        Some(Commands::Config(ConfigCommand::Set(args))) => {
+            let client = IpcClient::new()?;
+            let current = client
+                .get_config()
+                .await?
+                .data
+                .and_then(|data| data.config)
+                .context("デーモンから設定を取得できませんでした")?;
+            let response = client
+                .set_config(args.key.apply(current, args.value))
+                .await?;
+            Display::show_daemon_config(&response);
+        }
+        Some(Commands::Setup(SetupCommand::Notifications)) => setup_notifications().await?,
+        Some(Commands::Log(args)) => {
+            let tail = LogTail::new(paths::daemon_log_path()?);
+            let mut out = std::io::stdout();
+            let offset = tail.print_last(args.lines, &mut out)?;
+            if args.follow {
+                tail.follow(offset, &mut out, tokio::signal::ctrl_c())
+                    .await?;
+            }
+        }
+        Some(Commands::Notifications(command)) => manage_notifications(command).await?,
+        Some(Commands::Sound(SoundCommand::Devices)) => {
+            let devices = sound::list_output_devices().context("出力デバイスを確認できません")?;
+            if devices.is_empty() {
+                println!("出力デバイスが見つかりません");
+            }
+            for name in devices {
+                println!("{}", name);
+            }
+        }
+        Some(Commands::Completions {
+            shell,
+            install,
+            force,
+        }) => {
+            if install {
+                let home = dirs::home_dir().context("ホームディレクトリが見つかりません")?;
+                let path = cli::completions::install(shell, &home, force)?;
+                println!("* 補完スクリプトをインストールしました: {}", path.display());
+                println!("  {}", cli::completions::source_hint(shell, &path));
+            } else {
+                cli::completions::generate(shell, &mut std::io::stdout());
+            }
         }
         None => {
             // No command provided, show help
@@ -104,14 +446,48 @@ async fn execute(cli: Cli) -> Result<()> {
     Ok(())
 }
 
-/// Generates shell completion scripts.
-fn generate_completions(shell: clap_complete::Shell) {
-    use clap_complete::generate;
-    use std::io;
+/// Requests notification permission for `setup notifications`.
+///
+/// `main` runs on a `current_thread` runtime, so this is awaited on the main
+/// thread as macOS requires.
+#[cfg(target_os = "macos")]
+async fn setup_notifications() -> Result<()> {
+    match notification::NotificationManager::request_authorization().await {
+        Ok(granted) => {
+            Display::show_notification_setup(granted);
+            if !granted {
+                anyhow::bail!("通知許可が拒否されています");
+            }
+            Ok(())
+        }
+        Err(e) => anyhow::bail!("{}（{}）", e, e.suggestion()),
+    }
+}
+
+/// Notifications need no setup outside macOS.
+#[cfg(not(target_os = "macos"))]
+async fn setup_notifications() -> Result<()> {
+    anyhow::bail!("通知の設定はmacOSでのみ利用できます")
+}
 
-    let mut cmd = Cli::command();
-    let bin_name = cmd.get_name().to_string();
-    generate(shell, &mut cmd, bin_name, &mut io::stdout());
+/// Lists or clears the notification center for `notifications`.
+#[cfg(target_os = "macos")]
+async fn manage_notifications(command: NotificationsCommand) -> Result<()> {
+    let store = cli::notifications::SystemNotificationStore;
+    match command {
+        NotificationsCommand::List => cli::notifications::list(&store, std::io::stdout()).await,
+        NotificationsCommand::Clear => {
+            let count = cli::notifications::clear(&store).await?;
+            println!("* 通知を{}件削除しました", count);
+            Ok(())
+        }
+    }
+}
+
+/// There is no notification center to inspect outside macOS.
+#[cfg(not(target_os = "macos"))]
+async fn manage_notifications(_command: NotificationsCommand) -> Result<()> {
+    anyhow::bail!("通知の一覧と削除はmacOSでのみ利用できます")
 }
 
 // ============================================================================
@@ -122,6 +498,55 @@ fn generate_completions(shell: clap_complete::Shell) {
 mod tests {
     use super::*;
 
+    #[cfg(not(target_os = "macos"))]
+    #[tokio::test]
+    async fn test_setup_notifications_requires_macos() {
+        let error = setup_notifications().await.unwrap_err();
+        assert!(error.to_string().contains("macOS"));
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[tokio::test]
+    async fn test_manage_notifications_requires_macos() {
+        let error = manage_notifications(NotificationsCommand::List)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("macOS"));
+    }
+
+    #[test]
+    fn test_file_subscriber_writes_log_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("daemon.log");
+
+        let subscriber =
+            file_subscriber(&path, tracing_subscriber::EnvFilter::new("info")).unwrap();
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("Daemonを起動しました");
+            tracing::debug!("フィルタで除外される");
+        });
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("Daemonを起動しました"));
+        assert!(!contents.contains("フィルタで除外される"));
+        assert!(!contents.contains("\x1b["));
+    }
+
+    #[test]
+    fn test_file_subscriber_appends() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("daemon.log");
+        std::fs::write(&path, "previous run\n").unwrap();
+
+        let subscriber =
+            file_subscriber(&path, tracing_subscriber::EnvFilter::new("info")).unwrap();
+        tracing::subscriber::with_default(subscriber, || tracing::info!("next run"));
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with("previous run\n"));
+        assert!(contents.contains("next run"));
+    }
+
     #[test]
     fn test_cli_parse_no_args() {
         let cli = Cli::parse_from(["pomodoro"]);
@@ -131,7 +556,7 @@ mod tests {
     #[test]
     fn test_cli_parse_status() {
         let cli = Cli::parse_from(["pomodoro", "status"]);
-        assert!(matches!(cli.command, Some(Commands::Status)));
+        assert!(matches!(cli.command, Some(Commands::Status(_))));
     }
 
     #[test]
@@ -145,7 +570,7 @@ mod tests {
         let cli = Cli::parse_from(["pomodoro", "start", "--work", "30", "--task", "Test"]);
         match cli.command {
             Some(Commands::Start(args)) => {
-                assert_eq!(args.work, 30);
+                assert_eq!(args.work, Some(30));
                 assert_eq!(args.task, Some("Test".to_string()));
             }
             _ => panic!("Expected Start command"),
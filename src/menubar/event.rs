@@ -6,9 +6,16 @@
 //!
 //! The event types and command mapping are platform-independent.
 //! Actual event handling with tray-icon is done in the platform-specific code.
+//!
+//! Tray updates are fanned out by [`TrayUpdateFanout`], so the native tray
+//! and any other observer (a debug logger, a test capture) each get a copy.
 
 use std::fmt;
 
+use crossbeam_channel::Sender;
+
+use crate::types::TimerPhase;
+
 // ============================================================================
 // MenuAction
 // ============================================================================
@@ -168,12 +175,54 @@ impl EventHandler {
 pub enum TrayUpdate {
     /// Update the title text displayed in the menu bar
     SetTitle(String),
+    /// Swap the template icon for the given phase
+    SetPhase(TimerPhase),
     /// Update the menu items (rebuild the menu)
     RebuildMenu,
     /// Shutdown the tray icon
     Shutdown,
 }
 
+// ============================================================================
+// TrayUpdateFanout
+// ============================================================================
+
+/// Sends every tray update to all registered observers.
+///
+/// Each observer is the sending half of its own channel, e.g. the one the
+/// [`TrayIconManager`](super::TrayIconManager) receives from. An observer
+/// whose receiver has been dropped is removed on the next send.
+#[derive(Debug, Clone, Default)]
+pub struct TrayUpdateFanout {
+    observers: Vec<Sender<TrayUpdate>>,
+}
+
+impl TrayUpdateFanout {
+    /// Creates a fan-out without observers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an observer that receives every following update.
+    pub fn add_observer(&mut self, observer: Sender<TrayUpdate>) {
+        self.observers.push(observer);
+    }
+
+    /// Returns the number of registered observers.
+    pub fn observer_count(&self) -> usize {
+        self.observers.len()
+    }
+
+    /// Sends `update` to every observer.
+    ///
+    /// Returns the number of observers that received it.
+    pub fn send(&mut self, update: TrayUpdate) -> usize {
+        self.observers
+            .retain(|observer| observer.send(update.clone()).is_ok());
+        self.observers.len()
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -363,6 +412,39 @@ mod tests {
             assert!(matches!(update, TrayUpdate::Shutdown));
         }
 
+        #[test]
+        fn test_fanout_reaches_every_observer() {
+            let (tray_tx, tray_rx) = crossbeam_channel::unbounded();
+            let (log_tx, log_rx) = crossbeam_channel::unbounded();
+            let mut fanout = TrayUpdateFanout::new();
+            fanout.add_observer(tray_tx);
+            fanout.add_observer(log_tx);
+
+            let delivered = fanout.send(TrayUpdate::SetTitle("🍅 15:30".to_string()));
+
+            assert_eq!(delivered, 2);
+            for rx in [&tray_rx, &log_rx] {
+                match rx.try_recv().unwrap() {
+                    TrayUpdate::SetTitle(title) => assert_eq!(title, "🍅 15:30"),
+                    _ => panic!("Expected SetTitle"),
+                }
+            }
+        }
+
+        #[test]
+        fn test_fanout_drops_disconnected_observer() {
+            let (tray_tx, tray_rx) = crossbeam_channel::unbounded();
+            let (log_tx, log_rx) = crossbeam_channel::unbounded();
+            let mut fanout = TrayUpdateFanout::new();
+            fanout.add_observer(tray_tx);
+            fanout.add_observer(log_tx);
+            drop(log_rx);
+
+            assert_eq!(fanout.send(TrayUpdate::RebuildMenu), 1);
+            assert_eq!(fanout.observer_count(), 1);
+            assert!(matches!(tray_rx.try_recv(), Ok(TrayUpdate::RebuildMenu)));
+        }
+
         #[test]
         fn test_clone() {
             let update = TrayUpdate::SetTitle("test".to_string());
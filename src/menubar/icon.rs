@@ -3,8 +3,16 @@
 //! This module handles:
 //! - Generating display text for the menu bar (e.g., "🍅 15:30")
 //! - Managing icon state based on timer phase
+//! - Selecting and decoding the embedded template icons
+//! - Choosing the title glyphs ([`IconStyle`]) for terminals without emoji
+//! - Choosing whether the time shows seconds ([`ShowSeconds`])
 //!
-//! The text generation logic is platform-independent and fully testable.
+//! The text generation and icon selection logic is platform-independent
+//! and fully testable.
+
+use anyhow::{bail, Context};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{TimerPhase, TimerState};
 
@@ -21,6 +29,110 @@ const BREAK_EMOJI: &str = "☕";
 /// Emoji for paused/stopped state
 const STOPPED_EMOJI: &str = "⏸";
 
+/// Nerd Font glyph for work session (nf-fa-clock_o)
+const WORKING_NERDFONT: &str = "\u{f017}";
+
+/// Nerd Font glyph for break session (nf-fa-coffee)
+const BREAK_NERDFONT: &str = "\u{f0f4}";
+
+/// Nerd Font glyph for paused/stopped state (nf-fa-pause)
+const STOPPED_NERDFONT: &str = "\u{f04c}";
+
+/// Emoji for a cycle that just completed
+const DONE_EMOJI: &str = "✅";
+
+/// Nerd Font glyph for a cycle that just completed (nf-fa-check)
+const DONE_NERDFONT: &str = "\u{f00c}";
+
+/// Tooltip of the menu bar item
+const TOOLTIP: &str = "ポモドーロタイマー";
+
+/// Template icon for work sessions (tomato)
+pub const WORKING_ICON: &[u8] = include_bytes!("../../assets/icons/working.png");
+
+/// Template icon for break sessions (hourglass)
+pub const BREAK_ICON: &[u8] = include_bytes!("../../assets/icons/break.png");
+
+// ============================================================================
+// IconStyle
+// ============================================================================
+
+/// Glyph set for the phase prefix of the title (`--icon-style`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum IconStyle {
+    /// 🍅 / ☕ / ⏸
+    #[default]
+    Emoji,
+    /// [W] / [B] / [-], for terminals without emoji support
+    Ascii,
+    /// Nerd Font glyphs, for terminals with a patched font
+    Nerdfont,
+}
+
+impl IconStyle {
+    /// Returns the glyph for the given phase.
+    pub fn glyph(self, phase: &TimerPhase) -> &'static str {
+        match (self, phase) {
+            (Self::Emoji, TimerPhase::Working) => WORKING_EMOJI,
+            (Self::Emoji, TimerPhase::Breaking | TimerPhase::LongBreaking) => BREAK_EMOJI,
+            (Self::Emoji, TimerPhase::Paused | TimerPhase::Stopped) => STOPPED_EMOJI,
+            (Self::Ascii, TimerPhase::Working) => "[W]",
+            (Self::Ascii, TimerPhase::Breaking | TimerPhase::LongBreaking) => "[B]",
+            (Self::Ascii, TimerPhase::Paused | TimerPhase::Stopped) => "[-]",
+            (Self::Nerdfont, TimerPhase::Working) => WORKING_NERDFONT,
+            (Self::Nerdfont, TimerPhase::Breaking | TimerPhase::LongBreaking) => BREAK_NERDFONT,
+            (Self::Nerdfont, TimerPhase::Paused | TimerPhase::Stopped) => STOPPED_NERDFONT,
+        }
+    }
+
+    /// Returns the glyph for a cycle that just completed.
+    pub fn done_glyph(self) -> &'static str {
+        match self {
+            Self::Emoji => DONE_EMOJI,
+            Self::Ascii => "[v]",
+            Self::Nerdfont => DONE_NERDFONT,
+        }
+    }
+}
+
+// ============================================================================
+// ShowSeconds
+// ============================================================================
+
+/// Precision of the remaining time in titles and countdowns.
+///
+/// Without seconds the time is rounded up to whole minutes, so "15:30"
+/// becomes "16m" and the last minute still reads "1m" rather than "0m".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ShowSeconds {
+    /// "MM:SS"
+    #[default]
+    Yes,
+    /// Whole minutes ("16m")
+    No,
+}
+
+impl ShowSeconds {
+    /// Formats `remaining_seconds` at this precision.
+    pub fn format(self, remaining_seconds: u32) -> String {
+        match self {
+            ShowSeconds::Yes => IconManager::format_time(remaining_seconds),
+            ShowSeconds::No => format!("{}m", remaining_seconds.div_ceil(60)),
+        }
+    }
+}
+
+impl From<bool> for ShowSeconds {
+    fn from(show: bool) -> Self {
+        if show {
+            ShowSeconds::Yes
+        } else {
+            ShowSeconds::No
+        }
+    }
+}
+
 // ============================================================================
 // IconManager
 // ============================================================================
@@ -34,12 +146,45 @@ const STOPPED_EMOJI: &str = "⏸";
 pub struct IconManager {
     /// Last known timer phase (for optimization)
     last_phase: Option<TimerPhase>,
+    /// Glyph set for the title prefix
+    style: IconStyle,
+    /// Precision of the time in titles
+    show_seconds: ShowSeconds,
 }
 
 impl IconManager {
     /// Creates a new IconManager.
     pub fn new() -> Self {
-        Self { last_phase: None }
+        Self::with_style(IconStyle::default())
+    }
+
+    /// Creates a new IconManager that uses the given glyph set.
+    pub fn with_style(style: IconStyle) -> Self {
+        Self {
+            last_phase: None,
+            style,
+            show_seconds: ShowSeconds::default(),
+        }
+    }
+
+    /// Returns the glyph set used for titles.
+    pub fn style(&self) -> IconStyle {
+        self.style
+    }
+
+    /// Changes the glyph set used for titles.
+    pub fn set_style(&mut self, style: IconStyle) {
+        self.style = style;
+    }
+
+    /// Returns whether titles show seconds.
+    pub fn show_seconds(&self) -> ShowSeconds {
+        self.show_seconds
+    }
+
+    /// Changes whether titles show seconds.
+    pub fn set_show_seconds(&mut self, show_seconds: ShowSeconds) {
+        self.show_seconds = show_seconds;
     }
 
     /// Generates the title text for display in the menu bar.
@@ -49,6 +194,11 @@ impl IconManager {
     /// - Breaking/LongBreaking: "☕ MM:SS"
     /// - Paused: "⏸ 一時停止"
     /// - Stopped: "⏸ 停止中"
+    /// - Stopped right after a cycle completed: "✅ 完了"
+    ///
+    /// The glyphs depend on the [`IconStyle`] (e.g. "[W] MM:SS" with
+    /// `ascii`); the time is formatted the same way in every style. With
+    /// [`ShowSeconds::No`] the time is whole minutes instead ("🍅 16m").
     ///
     /// # Examples
     ///
@@ -64,37 +214,88 @@ impl IconManager {
     /// // Simulate: remaining_seconds would be 930
     /// ```
     pub fn generate_title(&self, state: &TimerState) -> String {
+        let glyph = self.style.glyph(&state.phase);
         match state.phase {
-            TimerPhase::Working => {
-                let minutes = state.remaining_seconds / 60;
-                let seconds = state.remaining_seconds % 60;
-                format!("{} {:02}:{:02}", WORKING_EMOJI, minutes, seconds)
-            }
-            TimerPhase::Breaking | TimerPhase::LongBreaking => {
-                let minutes = state.remaining_seconds / 60;
-                let seconds = state.remaining_seconds % 60;
-                format!("{} {:02}:{:02}", BREAK_EMOJI, minutes, seconds)
+            TimerPhase::Working | TimerPhase::Breaking | TimerPhase::LongBreaking => {
+                format!(
+                    "{} {}",
+                    glyph,
+                    self.show_seconds.format(state.remaining_seconds)
+                )
             }
             TimerPhase::Paused => {
-                format!("{} 一時停止", STOPPED_EMOJI)
+                format!("{} 一時停止", glyph)
+            }
+            TimerPhase::Stopped if state.cycle_completed => {
+                format!("{} 完了", self.style.done_glyph())
             }
             TimerPhase::Stopped => {
-                format!("{} 停止中", STOPPED_EMOJI)
+                format!("{} 停止中", glyph)
             }
         }
     }
 
     /// Returns the appropriate emoji for the current phase.
     ///
-    /// This is useful for generating status messages or menu items.
+    /// This is useful for generating status messages or menu items. It is
+    /// always the emoji, whatever the [`IconStyle`].
     pub fn get_emoji(&self, phase: &TimerPhase) -> &'static str {
+        IconStyle::Emoji.glyph(phase)
+    }
+
+    /// Generates the title text shown next to the template icon.
+    ///
+    /// The icon already conveys the phase, so the emoji is omitted:
+    /// - Working/Breaking/LongBreaking: "MM:SS" (or "16m" without seconds)
+    /// - Paused: "一時停止"
+    /// - Stopped: "停止中" ("完了" right after a cycle completed)
+    pub fn generate_icon_title(&self, state: &TimerState) -> String {
+        match state.phase {
+            TimerPhase::Working | TimerPhase::Breaking | TimerPhase::LongBreaking => {
+                self.show_seconds.format(state.remaining_seconds)
+            }
+            TimerPhase::Paused => "一時停止".to_string(),
+            TimerPhase::Stopped if state.cycle_completed => "完了".to_string(),
+            TimerPhase::Stopped => "停止中".to_string(),
+        }
+    }
+
+    /// Generates the tooltip of the menu bar item.
+    ///
+    /// The task and tag of the current run follow the app name, one per line.
+    pub fn generate_tooltip(&self, state: &TimerState) -> String {
+        let mut tooltip = TOOLTIP.to_string();
+        if let Some(task_name) = &state.task_name {
+            tooltip.push_str(&format!("\nタスク: {}", task_name));
+        }
+        if let Some(tag) = &state.tag {
+            tooltip.push_str(&format!("\nタグ: {}", tag));
+        }
+        tooltip
+    }
+
+    /// Returns the embedded PNG icon for the given phase.
+    ///
+    /// Breaks use the hourglass; every other phase uses the tomato.
+    pub fn icon_bytes(&self, phase: &TimerPhase) -> &'static [u8] {
         match phase {
-            TimerPhase::Working => WORKING_EMOJI,
-            TimerPhase::Breaking | TimerPhase::LongBreaking => BREAK_EMOJI,
-            TimerPhase::Paused | TimerPhase::Stopped => STOPPED_EMOJI,
+            TimerPhase::Breaking | TimerPhase::LongBreaking => BREAK_ICON,
+            TimerPhase::Working | TimerPhase::Paused | TimerPhase::Stopped => WORKING_ICON,
         }
     }
 
+    /// Decodes the icon for the given phase into RGBA pixels.
+    ///
+    /// Returns the pixels with the image width and height.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the embedded PNG is not 8-bit RGBA or cannot be
+    /// decoded.
+    pub fn icon_rgba(&self, phase: &TimerPhase) -> anyhow::Result<(Vec<u8>, u32, u32)> {
+        decode_rgba(self.icon_bytes(phase))
+    }
+
     /// Checks if the phase has changed since last update.
     ///
     /// This can be used to optimize icon updates - only update the icon
@@ -117,6 +318,24 @@ impl IconManager {
     }
 }
 
+/// Decodes an 8-bit RGBA PNG into raw pixels and its dimensions.
+fn decode_rgba(bytes: &[u8]) -> anyhow::Result<(Vec<u8>, u32, u32)> {
+    let mut reader = png::Decoder::new(bytes)
+        .read_info()
+        .context("アイコン画像の読み込みに失敗しました")?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buf)
+        .context("アイコン画像のデコードに失敗しました")?;
+
+    if info.color_type != png::ColorType::Rgba || info.bit_depth != png::BitDepth::Eight {
+        bail!("アイコン画像は8bit RGBAである必要があります");
+    }
+
+    buf.truncate(info.buffer_size());
+    Ok((buf, info.width, info.height))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -232,7 +451,7 @@ mod tests {
             let manager = IconManager::new();
             let config = PomodoroConfig::default();
             let mut state = TimerState::new(config);
-            state.pomodoro_count = 4; // After 4 pomodoros, get long break
+            state.total_completed_pomodoros = 4; // After 4 pomodoros, get long break
             state.start_breaking();
             // 15 minutes = 900 seconds
 
@@ -262,6 +481,22 @@ mod tests {
             let title = manager.generate_title(&state);
             assert_eq!(title, "⏸ 停止中");
         }
+
+        #[test]
+        fn test_completed_cycle_title() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.complete_cycle();
+
+            assert_eq!(IconManager::new().generate_title(&state), "✅ 完了");
+            assert_eq!(IconManager::new().generate_icon_title(&state), "完了");
+            assert_eq!(
+                IconManager::with_style(IconStyle::Ascii).generate_title(&state),
+                "[v] 完了"
+            );
+
+            state.cycle_completed = false;
+            assert_eq!(IconManager::new().generate_title(&state), "⏸ 停止中");
+        }
     }
 
     // ------------------------------------------------------------------------
@@ -302,6 +537,120 @@ mod tests {
         }
     }
 
+    // ------------------------------------------------------------------------
+    // Icon Style Tests
+    // ------------------------------------------------------------------------
+
+    mod icon_style_tests {
+        use super::*;
+
+        fn state_in(phase: TimerPhase, remaining_seconds: u32) -> TimerState {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.phase = phase;
+            state.remaining_seconds = remaining_seconds;
+            state
+        }
+
+        #[test]
+        fn test_ascii_prefix_per_phase() {
+            let manager = IconManager::with_style(IconStyle::Ascii);
+
+            let title = |phase| manager.generate_title(&state_in(phase, 930));
+            assert_eq!(title(TimerPhase::Working), "[W] 15:30");
+            assert_eq!(title(TimerPhase::Breaking), "[B] 15:30");
+            assert_eq!(title(TimerPhase::LongBreaking), "[B] 15:30");
+            assert_eq!(title(TimerPhase::Paused), "[-] 一時停止");
+            assert_eq!(title(TimerPhase::Stopped), "[-] 停止中");
+        }
+
+        #[test]
+        fn test_nerdfont_prefix_per_phase() {
+            let manager = IconManager::with_style(IconStyle::Nerdfont);
+
+            let title = |phase| manager.generate_title(&state_in(phase, 65));
+            assert_eq!(title(TimerPhase::Working), "\u{f017} 01:05");
+            assert_eq!(title(TimerPhase::Breaking), "\u{f0f4} 01:05");
+            assert_eq!(title(TimerPhase::Paused), "\u{f04c} 一時停止");
+        }
+
+        #[test]
+        fn test_time_formatting_is_the_same_in_every_style() {
+            for style in [IconStyle::Emoji, IconStyle::Ascii, IconStyle::Nerdfont] {
+                let manager = IconManager::with_style(style);
+                let title = manager.generate_title(&state_in(TimerPhase::Working, 1500));
+
+                assert_eq!(
+                    title,
+                    format!("{} 25:00", style.glyph(&TimerPhase::Working))
+                );
+            }
+            assert_eq!(IconManager::new().style(), IconStyle::Emoji);
+        }
+
+        #[test]
+        fn test_set_style_leaves_get_emoji_alone() {
+            let mut manager = IconManager::new();
+            manager.set_style(IconStyle::Ascii);
+
+            assert_eq!(manager.get_emoji(&TimerPhase::Working), "🍅");
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Template Icon Tests
+    // ------------------------------------------------------------------------
+
+    mod template_icon_tests {
+        use super::*;
+
+        #[test]
+        fn test_icon_bytes_per_phase() {
+            let manager = IconManager::new();
+            assert_eq!(manager.icon_bytes(&TimerPhase::Working), WORKING_ICON);
+            assert_eq!(manager.icon_bytes(&TimerPhase::Breaking), BREAK_ICON);
+            assert_eq!(manager.icon_bytes(&TimerPhase::LongBreaking), BREAK_ICON);
+            assert_eq!(manager.icon_bytes(&TimerPhase::Paused), WORKING_ICON);
+            assert_eq!(manager.icon_bytes(&TimerPhase::Stopped), WORKING_ICON);
+            assert_ne!(WORKING_ICON, BREAK_ICON);
+        }
+
+        #[test]
+        fn test_embedded_icons_decode() {
+            for bytes in [WORKING_ICON, BREAK_ICON] {
+                let (rgba, width, height) = decode_rgba(bytes).unwrap();
+                assert_eq!((width, height), (36, 36));
+                assert_eq!(rgba.len(), 36 * 36 * 4);
+            }
+        }
+
+        #[test]
+        fn test_icon_rgba() {
+            let manager = IconManager::new();
+            let (working, _, _) = manager.icon_rgba(&TimerPhase::Working).unwrap();
+            let (breaking, _, _) = manager.icon_rgba(&TimerPhase::Breaking).unwrap();
+            assert_ne!(working, breaking);
+        }
+
+        #[test]
+        fn test_decode_rejects_invalid_png() {
+            assert!(decode_rgba(b"not a png").is_err());
+        }
+
+        #[test]
+        fn test_icon_title_omits_emoji() {
+            let manager = IconManager::new();
+            let mut state = TimerState::new(PomodoroConfig::default());
+            assert_eq!(manager.generate_icon_title(&state), "停止中");
+
+            state.start_working(None);
+            state.remaining_seconds = 930;
+            assert_eq!(manager.generate_icon_title(&state), "15:30");
+
+            state.pause();
+            assert_eq!(manager.generate_icon_title(&state), "一時停止");
+        }
+    }
+
     // ------------------------------------------------------------------------
     // Phase Change Tests
     // ------------------------------------------------------------------------
@@ -393,4 +742,72 @@ mod tests {
             assert_eq!(IconManager::format_time(7200), "120:00");
         }
     }
+
+    // ------------------------------------------------------------------------
+    // Show Seconds Tests
+    // ------------------------------------------------------------------------
+
+    mod show_seconds_tests {
+        use super::*;
+
+        #[test]
+        fn test_minutes_round_up_at_boundaries() {
+            assert_eq!(ShowSeconds::No.format(59), "1m");
+            assert_eq!(ShowSeconds::No.format(60), "1m");
+            assert_eq!(ShowSeconds::No.format(61), "2m");
+            assert_eq!(ShowSeconds::No.format(930), "16m");
+            assert_eq!(ShowSeconds::No.format(0), "0m");
+        }
+
+        #[test]
+        fn test_seconds_keep_mm_ss() {
+            assert_eq!(ShowSeconds::Yes.format(59), "00:59");
+            assert_eq!(ShowSeconds::Yes.format(60), "01:00");
+            assert_eq!(ShowSeconds::from(true), ShowSeconds::Yes);
+            assert_eq!(ShowSeconds::from(false), ShowSeconds::No);
+        }
+
+        #[test]
+        fn test_titles_without_seconds() {
+            let mut manager = IconManager::new();
+            manager.set_show_seconds(ShowSeconds::No);
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.start_working(None);
+            state.remaining_seconds = 930;
+
+            assert_eq!(manager.generate_title(&state), "🍅 16m");
+            assert_eq!(manager.generate_icon_title(&state), "16m");
+
+            state.pause();
+            assert_eq!(manager.generate_title(&state), "⏸ 一時停止");
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Tooltip Tests
+    // ------------------------------------------------------------------------
+
+    mod tooltip_tests {
+        use super::*;
+
+        #[test]
+        fn test_tooltip_names_task_and_tag() {
+            let manager = IconManager::new();
+            let mut state = TimerState::new(PomodoroConfig::default());
+            assert_eq!(manager.generate_tooltip(&state), "ポモドーロタイマー");
+
+            state.start_working(Some("Docs".to_string()));
+            state.tag = Some("project-x".to_string());
+            assert_eq!(
+                manager.generate_tooltip(&state),
+                "ポモドーロタイマー\nタスク: Docs\nタグ: project-x"
+            );
+
+            state.task_name = None;
+            assert_eq!(
+                manager.generate_tooltip(&state),
+                "ポモドーロタイマー\nタグ: project-x"
+            );
+        }
+    }
 }
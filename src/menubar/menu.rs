@@ -56,6 +56,25 @@ pub struct MenuConfig {
     pub quit: MenuItemConfig,
 }
 
+// ============================================================================
+// Progress Bar
+// ============================================================================
+
+/// Number of cells in the progress bar
+const PROGRESS_BAR_WIDTH: usize = 10;
+
+/// Formats an elapsed fraction (0.0-1.0) as a text progress bar, e.g. "▓▓▓▓░░░░░░ 40%".
+pub fn format_progress_bar(fraction: f32) -> String {
+    let fraction = fraction.clamp(0.0, 1.0);
+    let filled = (fraction * PROGRESS_BAR_WIDTH as f32).round() as usize;
+    format!(
+        "{}{} {}%",
+        "▓".repeat(filled),
+        "░".repeat(PROGRESS_BAR_WIDTH - filled),
+        (fraction * 100.0).round() as u32
+    )
+}
+
 // ============================================================================
 // MenuBuilder
 // ============================================================================
@@ -129,6 +148,12 @@ impl MenuBuilder {
                     false,
                 ));
 
+                // Progress of the current phase
+                items.push(MenuItemConfig::new(
+                    format!("進捗: {}", format_progress_bar(state.progress_fraction())),
+                    false,
+                ));
+
                 // Pomodoro count
                 items.push(MenuItemConfig::new(
                     format!("ポモドーロ: #{}", state.pomodoro_count),
@@ -142,12 +167,9 @@ impl MenuBuilder {
 
     /// Builds the pause menu item.
     ///
-    /// Enabled when: Working or Breaking/LongBreaking
+    /// Enabled when: Working or Breaking/LongBreaking, and not in strict mode
     fn build_pause_item(&self, state: &TimerState) -> MenuItemConfig {
-        let enabled = matches!(
-            state.phase,
-            TimerPhase::Working | TimerPhase::Breaking | TimerPhase::LongBreaking
-        );
+        let enabled = Self::is_pause_enabled(&state.phase) && !state.config.strict;
         MenuItemConfig::new("⏸ 一時停止", enabled)
     }
 
@@ -281,6 +303,27 @@ mod tests {
         }
     }
 
+    // ------------------------------------------------------------------------
+    // Progress Bar Tests
+    // ------------------------------------------------------------------------
+
+    mod progress_bar_tests {
+        use super::*;
+
+        #[test]
+        fn test_format_progress_bar() {
+            assert_eq!(format_progress_bar(0.0), "░░░░░░░░░░ 0%");
+            assert_eq!(format_progress_bar(0.5), "▓▓▓▓▓░░░░░ 50%");
+            assert_eq!(format_progress_bar(1.0), "▓▓▓▓▓▓▓▓▓▓ 100%");
+        }
+
+        #[test]
+        fn test_format_progress_bar_clamps_out_of_range() {
+            assert_eq!(format_progress_bar(-0.5), format_progress_bar(0.0));
+            assert_eq!(format_progress_bar(1.5), format_progress_bar(1.0));
+        }
+    }
+
     // ------------------------------------------------------------------------
     // Menu Build Tests - Working State
     // ------------------------------------------------------------------------
@@ -299,10 +342,11 @@ mod tests {
             let config = builder.build(&state);
 
             // Status items
-            assert_eq!(config.status_items.len(), 3);
+            assert_eq!(config.status_items.len(), 4);
             assert_eq!(config.status_items[0].text, "作業中: API実装");
             assert_eq!(config.status_items[1].text, "残り時間: 15:30");
-            assert_eq!(config.status_items[2].text, "ポモドーロ: #2");
+            assert_eq!(config.status_items[2].text, "進捗: ▓▓▓▓░░░░░░ 38%");
+            assert_eq!(config.status_items[3].text, "ポモドーロ: #2");
 
             // Pause enabled
             assert!(config.pause.enabled);
@@ -324,9 +368,10 @@ mod tests {
             let config = builder.build(&state);
 
             // Status items (no task name)
-            assert_eq!(config.status_items.len(), 2);
+            assert_eq!(config.status_items.len(), 3);
             assert_eq!(config.status_items[0].text, "残り時間: 25:00");
-            assert_eq!(config.status_items[1].text, "ポモドーロ: #0");
+            assert_eq!(config.status_items[1].text, "進捗: ░░░░░░░░░░ 0%");
+            assert_eq!(config.status_items[2].text, "ポモドーロ: #0");
         }
     }
 
@@ -348,9 +393,10 @@ mod tests {
             let config = builder.build(&state);
 
             // Status items
-            assert_eq!(config.status_items.len(), 2);
+            assert_eq!(config.status_items.len(), 3);
             assert_eq!(config.status_items[0].text, "残り時間: 04:30");
-            assert_eq!(config.status_items[1].text, "ポモドーロ: #1");
+            assert_eq!(config.status_items[1].text, "進捗: ▓░░░░░░░░░ 10%");
+            assert_eq!(config.status_items[2].text, "ポモドーロ: #1");
 
             // Pause enabled (can pause during break)
             assert!(config.pause.enabled);
@@ -419,10 +465,11 @@ mod tests {
             let config = builder.build(&state);
 
             // Status items
-            assert_eq!(config.status_items.len(), 3);
+            assert_eq!(config.status_items.len(), 4);
             assert!(config.status_items[0].text.contains("一時停止"));
             assert_eq!(config.status_items[1].text, "残り時間: 08:20");
-            assert_eq!(config.status_items[2].text, "ポモドーロ: #1");
+            assert_eq!(config.status_items[2].text, "進捗: ▓▓▓▓▓▓▓░░░ 67%");
+            assert_eq!(config.status_items[3].text, "ポモドーロ: #1");
 
             // Pause disabled
             assert!(!config.pause.enabled);
@@ -433,6 +480,18 @@ mod tests {
             // Stop enabled
             assert!(config.stop.enabled);
         }
+
+        #[test]
+        fn test_pause_disabled_in_strict_mode() {
+            let builder = MenuBuilder::new();
+            let mut state = TimerState::new(PomodoroConfig::default().with_strict(true));
+            state.start_working(None);
+
+            let config = builder.build(&state);
+
+            assert!(!config.pause.enabled);
+            assert!(config.stop.enabled);
+        }
     }
 
     // ------------------------------------------------------------------------
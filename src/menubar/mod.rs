@@ -10,7 +10,7 @@
 //!
 //! The module is split into platform-independent and platform-specific parts:
 //!
-//! - `icon.rs`: Title text generation (platform-independent, fully testable)
+//! - `icon.rs`: Title text and template icon selection (platform-independent, fully testable)
 //! - `menu.rs`: Menu configuration (platform-independent, fully testable)
 //! - `event.rs`: Event types and handling (platform-independent, fully testable)
 //! - `mod.rs`: TrayIconManager (platform-specific on macOS)
@@ -18,22 +18,27 @@
 //! # Usage
 //!
 //! The tray icon is created and managed by the daemon. Updates are sent via
-//! a crossbeam channel from the timer engine (running in tokio) to the tray
-//! icon (running on the main thread).
+//! crossbeam channels from the timer engine (running in tokio) to the tray
+//! icon (running on the main thread). A [`TrayUpdateFanout`] delivers each
+//! update to every registered observer, so other UIs can listen as well.
 //!
 //! ```ignore
-//! use pomodoro::menubar::{TrayIconManager, TrayUpdate};
+//! use pomodoro::menubar::{TrayIconManager, TrayUpdate, TrayUpdateFanout};
 //! use crossbeam_channel::unbounded;
 //!
-//! // Create channel for updates
-//! let (tx, rx) = unbounded();
+//! // One channel per observer
+//! let (tray_tx, tray_rx) = unbounded();
+//! let (log_tx, log_rx) = unbounded();
+//! let mut fanout = TrayUpdateFanout::new();
+//! fanout.add_observer(tray_tx);
+//! fanout.add_observer(log_tx);
 //!
 //! // Create manager (on main thread, macOS only)
-//! let mut manager = TrayIconManager::new(rx)?;
+//! let mut manager = TrayIconManager::new(state, tray_rx);
 //! manager.initialize()?;
 //!
 //! // From timer engine (tokio task)
-//! tx.send(TrayUpdate::SetTitle("🍅 15:30".to_string()))?;
+//! fanout.send(TrayUpdate::SetTitle("🍅 15:30".to_string()));
 //! ```
 
 pub mod event;
@@ -41,11 +46,11 @@ pub mod icon;
 pub mod menu;
 
 // Re-export main types
-pub use event::{EventHandler, MenuAction, MenuItemId, TrayUpdate};
-pub use icon::IconManager;
-pub use menu::{MenuBuilder, MenuConfig, MenuItemConfig};
+pub use event::{EventHandler, MenuAction, MenuItemId, TrayUpdate, TrayUpdateFanout};
+pub use icon::{IconManager, IconStyle, ShowSeconds};
+pub use menu::{format_progress_bar, MenuBuilder, MenuConfig, MenuItemConfig};
 
-use crate::types::TimerState;
+use crate::types::{TimerPhase, TimerState};
 use crossbeam_channel::Receiver;
 use std::sync::{Arc, RwLock};
 
@@ -75,6 +80,8 @@ pub struct TrayIconManager {
     update_rx: Receiver<TrayUpdate>,
     /// Whether the manager is initialized
     initialized: bool,
+    /// Whether the template icon is shown (false means text-only fallback)
+    icon_enabled: bool,
     /// Platform-specific tray icon instance (macOS only)
     #[cfg(target_os = "macos")]
     tray_icon: Option<tray_icon::TrayIcon>,
@@ -101,6 +108,7 @@ impl TrayIconManager {
             current_state: Arc::new(RwLock::new(initial_state)),
             update_rx,
             initialized: false,
+            icon_enabled: false,
             #[cfg(target_os = "macos")]
             tray_icon: None,
         }
@@ -116,6 +124,11 @@ impl TrayIconManager {
         self.initialized
     }
 
+    /// Returns whether the template icon is shown instead of emoji text.
+    pub fn is_icon_enabled(&self) -> bool {
+        self.icon_enabled
+    }
+
     /// Returns a reference to the icon manager.
     pub fn icon_manager(&self) -> &IconManager {
         &self.icon_manager
@@ -137,9 +150,22 @@ impl TrayIconManager {
     }
 
     /// Generates the current title for the menu bar.
+    ///
+    /// With the template icon shown, the title is the countdown only;
+    /// otherwise it falls back to the emoji title.
     pub fn generate_title(&self) -> String {
         let state = self.current_state.read().unwrap();
-        self.icon_manager.generate_title(&state)
+        if self.icon_enabled {
+            self.icon_manager.generate_icon_title(&state)
+        } else {
+            self.icon_manager.generate_title(&state)
+        }
+    }
+
+    /// Generates the current tooltip (task and tag of the current run).
+    pub fn generate_tooltip(&self) -> String {
+        let state = self.current_state.read().unwrap();
+        self.icon_manager.generate_tooltip(&state)
     }
 
     /// Generates the current menu configuration.
@@ -181,6 +207,17 @@ impl TrayIconManager {
                     tray_icon.set_title(Some(&title));
                 }
             }
+            TrayUpdate::SetPhase(phase) => {
+                tracing::debug!(phase = %phase.as_str(), "メニューバーアイコン更新");
+                self.set_icon(&phase);
+                // A new run starts with a phase change, so the task and tag follow it
+                #[cfg(target_os = "macos")]
+                if let Some(ref tray_icon) = self.tray_icon {
+                    if let Err(e) = tray_icon.set_tooltip(Some(self.generate_tooltip())) {
+                        tracing::warn!("ツールチップの更新に失敗しました: {}", e);
+                    }
+                }
+            }
             TrayUpdate::RebuildMenu => {
                 tracing::debug!("メニュー再構築");
                 // Menu rebuilding is handled by the platform-specific code
@@ -214,18 +251,32 @@ impl TrayIconManager {
         use tray_icon::TrayIconBuilder;
 
         let state = self.current_state.read().unwrap();
-        let title = self.icon_manager.generate_title(&state);
+        let phase = state.phase;
         let menu_config = self.menu_builder.build(&state);
         drop(state);
 
         // Build the menu
         let menu = self.build_native_menu(&menu_config)?;
 
-        // Create the tray icon
-        let tray_icon = TrayIconBuilder::new()
-            .with_title(&title)
+        // Fall back to the emoji title if the template icon cannot be loaded
+        let mut builder = TrayIconBuilder::new()
             .with_menu(Box::new(menu))
-            .with_tooltip("ポモドーロタイマー")
+            .with_tooltip(self.generate_tooltip());
+        match load_tray_icon(&self.icon_manager, &phase) {
+            Ok(icon) => {
+                builder = builder.with_icon(icon).with_icon_as_template(true);
+                self.icon_enabled = true;
+            }
+            Err(e) => {
+                tracing::warn!("アイコンを読み込めないためテキスト表示にします: {:#}", e);
+                self.icon_enabled = false;
+            }
+        }
+        self.icon_manager.phase_changed(&phase);
+
+        // Create the tray icon
+        let tray_icon = builder
+            .with_title(self.generate_title())
             .build()
             .context("トレイアイコンの作成に失敗しました")?;
 
@@ -244,6 +295,39 @@ impl TrayIconManager {
         Ok(())
     }
 
+    /// Swaps the template icon to match the given phase (macOS only).
+    ///
+    /// Does nothing in text-only mode or when the phase is unchanged. If the
+    /// new icon cannot be set, the manager falls back to the emoji title.
+    #[cfg(target_os = "macos")]
+    pub fn set_icon(&mut self, phase: &TimerPhase) {
+        if !self.icon_enabled || !self.icon_manager.phase_changed(phase) {
+            return;
+        }
+
+        if let Some(ref tray_icon) = self.tray_icon {
+            use anyhow::Context;
+
+            let result = load_tray_icon(&self.icon_manager, phase).and_then(|icon| {
+                tray_icon
+                    .set_icon_with_as_template(Some(icon), true)
+                    .context("アイコンの設定に失敗しました")
+            });
+            if let Err(e) = result {
+                tracing::warn!("アイコンを更新できないためテキスト表示にします: {:#}", e);
+                self.icon_enabled = false;
+                let _ = tray_icon.set_icon(None);
+                tray_icon.set_title(Some(self.generate_title()));
+            }
+        }
+    }
+
+    /// Tracks the phase without an icon (non-macOS, text-only).
+    #[cfg(not(target_os = "macos"))]
+    pub fn set_icon(&mut self, phase: &TimerPhase) {
+        self.icon_manager.phase_changed(phase);
+    }
+
     /// Builds a native menu from the configuration (macOS only).
     #[cfg(target_os = "macos")]
     fn build_native_menu(&self, config: &MenuConfig) -> anyhow::Result<tray_icon::menu::Menu> {
@@ -285,6 +369,18 @@ impl TrayIconManager {
     }
 }
 
+/// Loads the template icon for a phase as a tray icon image (macOS only).
+#[cfg(target_os = "macos")]
+fn load_tray_icon(
+    icon_manager: &IconManager,
+    phase: &TimerPhase,
+) -> anyhow::Result<tray_icon::Icon> {
+    use anyhow::Context;
+
+    let (rgba, width, height) = icon_manager.icon_rgba(phase)?;
+    tray_icon::Icon::from_rgba(rgba, width, height).context("アイコン画像の作成に失敗しました")
+}
+
 impl std::fmt::Debug for TrayIconManager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TrayIconManager")
@@ -344,6 +440,30 @@ mod tests {
             assert_eq!(title, "🍅 15:30");
         }
 
+        #[test]
+        fn test_generate_title_with_icon() {
+            let (_, rx) = unbounded();
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.start_working(None);
+            state.remaining_seconds = 930;
+            let mut manager = TrayIconManager::new(state, rx);
+            manager.icon_enabled = true;
+
+            assert_eq!(manager.generate_title(), "15:30");
+        }
+
+        #[test]
+        fn test_set_icon_without_icon_is_noop() {
+            let (_, rx) = unbounded();
+            let state = TimerState::new(PomodoroConfig::default());
+            let mut manager = TrayIconManager::new(state, rx);
+
+            manager.set_icon(&TimerPhase::Breaking);
+
+            assert!(!manager.is_icon_enabled());
+            assert_eq!(manager.generate_title(), "⏸ 停止中");
+        }
+
         #[test]
         fn test_generate_menu_config() {
             let (_, rx) = unbounded();
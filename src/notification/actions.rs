@@ -7,9 +7,14 @@ use objc2_user_notifications::{
     UNNotificationCategoryOptions,
 };
 
+use super::delegate::EXTEND_WORK_MINUTES;
+
 pub mod action_ids {
     pub const PAUSE: &str = "PAUSE_ACTION";
     pub const STOP: &str = "STOP_ACTION";
+    pub const RESUME: &str = "RESUME_ACTION";
+    pub const START_BREAK: &str = "START_BREAK_ACTION";
+    pub const EXTEND_WORK: &str = "EXTEND_WORK_ACTION";
 }
 
 pub mod category_ids {
@@ -43,8 +48,83 @@ pub fn create_stop_action() -> Retained<UNNotificationAction> {
 }
 
 #[must_use]
-pub fn create_actions() -> Vec<Retained<UNNotificationAction>> {
-    vec![create_pause_action(), create_stop_action()]
+pub fn create_resume_action() -> Retained<UNNotificationAction> {
+    let identifier = NSString::from_str(action_ids::RESUME);
+    let title = NSString::from_str("再開");
+
+    UNNotificationAction::actionWithIdentifier_title_options(
+        &identifier,
+        &title,
+        UNNotificationActionOptions::Foreground,
+    )
+}
+
+#[must_use]
+pub fn create_start_break_action() -> Retained<UNNotificationAction> {
+    let identifier = NSString::from_str(action_ids::START_BREAK);
+    let title = NSString::from_str("休憩を開始");
+
+    UNNotificationAction::actionWithIdentifier_title_options(
+        &identifier,
+        &title,
+        UNNotificationActionOptions::Foreground,
+    )
+}
+
+#[must_use]
+pub fn create_extend_work_action() -> Retained<UNNotificationAction> {
+    let identifier = NSString::from_str(action_ids::EXTEND_WORK);
+    let title = NSString::from_str(&format!("+{}分", EXTEND_WORK_MINUTES));
+
+    UNNotificationAction::actionWithIdentifier_title_options(
+        &identifier,
+        &title,
+        UNNotificationActionOptions::empty(),
+    )
+}
+
+/// Returns the action IDs shown for the timer state.
+///
+/// A running timer offers pause and stop; a paused one offers resume and stop.
+#[must_use]
+pub fn action_ids_for(paused: bool) -> [&'static str; 2] {
+    if paused {
+        [action_ids::RESUME, action_ids::STOP]
+    } else {
+        [action_ids::PAUSE, action_ids::STOP]
+    }
+}
+
+/// Returns the action IDs of the work complete notification.
+///
+/// The first action goes back to the completed session for a few more
+/// minutes. With `prompt_break` the timer waits after a work session, so
+/// instead of pause it offers to start the break.
+#[must_use]
+pub fn work_complete_action_ids(paused: bool, prompt_break: bool) -> [&'static str; 3] {
+    let [first, stop] = if prompt_break {
+        [action_ids::START_BREAK, action_ids::STOP]
+    } else {
+        action_ids_for(paused)
+    };
+    [action_ids::EXTEND_WORK, first, stop]
+}
+
+fn create_actions_for(ids: &[&str]) -> Vec<Retained<UNNotificationAction>> {
+    ids.iter()
+        .map(|&id| match id {
+            action_ids::PAUSE => create_pause_action(),
+            action_ids::RESUME => create_resume_action(),
+            action_ids::START_BREAK => create_start_break_action(),
+            action_ids::EXTEND_WORK => create_extend_work_action(),
+            _ => create_stop_action(),
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn create_actions(paused: bool) -> Vec<Retained<UNNotificationAction>> {
+    create_actions_for(&action_ids_for(paused))
 }
 
 fn create_category(
@@ -66,12 +146,20 @@ fn create_category(
     )
 }
 
+/// Creates the notification categories with the actions for the timer state.
+///
+/// Registering them again replaces the action buttons of all categories.
+/// See [`work_complete_action_ids`] for `prompt_break`.
 #[must_use]
-pub fn create_categories() -> Vec<Retained<UNNotificationCategory>> {
-    let actions = create_actions();
+pub fn create_categories(
+    paused: bool,
+    prompt_break: bool,
+) -> Vec<Retained<UNNotificationCategory>> {
+    let actions = create_actions(paused);
+    let work_complete_actions = create_actions_for(&work_complete_action_ids(paused, prompt_break));
 
     vec![
-        create_category(category_ids::WORK_COMPLETE, &actions),
+        create_category(category_ids::WORK_COMPLETE, &work_complete_actions),
         create_category(category_ids::BREAK_COMPLETE, &actions),
         create_category(category_ids::LONG_BREAK_COMPLETE, &actions),
     ]
@@ -85,6 +173,35 @@ mod tests {
     fn test_action_ids() {
         assert_eq!(action_ids::PAUSE, "PAUSE_ACTION");
         assert_eq!(action_ids::STOP, "STOP_ACTION");
+        assert_eq!(action_ids::RESUME, "RESUME_ACTION");
+        assert_eq!(action_ids::START_BREAK, "START_BREAK_ACTION");
+        assert_eq!(action_ids::EXTEND_WORK, "EXTEND_WORK_ACTION");
+    }
+
+    #[test]
+    fn test_action_ids_for_state() {
+        assert_eq!(action_ids_for(false), [action_ids::PAUSE, action_ids::STOP]);
+        assert_eq!(action_ids_for(true), [action_ids::RESUME, action_ids::STOP]);
+    }
+
+    #[test]
+    fn test_work_complete_offers_start_break_with_prompt() {
+        assert_eq!(
+            work_complete_action_ids(false, true),
+            [
+                action_ids::EXTEND_WORK,
+                action_ids::START_BREAK,
+                action_ids::STOP
+            ]
+        );
+        assert_eq!(
+            work_complete_action_ids(true, false),
+            [
+                action_ids::EXTEND_WORK,
+                action_ids::RESUME,
+                action_ids::STOP
+            ]
+        );
     }
 
     #[test]
@@ -2,19 +2,42 @@
 
 use std::cell::RefCell;
 use std::ptr::NonNull;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use block2::RcBlock;
 use objc2::rc::Retained;
 use objc2::runtime::{Bool, ProtocolObject};
-use objc2_foundation::{NSError, NSSet};
+use objc2_foundation::{NSArray, NSError, NSSet, NSString};
 use objc2_user_notifications::{
-    UNAuthorizationOptions, UNAuthorizationStatus, UNNotificationCategory, UNNotificationRequest,
-    UNNotificationSettings, UNUserNotificationCenter, UNUserNotificationCenterDelegate,
+    UNAuthorizationOptions, UNAuthorizationStatus, UNNotification, UNNotificationCategory,
+    UNNotificationRequest, UNNotificationSettings, UNUserNotificationCenter,
+    UNUserNotificationCenterDelegate,
 };
 use tokio::sync::oneshot;
 
 use super::error::NotificationError;
 
+/// A notification request known to the notification center.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationSummary {
+    /// Request identifier (see [`super::NotificationType::identifier`])
+    pub identifier: String,
+    /// Notification title
+    pub title: String,
+    /// When it was delivered (`None` while still pending)
+    pub delivered_at: Option<SystemTime>,
+}
+
+impl NotificationSummary {
+    fn from_request(request: &UNNotificationRequest, delivered_at: Option<SystemTime>) -> Self {
+        Self {
+            identifier: request.identifier().to_string(),
+            title: request.content().title().to_string(),
+            delivered_at,
+        }
+    }
+}
+
 pub struct NotificationCenter;
 
 impl NotificationCenter {
@@ -115,6 +138,59 @@ impl NotificationCenter {
             .map_err(|_| NotificationError::SendFailed("Channel closed".to_string()))?
     }
 
+    /// Returns the notifications still shown in Notification Center.
+    pub async fn delivered_notifications() -> Result<Vec<NotificationSummary>, NotificationError> {
+        let (tx, rx) = oneshot::channel::<Vec<NotificationSummary>>();
+
+        let cb = RefCell::new(Some(tx));
+        let block = RcBlock::new(move |notifications: NonNull<NSArray<UNNotification>>| {
+            if let Some(sender) = cb.borrow_mut().take() {
+                let notifications = unsafe { notifications.as_ref() };
+                let summaries = notifications
+                    .to_vec()
+                    .iter()
+                    .map(|notification| {
+                        let seconds = notification.date().timeIntervalSince1970();
+                        let delivered_at = Duration::try_from_secs_f64(seconds)
+                            .ok()
+                            .map(|since| UNIX_EPOCH + since);
+                        NotificationSummary::from_request(&notification.request(), delivered_at)
+                    })
+                    .collect();
+                let _ = sender.send(summaries);
+            }
+        });
+
+        Self::current().getDeliveredNotificationsWithCompletionHandler(&block);
+
+        rx.await
+            .map_err(|_| NotificationError::InitializationFailed("Channel closed".to_string()))
+    }
+
+    /// Returns the notification requests that have not been delivered yet.
+    pub async fn pending_notification_requests(
+    ) -> Result<Vec<NotificationSummary>, NotificationError> {
+        let (tx, rx) = oneshot::channel::<Vec<NotificationSummary>>();
+
+        let cb = RefCell::new(Some(tx));
+        let block = RcBlock::new(move |requests: NonNull<NSArray<UNNotificationRequest>>| {
+            if let Some(sender) = cb.borrow_mut().take() {
+                let requests = unsafe { requests.as_ref() };
+                let summaries = requests
+                    .to_vec()
+                    .iter()
+                    .map(|request| NotificationSummary::from_request(request, None))
+                    .collect();
+                let _ = sender.send(summaries);
+            }
+        });
+
+        Self::current().getPendingNotificationRequestsWithCompletionHandler(&block);
+
+        rx.await
+            .map_err(|_| NotificationError::InitializationFailed("Channel closed".to_string()))
+    }
+
     pub fn remove_all_pending_notifications() {
         Self::current().removeAllPendingNotificationRequests();
     }
@@ -122,6 +198,14 @@ impl NotificationCenter {
     pub fn remove_all_delivered_notifications() {
         Self::current().removeAllDeliveredNotifications();
     }
+
+    /// Removes the delivered notification with the given request identifier.
+    pub fn remove_delivered(identifier: &str) {
+        let identifier = NSString::from_str(identifier);
+        let identifiers: Retained<NSArray<NSString>> = NSArray::from_slice(&[&*identifier]);
+
+        Self::current().removeDeliveredNotificationsWithIdentifiers(&identifiers);
+    }
 }
 
 #[cfg(test)]
@@ -4,10 +4,17 @@ use objc2::rc::Retained;
 use objc2_foundation::NSString;
 use objc2_user_notifications::{UNMutableNotificationContent, UNNotificationSound};
 
+use crate::config::NotificationsConfig;
+use crate::template::render_notification_title;
+
 use super::actions::category_ids;
+use super::NotificationType;
 
 const MAX_TASK_NAME_LENGTH: usize = 100;
 
+/// Title of the reminders sent during a long break.
+pub const BREAK_REMINDER_TITLE: &str = "長い休憩中";
+
 pub struct NotificationContentBuilder {
     content: Retained<UNMutableNotificationContent>,
 }
@@ -59,6 +66,21 @@ impl NotificationContentBuilder {
         self.sound(sound)
     }
 
+    /// Attaches the named sound, or the system default if `name` is `None`.
+    ///
+    /// Notification Center looks the file up in the `Library/Sounds` folders
+    /// and plays the default sound if it is not found.
+    #[must_use]
+    pub fn sound_named(self, name: Option<&str>) -> Self {
+        match name {
+            Some(name) => {
+                let name = NSString::from_str(name);
+                self.sound(UNNotificationSound::soundNamed(&name))
+            }
+            None => self.default_sound(),
+        }
+    }
+
     #[must_use]
     pub fn build(self) -> Retained<UNMutableNotificationContent> {
         self.content
@@ -82,15 +104,92 @@ pub fn validate_task_name(task_name: &str) -> Option<String> {
     }
 }
 
+/// Title templates of the completion notifications.
+///
+/// Taken from the `*_title` keys of the `[notifications]` table; `{task}`
+/// is replaced with the task name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationTitles {
+    work_complete: String,
+    break_complete: String,
+    long_break_complete: String,
+}
+
+impl NotificationTitles {
+    /// Takes the title templates from the notification settings.
+    #[must_use]
+    pub fn from_config(config: &NotificationsConfig) -> Self {
+        Self {
+            work_complete: config.work_complete_title.clone(),
+            break_complete: config.break_complete_title.clone(),
+            long_break_complete: config.long_break_complete_title.clone(),
+        }
+    }
+
+    /// Renders the title of a notification type for a task.
+    ///
+    /// Without a (valid) task `{task}` renders as empty text. A template
+    /// that cannot be rendered is shown as written.
+    #[must_use]
+    pub fn render(&self, notification_type: NotificationType, task_name: Option<&str>) -> String {
+        let template = match notification_type {
+            NotificationType::WorkComplete => &self.work_complete,
+            NotificationType::BreakComplete => &self.break_complete,
+            NotificationType::LongBreakComplete => &self.long_break_complete,
+        };
+        let task = task_name.and_then(validate_task_name);
+        render_notification_title(template, task.as_deref()).unwrap_or_else(|_| template.clone())
+    }
+}
+
+impl Default for NotificationTitles {
+    fn default() -> Self {
+        Self::from_config(&NotificationsConfig::default())
+    }
+}
+
+/// Returns the body shown for a notification type.
+#[must_use]
+pub fn notification_body(notification_type: NotificationType) -> &'static str {
+    match notification_type {
+        NotificationType::WorkComplete => "作業時間が終了しました。休憩してください。",
+        NotificationType::BreakComplete => "休憩時間が終了しました。作業を再開してください。",
+        NotificationType::LongBreakComplete => {
+            "長い休憩時間が終了しました。作業を再開してください。"
+        }
+    }
+}
+
+/// Returns the body of a long break reminder.
+#[must_use]
+pub fn break_reminder_body(remaining_minutes: u32) -> String {
+    format!("休憩の残りは{}分です。", remaining_minutes)
+}
+
+/// Appends a break activity suggestion to a notification body.
+///
+/// A missing or blank suggestion leaves the body unchanged.
+#[must_use]
+pub fn with_break_activity(body: &str, break_activity: Option<&str>) -> String {
+    match break_activity.map(str::trim).filter(|a| !a.is_empty()) {
+        Some(activity) => format!("{} おすすめ: {}", body, activity),
+        None => body.to_string(),
+    }
+}
+
 #[must_use]
 pub fn create_work_complete_content(
+    titles: &NotificationTitles,
     task_name: Option<&str>,
+    break_activity: Option<&str>,
+    sound: Option<&str>,
 ) -> Retained<UNMutableNotificationContent> {
+    let body = notification_body(NotificationType::WorkComplete);
     let mut builder = NotificationContentBuilder::new()
-        .title("🍅 ポモドーロタイマー")
-        .body("作業時間が終了しました。休憩してください。")
+        .title(&titles.render(NotificationType::WorkComplete, task_name))
+        .body(&with_break_activity(body, break_activity))
         .category_identifier(category_ids::WORK_COMPLETE)
-        .default_sound();
+        .sound_named(sound);
 
     if let Some(task) = task_name.and_then(validate_task_name).as_deref() {
         builder = builder.subtitle(task);
@@ -101,13 +200,16 @@ pub fn create_work_complete_content(
 
 #[must_use]
 pub fn create_break_complete_content(
+    titles: &NotificationTitles,
     task_name: Option<&str>,
+    sound: Option<&str>,
 ) -> Retained<UNMutableNotificationContent> {
+    let body = notification_body(NotificationType::BreakComplete);
     let mut builder = NotificationContentBuilder::new()
-        .title("☕ ポモドーロタイマー")
-        .body("休憩時間が終了しました。作業を再開してください。")
+        .title(&titles.render(NotificationType::BreakComplete, task_name))
+        .body(body)
         .category_identifier(category_ids::BREAK_COMPLETE)
-        .default_sound();
+        .sound_named(sound);
 
     if let Some(task) = task_name.and_then(validate_task_name).as_deref() {
         builder = builder.subtitle(task);
@@ -118,13 +220,16 @@ pub fn create_break_complete_content(
 
 #[must_use]
 pub fn create_long_break_complete_content(
+    titles: &NotificationTitles,
     task_name: Option<&str>,
+    sound: Option<&str>,
 ) -> Retained<UNMutableNotificationContent> {
+    let body = notification_body(NotificationType::LongBreakComplete);
     let mut builder = NotificationContentBuilder::new()
-        .title("☕ ポモドーロタイマー")
-        .body("長い休憩時間が終了しました。作業を再開してください。")
+        .title(&titles.render(NotificationType::LongBreakComplete, task_name))
+        .body(body)
         .category_identifier(category_ids::LONG_BREAK_COMPLETE)
-        .default_sound();
+        .sound_named(sound);
 
     if let Some(task) = task_name.and_then(validate_task_name).as_deref() {
         builder = builder.subtitle(task);
@@ -133,10 +238,104 @@ pub fn create_long_break_complete_content(
     builder.build()
 }
 
+#[must_use]
+pub fn create_break_reminder_content(
+    remaining_minutes: u32,
+    sound: Option<&str>,
+) -> Retained<UNMutableNotificationContent> {
+    NotificationContentBuilder::new()
+        .title(BREAK_REMINDER_TITLE)
+        .body(&break_reminder_body(remaining_minutes))
+        .sound_named(sound)
+        .build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_break_reminder_body() {
+        assert_eq!(break_reminder_body(15), "休憩の残りは15分です。");
+    }
+
+    #[test]
+    fn test_notification_body() {
+        let body = notification_body(NotificationType::LongBreakComplete);
+        assert!(body.contains("長い休憩"));
+    }
+
+    #[test]
+    fn test_titles_render_task_template() {
+        let config = NotificationsConfig {
+            work_complete_title: "✅ {task} 完了".to_string(),
+            ..Default::default()
+        };
+        let titles = NotificationTitles::from_config(&config);
+
+        assert_eq!(
+            titles.render(NotificationType::WorkComplete, Some("API実装")),
+            "✅ API実装 完了"
+        );
+        assert_eq!(
+            titles.render(NotificationType::BreakComplete, Some("API実装")),
+            "☕ ポモドーロタイマー"
+        );
+
+        let content = create_work_complete_content(&titles, Some("API実装"), None, None);
+        assert_eq!(content.title().to_string(), "✅ API実装 完了");
+    }
+
+    #[test]
+    fn test_titles_without_task() {
+        let config = NotificationsConfig {
+            work_complete_title: "✅ {task} 完了".to_string(),
+            ..Default::default()
+        };
+        let titles = NotificationTitles::from_config(&config);
+
+        assert_eq!(
+            titles.render(NotificationType::WorkComplete, None),
+            "✅ 完了"
+        );
+        assert_eq!(
+            titles.render(NotificationType::WorkComplete, Some("\n")),
+            "✅ 完了"
+        );
+        assert_eq!(
+            NotificationTitles::default().render(NotificationType::WorkComplete, None),
+            "🍅 ポモドーロタイマー"
+        );
+    }
+
+    #[test]
+    fn test_content_carries_sound() {
+        let content = create_work_complete_content(
+            &NotificationTitles::default(),
+            Some("API実装"),
+            None,
+            Some("Glass.aiff"),
+        );
+        assert!(content.sound().is_some());
+
+        let content = create_break_complete_content(&NotificationTitles::default(), None, None);
+        assert!(content.sound().is_some());
+    }
+
+    #[test]
+    fn test_content_suggests_break_activity() {
+        let content = create_work_complete_content(
+            &NotificationTitles::default(),
+            None,
+            Some("ストレッチ"),
+            None,
+        );
+        assert!(content.body().to_string().ends_with("おすすめ: ストレッチ"));
+
+        let body = notification_body(NotificationType::WorkComplete);
+        assert_eq!(with_break_activity(body, Some("  ")), body);
+    }
+
     #[test]
     fn test_validate_task_name_valid() {
         let result = validate_task_name("API実装");
@@ -0,0 +1,140 @@
+//! Debouncing for notifications.
+//!
+//! Skipping through several phases in quick succession would otherwise fire
+//! one notification per completion. A notification of the same type sent
+//! again within the debounce window is suppressed.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::daemon::{Clock, SystemClock};
+
+use super::NotificationType;
+
+/// Default window in which a duplicate notification is suppressed.
+pub const DEFAULT_DEBOUNCE_WINDOW: Duration = Duration::from_secs(2);
+
+// ============================================================================
+// NotificationDebouncer
+// ============================================================================
+
+/// Tracks when each notification type was last sent.
+#[derive(Debug)]
+pub struct NotificationDebouncer<C: Clock = SystemClock> {
+    clock: C,
+    window: Duration,
+    last_sent: Mutex<HashMap<NotificationType, Instant>>,
+}
+
+impl Default for NotificationDebouncer {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEBOUNCE_WINDOW)
+    }
+}
+
+impl NotificationDebouncer {
+    /// Creates a debouncer using the system clock.
+    #[must_use]
+    pub fn new(window: Duration) -> Self {
+        Self::with_clock(window, SystemClock)
+    }
+}
+
+impl<C: Clock> NotificationDebouncer<C> {
+    /// Creates a debouncer using the given clock.
+    #[must_use]
+    pub fn with_clock(window: Duration, clock: C) -> Self {
+        Self {
+            clock,
+            window,
+            last_sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns whether a notification of this type should be sent now.
+    ///
+    /// When it returns `true` the current time is recorded as the last send
+    /// for the type, so the caller is expected to actually send it.
+    pub fn should_send(&self, notification_type: NotificationType) -> bool {
+        let now = self.clock.instant();
+        let mut last_sent = match self.last_sent.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        if let Some(last) = last_sent.get(&notification_type) {
+            if now.saturating_duration_since(*last) < self.window {
+                return false;
+            }
+        }
+
+        last_sent.insert(notification_type, now);
+        true
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::MockClock;
+    use crate::notification::{MockNotificationSender, NotificationSender};
+
+    async fn send_debounced(
+        debouncer: &NotificationDebouncer<MockClock>,
+        sender: &MockNotificationSender,
+    ) {
+        if debouncer.should_send(NotificationType::WorkComplete) {
+            sender.send_work_complete(None, None).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_back_to_back_sends_deliver_once() {
+        let clock = MockClock::default();
+        let debouncer = NotificationDebouncer::with_clock(DEFAULT_DEBOUNCE_WINDOW, clock.clone());
+        let sender = MockNotificationSender::new();
+
+        send_debounced(&debouncer, &sender).await;
+        clock.advance(Duration::from_millis(500));
+        send_debounced(&debouncer, &sender).await;
+
+        assert_eq!(sender.notification_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sends_after_window_both_deliver() {
+        let clock = MockClock::default();
+        let debouncer = NotificationDebouncer::with_clock(DEFAULT_DEBOUNCE_WINDOW, clock.clone());
+        let sender = MockNotificationSender::new();
+
+        send_debounced(&debouncer, &sender).await;
+        clock.advance(DEFAULT_DEBOUNCE_WINDOW);
+        send_debounced(&debouncer, &sender).await;
+
+        assert_eq!(sender.notification_count(), 2);
+    }
+
+    #[test]
+    fn test_types_are_debounced_independently() {
+        let debouncer =
+            NotificationDebouncer::with_clock(DEFAULT_DEBOUNCE_WINDOW, MockClock::default());
+
+        assert!(debouncer.should_send(NotificationType::WorkComplete));
+        assert!(debouncer.should_send(NotificationType::BreakComplete));
+        assert!(!debouncer.should_send(NotificationType::WorkComplete));
+        assert!(!debouncer.should_send(NotificationType::BreakComplete));
+    }
+
+    #[test]
+    fn test_zero_window_never_suppresses() {
+        let debouncer = NotificationDebouncer::with_clock(Duration::ZERO, MockClock::default());
+
+        assert!(debouncer.should_send(NotificationType::LongBreakComplete));
+        assert!(debouncer.should_send(NotificationType::LongBreakComplete));
+    }
+}
@@ -15,8 +15,13 @@ use objc2_user_notifications::{
     UNUserNotificationCenter, UNUserNotificationCenterDelegate,
 };
 
+use crate::types::{IpcRequest, StartParams};
+
 use super::actions::action_ids;
 
+/// Minutes of work added by the "+5分" action on the work complete notification.
+pub const EXTEND_WORK_MINUTES: u32 = 5;
+
 /// Events triggered by notification actions.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NotificationActionEvent {
@@ -24,12 +29,41 @@ pub enum NotificationActionEvent {
     Pause,
     /// User clicked the stop action button.
     Stop,
+    /// User clicked the resume action button.
+    Resume,
+    /// User clicked the start break action button (`prompt_break`).
+    StartBreak,
+    /// User clicked the "+5分" action button on the work complete notification.
+    ExtendWork,
     /// User clicked the notification itself (default action).
     Default,
     /// User dismissed the notification.
     Dismiss,
 }
 
+impl NotificationActionEvent {
+    /// Returns the IPC request this action triggers, if any.
+    ///
+    /// Clicking or dismissing the notification does not change the timer.
+    #[must_use]
+    pub fn to_request(&self) -> Option<IpcRequest> {
+        match self {
+            Self::Pause => Some(IpcRequest::Pause { reason: None }),
+            Self::Resume => Some(IpcRequest::Resume),
+            Self::Stop => Some(IpcRequest::Stop),
+            // `start` begins the break the timer is waiting for
+            Self::StartBreak => Some(IpcRequest::Start {
+                params: StartParams::default(),
+            }),
+            // Back to the completed work session instead of the break
+            Self::ExtendWork => Some(IpcRequest::ExtendWork {
+                minutes: EXTEND_WORK_MINUTES,
+            }),
+            Self::Default | Self::Dismiss => None,
+        }
+    }
+}
+
 /// Instance variables for the notification delegate.
 #[derive(Clone)]
 pub struct NotificationDelegateIvars {
@@ -87,6 +121,9 @@ define_class!(
             let event = match action_str.as_str() {
                 id if id == action_ids::PAUSE => Some(NotificationActionEvent::Pause),
                 id if id == action_ids::STOP => Some(NotificationActionEvent::Stop),
+                id if id == action_ids::RESUME => Some(NotificationActionEvent::Resume),
+                id if id == action_ids::START_BREAK => Some(NotificationActionEvent::StartBreak),
+                id if id == action_ids::EXTEND_WORK => Some(NotificationActionEvent::ExtendWork),
                 "com.apple.UNNotificationDefaultActionIdentifier" => {
                     Some(NotificationActionEvent::Default)
                 }
@@ -172,4 +209,12 @@ mod tests {
         let event = NotificationActionEvent::Pause;
         assert_eq!(format!("{:?}", event), "Pause");
     }
+
+    #[test]
+    fn test_extend_work_requests_five_minutes() {
+        assert!(matches!(
+            NotificationActionEvent::ExtendWork.to_request(),
+            Some(IpcRequest::ExtendWork { minutes: 5 })
+        ));
+    }
 }
@@ -7,6 +7,9 @@
 //! - Action buttons (pause/stop) on notifications
 //! - Delegate-based event handling
 //! - Async-friendly APIs
+//! - A terminal fallback (stderr + bell) when notifications are unavailable
+//! - Debouncing of duplicate notifications sent in quick succession
+//! - Listing and clearing pending/delivered notifications (`notifications`)
 //!
 //! # Example
 //!
@@ -19,7 +22,7 @@
 //!     let manager = NotificationManager::new().await?;
 //!
 //!     // Send a work complete notification
-//!     manager.send_work_complete_notification(Some("API実装")).await?;
+//!     manager.send_work_complete_notification(Some("API実装"), None).await?;
 //!
 //!     // Handle action events
 //!     while let Some(event) = manager.try_recv_action() {
@@ -49,26 +52,33 @@
 mod actions;
 mod center;
 mod content;
+mod debounce;
 mod delegate;
 pub mod error;
 mod request;
+mod terminal;
 
 use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::Duration;
 
 use objc2::rc::Retained;
 use objc2::MainThreadMarker;
 
 pub use self::actions::{action_ids, category_ids};
+pub use self::center::NotificationSummary;
 pub use self::content::{
-    create_break_complete_content, create_long_break_complete_content,
-    create_work_complete_content, validate_task_name, NotificationContentBuilder,
+    break_reminder_body, create_break_complete_content, create_break_reminder_content,
+    create_long_break_complete_content, create_work_complete_content, validate_task_name,
+    with_break_activity, NotificationContentBuilder, NotificationTitles, BREAK_REMINDER_TITLE,
 };
-pub use self::delegate::{NotificationActionEvent, NotificationDelegate};
+pub use self::debounce::{NotificationDebouncer, DEFAULT_DEBOUNCE_WINDOW};
+pub use self::delegate::{NotificationActionEvent, NotificationDelegate, EXTEND_WORK_MINUTES};
 pub use self::error::NotificationError;
+pub use self::terminal::TerminalNotifier;
 
 use self::actions::create_categories;
 use self::center::NotificationCenter;
-use self::request::create_notification_request;
+use self::request::{create_notification_request, create_notification_request_with_id};
 
 /// Maximum retry attempts for sending notifications.
 const MAX_RETRIES: u32 = 3;
@@ -76,6 +86,10 @@ const MAX_RETRIES: u32 = 3;
 /// Delay between retry attempts in milliseconds.
 const RETRY_DELAY_MS: u64 = 1000;
 
+/// Request identifier of the long break reminders; a newer reminder
+/// replaces the previous one.
+const BREAK_REMINDER_ID: &str = "pomodoro.break-reminder";
+
 /// Manages the notification system.
 ///
 /// This is the main entry point for sending notifications and receiving
@@ -85,6 +99,14 @@ pub struct NotificationManager {
     action_receiver: Receiver<NotificationActionEvent>,
     /// Retained delegate to keep it alive.
     _delegate: Retained<NotificationDelegate>,
+    /// Suppresses duplicate notifications sent in quick succession.
+    debouncer: NotificationDebouncer,
+    /// Sound attached to notifications (the system default if `None`).
+    sound: Option<String>,
+    /// Whether work complete notifications offer to start the break.
+    prompt_break: bool,
+    /// Title templates of the completion notifications.
+    titles: NotificationTitles,
 }
 
 impl NotificationManager {
@@ -123,15 +145,47 @@ impl NotificationManager {
         NotificationCenter::set_delegate(&NotificationDelegate::as_protocol(&delegate));
 
         // Register notification categories
-        let categories = create_categories();
+        let categories = create_categories(false, false);
         NotificationCenter::set_notification_categories(&categories);
 
         Ok(Self {
             action_receiver: receiver,
             _delegate: delegate,
+            debouncer: NotificationDebouncer::default(),
+            sound: None,
+            prompt_break: false,
+            titles: NotificationTitles::default(),
         })
     }
 
+    /// Sets the window in which a duplicate notification is suppressed.
+    ///
+    /// Defaults to [`DEFAULT_DEBOUNCE_WINDOW`]; `Duration::ZERO` disables debouncing.
+    #[must_use]
+    pub fn with_debounce_window(mut self, window: Duration) -> Self {
+        self.debouncer = NotificationDebouncer::new(window);
+        self
+    }
+
+    /// Sets the sound Notification Center plays with each notification.
+    ///
+    /// `None` uses the system default sound.
+    pub fn set_sound(&mut self, sound: Option<String>) {
+        self.sound = sound;
+    }
+
+    /// Sets the title templates of the completion notifications.
+    pub fn set_titles(&mut self, titles: NotificationTitles) {
+        self.titles = titles;
+    }
+
+    /// Sets whether the work complete notification offers to start the
+    /// break (`prompt_break`), re-registering the categories.
+    pub fn set_prompt_break(&mut self, prompt_break: bool) {
+        self.prompt_break = prompt_break;
+        self.update_categories(false);
+    }
+
     /// Creates a notification manager with fallback behavior.
     ///
     /// Returns `None` if initialization fails (with error logged),
@@ -158,6 +212,27 @@ impl NotificationManager {
         }
     }
 
+    /// Asks the user for notification permission and returns whether it
+    /// was granted.
+    ///
+    /// macOS only shows the prompt to a process on the main thread, so this
+    /// must be awaited on the main thread (e.g. a `current_thread` runtime
+    /// in `main`). The prompt appears once; later calls return the choice
+    /// the user made.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotificationError::InitializationFailed`] when called off
+    /// the main thread, or an error if the request itself fails.
+    pub async fn request_authorization() -> Result<bool, NotificationError> {
+        if MainThreadMarker::new().is_none() {
+            return Err(NotificationError::InitializationFailed(
+                "通知の許可はメインスレッドで要求する必要があります".to_string(),
+            ));
+        }
+        NotificationCenter::request_authorization().await
+    }
+
     /// Checks if notifications are currently authorized.
     pub async fn is_authorized() -> Result<bool, NotificationError> {
         NotificationCenter::is_authorized().await
@@ -167,12 +242,19 @@ impl NotificationManager {
     ///
     /// # Arguments
     /// * `task_name` - Optional task name to display in the notification
+    /// * `break_activity` - Optional suggestion for the break
     pub async fn send_work_complete_notification(
         &self,
         task_name: Option<&str>,
+        break_activity: Option<&str>,
     ) -> Result<(), NotificationError> {
-        let content = create_work_complete_content(task_name);
-        let request = create_notification_request(&content);
+        let content = create_work_complete_content(
+            &self.titles,
+            task_name,
+            break_activity,
+            self.sound.as_deref(),
+        );
+        let request = create_notification_request(NotificationType::WorkComplete, &content);
         NotificationCenter::add_notification_request(&request).await
     }
 
@@ -184,8 +266,8 @@ impl NotificationManager {
         &self,
         task_name: Option<&str>,
     ) -> Result<(), NotificationError> {
-        let content = create_break_complete_content(task_name);
-        let request = create_notification_request(&content);
+        let content = create_break_complete_content(&self.titles, task_name, self.sound.as_deref());
+        let request = create_notification_request(NotificationType::BreakComplete, &content);
         NotificationCenter::add_notification_request(&request).await
     }
 
@@ -197,28 +279,56 @@ impl NotificationManager {
         &self,
         task_name: Option<&str>,
     ) -> Result<(), NotificationError> {
-        let content = create_long_break_complete_content(task_name);
-        let request = create_notification_request(&content);
+        let content =
+            create_long_break_complete_content(&self.titles, task_name, self.sound.as_deref());
+        let request = create_notification_request(NotificationType::LongBreakComplete, &content);
+        NotificationCenter::add_notification_request(&request).await
+    }
+
+    /// Sends a reminder of the minutes left in a long break.
+    pub async fn send_break_reminder_notification(
+        &self,
+        remaining_minutes: u32,
+    ) -> Result<(), NotificationError> {
+        let content = create_break_reminder_content(remaining_minutes, self.sound.as_deref());
+        let request = create_notification_request_with_id(BREAK_REMINDER_ID, &content);
         NotificationCenter::add_notification_request(&request).await
     }
 
     /// Sends a notification with automatic retry on failure.
     ///
+    /// A notification of the same type already sent within the debounce
+    /// window is skipped and reported as success.
+    ///
     /// # Arguments
     /// * `task_name` - Optional task name
+    /// * `break_activity` - Optional break suggestion (work complete only)
     /// * `notification_type` - Type of notification to send
     pub async fn send_notification_with_retry(
         &self,
         task_name: Option<&str>,
+        break_activity: Option<&str>,
         notification_type: NotificationType,
     ) -> Result<(), NotificationError> {
+        if !self.debouncer.should_send(notification_type) {
+            tracing::debug!("重複した通知を抑制しました: {:?}", notification_type);
+            return Ok(());
+        }
+
+        let sound = self.sound.as_deref();
         let content = match notification_type {
-            NotificationType::WorkComplete => create_work_complete_content(task_name),
-            NotificationType::BreakComplete => create_break_complete_content(task_name),
-            NotificationType::LongBreakComplete => create_long_break_complete_content(task_name),
+            NotificationType::WorkComplete => {
+                create_work_complete_content(&self.titles, task_name, break_activity, sound)
+            }
+            NotificationType::BreakComplete => {
+                create_break_complete_content(&self.titles, task_name, sound)
+            }
+            NotificationType::LongBreakComplete => {
+                create_long_break_complete_content(&self.titles, task_name, sound)
+            }
         };
 
-        let request = create_notification_request(&content);
+        let request = create_notification_request(notification_type, &content);
         let mut retries = 0;
 
         loop {
@@ -260,15 +370,48 @@ impl NotificationManager {
         self.action_receiver.recv()
     }
 
+    /// Re-registers the categories with the action buttons for the timer state.
+    ///
+    /// A paused timer gets resume + stop, a running one pause + stop.
+    pub fn update_categories(&self, paused: bool) {
+        NotificationCenter::set_notification_categories(&create_categories(
+            paused,
+            self.prompt_break,
+        ));
+    }
+
+    /// Removes the delivered notification of the given type, if any.
+    pub fn remove_delivered_notification(&self, notification_type: NotificationType) {
+        NotificationCenter::remove_delivered(notification_type.identifier());
+    }
+
     /// Removes all pending and delivered notifications.
     pub fn clear_all_notifications(&self) {
-        NotificationCenter::remove_all_pending_notifications();
-        NotificationCenter::remove_all_delivered_notifications();
+        clear_notifications();
     }
 }
 
+/// Returns the notification requests that have not been delivered yet.
+///
+/// Unlike [`NotificationManager`], this needs no delegate, so one-shot CLI
+/// commands can call it directly.
+pub async fn pending_notifications() -> Result<Vec<NotificationSummary>, NotificationError> {
+    NotificationCenter::pending_notification_requests().await
+}
+
+/// Returns the notifications still shown in Notification Center.
+pub async fn delivered_notifications() -> Result<Vec<NotificationSummary>, NotificationError> {
+    NotificationCenter::delivered_notifications().await
+}
+
+/// Removes all pending and delivered notifications of this app.
+pub fn clear_notifications() {
+    NotificationCenter::remove_all_pending_notifications();
+    NotificationCenter::remove_all_delivered_notifications();
+}
+
 /// Types of notifications that can be sent.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NotificationType {
     /// Work session completed.
     WorkComplete,
@@ -278,15 +421,42 @@ pub enum NotificationType {
     LongBreakComplete,
 }
 
+impl NotificationType {
+    /// Returns the notification request identifier for this type.
+    ///
+    /// Only the latest notification of each type is kept in the
+    /// notification center.
+    #[must_use]
+    pub fn identifier(self) -> &'static str {
+        match self {
+            Self::WorkComplete => "pomodoro.work-complete",
+            Self::BreakComplete => "pomodoro.break-complete",
+            Self::LongBreakComplete => "pomodoro.long-break-complete",
+        }
+    }
+}
+
 #[allow(async_fn_in_trait)]
 pub trait NotificationSender {
-    async fn send_work_complete(&self, task_name: Option<&str>) -> Result<(), NotificationError>;
+    /// Sends the "take a break" notification, with an optional suggestion
+    /// for the break.
+    async fn send_work_complete(
+        &self,
+        task_name: Option<&str>,
+        break_activity: Option<&str>,
+    ) -> Result<(), NotificationError>;
     async fn send_break_complete(&self, task_name: Option<&str>) -> Result<(), NotificationError>;
     async fn send_long_break_complete(
         &self,
         task_name: Option<&str>,
     ) -> Result<(), NotificationError>;
+    /// Sends a reminder of the minutes left in a long break.
+    async fn send_break_reminder(&self, remaining_minutes: u32) -> Result<(), NotificationError>;
     fn try_recv_action(&self) -> Option<NotificationActionEvent>;
+    /// Updates the action buttons for a paused or running timer.
+    fn update_actions(&self, paused: bool);
+    /// Removes the delivered notification of the given type, if any.
+    fn remove_delivered(&self, notification_type: NotificationType);
     fn is_available(&self) -> bool;
     fn clear_all(&self);
 }
@@ -294,25 +464,45 @@ pub trait NotificationSender {
 // Implement NotificationSender for NotificationManager
 #[cfg(target_os = "macos")]
 impl NotificationSender for NotificationManager {
-    async fn send_work_complete(&self, task_name: Option<&str>) -> Result<(), NotificationError> {
-        self.send_work_complete_notification(task_name).await
+    async fn send_work_complete(
+        &self,
+        task_name: Option<&str>,
+        break_activity: Option<&str>,
+    ) -> Result<(), NotificationError> {
+        self.send_notification_with_retry(task_name, break_activity, NotificationType::WorkComplete)
+            .await
     }
 
     async fn send_break_complete(&self, task_name: Option<&str>) -> Result<(), NotificationError> {
-        self.send_break_complete_notification(task_name).await
+        self.send_notification_with_retry(task_name, None, NotificationType::BreakComplete)
+            .await
     }
 
     async fn send_long_break_complete(
         &self,
         task_name: Option<&str>,
     ) -> Result<(), NotificationError> {
-        self.send_long_break_complete_notification(task_name).await
+        self.send_notification_with_retry(task_name, None, NotificationType::LongBreakComplete)
+            .await
+    }
+
+    async fn send_break_reminder(&self, remaining_minutes: u32) -> Result<(), NotificationError> {
+        self.send_break_reminder_notification(remaining_minutes)
+            .await
     }
 
     fn try_recv_action(&self) -> Option<NotificationActionEvent> {
         NotificationManager::try_recv_action(self)
     }
 
+    fn update_actions(&self, paused: bool) {
+        self.update_categories(paused)
+    }
+
+    fn remove_delivered(&self, notification_type: NotificationType) {
+        self.remove_delivered_notification(notification_type)
+    }
+
     fn is_available(&self) -> bool {
         true
     }
@@ -322,10 +512,142 @@ impl NotificationSender for NotificationManager {
     }
 }
 
+/// Notification sender chosen at daemon startup.
+///
+/// Uses the notification center when possible and otherwise falls back to
+/// terminal alerts.
+pub enum Notifier {
+    /// macOS notification center.
+    System(NotificationManager),
+    /// Terminal alerts (stderr + bell).
+    Terminal(TerminalNotifier),
+}
+
+impl Notifier {
+    /// Selects a notifier.
+    ///
+    /// With `terminal_only` the notification center is not tried at all.
+    /// Otherwise it is tried first and terminal alerts are used if it fails.
+    /// `quiet` silences terminal alerts.
+    pub async fn select(terminal_only: bool, quiet: bool) -> Self {
+        if !terminal_only {
+            if let Some(manager) = NotificationManager::new_with_fallback().await {
+                return Self::System(manager);
+            }
+            tracing::info!("通知センターの代わりにターミナルへ通知します");
+        }
+        Self::Terminal(TerminalNotifier::new(quiet))
+    }
+
+    /// Sets the sound attached to notification center notifications.
+    ///
+    /// Terminal alerts are unaffected.
+    pub fn set_sound(&mut self, sound: Option<String>) {
+        if let Self::System(manager) = self {
+            manager.set_sound(sound);
+        }
+    }
+
+    /// Sets the title templates of the completion notifications.
+    pub fn set_titles(&mut self, titles: NotificationTitles) {
+        match self {
+            Self::System(manager) => manager.set_titles(titles),
+            Self::Terminal(terminal) => terminal.set_titles(titles),
+        }
+    }
+
+    /// Sets whether work complete notifications offer to start the break.
+    ///
+    /// Terminal alerts have no action buttons and are unaffected.
+    pub fn set_prompt_break(&mut self, prompt_break: bool) {
+        if let Self::System(manager) = self {
+            manager.set_prompt_break(prompt_break);
+        }
+    }
+}
+
+impl NotificationSender for Notifier {
+    async fn send_work_complete(
+        &self,
+        task_name: Option<&str>,
+        break_activity: Option<&str>,
+    ) -> Result<(), NotificationError> {
+        match self {
+            Self::System(manager) => manager.send_work_complete(task_name, break_activity).await,
+            Self::Terminal(terminal) => {
+                terminal.send_work_complete(task_name, break_activity).await
+            }
+        }
+    }
+
+    async fn send_break_complete(&self, task_name: Option<&str>) -> Result<(), NotificationError> {
+        match self {
+            Self::System(manager) => manager.send_break_complete(task_name).await,
+            Self::Terminal(terminal) => terminal.send_break_complete(task_name).await,
+        }
+    }
+
+    async fn send_long_break_complete(
+        &self,
+        task_name: Option<&str>,
+    ) -> Result<(), NotificationError> {
+        match self {
+            Self::System(manager) => manager.send_long_break_complete(task_name).await,
+            Self::Terminal(terminal) => terminal.send_long_break_complete(task_name).await,
+        }
+    }
+
+    async fn send_break_reminder(&self, remaining_minutes: u32) -> Result<(), NotificationError> {
+        match self {
+            Self::System(manager) => manager.send_break_reminder(remaining_minutes).await,
+            Self::Terminal(terminal) => terminal.send_break_reminder(remaining_minutes).await,
+        }
+    }
+
+    fn try_recv_action(&self) -> Option<NotificationActionEvent> {
+        match self {
+            Self::System(manager) => NotificationSender::try_recv_action(manager),
+            Self::Terminal(terminal) => terminal.try_recv_action(),
+        }
+    }
+
+    fn update_actions(&self, paused: bool) {
+        match self {
+            Self::System(manager) => manager.update_actions(paused),
+            Self::Terminal(terminal) => terminal.update_actions(paused),
+        }
+    }
+
+    fn remove_delivered(&self, notification_type: NotificationType) {
+        match self {
+            Self::System(manager) => manager.remove_delivered(notification_type),
+            Self::Terminal(terminal) => terminal.remove_delivered(notification_type),
+        }
+    }
+
+    fn is_available(&self) -> bool {
+        match self {
+            Self::System(manager) => manager.is_available(),
+            Self::Terminal(terminal) => terminal.is_available(),
+        }
+    }
+
+    fn clear_all(&self) {
+        match self {
+            Self::System(manager) => manager.clear_all(),
+            Self::Terminal(terminal) => terminal.clear_all(),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct MockNotificationSender {
     notifications: std::sync::Mutex<Vec<(NotificationType, Option<String>)>>,
+    break_activities: std::sync::Mutex<Vec<Option<String>>>,
+    break_reminders: std::sync::Mutex<Vec<u32>>,
     action_events: std::sync::Mutex<Vec<NotificationActionEvent>>,
+    actions_paused: std::sync::atomic::AtomicBool,
+    removed: std::sync::Mutex<Vec<&'static str>>,
     available: std::sync::atomic::AtomicBool,
     should_fail: std::sync::atomic::AtomicBool,
 }
@@ -335,7 +657,11 @@ impl MockNotificationSender {
     pub fn new() -> Self {
         Self {
             notifications: std::sync::Mutex::new(Vec::new()),
+            break_activities: std::sync::Mutex::new(Vec::new()),
+            break_reminders: std::sync::Mutex::new(Vec::new()),
             action_events: std::sync::Mutex::new(Vec::new()),
+            actions_paused: std::sync::atomic::AtomicBool::new(false),
+            removed: std::sync::Mutex::new(Vec::new()),
             available: std::sync::atomic::AtomicBool::new(true),
             should_fail: std::sync::atomic::AtomicBool::new(false),
         }
@@ -355,11 +681,36 @@ impl MockNotificationSender {
         self.action_events.lock().unwrap().push(event);
     }
 
+    /// Returns whether the last action update was for a paused timer.
+    #[must_use]
+    pub fn actions_paused(&self) -> bool {
+        self.actions_paused
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Returns the identifiers of the removed notifications, in order.
+    #[must_use]
+    pub fn removed_identifiers(&self) -> Vec<&'static str> {
+        self.removed.lock().unwrap().clone()
+    }
+
     #[must_use]
     pub fn get_notifications(&self) -> Vec<(NotificationType, Option<String>)> {
         self.notifications.lock().unwrap().clone()
     }
 
+    /// Returns the break suggestions of the work complete notifications, in order.
+    #[must_use]
+    pub fn break_activities(&self) -> Vec<Option<String>> {
+        self.break_activities.lock().unwrap().clone()
+    }
+
+    /// Returns the minutes left of the long break reminders, in order.
+    #[must_use]
+    pub fn break_reminders(&self) -> Vec<u32> {
+        self.break_reminders.lock().unwrap().clone()
+    }
+
     #[must_use]
     pub fn notification_count(&self) -> usize {
         self.notifications.lock().unwrap().len()
@@ -371,7 +722,11 @@ impl MockNotificationSender {
 }
 
 impl NotificationSender for MockNotificationSender {
-    async fn send_work_complete(&self, task_name: Option<&str>) -> Result<(), NotificationError> {
+    async fn send_work_complete(
+        &self,
+        task_name: Option<&str>,
+        break_activity: Option<&str>,
+    ) -> Result<(), NotificationError> {
         if self.should_fail.load(std::sync::atomic::Ordering::SeqCst) {
             return Err(NotificationError::SendFailed("Mock failure".to_string()));
         }
@@ -379,6 +734,10 @@ impl NotificationSender for MockNotificationSender {
             .lock()
             .unwrap()
             .push((NotificationType::WorkComplete, task_name.map(String::from)));
+        self.break_activities
+            .lock()
+            .unwrap()
+            .push(break_activity.map(String::from));
         Ok(())
     }
 
@@ -407,6 +766,14 @@ impl NotificationSender for MockNotificationSender {
         Ok(())
     }
 
+    async fn send_break_reminder(&self, remaining_minutes: u32) -> Result<(), NotificationError> {
+        if self.should_fail.load(std::sync::atomic::Ordering::SeqCst) {
+            return Err(NotificationError::SendFailed("Mock failure".to_string()));
+        }
+        self.break_reminders.lock().unwrap().push(remaining_minutes);
+        Ok(())
+    }
+
     fn try_recv_action(&self) -> Option<NotificationActionEvent> {
         let mut events = self.action_events.lock().unwrap();
         if events.is_empty() {
@@ -416,6 +783,18 @@ impl NotificationSender for MockNotificationSender {
         }
     }
 
+    fn update_actions(&self, paused: bool) {
+        self.actions_paused
+            .store(paused, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    fn remove_delivered(&self, notification_type: NotificationType) {
+        self.removed
+            .lock()
+            .unwrap()
+            .push(notification_type.identifier());
+    }
+
     fn is_available(&self) -> bool {
         self.available.load(std::sync::atomic::Ordering::SeqCst)
     }
@@ -440,7 +819,9 @@ mod tests {
         let mock = MockNotificationSender::new();
 
         // Send notifications
-        mock.send_work_complete(Some("Test Task")).await.unwrap();
+        mock.send_work_complete(Some("Test Task"), None)
+            .await
+            .unwrap();
         mock.send_break_complete(None).await.unwrap();
 
         // Verify
@@ -456,12 +837,39 @@ mod tests {
         assert_eq!(notifications[1], (NotificationType::BreakComplete, None));
     }
 
+    #[test]
+    fn test_identifiers_are_stable_and_distinct() {
+        let ids = [
+            NotificationType::WorkComplete.identifier(),
+            NotificationType::BreakComplete.identifier(),
+            NotificationType::LongBreakComplete.identifier(),
+        ];
+
+        assert_eq!(ids[0], NotificationType::WorkComplete.identifier());
+        assert_ne!(ids[0], ids[1]);
+        assert_ne!(ids[1], ids[2]);
+        assert_ne!(ids[0], ids[2]);
+    }
+
+    #[test]
+    fn test_mock_tracks_removed_identifiers() {
+        let mock = MockNotificationSender::new();
+
+        mock.remove_delivered(NotificationType::WorkComplete);
+        mock.remove_delivered(NotificationType::LongBreakComplete);
+
+        assert_eq!(
+            mock.removed_identifiers(),
+            vec!["pomodoro.work-complete", "pomodoro.long-break-complete"]
+        );
+    }
+
     #[tokio::test]
     async fn test_mock_notification_sender_failure() {
         let mock = MockNotificationSender::new();
         mock.set_should_fail(true);
 
-        let result = mock.send_work_complete(None).await;
+        let result = mock.send_work_complete(None, None).await;
         assert!(result.is_err());
     }
 
@@ -482,6 +890,56 @@ mod tests {
         assert!(mock.try_recv_action().is_none());
     }
 
+    #[test]
+    fn test_action_events_map_to_requests() {
+        use crate::types::IpcRequest;
+
+        let mock = MockNotificationSender::new();
+        mock.inject_action_event(NotificationActionEvent::Resume);
+        mock.inject_action_event(NotificationActionEvent::Pause);
+        mock.inject_action_event(NotificationActionEvent::Stop);
+        mock.inject_action_event(NotificationActionEvent::Dismiss);
+
+        let requests: Vec<_> = std::iter::from_fn(|| mock.try_recv_action())
+            .map(|action| action.to_request())
+            .collect();
+
+        assert_eq!(requests.len(), 4);
+        assert!(matches!(requests[0], Some(IpcRequest::Resume)));
+        assert!(matches!(requests[1], Some(IpcRequest::Pause { .. })));
+        assert!(matches!(requests[2], Some(IpcRequest::Stop)));
+        assert!(requests[3].is_none());
+    }
+
+    #[test]
+    fn test_extend_work_action_maps_to_request() {
+        use crate::types::IpcRequest;
+
+        let mock = MockNotificationSender::new();
+        mock.inject_action_event(NotificationActionEvent::ExtendWork);
+
+        let action = mock.try_recv_action().unwrap();
+        assert_eq!(action, NotificationActionEvent::ExtendWork);
+        assert!(matches!(
+            action.to_request(),
+            Some(IpcRequest::ExtendWork {
+                minutes: EXTEND_WORK_MINUTES
+            })
+        ));
+    }
+
+    #[test]
+    fn test_mock_notification_sender_update_actions() {
+        let mock = MockNotificationSender::new();
+        assert!(!mock.actions_paused());
+
+        mock.update_actions(true);
+        assert!(mock.actions_paused());
+
+        mock.update_actions(false);
+        assert!(!mock.actions_paused());
+    }
+
     #[test]
     fn test_mock_notification_sender_availability() {
         let mock = MockNotificationSender::new();
@@ -3,19 +3,23 @@
 use objc2::rc::Retained;
 use objc2_foundation::NSString;
 use objc2_user_notifications::{UNMutableNotificationContent, UNNotificationRequest};
-use uuid::Uuid;
 
+use super::NotificationType;
+
+/// Creates a request for a notification of the given type.
+///
+/// The identifier is stable per type (see [`NotificationType::identifier`]),
+/// so a newer notification replaces an older one of the same type and a
+/// delivered one can be removed later.
 #[must_use]
 pub fn create_notification_request(
+    notification_type: NotificationType,
     content: &UNMutableNotificationContent,
 ) -> Retained<UNNotificationRequest> {
-    let identifier = NSString::from_str(&Uuid::new_v4().to_string());
-
-    UNNotificationRequest::requestWithIdentifier_content_trigger(&identifier, content, None)
+    create_notification_request_with_id(notification_type.identifier(), content)
 }
 
 #[must_use]
-#[allow(dead_code)]
 pub fn create_notification_request_with_id(
     identifier: &str,
     content: &UNMutableNotificationContent,
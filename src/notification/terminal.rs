@@ -0,0 +1,253 @@
+//! Terminal fallback for notifications.
+//!
+//! When the notification center is unavailable (unsigned binary, denied
+//! permission, headless session), alerts are written to stderr as a colored
+//! line followed by the ASCII bell.
+
+use std::io::{self, Write};
+use std::sync::Mutex;
+
+use super::content::{
+    break_reminder_body, notification_body, validate_task_name, with_break_activity,
+    NotificationTitles, BREAK_REMINDER_TITLE,
+};
+use super::delegate::NotificationActionEvent;
+use super::error::NotificationError;
+use super::{NotificationSender, NotificationType};
+
+/// ANSI escape for bold yellow text.
+const COLOR_START: &str = "\x1b[1;33m";
+
+/// ANSI escape resetting text attributes.
+const COLOR_RESET: &str = "\x1b[0m";
+
+/// ASCII bell.
+const BELL: &str = "\x07";
+
+/// Notification sender that alerts on the terminal.
+pub struct TerminalNotifier {
+    /// Destination of the alert lines (stderr by default).
+    writer: Mutex<Box<dyn Write + Send>>,
+    /// Suppresses all output when set.
+    quiet: bool,
+    /// Title templates of the alerts.
+    titles: NotificationTitles,
+}
+
+impl TerminalNotifier {
+    /// Creates a terminal notifier writing to stderr.
+    #[must_use]
+    pub fn new(quiet: bool) -> Self {
+        Self::with_writer(Box::new(io::stderr()), quiet)
+    }
+
+    /// Creates a terminal notifier writing to the given writer.
+    #[must_use]
+    pub fn with_writer(writer: Box<dyn Write + Send>, quiet: bool) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+            quiet,
+            titles: NotificationTitles::default(),
+        }
+    }
+
+    /// Sets the title templates of the alerts.
+    pub fn set_titles(&mut self, titles: NotificationTitles) {
+        self.titles = titles;
+    }
+
+    /// Formats the alert line for a notification (without the trailing newline).
+    #[must_use]
+    pub fn format_alert(
+        titles: &NotificationTitles,
+        notification_type: NotificationType,
+        task_name: Option<&str>,
+        break_activity: Option<&str>,
+    ) -> String {
+        let title = titles.render(notification_type, task_name);
+        let body = with_break_activity(notification_body(notification_type), break_activity);
+        let mut line = format!("{}{}: {}", COLOR_START, title, body);
+        if let Some(task) = task_name.and_then(validate_task_name) {
+            line.push_str(&format!(" ({})", task));
+        }
+        line.push_str(COLOR_RESET);
+        line.push_str(BELL);
+        line
+    }
+
+    /// Formats the alert line for a long break reminder.
+    #[must_use]
+    pub fn format_break_reminder(remaining_minutes: u32) -> String {
+        format!(
+            "{}{}: {}{}{}",
+            COLOR_START,
+            BREAK_REMINDER_TITLE,
+            break_reminder_body(remaining_minutes),
+            COLOR_RESET,
+            BELL
+        )
+    }
+
+    fn alert(
+        &self,
+        notification_type: NotificationType,
+        task_name: Option<&str>,
+        break_activity: Option<&str>,
+    ) -> Result<(), NotificationError> {
+        self.write_line(&Self::format_alert(
+            &self.titles,
+            notification_type,
+            task_name,
+            break_activity,
+        ))
+    }
+
+    fn write_line(&self, line: &str) -> Result<(), NotificationError> {
+        if self.quiet {
+            return Ok(());
+        }
+
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| NotificationError::SendFailed("writer lock poisoned".to_string()))?;
+        writeln!(writer, "{}", line)
+            .and_then(|()| writer.flush())
+            .map_err(|e| NotificationError::SendFailed(e.to_string()))
+    }
+}
+
+impl NotificationSender for TerminalNotifier {
+    async fn send_work_complete(
+        &self,
+        task_name: Option<&str>,
+        break_activity: Option<&str>,
+    ) -> Result<(), NotificationError> {
+        self.alert(NotificationType::WorkComplete, task_name, break_activity)
+    }
+
+    async fn send_break_complete(&self, task_name: Option<&str>) -> Result<(), NotificationError> {
+        self.alert(NotificationType::BreakComplete, task_name, None)
+    }
+
+    async fn send_long_break_complete(
+        &self,
+        task_name: Option<&str>,
+    ) -> Result<(), NotificationError> {
+        self.alert(NotificationType::LongBreakComplete, task_name, None)
+    }
+
+    async fn send_break_reminder(&self, remaining_minutes: u32) -> Result<(), NotificationError> {
+        self.write_line(&Self::format_break_reminder(remaining_minutes))
+    }
+
+    fn try_recv_action(&self) -> Option<NotificationActionEvent> {
+        // Terminal alerts have no action buttons
+        None
+    }
+
+    fn update_actions(&self, _paused: bool) {
+        // Terminal alerts have no action buttons
+    }
+
+    fn remove_delivered(&self, _notification_type: NotificationType) {
+        // Terminal alerts cannot be taken back
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn clear_all(&self) {
+        // Nothing to clear on the terminal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    /// Writer that records everything written to it.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl SharedBuffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_format_alert() {
+        let line = TerminalNotifier::format_alert(
+            &NotificationTitles::default(),
+            NotificationType::WorkComplete,
+            Some("API実装"),
+            Some("水を飲む"),
+        );
+
+        assert!(line.starts_with(COLOR_START));
+        assert!(line.contains("作業時間が終了しました"));
+        assert!(line.contains("おすすめ: 水を飲む"));
+        assert!(line.contains("(API実装)"));
+        assert!(line.ends_with(BELL));
+    }
+
+    #[tokio::test]
+    async fn test_sends_colored_line_with_bell() {
+        let buffer = SharedBuffer::default();
+        let notifier = TerminalNotifier::with_writer(Box::new(buffer.clone()), false);
+
+        notifier.send_long_break_complete(None).await.unwrap();
+
+        let output = buffer.contents();
+        assert!(output.contains("長い休憩時間が終了しました"));
+        assert!(output.contains(BELL));
+        assert!(output.ends_with('\n'));
+    }
+
+    #[tokio::test]
+    async fn test_sends_break_reminder() {
+        let buffer = SharedBuffer::default();
+        let notifier = TerminalNotifier::with_writer(Box::new(buffer.clone()), false);
+
+        notifier.send_break_reminder(15).await.unwrap();
+
+        let output = buffer.contents();
+        assert!(output.contains("長い休憩中: 休憩の残りは15分です。"));
+        assert!(output.ends_with(&format!("{}\n", BELL)));
+    }
+
+    #[tokio::test]
+    async fn test_quiet_writes_nothing() {
+        let buffer = SharedBuffer::default();
+        let notifier = TerminalNotifier::with_writer(Box::new(buffer.clone()), true);
+
+        notifier
+            .send_work_complete(Some("Task"), None)
+            .await
+            .unwrap();
+        notifier.send_break_complete(None).await.unwrap();
+
+        assert!(buffer.contents().is_empty());
+    }
+
+    #[test]
+    fn test_has_no_actions() {
+        let notifier = TerminalNotifier::new(true);
+        assert!(notifier.is_available());
+        assert!(notifier.try_recv_action().is_none());
+    }
+}
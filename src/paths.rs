@@ -0,0 +1,529 @@
+//! Filesystem locations for the Pomodoro Timer.
+//!
+//! Every path is resolved here so that the CLI and the daemon always agree
+//! on where things live. On macOS everything lives in `~/.pomodoro`. Other
+//! systems follow the XDG base directories:
+//! - the configuration file in `$XDG_CONFIG_HOME/pomodoro` (`~/.config/pomodoro`)
+//! - state, history, the status cache and logs in `$XDG_STATE_HOME/pomodoro`
+//!   (`~/.local/state/pomodoro`)
+//! - the socket in `$XDG_RUNTIME_DIR/pomodoro` (the state directory if unset)
+//!
+//! Two overrides apply on every platform:
+//! - `POMODORO_HOME` puts everything in one base directory
+//! - `POMODORO_SOCKET` overrides the socket path only
+//!
+//! Both overrides may start with `~` or `$HOME`, which are expanded.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::daemon::history;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Environment variable overriding the base directory
+pub const HOME_ENV: &str = "POMODORO_HOME";
+
+/// Environment variable overriding the socket path
+pub const SOCKET_ENV: &str = "POMODORO_SOCKET";
+
+/// Base directory name under the user's home directory
+const BASE_DIR_NAME: &str = ".pomodoro";
+
+/// Directory name under the XDG base directories
+const XDG_DIR_NAME: &str = "pomodoro";
+
+/// Socket file name
+const SOCKET_FILE_NAME: &str = "pomodoro.sock";
+
+/// Configuration file name
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// Persisted timer state file name
+const STATE_FILE_NAME: &str = "state.json";
+
+/// Session history file name
+const HISTORY_FILE_NAME: &str = "history.jsonl";
+
+/// Cached `status --short` response file name
+const STATUS_CACHE_FILE_NAME: &str = "status-cache.json";
+
+/// Log directory name
+const LOG_DIR_NAME: &str = "logs";
+
+/// Daemon log file name inside the log directory
+const DAEMON_LOG_FILE_NAME: &str = "daemon.log";
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Returns the Unix socket path used for daemon-CLI communication.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn socket_path() -> Result<PathBuf> {
+    resolve_socket_path(Layout::current(), &env_lookup)
+}
+
+/// Returns the directory holding the configuration file.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn config_dir() -> Result<PathBuf> {
+    resolve_config_dir(Layout::current(), &env_lookup)
+}
+
+/// Returns the directory holding state, history, the status cache and logs.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn state_dir() -> Result<PathBuf> {
+    resolve_state_dir(Layout::current(), &env_lookup)
+}
+
+/// Returns the path of the configuration file.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn config_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join(CONFIG_FILE_NAME))
+}
+
+/// Returns the path of the persisted timer state file.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn state_path() -> Result<PathBuf> {
+    Ok(state_dir()?.join(STATE_FILE_NAME))
+}
+
+/// Returns the path of the session history file.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn history_path() -> Result<PathBuf> {
+    Ok(state_dir()?.join(HISTORY_FILE_NAME))
+}
+
+/// Returns the path of the `status --short` cache file.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn status_cache_path() -> Result<PathBuf> {
+    Ok(state_dir()?.join(STATUS_CACHE_FILE_NAME))
+}
+
+/// Returns the directory for daemon log files.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn log_dir() -> Result<PathBuf> {
+    Ok(state_dir()?.join(LOG_DIR_NAME))
+}
+
+/// Returns the path of the daemon log file written under the LaunchAgent.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn daemon_log_path() -> Result<PathBuf> {
+    Ok(log_dir()?.join(DAEMON_LOG_FILE_NAME))
+}
+
+/// Returns the runtime files owned by the app, removed by `uninstall --purge`.
+///
+/// These are the state file, the history file and its rotated backup, the
+/// status cache, the log directory and the socket. The configuration file is written by the user and not included.
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined.
+pub fn app_data_paths() -> Result<Vec<PathBuf>> {
+    resolve_app_data_paths(Layout::current(), &env_lookup)
+}
+
+// ============================================================================
+// Resolution
+// ============================================================================
+
+/// Where files live when `POMODORO_HOME` is not set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Layout {
+    /// Everything in `~/.pomodoro` (macOS)
+    Home,
+    /// XDG base directories (other systems)
+    Xdg,
+}
+
+impl Layout {
+    /// Returns the layout of the platform this was built for.
+    pub(crate) const fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::Home
+        } else {
+            Self::Xdg
+        }
+    }
+}
+
+fn env_lookup(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|value| !value.is_empty())
+}
+
+fn resolve_socket_path(layout: Layout, lookup: &dyn Fn(&str) -> Option<String>) -> Result<PathBuf> {
+    if let Some(path) = lookup(SOCKET_ENV) {
+        return expand_home(&path, lookup);
+    }
+    let dir = match (layout, xdg_dir(lookup, "XDG_RUNTIME_DIR")) {
+        (Layout::Xdg, Some(runtime)) if lookup(HOME_ENV).is_none() => runtime,
+        _ => resolve_state_dir(layout, lookup)?,
+    };
+    Ok(dir.join(SOCKET_FILE_NAME))
+}
+
+fn resolve_config_dir(layout: Layout, lookup: &dyn Fn(&str) -> Option<String>) -> Result<PathBuf> {
+    resolve_base_dir(layout, lookup, "XDG_CONFIG_HOME", ".config")
+}
+
+fn resolve_state_dir(layout: Layout, lookup: &dyn Fn(&str) -> Option<String>) -> Result<PathBuf> {
+    resolve_base_dir(layout, lookup, "XDG_STATE_HOME", ".local/state")
+}
+
+/// Resolves `POMODORO_HOME`, then `~/.pomodoro` or the XDG directory named
+/// by `xdg_var` (`~/<fallback>/pomodoro` if unset).
+fn resolve_base_dir(
+    layout: Layout,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    xdg_var: &str,
+    fallback: &str,
+) -> Result<PathBuf> {
+    if let Some(path) = lookup(HOME_ENV) {
+        return expand_home(&path, lookup);
+    }
+    match layout {
+        Layout::Home => Ok(home_dir(lookup)?.join(BASE_DIR_NAME)),
+        Layout::Xdg => match xdg_dir(lookup, xdg_var) {
+            Some(dir) => Ok(dir),
+            None => Ok(home_dir(lookup)?.join(fallback).join(XDG_DIR_NAME)),
+        },
+    }
+}
+
+/// Returns `$<var>/pomodoro`, ignoring a relative value as the XDG spec requires.
+fn xdg_dir(lookup: &dyn Fn(&str) -> Option<String>, var: &str) -> Option<PathBuf> {
+    lookup(var)
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .map(|dir| dir.join(XDG_DIR_NAME))
+}
+
+pub(crate) fn resolve_app_data_paths(
+    layout: Layout,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<Vec<PathBuf>> {
+    let dir = resolve_state_dir(layout, lookup)?;
+    let history = dir.join(HISTORY_FILE_NAME);
+    Ok(vec![
+        dir.join(STATE_FILE_NAME),
+        history::rotated_path(&history),
+        history,
+        dir.join(STATUS_CACHE_FILE_NAME),
+        dir.join(LOG_DIR_NAME),
+        resolve_socket_path(layout, lookup)?,
+    ])
+}
+
+fn home_dir(lookup: &dyn Fn(&str) -> Option<String>) -> Result<PathBuf> {
+    lookup("HOME")
+        .map(PathBuf::from)
+        .context("HOME環境変数が設定されていません")
+}
+
+/// Expands a leading `~` or `$HOME` to the home directory.
+fn expand_home(path: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Result<PathBuf> {
+    for prefix in ["~", "$HOME"] {
+        if path == prefix {
+            return home_dir(lookup);
+        }
+        if let Some(rest) = path.strip_prefix(prefix).and_then(|r| r.strip_prefix('/')) {
+            return Ok(home_dir(lookup)?.join(rest));
+        }
+    }
+    Ok(PathBuf::from(path))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lookup_from(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| vars.get(key).cloned()
+    }
+
+    // ------------------------------------------------------------------------
+    // Default Path Tests
+    // ------------------------------------------------------------------------
+
+    mod default_tests {
+        use super::*;
+
+        #[test]
+        fn test_defaults_under_home() {
+            let lookup = lookup_from(&[("HOME", "/Users/test")]);
+
+            assert_eq!(
+                resolve_config_dir(Layout::Home, &lookup).unwrap(),
+                PathBuf::from("/Users/test/.pomodoro")
+            );
+            assert_eq!(
+                resolve_socket_path(Layout::Home, &lookup).unwrap(),
+                PathBuf::from("/Users/test/.pomodoro/pomodoro.sock")
+            );
+        }
+
+        #[test]
+        fn test_app_data_paths_exclude_config_file() {
+            let lookup = lookup_from(&[("HOME", "/Users/test")]);
+            let base = PathBuf::from("/Users/test/.pomodoro");
+
+            assert_eq!(
+                resolve_app_data_paths(Layout::Home, &lookup).unwrap(),
+                vec![
+                    base.join("state.json"),
+                    base.join("history.jsonl.1"),
+                    base.join("history.jsonl"),
+                    base.join("status-cache.json"),
+                    base.join("logs"),
+                    base.join("pomodoro.sock"),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_missing_home_is_error() {
+            let lookup = lookup_from(&[]);
+
+            for layout in [Layout::Home, Layout::Xdg] {
+                assert!(resolve_config_dir(layout, &lookup).is_err());
+                assert!(resolve_socket_path(layout, &lookup).is_err());
+            }
+        }
+
+        #[test]
+        fn test_derived_paths_share_dirs() {
+            let config = config_dir().unwrap();
+            let state = state_dir().unwrap();
+
+            assert_eq!(config_path().unwrap(), config.join("config.toml"));
+            assert_eq!(state_path().unwrap(), state.join("state.json"));
+            assert_eq!(history_path().unwrap(), state.join("history.jsonl"));
+            assert_eq!(log_dir().unwrap(), state.join("logs"));
+            assert_eq!(
+                daemon_log_path().unwrap(),
+                state.join("logs").join("daemon.log")
+            );
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Environment Override Tests
+    // ------------------------------------------------------------------------
+
+    mod override_tests {
+        use super::*;
+
+        #[test]
+        fn test_pomodoro_home_overrides_base_dir() {
+            let lookup = lookup_from(&[
+                ("HOME", "/Users/test"),
+                ("XDG_RUNTIME_DIR", "/run/user/1000"),
+                (HOME_ENV, "/opt/pomodoro"),
+            ]);
+
+            for layout in [Layout::Home, Layout::Xdg] {
+                assert_eq!(
+                    resolve_config_dir(layout, &lookup).unwrap(),
+                    PathBuf::from("/opt/pomodoro")
+                );
+                assert_eq!(
+                    resolve_state_dir(layout, &lookup).unwrap(),
+                    PathBuf::from("/opt/pomodoro")
+                );
+                assert_eq!(
+                    resolve_socket_path(layout, &lookup).unwrap(),
+                    PathBuf::from("/opt/pomodoro/pomodoro.sock")
+                );
+            }
+        }
+
+        #[test]
+        fn test_pomodoro_socket_takes_precedence_over_home() {
+            let lookup = lookup_from(&[
+                ("HOME", "/Users/test"),
+                (HOME_ENV, "/opt/pomodoro"),
+                (SOCKET_ENV, "/tmp/custom.sock"),
+            ]);
+
+            assert_eq!(
+                resolve_socket_path(Layout::Home, &lookup).unwrap(),
+                PathBuf::from("/tmp/custom.sock")
+            );
+            // The socket override does not move the base directory
+            assert_eq!(
+                resolve_config_dir(Layout::Home, &lookup).unwrap(),
+                PathBuf::from("/opt/pomodoro")
+            );
+        }
+
+        #[test]
+        fn test_overrides_expand_tilde_and_home_var() {
+            let lookup = lookup_from(&[
+                ("HOME", "/Users/test"),
+                (HOME_ENV, "~/pomo"),
+                (SOCKET_ENV, "$HOME/pomo.sock"),
+            ]);
+
+            assert_eq!(
+                resolve_config_dir(Layout::Home, &lookup).unwrap(),
+                PathBuf::from("/Users/test/pomo")
+            );
+            assert_eq!(
+                resolve_socket_path(Layout::Home, &lookup).unwrap(),
+                PathBuf::from("/Users/test/pomo.sock")
+            );
+        }
+
+        #[test]
+        fn test_expand_home_leaves_other_paths_alone() {
+            let lookup = lookup_from(&[("HOME", "/Users/test")]);
+
+            assert_eq!(
+                expand_home("/var/run/p.sock", &lookup).unwrap(),
+                PathBuf::from("/var/run/p.sock")
+            );
+            assert_eq!(
+                expand_home("~user/p.sock", &lookup).unwrap(),
+                PathBuf::from("~user/p.sock")
+            );
+            assert_eq!(
+                expand_home("~", &lookup).unwrap(),
+                PathBuf::from("/Users/test")
+            );
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // XDG Layout Tests
+    // ------------------------------------------------------------------------
+
+    mod xdg_tests {
+        use super::*;
+
+        #[test]
+        fn test_xdg_vars_are_honored() {
+            let lookup = lookup_from(&[
+                ("HOME", "/home/test"),
+                ("XDG_CONFIG_HOME", "/home/test/cfg"),
+                ("XDG_STATE_HOME", "/home/test/st"),
+                ("XDG_RUNTIME_DIR", "/run/user/1000"),
+            ]);
+
+            assert_eq!(
+                resolve_config_dir(Layout::Xdg, &lookup).unwrap(),
+                PathBuf::from("/home/test/cfg/pomodoro")
+            );
+            assert_eq!(
+                resolve_state_dir(Layout::Xdg, &lookup).unwrap(),
+                PathBuf::from("/home/test/st/pomodoro")
+            );
+            assert_eq!(
+                resolve_socket_path(Layout::Xdg, &lookup).unwrap(),
+                PathBuf::from("/run/user/1000/pomodoro/pomodoro.sock")
+            );
+        }
+
+        #[test]
+        fn test_xdg_fallbacks_under_home() {
+            let lookup = lookup_from(&[("HOME", "/home/test")]);
+            let state = PathBuf::from("/home/test/.local/state/pomodoro");
+
+            assert_eq!(
+                resolve_config_dir(Layout::Xdg, &lookup).unwrap(),
+                PathBuf::from("/home/test/.config/pomodoro")
+            );
+            assert_eq!(resolve_state_dir(Layout::Xdg, &lookup).unwrap(), state);
+            assert_eq!(
+                resolve_socket_path(Layout::Xdg, &lookup).unwrap(),
+                state.join("pomodoro.sock")
+            );
+            assert_eq!(
+                resolve_app_data_paths(Layout::Xdg, &lookup).unwrap(),
+                vec![
+                    state.join("state.json"),
+                    state.join("history.jsonl.1"),
+                    state.join("history.jsonl"),
+                    state.join("status-cache.json"),
+                    state.join("logs"),
+                    state.join("pomodoro.sock"),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_relative_xdg_vars_are_ignored() {
+            let lookup = lookup_from(&[
+                ("HOME", "/home/test"),
+                ("XDG_CONFIG_HOME", "relative/cfg"),
+                ("XDG_RUNTIME_DIR", "run"),
+            ]);
+
+            assert_eq!(
+                resolve_config_dir(Layout::Xdg, &lookup).unwrap(),
+                PathBuf::from("/home/test/.config/pomodoro")
+            );
+            assert_eq!(
+                resolve_socket_path(Layout::Xdg, &lookup).unwrap(),
+                PathBuf::from("/home/test/.local/state/pomodoro/pomodoro.sock")
+            );
+        }
+
+        #[test]
+        fn test_home_layout_ignores_xdg_vars() {
+            let lookup = lookup_from(&[
+                ("HOME", "/Users/test"),
+                ("XDG_CONFIG_HOME", "/Users/test/cfg"),
+                ("XDG_RUNTIME_DIR", "/run/user/501"),
+            ]);
+
+            assert_eq!(
+                resolve_config_dir(Layout::Home, &lookup).unwrap(),
+                PathBuf::from("/Users/test/.pomodoro")
+            );
+            assert_eq!(
+                resolve_socket_path(Layout::Home, &lookup).unwrap(),
+                PathBuf::from("/Users/test/.pomodoro/pomodoro.sock")
+            );
+        }
+    }
+}
@@ -41,6 +41,26 @@ pub const fn get_embedded_sound() -> &'static [u8] {
     DEFAULT_SOUND_DATA
 }
 
+/// Name of the embedded countdown tick sound.
+pub const TICK_SOUND_NAME: &str = "tick";
+
+/// Countdown tick sound data.
+///
+/// A 25ms 2kHz click (16-bit PCM, 22.05kHz, mono), short enough to play
+/// once per second without overlapping itself.
+pub const TICK_SOUND_DATA: &[u8] = include_bytes!("../../assets/sounds/tick.wav");
+
+/// Returns the embedded sound data for the given name.
+///
+/// Unknown names fall back to the default sound.
+#[must_use]
+pub fn get_embedded_sound_named(name: &str) -> &'static [u8] {
+    match name {
+        TICK_SOUND_NAME => TICK_SOUND_DATA,
+        _ => DEFAULT_SOUND_DATA,
+    }
+}
+
 /// Returns the format description of the embedded sound.
 #[must_use]
 pub const fn get_embedded_sound_format() -> &'static str {
@@ -78,6 +98,19 @@ mod tests {
         assert_eq!(&data[12..16], b"fmt ");
     }
 
+    #[test]
+    fn test_tick_sound_is_valid_wav() {
+        let data = get_embedded_sound_named(TICK_SOUND_NAME);
+        assert_eq!(&data[0..4], b"RIFF");
+        assert_eq!(&data[8..12], b"WAVE");
+        assert!(data.len() > 44, "tick sound should contain audio data");
+    }
+
+    #[test]
+    fn test_unknown_name_falls_back_to_default() {
+        assert_eq!(get_embedded_sound_named("unknown"), DEFAULT_SOUND_DATA);
+    }
+
     #[test]
     fn test_embedded_sound_format_description() {
         let format = get_embedded_sound_format();
@@ -4,26 +4,63 @@
 //! All errors are designed to provide helpful messages for debugging
 //! and graceful degradation when audio is unavailable.
 
+use std::error::Error as _;
+use std::io;
+use std::path::PathBuf;
+
+use rodio::decoder::DecoderError;
+use rodio::{DevicesError, PlayError, StreamError};
 use thiserror::Error;
 
 /// Errors that can occur in the sound playback system.
+///
+/// The messages are meant for users; the underlying rodio or I/O error is
+/// kept as the [`source`](std::error::Error::source) and included by
+/// [`SoundError::detail`].
 #[derive(Debug, Error)]
 pub enum SoundError {
     /// Audio device is not available (e.g., no speakers connected).
-    #[error("オーディオデバイスが利用できません: {0}")]
-    DeviceNotAvailable(String),
+    #[error("オーディオデバイスが利用できません")]
+    DeviceUnavailable(#[source] StreamError),
+
+    /// The output devices could not be enumerated.
+    #[error("出力デバイスの一覧を取得できません")]
+    DeviceList(#[source] DevicesError),
 
     /// Sound file was not found at the specified path.
-    #[error("サウンドファイルが見つかりません: {0}")]
-    FileNotFound(String),
+    #[error("サウンドファイルが見つかりません: {}", .0.display())]
+    FileNotFound(PathBuf),
+
+    /// Sound file exists but could not be opened (e.g., permissions).
+    #[error("サウンドファイルを開けません: {}", path.display())]
+    Open {
+        /// Path of the sound file.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: io::Error,
+    },
 
     /// Failed to decode the audio file.
-    #[error("サウンドファイルのデコードに失敗しました: {0}")]
-    DecodeError(String),
+    #[error("サウンドファイルのデコードに失敗しました: {}", path.display())]
+    Decode {
+        /// Path of the sound file.
+        path: PathBuf,
+        /// Underlying decoder error.
+        #[source]
+        source: DecoderError,
+    },
+
+    /// The file is not in an audio format the decoder recognizes.
+    #[error("対応していない音声形式です: {}", path.display())]
+    UnsupportedFormat {
+        /// Path of the sound file.
+        path: PathBuf,
+    },
 
     /// Failed to create the audio output stream.
-    #[error("オーディオストリームの作成に失敗しました: {0}")]
-    StreamError(String),
+    #[error("オーディオストリームの作成に失敗しました")]
+    Stream(#[source] PlayError),
 
     /// Generic sound playback error.
     #[error("サウンド再生エラー: {0}")]
@@ -35,10 +72,50 @@ pub enum SoundError {
 }
 
 impl SoundError {
+    /// Creates the error for a sound file that failed to open.
+    ///
+    /// A missing file becomes [`Self::FileNotFound`].
+    pub fn open(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        let path = path.into();
+        if source.kind() == io::ErrorKind::NotFound {
+            Self::FileNotFound(path)
+        } else {
+            Self::Open { path, source }
+        }
+    }
+
+    /// Creates the error for a sound that failed to decode.
+    ///
+    /// An unrecognized format becomes [`Self::UnsupportedFormat`].
+    pub fn decode(path: impl Into<PathBuf>, source: DecoderError) -> Self {
+        let path = path.into();
+        match source {
+            DecoderError::UnrecognizedFormat => Self::UnsupportedFormat { path },
+            source => Self::Decode { path, source },
+        }
+    }
+
+    /// Returns the message followed by its chain of causes
+    /// (e.g. "オーディオデバイスが利用できません: NoDevice"), for `doctor` and logs.
+    #[must_use]
+    pub fn detail(&self) -> String {
+        let mut detail = self.to_string();
+        let mut source = self.source();
+        while let Some(cause) = source {
+            detail.push_str(": ");
+            detail.push_str(&cause.to_string());
+            source = cause.source();
+        }
+        detail
+    }
+
     /// Returns true if this error is related to device availability.
     #[must_use]
     pub fn is_device_error(&self) -> bool {
-        matches!(self, Self::DeviceNotAvailable(_) | Self::StreamError(_))
+        matches!(
+            self,
+            Self::DeviceUnavailable(_) | Self::DeviceList(_) | Self::Stream(_)
+        )
     }
 
     /// Returns true if this error is related to the audio file.
@@ -46,7 +123,11 @@ impl SoundError {
     pub fn is_file_error(&self) -> bool {
         matches!(
             self,
-            Self::FileNotFound(_) | Self::DecodeError(_) | Self::InvalidPath(_)
+            Self::FileNotFound(_)
+                | Self::Open { .. }
+                | Self::Decode { .. }
+                | Self::UnsupportedFormat { .. }
+                | Self::InvalidPath(_)
         )
     }
 
@@ -60,10 +141,13 @@ impl SoundError {
     #[must_use]
     pub fn suggestion(&self) -> &'static str {
         match self {
-            Self::DeviceNotAvailable(_) => "オーディオデバイスを接続してください",
+            Self::DeviceUnavailable(_) => "オーディオデバイスを接続してください",
+            Self::DeviceList(_) => "オーディオ設定を確認してください",
             Self::FileNotFound(_) => "埋め込みサウンドで再生を試みます",
-            Self::DecodeError(_) => "サウンドファイルが破損している可能性があります",
-            Self::StreamError(_) => "オーディオ設定を確認してください",
+            Self::Open { .. } => "サウンドファイルの権限を確認してください",
+            Self::Decode { .. } => "サウンドファイルが破損している可能性があります",
+            Self::UnsupportedFormat { .. } => "aiff・wav・mp3などの音声ファイルを使用してください",
+            Self::Stream(_) => "オーディオ設定を確認してください",
             Self::PlaybackError(_) => "アプリケーションを再起動してください",
             Self::InvalidPath(_) => "許可されたシステムサウンドディレクトリを使用してください",
         }
@@ -74,64 +158,109 @@ impl SoundError {
 mod tests {
     use super::*;
 
+    fn not_found() -> io::Error {
+        io::Error::new(io::ErrorKind::NotFound, "No such file or directory")
+    }
+
     #[test]
     fn test_error_display() {
-        let err = SoundError::DeviceNotAvailable("no device".to_string());
-        assert!(err.to_string().contains("no device"));
-        assert!(err
-            .to_string()
-            .contains("オーディオデバイスが利用できません"));
+        let err = SoundError::DeviceUnavailable(StreamError::NoDevice);
+        assert_eq!(err.to_string(), "オーディオデバイスが利用できません");
 
-        let err = SoundError::FileNotFound("/path/to/sound.aiff".to_string());
+        let err = SoundError::FileNotFound(PathBuf::from("/path/to/sound.aiff"));
         assert!(err.to_string().contains("/path/to/sound.aiff"));
 
-        let err = SoundError::DecodeError("invalid format".to_string());
-        assert!(err.to_string().contains("invalid format"));
+        let err = SoundError::decode("/path/to/bad.mp3", DecoderError::DecodeError("bad frame"));
+        assert!(err.to_string().contains("/path/to/bad.mp3"));
 
-        let err = SoundError::StreamError("stream failed".to_string());
-        assert!(err.to_string().contains("stream failed"));
+        let err = SoundError::Stream(PlayError::NoDevice);
+        assert!(err.to_string().contains("オーディオストリーム"));
 
         let err = SoundError::PlaybackError("unknown error".to_string());
         assert!(err.to_string().contains("unknown error"));
     }
 
+    #[test]
+    fn test_source_is_preserved() {
+        let err = SoundError::DeviceUnavailable(StreamError::NoDevice);
+        assert!(err.source().is_some());
+        assert!(err
+            .detail()
+            .starts_with("オーディオデバイスが利用できません: "));
+
+        let err = SoundError::decode("/path/to/bad.mp3", DecoderError::DecodeError("bad frame"));
+        assert!(err.detail().ends_with("bad frame"));
+
+        let err = SoundError::FileNotFound(PathBuf::from("/x.aiff"));
+        assert!(err.source().is_none());
+        assert_eq!(err.detail(), err.to_string());
+    }
+
+    #[test]
+    fn test_open_missing_file_is_file_not_found() {
+        let err = SoundError::open("/missing.aiff", not_found());
+        assert!(
+            matches!(&err, SoundError::FileNotFound(path) if path == &PathBuf::from("/missing.aiff"))
+        );
+
+        let denied = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+        let err = SoundError::open("/locked.aiff", denied);
+        assert!(matches!(err, SoundError::Open { .. }));
+        assert!(err.detail().ends_with("denied"));
+    }
+
+    #[test]
+    fn test_decode_unrecognized_is_unsupported_format() {
+        let err = SoundError::decode("/notes.txt", DecoderError::UnrecognizedFormat);
+        assert!(
+            matches!(&err, SoundError::UnsupportedFormat { path } if path == &PathBuf::from("/notes.txt"))
+        );
+    }
+
     #[test]
     fn test_is_device_error() {
-        assert!(SoundError::DeviceNotAvailable("x".into()).is_device_error());
-        assert!(SoundError::StreamError("x".into()).is_device_error());
+        assert!(SoundError::DeviceUnavailable(StreamError::NoDevice).is_device_error());
+        assert!(SoundError::Stream(PlayError::NoDevice).is_device_error());
+        let err = DevicesError::BackendSpecific {
+            err: rodio::cpal::BackendSpecificError {
+                description: "x".into(),
+            },
+        };
+        assert!(SoundError::DeviceList(err).is_device_error());
         assert!(!SoundError::FileNotFound("x".into()).is_device_error());
-        assert!(!SoundError::DecodeError("x".into()).is_device_error());
+        assert!(!SoundError::UnsupportedFormat { path: "x".into() }.is_device_error());
         assert!(!SoundError::PlaybackError("x".into()).is_device_error());
     }
 
     #[test]
     fn test_is_file_error() {
         assert!(SoundError::FileNotFound("x".into()).is_file_error());
-        assert!(SoundError::DecodeError("x".into()).is_file_error());
-        assert!(!SoundError::DeviceNotAvailable("x".into()).is_file_error());
-        assert!(!SoundError::StreamError("x".into()).is_file_error());
+        assert!(SoundError::open("x", io::Error::other("x")).is_file_error());
+        assert!(SoundError::decode("x", DecoderError::UnrecognizedFormat).is_file_error());
+        assert!(!SoundError::DeviceUnavailable(StreamError::NoDevice).is_file_error());
+        assert!(!SoundError::Stream(PlayError::NoDevice).is_file_error());
         assert!(!SoundError::PlaybackError("x".into()).is_file_error());
     }
 
     #[test]
     fn test_should_fallback_to_embedded() {
         assert!(SoundError::FileNotFound("x".into()).should_fallback_to_embedded());
-        assert!(!SoundError::DecodeError("x".into()).should_fallback_to_embedded());
-        assert!(!SoundError::DeviceNotAvailable("x".into()).should_fallback_to_embedded());
+        assert!(!SoundError::UnsupportedFormat { path: "x".into() }.should_fallback_to_embedded());
+        assert!(!SoundError::DeviceUnavailable(StreamError::NoDevice).should_fallback_to_embedded());
     }
 
     #[test]
     fn test_suggestion() {
-        let err = SoundError::DeviceNotAvailable("x".into());
+        let err = SoundError::DeviceUnavailable(StreamError::NoDevice);
         assert!(err.suggestion().contains("オーディオデバイス"));
 
         let err = SoundError::FileNotFound("x".into());
         assert!(err.suggestion().contains("埋め込みサウンド"));
 
-        let err = SoundError::DecodeError("x".into());
+        let err = SoundError::decode("x", DecoderError::DecodeError("x"));
         assert!(err.suggestion().contains("破損"));
 
-        let err = SoundError::StreamError("x".into());
+        let err = SoundError::Stream(PlayError::NoDevice);
         assert!(err.suggestion().contains("オーディオ設定"));
 
         let err = SoundError::PlaybackError("x".into());
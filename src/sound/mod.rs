@@ -51,9 +51,12 @@ mod error;
 mod player;
 mod source;
 
-pub use embedded::{get_embedded_sound, get_embedded_sound_format, DEFAULT_SOUND_DATA};
+pub use embedded::{
+    get_embedded_sound, get_embedded_sound_format, get_embedded_sound_named, DEFAULT_SOUND_DATA,
+    TICK_SOUND_DATA, TICK_SOUND_NAME,
+};
 pub use error::SoundError;
-pub use player::{try_create_player, RodioSoundPlayer};
+pub use player::{list_output_devices, try_create_player, RodioSoundPlayer};
 pub use source::{discover_system_sounds, find_system_sound, get_default_sound, SoundSource};
 
 /// Trait for sound playback implementations.
@@ -70,6 +73,14 @@ pub trait SoundPlayer {
     /// Returns an error if playback fails.
     fn play(&self, source: &SoundSource) -> Result<(), SoundError>;
 
+    /// Prepares the audio output so the first [`Self::play`] starts
+    /// without delay.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the audio output cannot be prepared.
+    fn prewarm(&self) -> Result<(), SoundError>;
+
     /// Returns true if the audio system is available.
     fn is_available(&self) -> bool;
 
@@ -88,6 +99,10 @@ impl SoundPlayer for RodioSoundPlayer {
         RodioSoundPlayer::play(self, source)
     }
 
+    fn prewarm(&self) -> Result<(), SoundError> {
+        RodioSoundPlayer::prewarm(self)
+    }
+
     fn is_available(&self) -> bool {
         RodioSoundPlayer::is_available(self)
     }
@@ -109,6 +124,7 @@ impl SoundPlayer for RodioSoundPlayer {
 #[derive(Debug, Default)]
 pub struct MockSoundPlayer {
     play_calls: std::sync::Mutex<Vec<SoundSource>>,
+    prewarm_calls: std::sync::atomic::AtomicUsize,
     available: std::sync::atomic::AtomicBool,
     disabled: std::sync::atomic::AtomicBool,
     should_fail: std::sync::atomic::AtomicBool,
@@ -119,6 +135,7 @@ impl MockSoundPlayer {
     pub fn new() -> Self {
         Self {
             play_calls: std::sync::Mutex::new(Vec::new()),
+            prewarm_calls: std::sync::atomic::AtomicUsize::new(0),
             available: std::sync::atomic::AtomicBool::new(true),
             disabled: std::sync::atomic::AtomicBool::new(false),
             should_fail: std::sync::atomic::AtomicBool::new(false),
@@ -140,6 +157,11 @@ impl MockSoundPlayer {
         self.play_calls.lock().unwrap().len()
     }
 
+    #[must_use]
+    pub fn prewarm_count(&self) -> usize {
+        self.prewarm_calls.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
     #[must_use]
     pub fn get_play_calls(&self) -> Vec<SoundSource> {
         self.play_calls.lock().unwrap().clone()
@@ -162,6 +184,15 @@ impl SoundPlayer for MockSoundPlayer {
         Ok(())
     }
 
+    fn prewarm(&self) -> Result<(), SoundError> {
+        if self.should_fail.load(std::sync::atomic::Ordering::SeqCst) {
+            return Err(SoundError::PlaybackError("Mock failure".to_string()));
+        }
+        self.prewarm_calls
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Ok(())
+    }
+
     fn is_available(&self) -> bool {
         self.available.load(std::sync::atomic::Ordering::SeqCst)
     }
@@ -206,6 +237,25 @@ pub fn play_notification_sound() -> Result<(), SoundError> {
     player.play(&source)
 }
 
+/// Prewarms `player` if audio is available and playback is enabled.
+///
+/// Called at startup so the first notification plays without the delay of
+/// starting the output device. A failure is logged and otherwise ignored.
+///
+/// Returns whether the player was prewarmed.
+pub fn prewarm_player<P: SoundPlayer>(player: &P) -> bool {
+    if !player.is_available() || player.is_disabled() {
+        return false;
+    }
+    match player.prewarm() {
+        Ok(()) => true,
+        Err(e) => {
+            tracing::warn!("Failed to prewarm audio output: {}", e);
+            false
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,6 +302,24 @@ mod tests {
         let _ = discover_system_sounds();
     }
 
+    #[test]
+    fn test_prewarm_player_behind_availability_check() {
+        let player = MockSoundPlayer::new();
+        assert!(prewarm_player(&player));
+        assert_eq!(player.prewarm_count(), 1);
+        assert_eq!(player.play_count(), 0);
+
+        let unavailable = MockSoundPlayer::new();
+        unavailable.set_available(false);
+        assert!(!prewarm_player(&unavailable));
+        assert_eq!(unavailable.prewarm_count(), 0);
+
+        let disabled = MockSoundPlayer::new();
+        disabled.disable();
+        assert!(!prewarm_player(&disabled));
+        assert_eq!(disabled.prewarm_count(), 0);
+    }
+
     #[test]
     fn test_play_notification_sound_graceful_failure() {
         // May fail in container without audio, that's expected
@@ -1,19 +1,31 @@
 //! Sound player implementation using rodio.
 //!
 //! This module provides the `RodioSoundPlayer` which uses the rodio v0.20
-//! audio library for cross-platform sound playback.
+//! audio library for cross-platform sound playback. Sounds go to the
+//! system default output device unless a device is picked by name (see
+//! [`list_output_devices`]).
 
 use std::fs::File;
 use std::io::{BufReader, Cursor};
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
-use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
+use rodio::cpal::traits::HostTrait;
+use rodio::source::Zero;
+use rodio::{Decoder, DeviceTrait, OutputStream, OutputStreamHandle, Sink, Source};
 use tracing::{debug, warn};
 
-use super::embedded::get_embedded_sound;
+use super::embedded::{get_embedded_sound, get_embedded_sound_named};
 use super::error::SoundError;
 use super::source::SoundSource;
 
+/// Length of the silent buffer played by [`RodioSoundPlayer::prewarm`].
+const PREWARM_LENGTH: Duration = Duration::from_millis(50);
+
+/// Sample rate of the silent buffer (the mixer resamples it as needed).
+const PREWARM_SAMPLE_RATE: u32 = 44_100;
+
 /// A sound player that uses rodio for audio playback.
 ///
 /// This player is thread-safe and can be shared across threads using `Arc`.
@@ -36,11 +48,11 @@ impl RodioSoundPlayer {
     ///
     /// # Errors
     ///
-    /// Returns `SoundError::DeviceNotAvailable` if no audio output device
+    /// Returns `SoundError::DeviceUnavailable` if no audio output device
     /// is available.
     pub fn new(disabled: bool) -> Result<Self, SoundError> {
-        let (stream, stream_handle) = OutputStream::try_default()
-            .map_err(|e| SoundError::DeviceNotAvailable(e.to_string()))?;
+        let (stream, stream_handle) =
+            OutputStream::try_default().map_err(SoundError::DeviceUnavailable)?;
 
         debug!("Audio output stream initialized");
 
@@ -51,6 +63,45 @@ impl RodioSoundPlayer {
         })
     }
 
+    /// Creates a sound player on the output device named `name`.
+    ///
+    /// If no device has that name, a warning is logged and the default
+    /// device is used, as with [`Self::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `SoundError::DeviceUnavailable` if the device cannot be
+    /// opened (or, after falling back, no default device is available).
+    pub fn with_device(name: &str, disabled: bool) -> Result<Self, SoundError> {
+        let devices = match rodio::cpal::default_host().output_devices() {
+            Ok(devices) => devices
+                .filter_map(|device| device.name().ok().map(|name| (name, device)))
+                .collect(),
+            Err(e) => {
+                warn!("Failed to enumerate output devices: {}", e);
+                Vec::new()
+            }
+        };
+        let Some(device) = find_device(devices, name) else {
+            warn!(
+                "Output device '{}' not found, using the default device",
+                name
+            );
+            return Self::new(disabled);
+        };
+
+        let (stream, stream_handle) =
+            OutputStream::try_from_device(&device).map_err(SoundError::DeviceUnavailable)?;
+
+        debug!("Audio output stream initialized on '{}'", name);
+
+        Ok(Self {
+            _stream: stream,
+            stream_handle,
+            disabled: AtomicBool::new(disabled),
+        })
+    }
+
     /// Creates a disabled sound player without initializing audio hardware.
     ///
     /// This is useful for testing or when audio is not needed.
@@ -94,35 +145,30 @@ impl RodioSoundPlayer {
                     Err(e) => {
                         warn!(
                             "Failed to play system sound '{}': {}, falling back to embedded",
-                            name, e
+                            name,
+                            e.detail()
                         );
-                        self.play_embedded()
+                        self.play_data("default", get_embedded_sound())
                     }
                 }
             }
             SoundSource::Embedded { name } => {
                 debug!("Playing embedded sound: {}", name);
-                self.play_embedded()
+                self.play_data(name, get_embedded_sound_named(name))
             }
         }
     }
 
     /// Plays a sound file from the filesystem.
-    fn play_file(&self, path: &std::path::Path) -> Result<(), SoundError> {
-        let file = File::open(path)
-            .map_err(|e| SoundError::FileNotFound(format!("{}: {}", path.display(), e)))?;
-
-        let reader = BufReader::new(file);
-        let decoder = Decoder::new(reader).map_err(|e| SoundError::DecodeError(e.to_string()))?;
-
-        self.play_decoder(decoder)
+    fn play_file(&self, path: &Path) -> Result<(), SoundError> {
+        self.play_decoder(open_decoder(path)?)
     }
 
-    /// Plays the embedded fallback sound.
-    fn play_embedded(&self) -> Result<(), SoundError> {
-        let cursor = Cursor::new(get_embedded_sound());
+    /// Plays embedded sound data.
+    fn play_data(&self, name: &str, data: &'static [u8]) -> Result<(), SoundError> {
+        let cursor = Cursor::new(data);
         let decoder = Decoder::new(cursor)
-            .map_err(|e| SoundError::DecodeError(format!("embedded sound: {}", e)))?;
+            .map_err(|e| SoundError::decode(format!("embedded:{}", name), e))?;
 
         self.play_decoder(decoder)
     }
@@ -132,8 +178,7 @@ impl RodioSoundPlayer {
     where
         R: std::io::Read + std::io::Seek + Send + Sync + 'static,
     {
-        let sink = Sink::try_new(&self.stream_handle)
-            .map_err(|e| SoundError::StreamError(e.to_string()))?;
+        let sink = Sink::try_new(&self.stream_handle).map_err(SoundError::Stream)?;
 
         sink.append(decoder);
         sink.detach(); // Non-blocking: sound continues after function returns
@@ -142,6 +187,30 @@ impl RodioSoundPlayer {
         Ok(())
     }
 
+    /// Gets the output device running before the first real sound.
+    ///
+    /// The stream is opened in the constructor, but the device only starts
+    /// pulling samples once something is played, which can delay the first
+    /// sound noticeably. This plays a short silent buffer so that the first
+    /// notification starts instantly. Does nothing while disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SoundError::Stream` if no sink can be created on the stream.
+    pub fn prewarm(&self) -> Result<(), SoundError> {
+        if self.disabled.load(Ordering::Relaxed) {
+            debug!("Sound playback disabled, skipping prewarm");
+            return Ok(());
+        }
+
+        let sink = Sink::try_new(&self.stream_handle).map_err(SoundError::Stream)?;
+        sink.append(Zero::<f32>::new(1, PREWARM_SAMPLE_RATE).take_duration(PREWARM_LENGTH));
+        sink.detach();
+
+        debug!("Audio output prewarmed");
+        Ok(())
+    }
+
     /// Returns true if sound playback is currently disabled.
     #[must_use]
     pub fn is_disabled(&self) -> bool {
@@ -178,16 +247,60 @@ impl std::fmt::Debug for RodioSoundPlayer {
     }
 }
 
+/// Opens and decodes a sound file.
+///
+/// # Errors
+///
+/// Returns `SoundError::FileNotFound` with the path if the file does not
+/// exist, or a decode error if it is not a playable sound.
+fn open_decoder(path: &Path) -> Result<Decoder<BufReader<File>>, SoundError> {
+    let file = File::open(path).map_err(|e| SoundError::open(path, e))?;
+    Decoder::new(BufReader::new(file)).map_err(|e| SoundError::decode(path, e))
+}
+
+/// Returns the names of the audio output devices, for `sound devices`.
+///
+/// # Errors
+///
+/// Returns `SoundError::DeviceList` if the devices cannot be enumerated.
+pub fn list_output_devices() -> Result<Vec<String>, SoundError> {
+    let devices = rodio::cpal::default_host()
+        .output_devices()
+        .map_err(SoundError::DeviceList)?;
+    Ok(devices.filter_map(|device| device.name().ok()).collect())
+}
+
+/// Picks the device named `name` from `(name, device)` pairs.
+///
+/// An exact match wins; otherwise the first case-insensitive match is used.
+fn find_device<D>(devices: Vec<(String, D)>, name: &str) -> Option<D> {
+    let index = devices
+        .iter()
+        .position(|(candidate, _)| candidate == name)
+        .or_else(|| {
+            devices
+                .iter()
+                .position(|(candidate, _)| candidate.eq_ignore_ascii_case(name))
+        })?;
+    devices.into_iter().nth(index).map(|(_, device)| device)
+}
+
 /// Creates a sound player, returning None if audio is unavailable.
 ///
-/// This is a convenience function for optional sound support.
-/// If audio initialization fails, a warning is logged and None is returned.
+/// This is a convenience function for optional sound support. The player
+/// uses the output device named `device` if given (see
+/// [`RodioSoundPlayer::with_device`]). If audio initialization fails, a
+/// warning is logged and None is returned.
 #[must_use]
-pub fn try_create_player(disabled: bool) -> Option<RodioSoundPlayer> {
-    match RodioSoundPlayer::new(disabled) {
+pub fn try_create_player(device: Option<&str>, disabled: bool) -> Option<RodioSoundPlayer> {
+    let player = match device {
+        Some(name) => RodioSoundPlayer::with_device(name, disabled),
+        None => RodioSoundPlayer::new(disabled),
+    };
+    match player {
         Ok(player) => Some(player),
         Err(e) => {
-            warn!("Audio not available, sound disabled: {}", e);
+            warn!("Audio not available, sound disabled: {}", e.detail());
             None
         }
     }
@@ -234,10 +347,36 @@ mod tests {
     #[test]
     fn test_try_create_player_with_disabled() {
         // Should return None or Some depending on audio availability
-        let _result = try_create_player(true);
+        let _result = try_create_player(None, true);
         // Just verify it doesn't panic
     }
 
+    #[test]
+    fn test_unknown_device_falls_back_to_default() {
+        // Same availability as the default device; must not fail on the name
+        let default = RodioSoundPlayer::new(true).is_ok();
+        let named = RodioSoundPlayer::with_device("No Such Device", true).is_ok();
+        assert_eq!(named, default);
+    }
+
+    #[test]
+    fn test_find_device_prefers_exact_match() {
+        let devices = vec![
+            ("MacBook Pro Speakers".to_string(), 1),
+            ("AirPods".to_string(), 2),
+            ("airpods".to_string(), 3),
+        ];
+
+        assert_eq!(find_device(devices.clone(), "airpods"), Some(3));
+        assert_eq!(find_device(devices.clone(), "AIRPODS"), Some(2));
+        assert_eq!(
+            find_device(devices.clone(), "MacBook Pro Speakers"),
+            Some(1)
+        );
+        assert_eq!(find_device(devices, "External Headphones"), None);
+        assert_eq!(find_device(Vec::<(String, u32)>::new(), "AirPods"), None);
+    }
+
     #[test]
     fn test_debug_impl() {
         let player = match RodioSoundPlayer::disabled() {
@@ -260,6 +399,28 @@ mod tests {
         assert!(player.is_available());
     }
 
+    #[test]
+    fn test_open_missing_file_is_file_not_found() {
+        let path = Path::new("/nonexistent/path/to/sound.wav");
+
+        match open_decoder(path) {
+            Err(SoundError::FileNotFound(missing)) => assert_eq!(missing, path),
+            other => panic!("Expected FileNotFound, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn test_open_non_audio_file_is_unsupported_format() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("notes.wav");
+        std::fs::write(&path, "not audio").unwrap();
+
+        match open_decoder(&path) {
+            Err(SoundError::UnsupportedFormat { path: bad }) => assert_eq!(bad, path),
+            other => panic!("Expected UnsupportedFormat, got {:?}", other.err()),
+        }
+    }
+
     #[test]
     fn test_play_nonexistent_file_falls_back() {
         let player = match RodioSoundPlayer::new(false) {
@@ -212,7 +212,7 @@ pub fn find_system_sound(name: &str) -> Result<SoundSource, SoundError> {
     sounds
         .into_iter()
         .find(|s| s.name().eq_ignore_ascii_case(name))
-        .ok_or_else(|| SoundError::FileNotFound(format!("System sound '{}' not found", name)))
+        .ok_or_else(|| SoundError::FileNotFound(PathBuf::from(name)))
 }
 
 #[cfg(test)]
@@ -275,8 +275,8 @@ mod tests {
     fn test_find_system_sound_not_found() {
         let result = find_system_sound("NonExistentSound12345");
         assert!(result.is_err());
-        if let Err(SoundError::FileNotFound(msg)) = result {
-            assert!(msg.contains("NonExistentSound12345"));
+        if let Err(SoundError::FileNotFound(path)) = result {
+            assert_eq!(path, PathBuf::from("NonExistentSound12345"));
         } else {
             panic!("Expected FileNotFound error");
         }
@@ -0,0 +1,258 @@
+//! Text templates, for `status --format` and completion notification titles.
+//!
+//! A template is plain text with `{name}` placeholders; `{{` and `}}` print
+//! literal braces. [`render`] only finds the placeholders and asks a lookup
+//! function for their values, so each output (the CLI status line, the
+//! titles built by the daemon's notifications, ...) brings its own set of
+//! placeholders.
+
+use thiserror::Error;
+
+use crate::types::{PhaseDuration, ResponseData};
+
+/// Placeholders available in `status --format`.
+pub const STATUS_PLACEHOLDERS: [&str; 6] = [
+    "phase",
+    "remaining",
+    "remaining_mmss",
+    "count",
+    "task",
+    "tag",
+];
+
+/// Placeholders available in completion notification titles.
+pub const NOTIFICATION_TITLE_PLACEHOLDERS: [&str; 1] = ["task"];
+
+// ============================================================================
+// TemplateError
+// ============================================================================
+
+/// Errors from parsing a template.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TemplateError {
+    /// The placeholder name is not known
+    #[error("不明なプレースホルダーです: {{{0}}}")]
+    UnknownPlaceholder(String),
+
+    /// A `{` is never closed
+    #[error("プレースホルダーが閉じられていません")]
+    Unclosed,
+
+    /// A `}` has no matching `{`
+    #[error("対応する '{{' のない '}}' があります（'}}' は '}}}}' と書いてください）")]
+    UnmatchedClose,
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/// Renders a template, replacing each placeholder with `lookup(name)`.
+///
+/// # Errors
+///
+/// Returns an error if the template is malformed or `lookup` returns
+/// `None` for a placeholder.
+pub fn render<F>(template: &str, mut lookup: F) -> Result<String, TemplateError>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut output = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.next_if_eq(&'{').is_some() => output.push('{'),
+            '}' if chars.next_if_eq(&'}').is_some() => output.push('}'),
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(TemplateError::Unclosed),
+                    }
+                }
+                let value = lookup(&name).ok_or(TemplateError::UnknownPlaceholder(name))?;
+                output.push_str(&value);
+            }
+            '}' => return Err(TemplateError::UnmatchedClose),
+            c => output.push(c),
+        }
+    }
+
+    Ok(output)
+}
+
+/// Renders a `status --format` template for a status response.
+///
+/// Missing values (such as the task when none is set) render as empty text.
+///
+/// # Errors
+///
+/// Returns an error if the template is malformed or uses an unknown placeholder.
+pub fn render_status(template: &str, data: &ResponseData) -> Result<String, TemplateError> {
+    render(template, |name| status_value(data, name))
+}
+
+/// Returns the value of a `status --format` placeholder.
+fn status_value(data: &ResponseData, name: &str) -> Option<String> {
+    let remaining = data.remaining_seconds.unwrap_or(0);
+    let value = match name {
+        "phase" => data.state.clone().unwrap_or_default(),
+        "remaining" => remaining.to_string(),
+        "remaining_mmss" => format_mmss(remaining),
+        "count" => data.pomodoro_count.unwrap_or(0).to_string(),
+        "task" => data.task_name.clone().unwrap_or_default(),
+        "tag" => data.tag.clone().unwrap_or_default(),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Renders a completion notification title template for a task.
+///
+/// A missing task renders as empty text, and the whitespace it leaves
+/// behind is collapsed, so `"{task} 完了"` reads `"完了"` without a task.
+///
+/// # Errors
+///
+/// Returns an error if the template is malformed or uses an unknown placeholder.
+pub fn render_notification_title(
+    template: &str,
+    task: Option<&str>,
+) -> Result<String, TemplateError> {
+    let rendered = render(template, |name| match name {
+        "task" => Some(task.unwrap_or_default().to_string()),
+        _ => None,
+    })?;
+    Ok(rendered.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Formats seconds as `MM:SS` (minutes are not wrapped into hours).
+pub fn format_mmss(seconds: u32) -> String {
+    PhaseDuration::from_secs(seconds).to_string()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn working() -> ResponseData {
+        ResponseData {
+            state: Some("working".to_string()),
+            remaining_seconds: Some(930),
+            pomodoro_count: Some(3),
+            task_name: Some("Write docs".to_string()),
+            tag: Some("project-x".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_render_status_templates() {
+        let data = working();
+
+        assert_eq!(
+            render_status("{phase} {remaining} ({count})", &data).unwrap(),
+            "working 930 (3)"
+        );
+        assert_eq!(
+            render_status("🍅 {remaining_mmss} {task}", &data).unwrap(),
+            "🍅 15:30 Write docs"
+        );
+        assert_eq!(
+            render_status("{task} [{tag}]", &data).unwrap(),
+            "Write docs [project-x]"
+        );
+        assert_eq!(
+            render_status("no placeholders", &data).unwrap(),
+            "no placeholders"
+        );
+    }
+
+    #[test]
+    fn test_missing_values_render_empty() {
+        let data = ResponseData {
+            state: Some("stopped".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            render_status("[{phase}|{task}|{remaining_mmss}]", &data).unwrap(),
+            "[stopped||00:00]"
+        );
+    }
+
+    #[test]
+    fn test_escaped_braces() {
+        assert_eq!(render_status("{{{count}}}", &working()).unwrap(), "{3}");
+    }
+
+    #[test]
+    fn test_unknown_placeholder_is_error() {
+        let err = render_status("{phase} {elapsed}", &working()).unwrap_err();
+
+        assert!(matches!(err, TemplateError::UnknownPlaceholder(ref name) if name == "elapsed"));
+        assert!(err.to_string().contains("{elapsed}"));
+    }
+
+    #[test]
+    fn test_malformed_templates_are_errors() {
+        assert_eq!(
+            render_status("{phase", &working()),
+            Err(TemplateError::Unclosed)
+        );
+        assert_eq!(
+            render_status("phase}", &working()),
+            Err(TemplateError::UnmatchedClose)
+        );
+    }
+
+    #[test]
+    fn test_format_mmss() {
+        assert_eq!(format_mmss(0), "00:00");
+        assert_eq!(format_mmss(59), "00:59");
+        assert_eq!(format_mmss(25 * 60), "25:00");
+        assert_eq!(format_mmss(120 * 60 + 5), "120:05");
+    }
+
+    #[test]
+    fn test_render_notification_title_with_task() {
+        assert_eq!(
+            render_notification_title("✅ {task} が終わりました", Some("Write docs")).unwrap(),
+            "✅ Write docs が終わりました"
+        );
+        assert_eq!(
+            render_notification_title("🍅 ポモドーロタイマー", Some("Write docs")).unwrap(),
+            "🍅 ポモドーロタイマー"
+        );
+    }
+
+    #[test]
+    fn test_render_notification_title_without_task() {
+        assert_eq!(
+            render_notification_title("✅ {task} が終わりました", None).unwrap(),
+            "✅ が終わりました"
+        );
+        assert_eq!(
+            render_notification_title("[{task}] 完了", None).unwrap(),
+            "[] 完了"
+        );
+        assert!(matches!(
+            render_notification_title("{count} 完了", None),
+            Err(TemplateError::UnknownPlaceholder(ref name)) if name == "count"
+        ));
+    }
+
+    #[test]
+    fn test_render_with_custom_lookup() {
+        let rendered = render("{a}-{b}", |name| Some(name.to_uppercase())).unwrap();
+
+        assert_eq!(rendered, "A-B");
+    }
+}
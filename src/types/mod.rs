@@ -5,6 +5,10 @@
 //! - Timer configuration with validation
 //! - IPC request/response serialization
 
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -22,7 +26,7 @@ pub enum TimerPhase {
     Working,
     /// Currently in a short break
     Breaking,
-    /// Currently in a long break (after 4 pomodoros)
+    /// Currently in a long break (after `long_break_interval` pomodoros)
     LongBreaking,
     /// Timer is paused
     Paused,
@@ -40,6 +44,18 @@ impl TimerPhase {
         }
     }
 
+    /// Parses a phase from its string representation (see [`Self::as_str`]).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "stopped" => Some(TimerPhase::Stopped),
+            "working" => Some(TimerPhase::Working),
+            "breaking" => Some(TimerPhase::Breaking),
+            "long_breaking" => Some(TimerPhase::LongBreaking),
+            "paused" => Some(TimerPhase::Paused),
+            _ => None,
+        }
+    }
+
     /// Returns true if the timer is actively counting down.
     pub fn is_active(&self) -> bool {
         matches!(
@@ -49,53 +65,265 @@ impl TimerPhase {
     }
 }
 
+/// Error returned when a string is not a known [`TimerPhase`] name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePhaseError(String);
+
+impl fmt::Display for ParsePhaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "不明なタイマー状態です: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParsePhaseError {}
+
+impl FromStr for TimerPhase {
+    type Err = ParsePhaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or_else(|| ParsePhaseError(s.to_string()))
+    }
+}
+
+impl TryFrom<&str> for TimerPhase {
+    type Error = ParsePhaseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+// ============================================================================
+// PhaseDuration
+// ============================================================================
+
+/// Longest time a single phase can run, in seconds (one day).
+///
+/// Durations are clamped to this instead of overflowing `u32` seconds, so
+/// absurd lengths from a request or an unvalidated config are harmless.
+pub const MAX_PHASE_SECONDS: u32 = 24 * 60 * 60;
+
+/// Length of a timer phase, stored in seconds.
+///
+/// Constructors clamp to [`MAX_PHASE_SECONDS`]. `Display` formats as
+/// `MM:SS` without wrapping into hours (e.g. "90:00"). It is serialized as
+/// whole minutes, the format config files and IPC messages have always
+/// used; any leftover seconds are dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PhaseDuration {
+    seconds: u32,
+}
+
+impl PhaseDuration {
+    /// A zero-length duration.
+    pub const ZERO: Self = Self { seconds: 0 };
+
+    /// Creates a duration of `seconds`.
+    pub const fn from_secs(seconds: u32) -> Self {
+        if seconds > MAX_PHASE_SECONDS {
+            Self {
+                seconds: MAX_PHASE_SECONDS,
+            }
+        } else {
+            Self { seconds }
+        }
+    }
+
+    /// Creates a duration of `minutes`.
+    pub const fn from_minutes(minutes: u32) -> Self {
+        Self::from_secs(minutes.saturating_mul(60))
+    }
+
+    /// Returns the duration in seconds.
+    pub const fn as_secs(self) -> u32 {
+        self.seconds
+    }
+
+    /// Returns the duration in whole minutes, rounded down.
+    pub const fn as_minutes(self) -> u32 {
+        self.seconds / 60
+    }
+}
+
+impl fmt::Display for PhaseDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.seconds / 60, self.seconds % 60)
+    }
+}
+
+impl Serialize for PhaseDuration {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.as_minutes())
+    }
+}
+
+impl<'de> Deserialize<'de> for PhaseDuration {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::from_minutes)
+    }
+}
+
 // ============================================================================
 // PomodoroConfig
 // ============================================================================
 
 /// Configuration for the Pomodoro timer.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PomodoroConfig {
-    /// Work duration in minutes (1-120)
-    pub work_minutes: u32,
-    /// Short break duration in minutes (1-60)
-    pub break_minutes: u32,
-    /// Long break duration in minutes (1-60)
-    pub long_break_minutes: u32,
+    /// Work duration (1-120 minutes)
+    #[serde(rename = "work_minutes")]
+    pub work_duration: PhaseDuration,
+    /// Short break duration (1-60 minutes)
+    #[serde(rename = "break_minutes")]
+    pub break_duration: PhaseDuration,
+    /// Long break duration (1-60 minutes)
+    #[serde(rename = "long_break_minutes")]
+    pub long_break_duration: PhaseDuration,
+    /// Number of pomodoros before a long break (1-10)
+    #[serde(default = "default_long_break_interval")]
+    pub long_break_interval: u32,
+    /// Whether long breaks are taken at all (false = always a short break)
+    #[serde(default = "default_long_break_enabled")]
+    pub long_break_enabled: bool,
     /// Whether to automatically start the next cycle
     pub auto_cycle: bool,
     /// Whether to enable Focus Mode integration
     pub focus_mode: bool,
+    /// Whether pausing is disabled (strict mode)
+    #[serde(default)]
+    pub strict: bool,
+    /// Number of work sessions to run before auto-cycle stops (None = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cycles: Option<u32>,
+    /// Seconds into a work session during which stopping is not an abandonment
+    #[serde(default = "default_abandon_grace_seconds")]
+    pub abandon_grace_seconds: u32,
+    /// Seconds of a break that must elapse before it can be skipped (0 = no floor)
+    #[serde(default)]
+    pub min_break_seconds: u32,
+    /// Shortest work session in minutes the daemon accepts on `start`
+    #[serde(default = "default_min_work_minutes")]
+    pub min_work_minutes: u32,
+    /// Hard cap in minutes on the length of any one phase, enforced by the daemon
+    #[serde(default = "default_max_work_minutes")]
+    pub max_work_minutes: u32,
+    /// Whether a completed work session waits for the user to start the break
+    #[serde(default)]
+    pub prompt_break: bool,
 }
 
 impl Default for PomodoroConfig {
     fn default() -> Self {
         Self {
-            work_minutes: 25,
-            break_minutes: 5,
-            long_break_minutes: 15,
+            work_duration: PhaseDuration::from_minutes(25),
+            break_duration: PhaseDuration::from_minutes(5),
+            long_break_duration: PhaseDuration::from_minutes(15),
+            long_break_interval: default_long_break_interval(),
+            long_break_enabled: default_long_break_enabled(),
             auto_cycle: false,
             focus_mode: false,
+            strict: false,
+            max_cycles: None,
+            abandon_grace_seconds: default_abandon_grace_seconds(),
+            min_break_seconds: 0,
+            min_work_minutes: default_min_work_minutes(),
+            max_work_minutes: default_max_work_minutes(),
+            prompt_break: false,
         }
     }
 }
 
+fn default_long_break_interval() -> u32 {
+    4
+}
+
+fn default_long_break_enabled() -> bool {
+    true
+}
+
+fn default_abandon_grace_seconds() -> u32 {
+    60
+}
+
+fn default_min_work_minutes() -> u32 {
+    1
+}
+
+fn default_max_work_minutes() -> u32 {
+    120
+}
+
 impl PomodoroConfig {
     /// Creates a new configuration with the specified work duration.
     pub fn with_work_minutes(mut self, minutes: u32) -> Self {
-        self.work_minutes = minutes;
+        self.work_duration = PhaseDuration::from_minutes(minutes);
         self
     }
 
     /// Creates a new configuration with the specified break duration.
     pub fn with_break_minutes(mut self, minutes: u32) -> Self {
-        self.break_minutes = minutes;
+        self.break_duration = PhaseDuration::from_minutes(minutes);
         self
     }
 
     /// Creates a new configuration with the specified long break duration.
     pub fn with_long_break_minutes(mut self, minutes: u32) -> Self {
-        self.long_break_minutes = minutes;
+        self.long_break_duration = PhaseDuration::from_minutes(minutes);
+        self
+    }
+
+    /// Creates a new configuration with the specified long break interval.
+    pub fn with_long_break_interval(mut self, interval: u32) -> Self {
+        self.long_break_interval = interval;
+        self
+    }
+
+    /// Creates a new configuration with long breaks enabled or disabled.
+    pub fn with_long_break_enabled(mut self, enabled: bool) -> Self {
+        self.long_break_enabled = enabled;
+        self
+    }
+
+    /// Creates a new configuration with strict mode enabled or disabled.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Creates a new configuration with the given abandonment grace period.
+    pub fn with_abandon_grace_seconds(mut self, seconds: u32) -> Self {
+        self.abandon_grace_seconds = seconds;
+        self
+    }
+
+    /// Creates a new configuration with the given minimum break before skipping.
+    pub fn with_min_break_seconds(mut self, seconds: u32) -> Self {
+        self.min_break_seconds = seconds;
+        self
+    }
+
+    /// Creates a new configuration that waits for the user to start each break.
+    pub fn with_prompt_break(mut self, prompt_break: bool) -> Self {
+        self.prompt_break = prompt_break;
+        self
+    }
+
+    /// Creates a new configuration with the given shortest work session.
+    pub fn with_min_work_minutes(mut self, minutes: u32) -> Self {
+        self.min_work_minutes = minutes;
+        self
+    }
+
+    /// Creates a new configuration with the given session length cap.
+    pub fn with_max_work_minutes(mut self, minutes: u32) -> Self {
+        self.max_work_minutes = minutes;
+        self
+    }
+
+    /// Creates a new configuration that stops auto-cycle after `cycles` work sessions.
+    pub fn with_max_cycles(mut self, cycles: u32) -> Self {
+        self.max_cycles = Some(cycles);
         self
     }
 
@@ -103,17 +331,53 @@ impl PomodoroConfig {
     ///
     /// Returns an error message if validation fails.
     pub fn validate(&self) -> Result<(), String> {
-        if self.work_minutes < 1 || self.work_minutes > 120 {
+        let minutes = PhaseDuration::from_minutes;
+        if !(minutes(1)..=minutes(120)).contains(&self.work_duration) {
             return Err("作業時間は1-120分の範囲で指定してください".to_string());
         }
-        if self.break_minutes < 1 || self.break_minutes > 60 {
+        if !(minutes(1)..=minutes(60)).contains(&self.break_duration) {
             return Err("休憩時間は1-60分の範囲で指定してください".to_string());
         }
-        if self.long_break_minutes < 1 || self.long_break_minutes > 60 {
+        if !(minutes(1)..=minutes(60)).contains(&self.long_break_duration) {
             return Err("長い休憩時間は1-60分の範囲で指定してください".to_string());
         }
+        if self.long_break_interval < 1 || self.long_break_interval > 10 {
+            return Err("長い休憩までのポモドーロ数は1-10の範囲で指定してください".to_string());
+        }
+        if self.max_cycles == Some(0) {
+            return Err("サイクル数は1以上で指定してください".to_string());
+        }
+        if self.max_work_minutes < 1 {
+            return Err("作業時間の上限は1分以上で指定してください".to_string());
+        }
+        if self.min_work_minutes < 1 || self.min_work_minutes > self.max_work_minutes {
+            return Err("作業時間の下限は1分以上、上限以下で指定してください".to_string());
+        }
         Ok(())
     }
+
+    /// Returns warnings about settings that are valid but probably a mistake.
+    ///
+    /// Unlike [`Self::validate`], these never reject the configuration; the
+    /// CLI prints them and carries on.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.break_duration > self.work_duration {
+            warnings.push(format!(
+                "休憩時間（{}分）が作業時間（{}分）より長くなっています",
+                self.break_duration.as_minutes(),
+                self.work_duration.as_minutes()
+            ));
+        }
+        if self.long_break_enabled && self.long_break_duration < self.break_duration {
+            warnings.push(format!(
+                "長い休憩時間（{}分）が休憩時間（{}分）より短くなっています",
+                self.long_break_duration.as_minutes(),
+                self.break_duration.as_minutes()
+            ));
+        }
+        warnings
+    }
 }
 
 // ============================================================================
@@ -129,13 +393,46 @@ pub struct TimerState {
     pub remaining_seconds: u32,
     /// Number of completed pomodoros
     pub pomodoro_count: u32,
+    /// Completed pomodoros across sessions (persisted; drives long-break cadence)
+    #[serde(default)]
+    pub total_completed_pomodoros: u32,
     /// Current task name (if any)
     pub task_name: Option<String>,
+    /// Tag of the current run (`start --tag`), kept across auto-cycled sessions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Tasks for the following work sessions (`start --task-queue`)
+    #[serde(default, skip_serializing_if = "VecDeque::is_empty")]
+    pub task_queue: VecDeque<String>,
     /// Timer configuration
     pub config: PomodoroConfig,
     /// Previous phase (used for resume after pause)
     #[serde(skip_serializing_if = "Option::is_none")]
     previous_phase: Option<TimerPhase>,
+    /// Whether a completed work session is waiting for its break to be
+    /// started (`prompt_break`); the phase is `Stopped` meanwhile
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub awaiting_break: bool,
+    /// Whether the run just ended on its own, for a brief "done" display
+    ///
+    /// A transient flag rather than a [`TimerPhase`]: the phase stays
+    /// `Stopped`, so persisted state, the IPC `state` value and matches on
+    /// the phase are unchanged, and UIs that ignore it keep showing
+    /// "stopped". The engine clears it again after a few seconds.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub cycle_completed: bool,
+    /// Why the timer was paused (`pause --reason`), while it is paused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pause_reason: Option<String>,
+    /// Full length of the current phase in seconds, fixed when it starts
+    /// (so a `break --minutes` length counts, not the configured one); 0
+    /// when stopped
+    #[serde(default)]
+    pub phase_seconds: u32,
+    /// Incremented whenever a phase starts or the timer stops, so a
+    /// completion can tell whether its phase is still the current one
+    #[serde(skip)]
+    phase_generation: u64,
 }
 
 impl TimerState {
@@ -145,33 +442,101 @@ impl TimerState {
             phase: TimerPhase::Stopped,
             remaining_seconds: 0,
             pomodoro_count: 0,
+            total_completed_pomodoros: 0,
             task_name: None,
+            tag: None,
+            task_queue: VecDeque::new(),
             config,
             previous_phase: None,
+            awaiting_break: false,
+            cycle_completed: false,
+            pause_reason: None,
+            phase_seconds: 0,
+            phase_generation: 0,
         }
     }
 
+    /// Returns the generation of the current phase.
+    ///
+    /// It changes whenever a phase starts (including a restarted phase of
+    /// the same kind) or the timer stops, but not on pause or resume.
+    pub fn phase_generation(&self) -> u64 {
+        self.phase_generation
+    }
+
     /// Starts a work session.
     pub fn start_working(&mut self, task_name: Option<String>) {
         self.phase = TimerPhase::Working;
-        self.remaining_seconds = self.config.work_minutes * 60;
+        self.phase_seconds = self.configured_duration(TimerPhase::Working).as_secs();
+        self.remaining_seconds = self.phase_seconds;
         self.task_name = task_name;
         self.previous_phase = None;
+        self.awaiting_break = false;
+        self.cycle_completed = false;
+        self.phase_generation += 1;
+    }
+
+    /// Starts a standalone short break of the given length.
+    ///
+    /// Unlike [`start_breaking`](Self::start_breaking), no work session is
+    /// involved and the task name and tag are cleared.
+    pub fn start_break_only(&mut self, minutes: u32) {
+        self.phase = TimerPhase::Breaking;
+        self.phase_seconds = PhaseDuration::from_minutes(minutes).as_secs();
+        self.remaining_seconds = self.phase_seconds;
+        self.task_name = None;
+        self.tag = None;
+        self.previous_phase = None;
+        self.awaiting_break = false;
+        self.cycle_completed = false;
+        self.phase_generation += 1;
+    }
+
+    /// Starts a standalone long break of the given length.
+    ///
+    /// Like [`start_break_only`](Self::start_break_only), but in
+    /// `LongBreaking`; the pomodoro count is not consulted.
+    pub fn start_long_break_only(&mut self, minutes: u32) {
+        self.start_break_only(minutes);
+        self.phase = TimerPhase::LongBreaking;
     }
 
     /// Starts a break session.
     ///
-    /// Automatically chooses between short and long break based on pomodoro count.
+    /// Automatically chooses between short and long break based on the total
+    /// number of completed pomodoros, which survives `stop`.
     pub fn start_breaking(&mut self) {
-        // Long break after every 4 pomodoros
-        if self.pomodoro_count > 0 && self.pomodoro_count % 4 == 0 {
+        // Long break after every `long_break_interval` pomodoros (unless disabled)
+        let interval = self.config.long_break_interval.max(1);
+        if self.config.long_break_enabled
+            && self.total_completed_pomodoros > 0
+            && self.total_completed_pomodoros % interval == 0
+        {
             self.phase = TimerPhase::LongBreaking;
-            self.remaining_seconds = self.config.long_break_minutes * 60;
         } else {
             self.phase = TimerPhase::Breaking;
-            self.remaining_seconds = self.config.break_minutes * 60;
         }
+        self.phase_seconds = self.configured_duration(self.phase).as_secs();
+        self.remaining_seconds = self.phase_seconds;
         self.previous_phase = None;
+        self.awaiting_break = false;
+        self.cycle_completed = false;
+        self.phase_generation += 1;
+    }
+
+    /// Holds the timer after a work session instead of starting the break.
+    ///
+    /// The phase becomes `Stopped` until [`start_breaking`](Self::start_breaking)
+    /// or [`start_working`](Self::start_working) is called. Unlike
+    /// [`stop`](Self::stop), the task and task queue are kept for the
+    /// sessions that follow.
+    pub fn await_break(&mut self) {
+        self.phase = TimerPhase::Stopped;
+        self.remaining_seconds = 0;
+        self.phase_seconds = 0;
+        self.previous_phase = None;
+        self.awaiting_break = true;
+        self.phase_generation += 1;
     }
 
     /// Pauses the timer.
@@ -186,8 +551,9 @@ impl TimerState {
 
     /// Resumes the timer from pause.
     ///
-    /// Restores the previous phase before pause.
+    /// Restores the previous phase before pause and drops the pause reason.
     pub fn resume(&mut self) {
+        self.pause_reason = None;
         if self.phase == TimerPhase::Paused {
             if let Some(prev) = self.previous_phase.take() {
                 self.phase = prev;
@@ -202,17 +568,29 @@ impl TimerState {
     pub fn stop(&mut self) {
         self.phase = TimerPhase::Stopped;
         self.remaining_seconds = 0;
+        self.phase_seconds = 0;
         self.task_name = None;
+        self.tag = None;
+        self.task_queue.clear();
         self.previous_phase = None;
+        self.awaiting_break = false;
+        self.cycle_completed = false;
+        self.pause_reason = None;
+        self.phase_generation += 1;
+    }
+
+    /// Stops the timer at the natural end of a run, marking the cycle as
+    /// completed (see [`Self::cycle_completed`]).
+    pub fn complete_cycle(&mut self) {
+        self.stop();
+        self.cycle_completed = true;
     }
 
     /// Decrements the timer by one second.
     ///
     /// Returns true if the timer has completed (reached 0).
     pub fn tick(&mut self) -> bool {
-        if self.remaining_seconds > 0 {
-            self.remaining_seconds -= 1;
-        }
+        self.remaining_seconds = self.remaining_seconds.saturating_sub(1);
         self.remaining_seconds == 0
     }
 
@@ -226,9 +604,118 @@ impl TimerState {
         self.phase == TimerPhase::Paused
     }
 
-    /// Increments the pomodoro count.
+    /// Returns the running phase, or the paused one while paused.
+    ///
+    /// Returns `None` when stopped.
+    pub fn active_phase(&self) -> Option<TimerPhase> {
+        match self.phase {
+            TimerPhase::Stopped => None,
+            TimerPhase::Paused => Some(self.previous_phase.unwrap_or(TimerPhase::Working)),
+            phase => Some(phase),
+        }
+    }
+
+    /// Returns true during a short or long break, including a paused one.
+    pub fn is_in_break(&self) -> bool {
+        let phase = match self.phase {
+            TimerPhase::Paused => self.previous_phase,
+            phase => Some(phase),
+        };
+        matches!(phase, Some(TimerPhase::Breaking | TimerPhase::LongBreaking))
+    }
+
+    /// Increments the pomodoro count and the total completed count.
     pub fn increment_pomodoro_count(&mut self) {
         self.pomodoro_count += 1;
+        self.total_completed_pomodoros += 1;
+    }
+
+    /// Clears the pomodoro counts, restarting the long-break cadence.
+    pub fn reset_counts(&mut self) {
+        self.pomodoro_count = 0;
+        self.total_completed_pomodoros = 0;
+    }
+
+    /// Returns the configured duration of `phase`.
+    ///
+    /// `Paused` and `Stopped` have no configured duration.
+    pub fn configured_duration(&self, phase: TimerPhase) -> PhaseDuration {
+        match phase {
+            TimerPhase::Working => self.config.work_duration,
+            TimerPhase::Breaking => self.config.break_duration,
+            TimerPhase::LongBreaking => self.config.long_break_duration,
+            TimerPhase::Paused | TimerPhase::Stopped => PhaseDuration::ZERO,
+        }
+    }
+
+    /// Returns the full duration of `phase`.
+    ///
+    /// For the current phase this is the length it started with
+    /// ([`Self::phase_seconds`]), which a `break --minutes` length can make
+    /// differ from the configuration; other phases have their configured
+    /// duration. `Paused` resolves to the phase that was paused; `Stopped`
+    /// has no duration.
+    pub fn phase_duration(&self, phase: TimerPhase) -> PhaseDuration {
+        let phase = match phase {
+            TimerPhase::Paused => match self.previous_phase {
+                Some(previous) if previous != TimerPhase::Paused => previous,
+                _ => return PhaseDuration::ZERO,
+            },
+            phase => phase,
+        };
+        if self.phase_seconds > 0 && self.active_phase() == Some(phase) {
+            PhaseDuration::from_secs(self.phase_seconds)
+        } else {
+            self.configured_duration(phase)
+        }
+    }
+
+    /// Returns the full duration of `phase` in seconds.
+    pub fn phase_total_seconds(&self, phase: TimerPhase) -> u32 {
+        self.phase_duration(phase).as_secs()
+    }
+
+    /// Returns the seconds elapsed in the current work session.
+    ///
+    /// Paused time does not count. Returns `None` outside a work session
+    /// (including a paused break).
+    pub fn work_elapsed_seconds(&self) -> Option<u32> {
+        let in_work = self.phase == TimerPhase::Working
+            || (self.phase == TimerPhase::Paused
+                && self.previous_phase == Some(TimerPhase::Working));
+        if !in_work {
+            return None;
+        }
+        Some(
+            self.phase_total_seconds(self.phase)
+                .saturating_sub(self.remaining_seconds),
+        )
+    }
+
+    /// Returns how much of the current phase has elapsed, from 0.0 to 1.0.
+    ///
+    /// Measured against the length the phase started with (see
+    /// [`Self::phase_duration`]). Returns 0.0 when stopped. Time added by
+    /// snoozing counts as not yet elapsed, so the fraction never goes below 0.0.
+    pub fn progress_fraction(&self) -> f32 {
+        let total = self.phase_total_seconds(self.phase);
+        if total == 0 {
+            return 0.0;
+        }
+        let elapsed = total.saturating_sub(self.remaining_seconds);
+        (elapsed as f32 / total as f32).clamp(0.0, 1.0)
+    }
+
+    /// Returns the number of pomodoros left to complete before the next long break.
+    ///
+    /// For example, with an interval of 4 and 2 completed pomodoros, this returns 2.
+    /// Returns `None` when long breaks are disabled.
+    pub fn pomodoros_until_long_break(&self) -> Option<u32> {
+        if !self.config.long_break_enabled {
+            return None;
+        }
+        let interval = self.config.long_break_interval.max(1);
+        Some(interval - self.total_completed_pomodoros % interval)
     }
 }
 
@@ -248,17 +735,107 @@ pub struct StartParams {
     /// Long break duration in minutes
     #[serde(rename = "longBreakMinutes", skip_serializing_if = "Option::is_none")]
     pub long_break_minutes: Option<u32>,
+    /// Number of pomodoros before a long break
+    #[serde(rename = "longBreakInterval", skip_serializing_if = "Option::is_none")]
+    pub long_break_interval: Option<u32>,
     /// Task name
     #[serde(rename = "taskName", skip_serializing_if = "Option::is_none")]
     pub task_name: Option<String>,
+    /// Tag categorizing the session, separate from the task name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
     /// Auto cycle flag
     #[serde(rename = "autoCycle", skip_serializing_if = "Option::is_none")]
     pub auto_cycle: Option<bool>,
     /// Focus mode flag
     #[serde(rename = "focusMode", skip_serializing_if = "Option::is_none")]
     pub focus_mode: Option<bool>,
+    /// Strict mode flag (disables pause)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+    /// Long break flag (false = always a short break)
+    #[serde(rename = "longBreakEnabled", skip_serializing_if = "Option::is_none")]
+    pub long_break_enabled: Option<bool>,
+    /// Number of work sessions before auto-cycle stops
+    #[serde(rename = "maxCycles", skip_serializing_if = "Option::is_none")]
+    pub max_cycles: Option<u32>,
+    /// Start in the paused state (the countdown begins on resume)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+    /// Tasks to work through in order (the first replaces `taskName`)
+    #[serde(rename = "taskQueue", skip_serializing_if = "Option::is_none")]
+    pub task_queue: Option<Vec<String>>,
+    /// Succeed without change if the same session is already running
+    #[serde(rename = "ifNotRunning", skip_serializing_if = "Option::is_none")]
+    pub if_not_running: Option<bool>,
+    /// Stop a running session and start this one in its place (refused in strict mode)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
 }
 
+impl StartParams {
+    /// Returns the timer configuration a start with these parameters would
+    /// use on top of `base`, or `None` if they leave the configuration as it is.
+    pub fn apply_to(&self, base: &PomodoroConfig) -> Option<PomodoroConfig> {
+        if self.work_minutes.is_none()
+            && self.break_minutes.is_none()
+            && self.long_break_minutes.is_none()
+            && self.long_break_interval.is_none()
+            && self.auto_cycle.is_none()
+            && self.focus_mode.is_none()
+            && self.strict.is_none()
+            && self.long_break_enabled.is_none()
+            && self.max_cycles.is_none()
+        {
+            return None;
+        }
+
+        let mut config = base.clone();
+        if let Some(work) = self.work_minutes {
+            config.work_duration = PhaseDuration::from_minutes(work);
+        }
+        if let Some(brk) = self.break_minutes {
+            config.break_duration = PhaseDuration::from_minutes(brk);
+        }
+        if let Some(long_brk) = self.long_break_minutes {
+            config.long_break_duration = PhaseDuration::from_minutes(long_brk);
+        }
+        if let Some(interval) = self.long_break_interval {
+            config.long_break_interval = interval;
+        }
+        if let Some(auto) = self.auto_cycle {
+            config.auto_cycle = auto;
+        }
+        if let Some(focus) = self.focus_mode {
+            config.focus_mode = focus;
+        }
+        if let Some(strict) = self.strict {
+            config.strict = strict;
+        }
+        if let Some(long_break) = self.long_break_enabled {
+            config.long_break_enabled = long_break;
+        }
+        // A cycle limit applies to one run only, so an absent value clears it
+        config.max_cycles = self.max_cycles;
+        Some(config)
+    }
+}
+
+/// Version of the IPC protocol spoken by this build.
+///
+/// Bumped when a request or response changes in a way the other side may
+/// misread. Version 2 leaves the start settings not given on the command
+/// line out of `start`, for the daemon to take from its configuration.
+/// Responses from daemons that predate versioning decode as version `0`.
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// `errorCode` of the response to a command the daemon does not know.
+pub const UNSUPPORTED_COMMAND: &str = "unsupported_command";
+
+/// Message of the `start` response that left the same session running
+/// (`start --if-not-running`).
+pub const SESSION_ALREADY_RUNNING: &str = "同じセッションが既に実行中です";
+
 /// IPC request from client to daemon.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "command", rename_all = "lowercase")]
@@ -270,17 +847,95 @@ pub enum IpcRequest {
         params: StartParams,
     },
     /// Pause the current timer
-    Pause,
+    Pause {
+        /// Why the timer is paused (e.g. "meeting")
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
     /// Resume the paused timer
     Resume,
     /// Stop the current timer
     Stop,
     /// Query the current status
     Status,
+    /// Start a standalone break without a work session
+    Break {
+        /// Break length in minutes (the configured break length if omitted)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        minutes: Option<u32>,
+        /// Number of breaks to run back to back (1 if omitted)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        repeat: Option<u32>,
+    },
+    /// Start a standalone long break without a work session
+    #[serde(rename = "longBreak")]
+    LongBreak {
+        /// Break length in minutes (the configured long break length if omitted)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        minutes: Option<u32>,
+    },
+    /// Extend the current break (or re-enter a just-ended one)
+    Snooze {
+        /// Minutes to snooze for
+        minutes: u32,
+    },
+    /// Go back to the just-completed work session for a few more minutes
+    #[serde(rename = "extendWork")]
+    ExtendWork {
+        /// Minutes of extra work
+        minutes: u32,
+    },
+    /// Clear the completed pomodoro counts
+    Reset,
+    /// Stream timer events over this connection until it is closed
+    Subscribe,
+    /// End the current break early and start the next work session
+    Skip,
+    /// Set the current session aside (kept across restarts) and stop
+    Suspend,
+    /// Resume the session set aside with `Suspend`
+    Unsuspend,
+    /// Query the daemon's timer configuration
+    #[serde(rename = "getConfig")]
+    GetConfig,
+    /// Replace the daemon's timer configuration (applied from the next phase)
+    #[serde(rename = "setConfig")]
+    SetConfig {
+        /// The new configuration
+        config: PomodoroConfig,
+    },
+    /// A command this daemon does not know (sent by a newer client)
+    #[serde(other)]
+    Unsupported,
+}
+
+impl IpcRequest {
+    /// Returns the command name (the serialized `command` tag).
+    pub fn command_name(&self) -> &'static str {
+        match self {
+            IpcRequest::Start { .. } => "start",
+            IpcRequest::Pause { .. } => "pause",
+            IpcRequest::Resume => "resume",
+            IpcRequest::Stop => "stop",
+            IpcRequest::Status => "status",
+            IpcRequest::Break { .. } => "break",
+            IpcRequest::LongBreak { .. } => "longBreak",
+            IpcRequest::Snooze { .. } => "snooze",
+            IpcRequest::ExtendWork { .. } => "extendWork",
+            IpcRequest::Reset => "reset",
+            IpcRequest::Subscribe => "subscribe",
+            IpcRequest::Skip => "skip",
+            IpcRequest::Suspend => "suspend",
+            IpcRequest::Unsuspend => "unsuspend",
+            IpcRequest::GetConfig => "getConfig",
+            IpcRequest::SetConfig { .. } => "setConfig",
+            IpcRequest::Unsupported => "unsupported",
+        }
+    }
 }
 
 /// Response data for IPC responses.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ResponseData {
     /// Current state/phase
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -294,6 +949,42 @@ pub struct ResponseData {
     /// Current task name
     #[serde(rename = "taskName", skip_serializing_if = "Option::is_none")]
     pub task_name: Option<String>,
+    /// Tag of the current run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Tasks queued for the following work sessions
+    #[serde(rename = "taskQueue", skip_serializing_if = "Option::is_none")]
+    pub task_queue: Option<Vec<String>>,
+    /// Pomodoros left before the next long break
+    #[serde(
+        rename = "pomodorosUntilLongBreak",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pomodoros_until_long_break: Option<u32>,
+    /// Elapsed fraction of the current phase (0.0-1.0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f32>,
+    /// Seconds elapsed in the current work session (absent outside work)
+    #[serde(rename = "workElapsedSeconds", skip_serializing_if = "Option::is_none")]
+    pub work_elapsed_seconds: Option<u32>,
+    /// Whether a completed work session is waiting for its break to be started
+    #[serde(rename = "awaitingBreak", skip_serializing_if = "Option::is_none")]
+    pub awaiting_break: Option<bool>,
+    /// Whether the run just ended on its own (briefly, after the last break)
+    #[serde(rename = "cycleCompleted", skip_serializing_if = "Option::is_none")]
+    pub cycle_completed: Option<bool>,
+    /// Why the timer was paused (only while paused with a reason)
+    #[serde(rename = "pauseReason", skip_serializing_if = "Option::is_none")]
+    pub pause_reason: Option<String>,
+    /// Whether the next phase starts automatically
+    #[serde(rename = "autoCycle", skip_serializing_if = "Option::is_none")]
+    pub auto_cycle: Option<bool>,
+    /// Whether focus mode is turned on during work sessions
+    #[serde(rename = "focusMode", skip_serializing_if = "Option::is_none")]
+    pub focus_mode: Option<bool>,
+    /// Timer configuration (only in `getConfig`/`setConfig` responses)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<PomodoroConfig>,
 }
 
 impl ResponseData {
@@ -304,8 +995,27 @@ impl ResponseData {
             remaining_seconds: Some(state.remaining_seconds),
             pomodoro_count: Some(state.pomodoro_count),
             task_name: state.task_name.clone(),
+            tag: state.tag.clone(),
+            task_queue: (!state.task_queue.is_empty())
+                .then(|| state.task_queue.iter().cloned().collect()),
+            pomodoros_until_long_break: state.pomodoros_until_long_break(),
+            progress: Some(state.progress_fraction()),
+            work_elapsed_seconds: state.work_elapsed_seconds(),
+            awaiting_break: state.awaiting_break.then_some(true),
+            cycle_completed: state.cycle_completed.then_some(true),
+            pause_reason: state.pause_reason.clone(),
+            auto_cycle: Some(state.config.auto_cycle),
+            focus_mode: Some(state.config.focus_mode),
+            config: None,
         }
     }
+
+    /// Returns the typed phase for `state`.
+    ///
+    /// Returns `None` if the state is missing or not a known phase name.
+    pub fn phase(&self) -> Option<TimerPhase> {
+        self.state.as_deref()?.parse().ok()
+    }
 }
 
 /// IPC response from daemon to client.
@@ -318,6 +1028,12 @@ pub struct IpcResponse {
     /// Optional response data
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<ResponseData>,
+    /// Protocol version of the daemon (see [`PROTOCOL_VERSION`])
+    #[serde(rename = "protocolVersion", default)]
+    pub protocol_version: u32,
+    /// Machine-readable error kind (e.g. [`UNSUPPORTED_COMMAND`])
+    #[serde(rename = "errorCode", default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
 }
 
 impl IpcResponse {
@@ -327,6 +1043,8 @@ impl IpcResponse {
             status: "success".to_string(),
             message: message.into(),
             data,
+            protocol_version: PROTOCOL_VERSION,
+            error_code: None,
         }
     }
 
@@ -336,8 +1054,28 @@ impl IpcResponse {
             status: "error".to_string(),
             message: message.into(),
             data: None,
+            protocol_version: PROTOCOL_VERSION,
+            error_code: None,
         }
     }
+
+    /// Creates the error response to a command the daemon does not know.
+    pub fn unsupported_command() -> Self {
+        Self {
+            error_code: Some(UNSUPPORTED_COMMAND.to_string()),
+            ..Self::error("このバージョンのDaemonでは対応していないコマンドです")
+        }
+    }
+
+    /// Returns whether this is the response to an unknown command.
+    pub fn is_unsupported_command(&self) -> bool {
+        self.error_code.as_deref() == Some(UNSUPPORTED_COMMAND)
+    }
+
+    /// Returns the typed phase carried in the response data, if any.
+    pub fn phase(&self) -> Option<TimerPhase> {
+        self.data.as_ref()?.phase()
+    }
 }
 
 // ============================================================================
@@ -378,6 +1116,29 @@ mod tests {
             assert!(!TimerPhase::Paused.is_active());
         }
 
+        #[test]
+        fn test_from_name_round_trip() {
+            for phase in [
+                TimerPhase::Stopped,
+                TimerPhase::Working,
+                TimerPhase::Breaking,
+                TimerPhase::LongBreaking,
+                TimerPhase::Paused,
+            ] {
+                assert_eq!(TimerPhase::from_name(phase.as_str()), Some(phase));
+            }
+            assert_eq!(TimerPhase::from_name("unknown"), None);
+        }
+
+        #[test]
+        fn test_from_str_and_try_from() {
+            assert_eq!("long_breaking".parse(), Ok(TimerPhase::LongBreaking));
+            assert_eq!(TimerPhase::try_from("paused"), Ok(TimerPhase::Paused));
+
+            let err = "Working".parse::<TimerPhase>().unwrap_err();
+            assert_eq!(err.to_string(), "不明なタイマー状態です: Working");
+        }
+
         #[test]
         fn test_serialize_deserialize() {
             let phase = TimerPhase::Working;
@@ -408,9 +1169,10 @@ mod tests {
         #[test]
         fn test_default_values() {
             let config = PomodoroConfig::default();
-            assert_eq!(config.work_minutes, 25);
-            assert_eq!(config.break_minutes, 5);
-            assert_eq!(config.long_break_minutes, 15);
+            assert_eq!(config.work_duration.as_minutes(), 25);
+            assert_eq!(config.break_duration.as_minutes(), 5);
+            assert_eq!(config.long_break_duration.as_minutes(), 15);
+            assert_eq!(config.long_break_interval, 4);
             assert!(!config.auto_cycle);
             assert!(!config.focus_mode);
         }
@@ -422,19 +1184,20 @@ mod tests {
                 .with_break_minutes(10)
                 .with_long_break_minutes(20);
 
-            assert_eq!(config.work_minutes, 30);
-            assert_eq!(config.break_minutes, 10);
-            assert_eq!(config.long_break_minutes, 20);
+            assert_eq!(config.work_duration.as_minutes(), 30);
+            assert_eq!(config.break_duration.as_minutes(), 10);
+            assert_eq!(config.long_break_duration.as_minutes(), 20);
         }
 
         #[test]
         fn test_validate_success() {
             let config = PomodoroConfig {
-                work_minutes: 30,
-                break_minutes: 10,
-                long_break_minutes: 20,
+                work_duration: PhaseDuration::from_minutes(30),
+                break_duration: PhaseDuration::from_minutes(10),
+                long_break_duration: PhaseDuration::from_minutes(20),
                 auto_cycle: true,
                 focus_mode: true,
+                ..Default::default()
             };
             assert!(config.validate().is_ok());
         }
@@ -443,21 +1206,23 @@ mod tests {
         fn test_validate_boundary_values() {
             // Minimum valid values
             let config = PomodoroConfig {
-                work_minutes: 1,
-                break_minutes: 1,
-                long_break_minutes: 1,
+                work_duration: PhaseDuration::from_minutes(1),
+                break_duration: PhaseDuration::from_minutes(1),
+                long_break_duration: PhaseDuration::from_minutes(1),
                 auto_cycle: false,
                 focus_mode: false,
+                ..Default::default()
             };
             assert!(config.validate().is_ok());
 
             // Maximum valid values
             let config = PomodoroConfig {
-                work_minutes: 120,
-                break_minutes: 60,
-                long_break_minutes: 60,
+                work_duration: PhaseDuration::from_minutes(120),
+                break_duration: PhaseDuration::from_minutes(60),
+                long_break_duration: PhaseDuration::from_minutes(60),
                 auto_cycle: false,
                 focus_mode: false,
+                ..Default::default()
             };
             assert!(config.validate().is_ok());
         }
@@ -465,7 +1230,7 @@ mod tests {
         #[test]
         fn test_validate_work_minutes_too_low() {
             let config = PomodoroConfig {
-                work_minutes: 0,
+                work_duration: PhaseDuration::from_minutes(0),
                 ..Default::default()
             };
             assert!(config.validate().is_err());
@@ -474,7 +1239,7 @@ mod tests {
         #[test]
         fn test_validate_work_minutes_too_high() {
             let config = PomodoroConfig {
-                work_minutes: 121,
+                work_duration: PhaseDuration::from_minutes(121),
                 ..Default::default()
             };
             assert!(config.validate().is_err());
@@ -483,7 +1248,7 @@ mod tests {
         #[test]
         fn test_validate_break_minutes_too_low() {
             let config = PomodoroConfig {
-                break_minutes: 0,
+                break_duration: PhaseDuration::from_minutes(0),
                 ..Default::default()
             };
             assert!(config.validate().is_err());
@@ -492,7 +1257,7 @@ mod tests {
         #[test]
         fn test_validate_break_minutes_too_high() {
             let config = PomodoroConfig {
-                break_minutes: 61,
+                break_duration: PhaseDuration::from_minutes(61),
                 ..Default::default()
             };
             assert!(config.validate().is_err());
@@ -501,7 +1266,7 @@ mod tests {
         #[test]
         fn test_validate_long_break_minutes_too_low() {
             let config = PomodoroConfig {
-                long_break_minutes: 0,
+                long_break_duration: PhaseDuration::from_minutes(0),
                 ..Default::default()
             };
             assert!(config.validate().is_err());
@@ -510,20 +1275,110 @@ mod tests {
         #[test]
         fn test_validate_long_break_minutes_too_high() {
             let config = PomodoroConfig {
-                long_break_minutes: 61,
+                long_break_duration: PhaseDuration::from_minutes(61),
                 ..Default::default()
             };
             assert!(config.validate().is_err());
         }
 
+        #[test]
+        fn test_validate_long_break_interval_out_of_range() {
+            let config = PomodoroConfig::default().with_long_break_interval(0);
+            assert!(config.validate().is_err());
+
+            let config = PomodoroConfig::default().with_long_break_interval(11);
+            assert!(config.validate().is_err());
+        }
+
+        #[test]
+        fn test_warnings_for_odd_ratios() {
+            let config = PomodoroConfig::default()
+                .with_work_minutes(5)
+                .with_break_minutes(30)
+                .with_long_break_minutes(15);
+
+            assert_eq!(
+                config.warnings(),
+                vec![
+                    "休憩時間（30分）が作業時間（5分）より長くなっています".to_string(),
+                    "長い休憩時間（15分）が休憩時間（30分）より短くなっています".to_string(),
+                ]
+            );
+            // Soft warnings never fail validation
+            assert!(config.validate().is_ok());
+        }
+
+        #[test]
+        fn test_no_warnings_for_sensible_configs() {
+            assert!(PomodoroConfig::default().warnings().is_empty());
+            let config = PomodoroConfig::default()
+                .with_work_minutes(50)
+                .with_break_minutes(10)
+                .with_long_break_minutes(30);
+            assert!(config.warnings().is_empty());
+
+            // A short long break does not matter when long breaks are off
+            let config = PomodoroConfig {
+                long_break_duration: PhaseDuration::from_minutes(1),
+                long_break_enabled: false,
+                ..Default::default()
+            };
+            assert!(config.warnings().is_empty());
+        }
+
+        #[test]
+        fn test_validate_max_cycles() {
+            assert!(PomodoroConfig::default().validate().is_ok());
+            assert!(PomodoroConfig::default()
+                .with_max_cycles(1)
+                .validate()
+                .is_ok());
+            assert!(PomodoroConfig::default()
+                .with_max_cycles(0)
+                .validate()
+                .is_err());
+        }
+
+        #[test]
+        fn test_max_work_minutes_defaults_and_validates() {
+            assert_eq!(PomodoroConfig::default().max_work_minutes, 120);
+            assert!(PomodoroConfig::default()
+                .with_max_work_minutes(0)
+                .validate()
+                .is_err());
+        }
+
+        #[test]
+        fn test_min_work_minutes_defaults_and_validates() {
+            assert_eq!(PomodoroConfig::default().min_work_minutes, 1);
+            assert!(PomodoroConfig::default()
+                .with_min_work_minutes(0)
+                .validate()
+                .is_err());
+            assert!(PomodoroConfig::default()
+                .with_max_work_minutes(30)
+                .with_min_work_minutes(45)
+                .validate()
+                .is_err());
+        }
+
+        #[test]
+        fn test_deserialize_without_long_break_interval() {
+            let json = r#"{"work_minutes":25,"break_minutes":5,"long_break_minutes":15,"auto_cycle":false,"focus_mode":false}"#;
+            let config: PomodoroConfig = serde_json::from_str(json).unwrap();
+            assert_eq!(config.long_break_interval, 4);
+            assert!(config.long_break_enabled);
+        }
+
         #[test]
         fn test_serialize_deserialize() {
             let config = PomodoroConfig {
-                work_minutes: 30,
-                break_minutes: 10,
-                long_break_minutes: 20,
+                work_duration: PhaseDuration::from_minutes(30),
+                break_duration: PhaseDuration::from_minutes(10),
+                long_break_duration: PhaseDuration::from_minutes(20),
                 auto_cycle: true,
                 focus_mode: true,
+                ..Default::default()
             };
 
             let json = serde_json::to_string(&config).unwrap();
@@ -539,6 +1394,27 @@ mod tests {
     mod timer_state_tests {
         use super::*;
 
+        #[test]
+        fn test_phase_generation_changes_on_phase_start_only() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            let initial = state.phase_generation();
+
+            state.start_working(None);
+            let working = state.phase_generation();
+            assert!(working > initial);
+
+            state.pause();
+            state.resume();
+            assert_eq!(state.phase_generation(), working);
+
+            state.start_breaking();
+            assert!(state.phase_generation() > working);
+            let breaking = state.phase_generation();
+
+            state.stop();
+            assert!(state.phase_generation() > breaking);
+        }
+
         #[test]
         fn test_new_state() {
             let config = PomodoroConfig::default();
@@ -548,7 +1424,10 @@ mod tests {
             assert_eq!(state.remaining_seconds, 0);
             assert_eq!(state.pomodoro_count, 0);
             assert_eq!(state.task_name, None);
-            assert_eq!(state.config.work_minutes, config.work_minutes);
+            assert_eq!(
+                state.config.work_duration.as_minutes(),
+                config.work_duration.as_minutes()
+            );
         }
 
         #[test]
@@ -579,7 +1458,7 @@ mod tests {
         fn test_start_breaking_short() {
             let config = PomodoroConfig::default();
             let mut state = TimerState::new(config);
-            state.pomodoro_count = 1;
+            state.total_completed_pomodoros = 1;
 
             state.start_breaking();
 
@@ -591,7 +1470,7 @@ mod tests {
         fn test_start_breaking_long_after_4_pomodoros() {
             let config = PomodoroConfig::default();
             let mut state = TimerState::new(config);
-            state.pomodoro_count = 4;
+            state.total_completed_pomodoros = 4;
 
             state.start_breaking();
 
@@ -603,7 +1482,7 @@ mod tests {
         fn test_start_breaking_long_after_8_pomodoros() {
             let config = PomodoroConfig::default();
             let mut state = TimerState::new(config);
-            state.pomodoro_count = 8;
+            state.total_completed_pomodoros = 8;
 
             state.start_breaking();
 
@@ -777,6 +1656,252 @@ mod tests {
 
             state.increment_pomodoro_count();
             assert_eq!(state.pomodoro_count, 2);
+            assert_eq!(state.total_completed_pomodoros, 2);
+        }
+
+        #[test]
+        fn test_stop_then_start_preserves_cadence() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+
+            for _ in 0..3 {
+                state.start_working(None);
+                state.increment_pomodoro_count();
+                state.start_breaking();
+                state.stop();
+            }
+            // Clearing the session count must not reset the cadence
+            state.pomodoro_count = 0;
+            state.start_working(None);
+            state.increment_pomodoro_count();
+            state.start_breaking();
+
+            assert_eq!(state.total_completed_pomodoros, 4);
+            assert_eq!(state.phase, TimerPhase::LongBreaking);
+        }
+
+        #[test]
+        fn test_reset_counts() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.pomodoro_count = 3;
+            state.total_completed_pomodoros = 7;
+
+            state.reset_counts();
+
+            assert_eq!(state.pomodoro_count, 0);
+            assert_eq!(state.total_completed_pomodoros, 0);
+            assert_eq!(state.pomodoros_until_long_break(), Some(4));
+        }
+
+        #[test]
+        fn test_total_completed_defaults_when_missing() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.total_completed_pomodoros = 5;
+            let mut json = serde_json::to_value(&state).unwrap();
+            json.as_object_mut()
+                .unwrap()
+                .remove("total_completed_pomodoros");
+
+            let deserialized: TimerState = serde_json::from_value(json).unwrap();
+            assert_eq!(deserialized.total_completed_pomodoros, 0);
+        }
+
+        #[test]
+        fn test_phase_total_seconds_per_phase() {
+            let config = PomodoroConfig::default()
+                .with_work_minutes(50)
+                .with_break_minutes(10)
+                .with_long_break_minutes(30);
+            let state = TimerState::new(config);
+
+            assert_eq!(state.phase_total_seconds(TimerPhase::Working), 50 * 60);
+            assert_eq!(state.phase_total_seconds(TimerPhase::Breaking), 10 * 60);
+            assert_eq!(state.phase_total_seconds(TimerPhase::LongBreaking), 30 * 60);
+            assert_eq!(state.phase_total_seconds(TimerPhase::Stopped), 0);
+            assert_eq!(state.phase_total_seconds(TimerPhase::Paused), 0);
+        }
+
+        #[test]
+        fn test_phase_total_seconds_of_custom_length_break() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.start_break_only(12);
+
+            assert_eq!(state.phase_total_seconds(TimerPhase::Breaking), 12 * 60);
+            assert_eq!(state.phase_total_seconds(TimerPhase::Working), 25 * 60);
+            assert_eq!(state.phase_total_seconds(TimerPhase::LongBreaking), 15 * 60);
+
+            state.pause();
+            assert_eq!(state.phase_total_seconds(TimerPhase::Paused), 12 * 60);
+            assert_eq!(
+                state.configured_duration(TimerPhase::Breaking).as_minutes(),
+                5
+            );
+        }
+
+        #[test]
+        fn test_progress_fraction_per_phase() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            assert_eq!(state.progress_fraction(), 0.0);
+
+            state.start_working(None);
+            assert_eq!(state.progress_fraction(), 0.0);
+            state.remaining_seconds = 15 * 60;
+            assert!((state.progress_fraction() - 0.4).abs() < f32::EPSILON);
+
+            state.total_completed_pomodoros = 1;
+            state.start_breaking();
+            state.remaining_seconds = 60;
+            assert!((state.progress_fraction() - 0.8).abs() < f32::EPSILON);
+
+            state.total_completed_pomodoros = 4;
+            state.start_breaking();
+            state.remaining_seconds = 0;
+            assert_eq!(state.progress_fraction(), 1.0);
+        }
+
+        #[test]
+        fn test_progress_fraction_of_custom_length_break() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.start_break_only(15);
+            state.remaining_seconds = 10 * 60;
+            assert!((state.progress_fraction() - 1.0 / 3.0).abs() < f32::EPSILON);
+
+            state.pause();
+            assert!((state.progress_fraction() - 1.0 / 3.0).abs() < f32::EPSILON);
+
+            state.resume();
+            state.start_long_break_only(40);
+            state.remaining_seconds = 30 * 60;
+            assert!((state.progress_fraction() - 0.25).abs() < f32::EPSILON);
+        }
+
+        #[test]
+        fn test_progress_fraction_keeps_length_across_config_change() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.start_working(None);
+            state.remaining_seconds = 20 * 60;
+
+            state.config = PomodoroConfig::default().with_work_minutes(50);
+
+            assert!((state.progress_fraction() - 0.2).abs() < f32::EPSILON);
+            assert_eq!(state.work_elapsed_seconds(), Some(5 * 60));
+        }
+
+        #[test]
+        fn test_progress_fraction_when_paused_uses_previous_phase() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.start_working(None);
+            state.remaining_seconds = 20 * 60;
+            state.pause();
+
+            assert_eq!(state.phase_total_seconds(state.phase), 25 * 60);
+            assert!((state.progress_fraction() - 0.2).abs() < f32::EPSILON);
+        }
+
+        #[test]
+        fn test_work_elapsed_seconds() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            assert_eq!(state.work_elapsed_seconds(), None);
+
+            state.start_working(None);
+            state.remaining_seconds -= 90;
+            assert_eq!(state.work_elapsed_seconds(), Some(90));
+
+            state.pause();
+            assert_eq!(state.work_elapsed_seconds(), Some(90));
+
+            state.resume();
+            state.start_breaking();
+            assert_eq!(state.work_elapsed_seconds(), None);
+        }
+
+        #[test]
+        fn test_abandon_grace_defaults_when_missing() {
+            let config: PomodoroConfig = serde_json::from_str("{}").unwrap();
+            assert_eq!(config.abandon_grace_seconds, 60);
+        }
+
+        #[test]
+        fn test_min_break_defaults_to_no_floor() {
+            let config: PomodoroConfig = serde_json::from_str("{}").unwrap();
+            assert_eq!(config.min_break_seconds, 0);
+            assert_eq!(
+                PomodoroConfig::default()
+                    .with_min_break_seconds(120)
+                    .min_break_seconds,
+                120
+            );
+        }
+
+        #[test]
+        fn test_progress_fraction_clamped_after_snooze() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            state.total_completed_pomodoros = 1;
+            state.start_breaking();
+            state.remaining_seconds += 5 * 60;
+
+            assert_eq!(state.progress_fraction(), 0.0);
+        }
+
+        #[test]
+        fn test_pomodoros_until_long_break() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+            let expected = [4, 3, 2, 1, 4, 3, 2, 1, 4];
+
+            for (count, want) in expected.iter().enumerate() {
+                state.total_completed_pomodoros = count as u32;
+                assert_eq!(
+                    state.pomodoros_until_long_break(),
+                    Some(*want),
+                    "total_completed_pomodoros = {}",
+                    count
+                );
+            }
+        }
+
+        #[test]
+        fn test_start_breaking_always_short_when_long_break_disabled() {
+            let config = PomodoroConfig::default().with_long_break_enabled(false);
+            let mut state = TimerState::new(config);
+
+            for count in [1, 2, 3, 4, 5, 8, 12, 100] {
+                state.total_completed_pomodoros = count;
+                state.start_breaking();
+
+                assert_eq!(
+                    state.phase,
+                    TimerPhase::Breaking,
+                    "total_completed_pomodoros = {}",
+                    count
+                );
+                assert_eq!(state.remaining_seconds, 5 * 60);
+            }
+        }
+
+        #[test]
+        fn test_pomodoros_until_long_break_none_when_disabled() {
+            let config = PomodoroConfig::default().with_long_break_enabled(false);
+            let mut state = TimerState::new(config);
+            state.total_completed_pomodoros = 3;
+
+            assert_eq!(state.pomodoros_until_long_break(), None);
+            assert_eq!(
+                ResponseData::from_timer_state(&state).pomodoros_until_long_break,
+                None
+            );
+        }
+
+        #[test]
+        fn test_start_breaking_respects_long_break_interval() {
+            let config = PomodoroConfig::default().with_long_break_interval(2);
+            let mut state = TimerState::new(config);
+
+            state.total_completed_pomodoros = 2;
+            state.start_breaking();
+            assert_eq!(state.phase, TimerPhase::LongBreaking);
+
+            state.total_completed_pomodoros = 3;
+            state.start_breaking();
+            assert_eq!(state.phase, TimerPhase::Breaking);
         }
 
         #[test]
@@ -795,6 +1920,92 @@ mod tests {
             assert_eq!(deserialized.pomodoro_count, 5);
             assert_eq!(deserialized.task_name, Some("Test".to_string()));
         }
+
+        #[test]
+        fn test_huge_durations_are_clamped() {
+            let config = PomodoroConfig::default()
+                .with_work_minutes(u32::MAX)
+                .with_break_minutes(u32::MAX / 60 + 1);
+            let mut state = TimerState::new(config);
+
+            state.start_working(None);
+            assert_eq!(state.remaining_seconds, MAX_PHASE_SECONDS);
+            assert_eq!(state.work_elapsed_seconds(), Some(0));
+
+            state.increment_pomodoro_count();
+            state.start_breaking();
+            assert_eq!(state.remaining_seconds, MAX_PHASE_SECONDS);
+
+            state.start_break_only(u32::MAX);
+            assert_eq!(state.remaining_seconds, MAX_PHASE_SECONDS);
+            state.start_long_break_only(24 * 60);
+            assert_eq!(state.remaining_seconds, MAX_PHASE_SECONDS);
+        }
+
+        #[test]
+        fn test_tick_at_boundaries() {
+            let mut state = TimerState::new(PomodoroConfig::default());
+
+            state.remaining_seconds = u32::MAX;
+            assert!(!state.tick());
+            assert_eq!(state.remaining_seconds, u32::MAX - 1);
+
+            state.remaining_seconds = 0;
+            assert!(state.tick());
+            assert_eq!(state.remaining_seconds, 0);
+        }
+
+        #[test]
+        fn test_phase_duration_conversions() {
+            assert_eq!(PhaseDuration::from_minutes(0), PhaseDuration::ZERO);
+            assert_eq!(PhaseDuration::from_minutes(25).as_secs(), 25 * 60);
+            assert_eq!(PhaseDuration::from_secs(25 * 60 + 59).as_minutes(), 25);
+            assert_eq!(
+                PhaseDuration::from_minutes(24 * 60).as_secs(),
+                MAX_PHASE_SECONDS
+            );
+            assert_eq!(
+                PhaseDuration::from_minutes(24 * 60 + 1).as_secs(),
+                MAX_PHASE_SECONDS
+            );
+            assert_eq!(
+                PhaseDuration::from_minutes(u32::MAX).as_secs(),
+                MAX_PHASE_SECONDS
+            );
+            assert_eq!(
+                PhaseDuration::from_secs(u32::MAX).as_secs(),
+                MAX_PHASE_SECONDS
+            );
+            assert!(PhaseDuration::from_minutes(5) < PhaseDuration::from_secs(5 * 60 + 1));
+        }
+
+        #[test]
+        fn test_phase_duration_display() {
+            assert_eq!(PhaseDuration::ZERO.to_string(), "00:00");
+            assert_eq!(PhaseDuration::from_secs(59).to_string(), "00:59");
+            assert_eq!(PhaseDuration::from_minutes(25).to_string(), "25:00");
+            assert_eq!(PhaseDuration::from_secs(62 * 60 + 5).to_string(), "62:05");
+            assert_eq!(PhaseDuration::from_minutes(120).to_string(), "120:00");
+            assert_eq!(
+                PhaseDuration::from_secs(MAX_PHASE_SECONDS).to_string(),
+                "1440:00"
+            );
+        }
+
+        #[test]
+        fn test_phase_duration_serializes_as_minutes() {
+            let config = PomodoroConfig::default().with_work_minutes(50);
+            let json = serde_json::to_value(&config).unwrap();
+            assert_eq!(json["work_minutes"], 50);
+            assert_eq!(json["break_minutes"], 5);
+
+            let duration: PhaseDuration = serde_json::from_str("90").unwrap();
+            assert_eq!(duration.as_secs(), 90 * 60);
+            assert_eq!(
+                serde_json::to_string(&PhaseDuration::from_secs(90)).unwrap(),
+                "1"
+            );
+        }
     }
 
     // ------------------------------------------------------------------------
@@ -825,6 +2036,7 @@ mod tests {
                     task_name: Some("Test".to_string()),
                     auto_cycle: Some(true),
                     focus_mode: Some(false),
+                    ..Default::default()
                 },
             };
 
@@ -852,7 +2064,7 @@ mod tests {
 
         #[test]
         fn test_ipc_request_pause_serialize() {
-            let request = IpcRequest::Pause;
+            let request = IpcRequest::Pause { reason: None };
             let json = serde_json::to_string(&request).unwrap();
             assert_eq!(json, r#"{"command":"pause"}"#);
         }
@@ -861,7 +2073,19 @@ mod tests {
         fn test_ipc_request_pause_deserialize() {
             let json = r#"{"command":"pause"}"#;
             let request: IpcRequest = serde_json::from_str(json).unwrap();
-            assert!(matches!(request, IpcRequest::Pause));
+            assert!(matches!(request, IpcRequest::Pause { reason: None }));
+        }
+
+        #[test]
+        fn test_ipc_request_pause_with_reason() {
+            let request = IpcRequest::Pause {
+                reason: Some("meeting".to_string()),
+            };
+            let json = serde_json::to_string(&request).unwrap();
+            assert_eq!(json, r#"{"command":"pause","reason":"meeting"}"#);
+
+            let request: IpcRequest = serde_json::from_str(&json).unwrap();
+            assert!(matches!(request, IpcRequest::Pause { reason: Some(ref r) } if r == "meeting"));
         }
 
         #[test]
@@ -885,6 +2109,24 @@ mod tests {
             assert_eq!(json, r#"{"command":"status"}"#);
         }
 
+        #[test]
+        fn test_ipc_request_config_round_trip() {
+            let json = serde_json::to_string(&IpcRequest::GetConfig).unwrap();
+            assert_eq!(json, r#"{"command":"getConfig"}"#);
+
+            let request = IpcRequest::SetConfig {
+                config: PomodoroConfig::default().with_work_minutes(50),
+            };
+            let json = serde_json::to_string(&request).unwrap();
+            assert!(json.starts_with(r#"{"command":"setConfig","config":{"#));
+            match serde_json::from_str(&json).unwrap() {
+                IpcRequest::SetConfig { config } => {
+                    assert_eq!(config.work_duration.as_minutes(), 50)
+                }
+                other => panic!("Expected SetConfig, got {:?}", other),
+            }
+        }
+
         #[test]
         fn test_response_data_from_timer_state() {
             let config = PomodoroConfig::default();
@@ -892,6 +2134,7 @@ mod tests {
             state.start_working(Some("Test Task".to_string()));
             state.remaining_seconds = 1200;
             state.pomodoro_count = 3;
+            state.total_completed_pomodoros = 3;
 
             let data = ResponseData::from_timer_state(&state);
 
@@ -899,6 +2142,28 @@ mod tests {
             assert_eq!(data.remaining_seconds, Some(1200));
             assert_eq!(data.pomodoro_count, Some(3));
             assert_eq!(data.task_name, Some("Test Task".to_string()));
+            assert_eq!(data.pomodoros_until_long_break, Some(1));
+            assert!((data.progress.unwrap() - 0.2).abs() < f32::EPSILON);
+            assert_eq!(data.work_elapsed_seconds, Some(300));
+            assert_eq!(data.auto_cycle, Some(false));
+            assert_eq!(data.focus_mode, Some(false));
+        }
+
+        #[test]
+        fn test_response_data_serializes_modes() {
+            let config = PomodoroConfig {
+                auto_cycle: true,
+                ..Default::default()
+            };
+            let data = ResponseData::from_timer_state(&TimerState::new(config));
+
+            let json = serde_json::to_value(&data).unwrap();
+            assert_eq!(json["autoCycle"], true);
+            assert_eq!(json["focusMode"], false);
+
+            let json = serde_json::to_value(ResponseData::default()).unwrap();
+            assert!(json.get("autoCycle").is_none());
+            assert!(json.get("focusMode").is_none());
         }
 
         #[test]
@@ -910,6 +2175,7 @@ mod tests {
                     remaining_seconds: Some(1500),
                     pomodoro_count: Some(1),
                     task_name: Some("Test".to_string()),
+                    ..Default::default()
                 }),
             );
 
@@ -940,6 +2206,51 @@ mod tests {
             assert!(response.data.is_none());
         }
 
+        #[test]
+        fn test_ipc_response_carries_protocol_version() {
+            let json = serde_json::to_string(&IpcResponse::success("ok", None)).unwrap();
+            assert!(json.contains(&format!(r#""protocolVersion":{}"#, PROTOCOL_VERSION)));
+            assert!(!json.contains("errorCode"));
+
+            // Daemons from before versioning omit the field
+            let legacy: IpcResponse =
+                serde_json::from_str(r#"{"status":"success","message":"ok"}"#).unwrap();
+            assert_eq!(legacy.protocol_version, 0);
+        }
+
+        #[test]
+        fn test_unknown_command_deserializes_as_unsupported() {
+            let json = r#"{"command":"frobnicate","minutes":5}"#;
+            let request: IpcRequest = serde_json::from_str(json).unwrap();
+            assert!(matches!(request, IpcRequest::Unsupported));
+        }
+
+        #[test]
+        fn test_ipc_response_unsupported_command() {
+            let response = IpcResponse::unsupported_command();
+
+            assert_eq!(response.status, "error");
+            assert!(response.is_unsupported_command());
+            assert!(!IpcResponse::error("other").is_unsupported_command());
+
+            let json = serde_json::to_string(&response).unwrap();
+            assert!(json.contains(r#""errorCode":"unsupported_command""#));
+        }
+
+        #[test]
+        fn test_ipc_response_phase() {
+            let data = |state: Option<&str>| ResponseData {
+                state: state.map(str::to_string),
+                ..Default::default()
+            };
+
+            let response = IpcResponse::success("", Some(data(Some("long_breaking"))));
+            assert_eq!(response.phase(), Some(TimerPhase::LongBreaking));
+            assert_eq!(data(Some("bogus")).phase(), None);
+            assert_eq!(data(None).phase(), None);
+            assert_eq!(IpcResponse::error("x").phase(), None);
+        }
+
         #[test]
         fn test_ipc_response_serialize() {
             let response = IpcResponse::success(
@@ -949,6 +2260,7 @@ mod tests {
                     remaining_seconds: Some(1500),
                     pomodoro_count: Some(1),
                     task_name: None,
+                    ..Default::default()
                 }),
             );
 
@@ -988,7 +2300,7 @@ mod tests {
                 let request: IpcRequest = serde_json::from_str(json).unwrap();
                 match (&request, expected) {
                     (IpcRequest::Start { .. }, "start") => {}
-                    (IpcRequest::Pause, "pause") => {}
+                    (IpcRequest::Pause { .. }, "pause") => {}
                     (IpcRequest::Resume, "resume") => {}
                     (IpcRequest::Stop, "stop") => {}
                     (IpcRequest::Status, "status") => {}
@@ -1,30 +1,30 @@
 use std::sync::Arc;
 
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::Mutex;
 
+use pomodoro::daemon::channel::{event_channel, EventReceiver, DEFAULT_EVENT_CAPACITY};
 use pomodoro::daemon::timer::{TimerEngine, TimerEvent};
 use pomodoro::focus::{FocusModeController, MockFocusModeController};
 use pomodoro::sound::{MockSoundPlayer, SoundPlayer, SoundSource};
-use pomodoro::types::{PomodoroConfig, TimerPhase};
+use pomodoro::types::{PhaseDuration, PomodoroConfig, TimerPhase};
 
 #[cfg(target_os = "macos")]
 use pomodoro::notification::{MockNotificationSender, NotificationSender, NotificationType};
 
-fn create_engine_with_config(
-    config: PomodoroConfig,
-) -> (Arc<Mutex<TimerEngine>>, mpsc::UnboundedReceiver<TimerEvent>) {
-    let (tx, rx) = mpsc::unbounded_channel();
+fn create_engine_with_config(config: PomodoroConfig) -> (Arc<Mutex<TimerEngine>>, EventReceiver) {
+    let (tx, rx) = event_channel(DEFAULT_EVENT_CAPACITY);
     let engine = TimerEngine::new(config, tx);
     (Arc::new(Mutex::new(engine)), rx)
 }
 
 fn create_fast_config() -> PomodoroConfig {
     PomodoroConfig {
-        work_minutes: 1,
-        break_minutes: 1,
-        long_break_minutes: 2,
+        work_duration: PhaseDuration::from_minutes(1),
+        break_duration: PhaseDuration::from_minutes(1),
+        long_break_duration: PhaseDuration::from_minutes(2),
         auto_cycle: false,
         focus_mode: false,
+        ..Default::default()
     }
 }
 
@@ -35,7 +35,9 @@ mod notification_integration {
     #[tokio::test]
     async fn tc_i_005_work_complete_notification() {
         let mock = MockNotificationSender::new();
-        mock.send_work_complete(Some("Test Task")).await.unwrap();
+        mock.send_work_complete(Some("Test Task"), None)
+            .await
+            .unwrap();
 
         let notifications = mock.get_notifications();
         assert_eq!(notifications.len(), 1);
@@ -77,7 +79,7 @@ mod notification_integration {
         let mock = MockNotificationSender::new();
         mock.set_should_fail(true);
 
-        let result = mock.send_work_complete(None).await;
+        let result = mock.send_work_complete(None, None).await;
         assert!(result.is_err());
     }
 }
@@ -282,7 +284,7 @@ mod timer_event_integration {
             eng.start(Some("Test Task".to_string())).unwrap();
         }
 
-        let event = rx.recv().await.unwrap();
+        let event = rx.recv().await.unwrap().event;
         match event {
             TimerEvent::WorkStarted { task_name } => {
                 assert_eq!(task_name, Some("Test Task".to_string()));
@@ -306,8 +308,8 @@ mod timer_event_integration {
             eng.pause().unwrap();
         }
 
-        let event = rx.recv().await.unwrap();
-        assert!(matches!(event, TimerEvent::Paused));
+        let event = rx.recv().await.unwrap().event;
+        assert!(matches!(event, TimerEvent::Paused { .. }));
     }
 
     #[tokio::test]
@@ -327,7 +329,7 @@ mod timer_event_integration {
             eng.resume().unwrap();
         }
 
-        let event = rx.recv().await.unwrap();
+        let event = rx.recv().await.unwrap().event;
         assert!(matches!(event, TimerEvent::Resumed));
     }
 
@@ -346,7 +348,7 @@ mod timer_event_integration {
             eng.stop().unwrap();
         }
 
-        let event = rx.recv().await.unwrap();
+        let event = rx.recv().await.unwrap().event;
         assert!(matches!(event, TimerEvent::Stopped));
     }
 
@@ -425,7 +427,7 @@ mod component_handler_integration {
                     #[cfg(target_os = "macos")]
                     {
                         self.notification_sender
-                            .send_work_complete(task_name.as_deref())
+                            .send_work_complete(task_name.as_deref(), None)
                             .await
                             .ok();
                     }
@@ -466,6 +468,9 @@ mod component_handler_integration {
             .handle_event(TimerEvent::WorkCompleted {
                 pomodoro_count: 1,
                 task_name: Some("Integration Test".to_string()),
+                tag: None,
+                work_seconds: 1500,
+                extended: false,
             })
             .await;
 
@@ -505,6 +510,9 @@ mod component_handler_integration {
             .handle_event(TimerEvent::WorkCompleted {
                 pomodoro_count: 1,
                 task_name: None,
+                tag: None,
+                work_seconds: 1500,
+                extended: false,
             })
             .await;
 
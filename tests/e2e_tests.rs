@@ -7,20 +7,24 @@
 //! - TC-E-004: Auto-cycle mode
 //! - TC-E-005: Long break after 4 pomodoros
 //! - TC-E-006: Focus mode integration
+//! - TC-E-007: Daemon log file output
+//! - TC-E-008: Foreground session without the daemon (`run`)
+//! - TC-E-009: Daemon readiness line
 
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::Mutex;
 use tokio::time::timeout;
 
 use pomodoro::cli::client::IpcClient;
 use pomodoro::cli::commands::StartArgs;
+use pomodoro::daemon::channel::{event_channel, EventReceiver, DEFAULT_EVENT_CAPACITY};
 use pomodoro::daemon::ipc::{IpcServer, RequestHandler};
 use pomodoro::daemon::timer::{TimerEngine, TimerEvent};
 use pomodoro::focus::{FocusModeController, MockFocusModeController};
-use pomodoro::types::{PomodoroConfig, TimerPhase};
+use pomodoro::types::{PhaseDuration, PomodoroConfig, TimerPhase};
 
 // ============================================================================
 // Test Helpers
@@ -36,10 +40,8 @@ fn create_temp_socket_path() -> PathBuf {
 }
 
 /// Creates a TimerEngine with custom configuration.
-fn create_engine_with_config(
-    config: PomodoroConfig,
-) -> (Arc<Mutex<TimerEngine>>, mpsc::UnboundedReceiver<TimerEvent>) {
-    let (tx, rx) = mpsc::unbounded_channel();
+fn create_engine_with_config(config: PomodoroConfig) -> (Arc<Mutex<TimerEngine>>, EventReceiver) {
+    let (tx, rx) = event_channel(DEFAULT_EVENT_CAPACITY);
     let engine = TimerEngine::new(config, tx);
     (Arc::new(Mutex::new(engine)), rx)
 }
@@ -47,33 +49,36 @@ fn create_engine_with_config(
 /// Creates a fast configuration for quick tests (1-minute work sessions).
 fn create_fast_config() -> PomodoroConfig {
     PomodoroConfig {
-        work_minutes: 1,
-        break_minutes: 1,
-        long_break_minutes: 2,
+        work_duration: PhaseDuration::from_minutes(1),
+        break_duration: PhaseDuration::from_minutes(1),
+        long_break_duration: PhaseDuration::from_minutes(2),
         auto_cycle: false,
         focus_mode: false,
+        ..Default::default()
     }
 }
 
 /// Creates an auto-cycle configuration.
 fn create_auto_cycle_config() -> PomodoroConfig {
     PomodoroConfig {
-        work_minutes: 1,
-        break_minutes: 1,
-        long_break_minutes: 2,
+        work_duration: PhaseDuration::from_minutes(1),
+        break_duration: PhaseDuration::from_minutes(1),
+        long_break_duration: PhaseDuration::from_minutes(2),
         auto_cycle: true,
         focus_mode: false,
+        ..Default::default()
     }
 }
 
 /// Creates a focus mode enabled configuration.
 fn create_focus_mode_config() -> PomodoroConfig {
     PomodoroConfig {
-        work_minutes: 1,
-        break_minutes: 1,
-        long_break_minutes: 2,
+        work_duration: PhaseDuration::from_minutes(1),
+        break_duration: PhaseDuration::from_minutes(1),
+        long_break_duration: PhaseDuration::from_minutes(2),
         auto_cycle: false,
         focus_mode: true,
+        ..Default::default()
     }
 }
 
@@ -123,13 +128,14 @@ async fn tc_e_001_complete_pomodoro_cycle() {
 
     // Step 1: Start timer with task name
     let args = StartArgs {
-        work: 1,
-        break_time: 1,
-        long_break: 2,
+        work: Some(1),
+        break_time: Some(1),
+        long_break: Some(2),
         task: Some("E2Eテスト".to_string()),
         auto_cycle: false,
         focus_mode: false,
         no_sound: false,
+        ..Default::default()
     };
 
     let response = client.start(&args).await.unwrap();
@@ -143,7 +149,7 @@ async fn tc_e_001_complete_pomodoro_cycle() {
     // Verify WorkStarted event
     let event = timeout(Duration::from_secs(1), rx.recv()).await;
     assert!(event.is_ok());
-    match event.unwrap() {
+    match event.unwrap().map(|stamped| stamped.event) {
         Some(TimerEvent::WorkStarted { task_name }) => {
             assert_eq!(task_name, Some("E2Eテスト".to_string()));
         }
@@ -217,13 +223,14 @@ async fn tc_e_002_pause_resume_flow() {
 
     // Start timer
     let args = StartArgs {
-        work: 1,
-        break_time: 1,
-        long_break: 2,
+        work: Some(1),
+        break_time: Some(1),
+        long_break: Some(2),
         task: Some("Pause Test".to_string()),
         auto_cycle: false,
         focus_mode: false,
         no_sound: false,
+        ..Default::default()
     };
     let _ = client.start(&args).await.unwrap();
     let _ = rx.recv().await; // Drain start event
@@ -255,8 +262,8 @@ async fn tc_e_002_pause_resume_flow() {
     assert_eq!(pause_data.state, Some("paused".to_string()));
 
     // Verify Paused event
-    let event = rx.recv().await.unwrap();
-    assert!(matches!(event, TimerEvent::Paused));
+    let event = rx.recv().await.unwrap().event;
+    assert!(matches!(event, TimerEvent::Paused { .. }));
 
     // Step 2: Verify remaining time is preserved
     let status_paused = client.status().await.unwrap();
@@ -277,7 +284,7 @@ async fn tc_e_002_pause_resume_flow() {
     assert_eq!(resume_data.state, Some("working".to_string()));
 
     // Verify Resumed event
-    let event = rx.recv().await.unwrap();
+    let event = rx.recv().await.unwrap().event;
     assert!(matches!(event, TimerEvent::Resumed));
 
     // Step 4: Verify timer continues - simulate tick
@@ -329,13 +336,14 @@ async fn tc_e_003_stop_flow() {
 
     // Start timer
     let args = StartArgs {
-        work: 25,
-        break_time: 5,
-        long_break: 15,
+        work: Some(25),
+        break_time: Some(5),
+        long_break: Some(15),
         task: Some("Stop Test".to_string()),
         auto_cycle: false,
         focus_mode: false,
         no_sound: false,
+        ..Default::default()
     };
     let _ = client.start(&args).await.unwrap();
     let _ = rx.recv().await; // Drain start event
@@ -356,7 +364,7 @@ async fn tc_e_003_stop_flow() {
     assert_eq!(stop_data.state, Some("stopped".to_string()));
 
     // Verify Stopped event
-    let event = rx.recv().await.unwrap();
+    let event = rx.recv().await.unwrap().event;
     assert!(matches!(event, TimerEvent::Stopped));
 
     // Step 2: Verify state is reset
@@ -400,13 +408,14 @@ async fn tc_e_004_auto_cycle_mode() {
 
     // Step 1: Start with auto-cycle
     let args = StartArgs {
-        work: 1,
-        break_time: 1,
-        long_break: 2,
+        work: Some(1),
+        break_time: Some(1),
+        long_break: Some(2),
         task: Some("Auto Cycle Test".to_string()),
         auto_cycle: true,
         focus_mode: false,
         no_sound: false,
+        ..Default::default()
     };
     let response = client.start(&args).await.unwrap();
     assert_eq!(response.status, "success");
@@ -470,6 +479,7 @@ async fn tc_e_005_long_break_after_4_pomodoros() {
         // Simulate 3 completed pomodoros
         let state = eng.get_state_mut();
         state.pomodoro_count = 3;
+        state.total_completed_pomodoros = 3;
     }
     let _ = rx.recv().await; // Drain start event
 
@@ -694,3 +704,157 @@ async fn test_e2e_multiple_pause_resume() {
 
     server_handle.abort();
 }
+
+// ============================================================================
+// TC-E-007: Daemon Log File Output
+// ============================================================================
+
+/// TC-E-007: Daemonのログファイル出力
+///
+/// 前提条件: Daemon停止中
+/// テスト手順:
+/// 1. `pomodoro daemon --log-file <path>` を起動
+/// 2. 起動ログが書き込まれるまで待機
+/// 期待結果: ログファイルが作成され、起動メッセージが記録される
+#[tokio::test]
+async fn tc_e_007_daemon_log_file() {
+    let home = tempfile::tempdir().unwrap();
+    let log_path = home.path().join("logs").join("daemon.log");
+
+    let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin("pomodoro"))
+        .arg("daemon")
+        .arg("--log-file")
+        .arg(&log_path)
+        .env("HOME", home.path())
+        .env("RUST_LOG", "info")
+        .spawn()
+        .unwrap();
+
+    let written = timeout(Duration::from_secs(5), async {
+        loop {
+            if let Ok(contents) = std::fs::read_to_string(&log_path) {
+                if contents.contains("Daemonを起動しました") {
+                    return contents;
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await;
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    let contents = written.expect("daemon did not write its startup log");
+    assert!(
+        !contents.contains("\x1b["),
+        "log file should not contain ANSI colors"
+    );
+}
+
+// ============================================================================
+// TC-E-008: Foreground Session Without the Daemon
+// ============================================================================
+
+/// TC-E-008: Daemonなしのフォアグラウンド実行
+///
+/// 前提条件: Daemon停止中
+/// テスト手順:
+/// 1. `pomodoro run --work 1 --task foo --no-sound` を短い tick で実行
+/// 2. プロセスの終了を待機
+///
+/// 期待結果: カウントダウンと完了メッセージが表示され、終了コード 0 で終わる
+#[test]
+// `--tick-ms` only exists in debug builds
+#[cfg_attr(not(debug_assertions), ignore = "--tick-ms is debug-only")]
+fn tc_e_008_foreground_run_completes() {
+    let home = tempfile::tempdir().unwrap();
+
+    let output = std::process::Command::new(assert_cmd::cargo::cargo_bin("pomodoro"))
+        .args([
+            "run",
+            "--work",
+            "1",
+            "--task",
+            "foo",
+            "--no-sound",
+            "--tick-ms",
+            "1",
+        ])
+        .env("HOME", home.path())
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "run exited with {}", output.status);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("🍅 00:01 foo"));
+    assert!(stdout.contains("作業が完了しました"));
+}
+
+// ============================================================================
+// TC-E-009: Daemon Readiness Line
+// ============================================================================
+
+/// TC-E-009: デーモンの起動完了行
+///
+/// テスト手順:
+/// 1. `pomodoro daemon` を起動
+/// 2. 標準出力に `READY socket=<path>` が出るまで待機
+/// 3. 出力されたソケットに接続してステータスを取得
+/// 期待結果: READY 行の直後から接続でき、`--quiet` では出力されない
+#[tokio::test]
+async fn tc_e_009_daemon_ready_line() {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+
+    let home = tempfile::tempdir().unwrap();
+    let socket_path = home.path().join("ready.sock");
+
+    let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin("pomodoro"))
+        .arg("daemon")
+        .env("HOME", home.path())
+        .env("POMODORO_SOCKET", &socket_path)
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let stdout = child.stdout.take().unwrap();
+
+    let line = tokio::task::spawn_blocking(move || {
+        let mut line = String::new();
+        BufReader::new(stdout).read_line(&mut line).map(|_| line)
+    });
+    let line = timeout(Duration::from_secs(5), line).await;
+    // Connect right after the line, before the daemon is killed
+    let status = match &line {
+        Ok(_) => Some(
+            IpcClient::with_socket_path(socket_path.clone())
+                .status()
+                .await,
+        ),
+        Err(_) => None,
+    };
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    let line = line
+        .expect("daemon did not print a READY line")
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        line.trim_end(),
+        format!("READY socket={}", socket_path.display())
+    );
+    assert_eq!(status.unwrap().unwrap().status, "success");
+
+    let mut quiet = std::process::Command::new(assert_cmd::cargo::cargo_bin("pomodoro"))
+        .args(["daemon", "--quiet"])
+        .env("HOME", home.path())
+        .env("POMODORO_SOCKET", home.path().join("quiet.sock"))
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    quiet.kill().unwrap();
+    let output = quiet.wait_with_output().unwrap();
+    assert!(output.stdout.is_empty());
+}
@@ -7,16 +7,18 @@
 //! - TC-I-003: Status query via IPC
 //! - TC-I-004: Connection error handling
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{broadcast, Mutex};
 use tokio::time::{timeout, Duration};
 
 use pomodoro::cli::client::IpcClient;
 use pomodoro::cli::commands::StartArgs;
+use pomodoro::cli::wait::{wait_for_work, WaitOutcome};
+use pomodoro::daemon::channel::{event_channel, EventReceiver, DEFAULT_EVENT_CAPACITY};
 use pomodoro::daemon::ipc::{IpcServer, RequestHandler};
-use pomodoro::daemon::timer::{TimerEngine, TimerEvent};
+use pomodoro::daemon::timer::TimerEngine;
 use pomodoro::types::PomodoroConfig;
 
 // ============================================================================
@@ -33,13 +35,47 @@ fn create_temp_socket_path() -> PathBuf {
 }
 
 /// Creates a TimerEngine with event channel.
-fn create_engine() -> (Arc<Mutex<TimerEngine>>, mpsc::UnboundedReceiver<TimerEvent>) {
-    let (tx, rx) = mpsc::unbounded_channel();
+fn create_engine() -> (Arc<Mutex<TimerEngine>>, EventReceiver) {
+    let (tx, rx) = event_channel(DEFAULT_EVENT_CAPACITY);
     let config = PomodoroConfig::default();
     let engine = TimerEngine::new(config, tx);
     (Arc::new(Mutex::new(engine)), rx)
 }
 
+/// Serves every connection like the daemon does, with events forwarded to
+/// subscribers and one timer second passing per millisecond.
+///
+/// Returns the server, which must be kept alive for the test.
+fn spawn_fast_daemon(socket_path: &Path) -> Arc<IpcServer> {
+    let (engine, mut rx) = create_engine();
+    let (events_tx, _) = broadcast::channel(1024);
+    let handler = Arc::new(RequestHandler::new(Arc::clone(&engine)).with_events(events_tx.clone()));
+    let server = Arc::new(IpcServer::new(socket_path).unwrap());
+
+    let server_clone = server.clone();
+    tokio::spawn(async move {
+        while let Ok(stream) = server_clone.accept().await {
+            let handler = handler.clone();
+            tokio::spawn(async move {
+                let _ = IpcServer::serve_connection(stream, &handler).await;
+            });
+        }
+    });
+    tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            let _ = events_tx.send(event);
+        }
+    });
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            let _ = engine.lock().await.tick();
+        }
+    });
+
+    server
+}
+
 /// Runs a single request-response cycle on the server.
 async fn handle_single_request(server: &IpcServer, handler: &RequestHandler) {
     let mut stream = server.accept().await.unwrap();
@@ -96,13 +132,14 @@ async fn tc_i_001_timer_start_via_ipc() {
     // Act: CLI client sends start command
     let client = IpcClient::with_socket_path(socket_path);
     let args = StartArgs {
-        work: 25,
-        break_time: 5,
-        long_break: 15,
+        work: Some(25),
+        break_time: Some(5),
+        long_break: Some(15),
         task: Some("Integration Test Task".to_string()),
         auto_cycle: false,
         focus_mode: false,
         no_sound: false,
+        ..Default::default()
     };
 
     let response = client.start(&args).await;
@@ -145,13 +182,14 @@ async fn tc_i_001_timer_start_with_custom_settings() {
 
     let client = IpcClient::with_socket_path(socket_path);
     let args = StartArgs {
-        work: 45,       // Custom work time
-        break_time: 10, // Custom break time
-        long_break: 30, // Custom long break
+        work: Some(45),       // Custom work time
+        break_time: Some(10), // Custom break time
+        long_break: Some(30), // Custom long break
         task: Some("カスタム作業".to_string()),
         auto_cycle: true,
         focus_mode: false,
         no_sound: true,
+        ..Default::default()
     };
 
     let response = client.start(&args).await.unwrap();
@@ -504,13 +542,14 @@ async fn test_unicode_task_name() {
 
     let client = IpcClient::with_socket_path(socket_path);
     let args = StartArgs {
-        work: 25,
-        break_time: 5,
-        long_break: 15,
+        work: Some(25),
+        break_time: Some(5),
+        long_break: Some(15),
         task: Some("🍅 ポモドーロ作業 - API実装 (v2.0)".to_string()),
         auto_cycle: false,
         focus_mode: false,
         no_sound: false,
+        ..Default::default()
     };
 
     let response = client.start(&args).await.unwrap();
@@ -566,3 +605,59 @@ async fn test_concurrent_clients_sequential() {
 
     let _ = server_handle.await;
 }
+
+// ============================================================================
+// start --wait
+// ============================================================================
+
+/// `start --wait` returns once the work session completes.
+#[tokio::test]
+async fn test_start_wait_returns_on_completion() {
+    let socket_path = create_temp_socket_path();
+    let _server = spawn_fast_daemon(&socket_path);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let client = IpcClient::with_socket_path(socket_path);
+    let mut subscription = client.subscribe().await.unwrap();
+    let args = StartArgs {
+        work: Some(1),
+        wait: true,
+        ..Default::default()
+    };
+    client.start(&args).await.unwrap();
+
+    let mut out = Vec::new();
+    let outcome = timeout(
+        Duration::from_secs(5),
+        wait_for_work(&mut subscription, &mut out, std::future::pending::<()>()),
+    )
+    .await
+    .expect("wait should return once the session completes")
+    .unwrap();
+
+    assert_eq!(outcome, WaitOutcome::Completed);
+    assert!(String::from_utf8(out).unwrap().contains("\r🍅 00:01"));
+}
+
+/// `start --wait` reports a session stopped before it completes.
+#[tokio::test]
+async fn test_start_wait_reports_stop() {
+    let socket_path = create_temp_socket_path();
+    let _server = spawn_fast_daemon(&socket_path);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let client = IpcClient::with_socket_path(socket_path);
+    let mut subscription = client.subscribe().await.unwrap();
+    client.start(&StartArgs::default()).await.unwrap();
+    client.stop().await.unwrap();
+
+    let outcome = timeout(
+        Duration::from_secs(5),
+        wait_for_work(&mut subscription, Vec::new(), std::future::pending::<()>()),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(outcome, WaitOutcome::Stopped);
+}
@@ -19,8 +19,9 @@ use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, Mutex};
 
 use pomodoro::cli::client::IpcClient;
+use pomodoro::daemon::channel::{event_channel, EventReceiver, DEFAULT_EVENT_CAPACITY};
 use pomodoro::daemon::ipc::{IpcServer, RequestHandler};
-use pomodoro::daemon::timer::{TimerEngine, TimerEvent};
+use pomodoro::daemon::timer::TimerEngine;
 use pomodoro::sound::{MockSoundPlayer, SoundPlayer, SoundSource};
 use pomodoro::types::{IpcRequest, PomodoroConfig, StartParams};
 
@@ -42,15 +43,15 @@ fn create_temp_socket_path() -> PathBuf {
 /// Creates a default TimerEngine (without keeping the receiver).
 /// Note: This is suitable for tests that don't call start() on the engine.
 fn create_engine() -> Arc<Mutex<TimerEngine>> {
-    let (tx, _rx) = mpsc::unbounded_channel();
+    let (tx, _rx) = event_channel(DEFAULT_EVENT_CAPACITY);
     let config = PomodoroConfig::default();
     Arc::new(Mutex::new(TimerEngine::new(config, tx)))
 }
 
 /// Creates a default TimerEngine with its event receiver.
 /// Use this when calling start() or other methods that send events.
-fn create_engine_with_rx() -> (Arc<Mutex<TimerEngine>>, mpsc::UnboundedReceiver<TimerEvent>) {
-    let (tx, rx) = mpsc::unbounded_channel();
+fn create_engine_with_rx() -> (Arc<Mutex<TimerEngine>>, EventReceiver) {
+    let (tx, rx) = event_channel(DEFAULT_EVENT_CAPACITY);
     let config = PomodoroConfig::default();
     (Arc::new(Mutex::new(TimerEngine::new(config, tx))), rx)
 }
@@ -249,7 +250,9 @@ async fn tc_p_003_notification_send_latency() {
     let mock = MockNotificationSender::new();
 
     let start = Instant::now();
-    let result = mock.send_work_complete(Some("Performance Test")).await;
+    let result = mock
+        .send_work_complete(Some("Performance Test"), None)
+        .await;
     let duration = start.elapsed();
 
     assert!(result.is_ok(), "Notification should be sent successfully");
@@ -475,7 +478,7 @@ async fn tc_p_006_no_busy_wait() {
 /// Benchmark: Timer state transitions
 #[tokio::test]
 async fn benchmark_timer_state_transitions() {
-    let (tx, _rx) = mpsc::unbounded_channel();
+    let (tx, _rx) = event_channel(DEFAULT_EVENT_CAPACITY);
     let mut engine = TimerEngine::new(PomodoroConfig::default(), tx);
 
     let iterations = 1000;
@@ -509,7 +512,7 @@ async fn benchmark_timer_state_transitions() {
 /// Benchmark: Timer ticks
 #[tokio::test]
 async fn benchmark_timer_ticks() {
-    let (tx, _rx) = mpsc::unbounded_channel();
+    let (tx, _rx) = event_channel(DEFAULT_EVENT_CAPACITY);
     let mut engine = TimerEngine::new(PomodoroConfig::default(), tx);
     engine.start(None).unwrap();
 
@@ -552,6 +555,7 @@ fn benchmark_ipc_serialization() {
                 task_name: Some("Benchmark Task".to_string()),
                 auto_cycle: Some(false),
                 focus_mode: Some(false),
+                ..Default::default()
             },
         };
         let _json = serde_json::to_string(&request).unwrap();
@@ -576,7 +580,7 @@ fn benchmark_ipc_serialization() {
 /// Benchmark: IPC response deserialization
 #[test]
 fn benchmark_ipc_deserialization() {
-    use pomodoro::types::{IpcResponse, ResponseData};
+    use pomodoro::types::{IpcResponse, ResponseData, PROTOCOL_VERSION};
 
     let response = IpcResponse {
         status: "success".to_string(),
@@ -586,7 +590,10 @@ fn benchmark_ipc_deserialization() {
             remaining_seconds: Some(1500),
             pomodoro_count: Some(0),
             task_name: Some("Benchmark Task".to_string()),
+            ..Default::default()
         }),
+        protocol_version: PROTOCOL_VERSION,
+        error_code: None,
     };
     let json = serde_json::to_string(&response).unwrap();
 